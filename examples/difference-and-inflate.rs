@@ -11,8 +11,8 @@ async fn main() -> Result<(), ClipperError> {
     let rectangle = vec![(0.0, 0.0), (5.0, 0.0), (5.0, 6.0), (0.0, 6.0)];
 
     // Functional API
-    let _result = difference(circle.clone(), circle2.clone(), FillRule::default())?;
-    let _result = difference(_result, rectangle.clone(), FillRule::default())?;
+    let _result = difference(circle.clone(), circle2.clone(), FillRule::default())?.closed;
+    let _result = difference(_result, rectangle.clone(), FillRule::default())?.closed;
 
     let _result2 = inflate(_result, 1.0, JoinType::Round, EndType::Polygon, 0.0);
     let _result2 = simplify(_result2, 0.01, false);
@@ -22,7 +22,8 @@ async fn main() -> Result<(), ClipperError> {
         .to_clipper_subject()
         .add_clip(circle2)
         .add_clip(rectangle)
-        .difference(FillRule::default())?;
+        .difference(FillRule::default())?
+        .closed;
 
     let result2 = result
         .inflate(1.0, JoinType::Round, EndType::Polygon, 0.0)
@@ -0,0 +1,24 @@
+use clipper2::debug::viewer::{Viewer, ViewerLayer};
+use clipper2::*;
+
+fn main() -> Result<(), ClipperError> {
+    let subject: Paths = vec![(0.2, 0.2), (6.0, 0.2), (6.0, 6.0), (0.2, 6.0)].into();
+    let clip: Paths = vec![(5.0, 5.0), (8.0, 5.0), (8.0, 8.0), (5.0, 8.0)].into();
+
+    let result = subject
+        .to_clipper_subject()
+        .add_clip(clip.clone())
+        .xor(FillRule::default())?
+        .closed;
+
+    let inflated = result.inflate(0.5, JoinType::Round, EndType::Polygon, 0.0);
+
+    Viewer::new("Viewer")
+        .add_layer(ViewerLayer::new("subject", subject).visible(false))
+        .add_layer(ViewerLayer::new("clip", clip).visible(false))
+        .add_layer(ViewerLayer::new("xor", result).show_vertices(true))
+        .add_layer(ViewerLayer::new("inflated", inflated).open(true))
+        .show();
+
+    Ok(())
+}
@@ -16,7 +16,8 @@ async fn main() -> Result<(), ClipperError> {
     let result = path_a
         .to_clipper_subject()
         .add_clip(path_b.clone())
-        .xor(FillRule::default())?;
+        .xor(FillRule::default())?
+        .closed;
 
     loop {
         clear_background(BLACK);
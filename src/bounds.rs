@@ -42,4 +42,29 @@ impl<P: PointScaler> Bounds<P> {
         let size = self.size();
         Point::new(self.min.x() + size.x() / 2.0, self.min.y() + size.y() / 2.0)
     }
+
+    /// Return the smallest bounds covering both bounds.
+    #[must_use]
+    pub fn union(&self, other: &Bounds<P>) -> Self {
+        Self {
+            min: Point::new(
+                self.min.x().min(other.min.x()),
+                self.min.y().min(other.min.y()),
+            ),
+            max: Point::new(
+                self.max.x().max(other.max.x()),
+                self.max.y().max(other.max.y()),
+            ),
+        }
+    }
+
+    /// Return `true` if the bounds share at least one point, including bounds
+    /// that only touch at an edge or corner.
+    #[must_use]
+    pub fn overlaps(&self, other: &Bounds<P>) -> bool {
+        self.min.x() <= other.max.x()
+            && other.min.x() <= self.max.x()
+            && self.min.y() <= other.max.y()
+            && other.min.y() <= self.max.y()
+    }
 }
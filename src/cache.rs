@@ -0,0 +1,371 @@
+use std::collections::HashMap;
+
+use crate::{
+    BooleanResult, Centi, ClipType, Clipper, ClipperError, FillRule, Path, Paths, PointScaler,
+};
+
+/// A boolean operation executor that remembers its results, so repeating an
+/// identical operation returns the stored result instead of running the
+/// native engine again.
+///
+/// Interactive editors tend to re-execute the same operations while only
+/// some of the geometry changes. Operations are identified by the clip
+/// type, the fill rule and the subject and clip paths, where the order of
+/// the paths and the start point of each closed path don't matter, so
+/// inputs describing the same polygons share one result. The result of the
+/// first execution is returned for all of them.
+///
+/// The cache holds up to `capacity` results, dropping the least recently
+/// used one when full. Each entry keeps a copy of its input paths to tell
+/// operations apart reliably, so the memory used grows with the size of the
+/// inputs as well as of the results.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let mut cache = CachedClipper::<Centi>::new(16);
+/// let board: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+/// let cutout: Paths = Path::rectangle(4.0, 4.0, 2.0, 2.0).into();
+///
+/// for _ in 0..3 {
+///     let result = cache.difference(&board, &cutout, FillRule::NonZero).unwrap();
+///     assert_eq!(result.closed.signed_area(), 96.0);
+/// }
+///
+/// assert_eq!((cache.hits(), cache.misses()), (2, 1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CachedClipper<P: PointScaler = Centi> {
+    capacity: usize,
+    entries: HashMap<CacheKey, CacheEntry<P>>,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+/// The inputs of an operation, with each path in a canonical form and the
+/// paths in a canonical order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    clip_type: ClipType,
+    fill_rule: FillRule,
+    subjects: Vec<KeyPath>,
+    clips: Vec<KeyPath>,
+}
+
+/// A path of a [`CacheKey`]. Closed paths are canonicalized and keep their
+/// orientation in a separate flag, as it changes the result with most fill
+/// rules. Open paths are kept as they are.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct KeyPath {
+    points: Vec<(i64, i64)>,
+    reversed: bool,
+    open: bool,
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry<P: PointScaler> {
+    result: BooleanResult<P>,
+    last_used: u64,
+}
+
+impl<P: PointScaler> CachedClipper<P> {
+    /// Create an empty cache holding up to `capacity` results. A capacity of
+    /// zero stores nothing and executes every operation.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            clock: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Apply a boolean operation to the subjects and clips, or return the
+    /// stored result of an identical earlier operation.
+    ///
+    /// Open subject paths, see [`Paths::push_open`], are added as open
+    /// subjects. Failed operations are not stored.
+    pub fn boolean(
+        &mut self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+        subjects: &Paths<P>,
+        clips: &Paths<P>,
+    ) -> Result<BooleanResult<P>, ClipperError> {
+        let key = CacheKey {
+            clip_type,
+            fill_rule,
+            subjects: key_paths(subjects),
+            clips: key_paths(clips),
+        };
+
+        self.clock += 1;
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.last_used = self.clock;
+            self.hits += 1;
+            return Ok(entry.result.clone());
+        }
+
+        self.misses += 1;
+        let result = Clipper::new()
+            .add_subject_ref(subjects)
+            .add_clip_ref(clips)
+            .boolean_operation(clip_type, fill_rule)?;
+
+        if self.capacity > 0 {
+            if self.entries.len() >= self.capacity {
+                self.evict();
+            }
+            self.entries.insert(
+                key,
+                CacheEntry {
+                    result: result.clone(),
+                    last_used: self.clock,
+                },
+            );
+        }
+
+        Ok(result)
+    }
+
+    /// Join the subject and clip paths, see [`Clipper::union`].
+    pub fn union(
+        &mut self,
+        subject: &Paths<P>,
+        clip: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<BooleanResult<P>, ClipperError> {
+        self.boolean(ClipType::Union, fill_rule, subject, clip)
+    }
+
+    /// Subtract the clip paths from the subject, see [`Clipper::difference`].
+    pub fn difference(
+        &mut self,
+        subject: &Paths<P>,
+        clip: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<BooleanResult<P>, ClipperError> {
+        self.boolean(ClipType::Difference, fill_rule, subject, clip)
+    }
+
+    /// Intersect the subject and clip paths, see [`Clipper::intersect`].
+    pub fn intersect(
+        &mut self,
+        subject: &Paths<P>,
+        clip: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<BooleanResult<P>, ClipperError> {
+        self.boolean(ClipType::Intersection, fill_rule, subject, clip)
+    }
+
+    /// Keep the areas covered by either the subject or clip paths but not
+    /// both, see [`Clipper::xor`].
+    pub fn xor(
+        &mut self,
+        subject: &Paths<P>,
+        clip: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<BooleanResult<P>, ClipperError> {
+        self.boolean(ClipType::Xor, fill_rule, subject, clip)
+    }
+
+    /// Returns the maximum number of stored results.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of stored results.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no results are stored.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the number of operations answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of operations that were executed.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Drop all stored results, keeping the hit and miss counts.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Drop the least recently used result.
+    fn evict(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(key, _)| key.clone());
+
+        if let Some(key) = oldest {
+            self.entries.remove(&key);
+        }
+    }
+}
+
+impl<P: PointScaler> Default for CachedClipper<P> {
+    /// A cache holding up to 64 results.
+    fn default() -> Self {
+        Self::new(64)
+    }
+}
+
+fn key_paths<P: PointScaler>(paths: &Paths<P>) -> Vec<KeyPath> {
+    let mut keys = paths
+        .iter()
+        .enumerate()
+        .map(|(index, path)| {
+            let open = paths.is_open(index);
+            let (path, reversed) = if open {
+                (path.clone(), false)
+            } else {
+                (path.canonicalize(), crate::validate::doubled_area(path) < 0)
+            };
+
+            KeyPath {
+                points: points(&path),
+                reversed,
+                open,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    keys.sort_unstable();
+    keys
+}
+
+fn points<P: PointScaler>(path: &Path<P>) -> Vec<(i64, i64)> {
+    path.iter()
+        .map(|point| (point.x_scaled(), point.y_scaled()))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn square(x: f64) -> Paths<Centi> {
+        Path::rectangle(x, 0.0, 2.0, 2.0).into()
+    }
+
+    #[test]
+    fn test_hits_for_equivalent_inputs() {
+        let mut cache = CachedClipper::<Centi>::new(8);
+        let subject: Paths<Centi> = vec![
+            Path::rectangle(0.0, 0.0, 2.0, 2.0),
+            Path::rectangle(5.0, 0.0, 2.0, 2.0),
+        ]
+        .into();
+        let clip = square(1.0);
+
+        let first = cache.union(&subject, &clip, FillRule::NonZero).unwrap();
+
+        // The same polygons in another order and with other start points.
+        let shuffled: Paths<Centi> = vec![
+            vec![(7.0, 2.0), (5.0, 2.0), (5.0, 0.0), (7.0, 0.0)],
+            vec![(2.0, 0.0), (2.0, 2.0), (0.0, 2.0), (0.0, 0.0)],
+        ]
+        .into();
+        let second = cache.union(&shuffled, &clip, FillRule::NonZero).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!((cache.hits(), cache.misses(), cache.len()), (1, 1, 1));
+
+        cache.intersect(&subject, &clip, FillRule::NonZero).unwrap();
+        cache.union(&subject, &clip, FillRule::EvenOdd).unwrap();
+        assert_eq!((cache.hits(), cache.misses(), cache.len()), (1, 3, 3));
+    }
+
+    #[test]
+    fn test_orientation_and_open_paths_are_part_of_the_key() {
+        let mut cache = CachedClipper::<Centi>::new(8);
+        let outer = Path::<Centi>::rectangle(0.0, 0.0, 10.0, 10.0);
+        let inner = Path::<Centi>::rectangle(2.0, 2.0, 2.0, 2.0);
+        let hole = inner.iter().rev().copied().collect::<Path<Centi>>();
+
+        let same = Paths::new(vec![outer.clone(), inner.clone()]);
+        let with_hole = Paths::new(vec![outer, hole]);
+
+        let a = cache
+            .union(&same, &Paths::default(), FillRule::NonZero)
+            .unwrap();
+        let b = cache
+            .union(&with_hole, &Paths::default(), FillRule::NonZero)
+            .unwrap();
+        assert_eq!(a.closed.signed_area(), 100.0);
+        assert_eq!(b.closed.signed_area(), 96.0);
+        assert_eq!(cache.misses(), 2);
+
+        let mut line = Paths::<Centi>::default();
+        line.push_open(vec![(0.0, 1.0), (5.0, 1.0)]);
+        let mut closed = Paths::<Centi>::default();
+        closed.push_closed(vec![(0.0, 1.0), (5.0, 1.0)]);
+
+        let open = cache
+            .intersect(&line, &square(1.0), FillRule::NonZero)
+            .unwrap();
+        let closed = cache
+            .intersect(&closed, &square(1.0), FillRule::NonZero)
+            .unwrap();
+        assert_eq!(open.open.len(), 1);
+        assert!(closed.open.is_empty());
+        assert_eq!(cache.misses(), 4);
+    }
+
+    #[test]
+    fn test_least_recently_used_is_evicted() {
+        let mut cache = CachedClipper::<Centi>::new(2);
+        let subject = square(0.0);
+
+        cache
+            .union(&subject, &square(1.0), FillRule::NonZero)
+            .unwrap();
+        cache
+            .union(&subject, &square(2.0), FillRule::NonZero)
+            .unwrap();
+        cache
+            .union(&subject, &square(1.0), FillRule::NonZero)
+            .unwrap();
+        cache
+            .union(&subject, &square(3.0), FillRule::NonZero)
+            .unwrap();
+        assert_eq!(cache.len(), 2);
+
+        // The result for the second clip was evicted, the first was kept.
+        cache
+            .union(&subject, &square(1.0), FillRule::NonZero)
+            .unwrap();
+        assert_eq!(cache.hits(), 2);
+        cache
+            .union(&subject, &square(2.0), FillRule::NonZero)
+            .unwrap();
+        assert_eq!(cache.misses(), 4);
+
+        let mut disabled = CachedClipper::<Centi>::new(0);
+        disabled
+            .union(&subject, &square(1.0), FillRule::NonZero)
+            .unwrap();
+        disabled
+            .union(&subject, &square(1.0), FillRule::NonZero)
+            .unwrap();
+        assert!(disabled.is_empty());
+        assert_eq!(disabled.misses(), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,339 @@
+//! Toolpath generation for 2D machining.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use clipper2::*;
+//! use clipper2::cam::{pocket, PocketOptions, PocketStrategy};
+//!
+//! let pocket_shape: Paths = vec![
+//!     Path::rectangle(0.0, 0.0, 20.0, 10.0),
+//!     Path::rectangle(8.0, 3.0, 4.0, 4.0).iter().rev().copied().collect(),
+//! ]
+//! .into();
+//!
+//! let rings = pocket(&pocket_shape, &PocketOptions::new(1.0, 1.5)).unwrap();
+//! assert!(!rings.has_open_paths());
+//!
+//! let zigzag = pocket(
+//!     &pocket_shape,
+//!     &PocketOptions::new(1.0, 1.5).strategy(PocketStrategy::Zigzag { angle: 0.0 }),
+//! )
+//! .unwrap();
+//! assert!(zigzag.has_open_paths());
+//! ```
+
+use crate::{
+    hatch_lines, Clipper, ClipperError, EndType, FillRule, JoinType, Path, Paths, PointScaler,
+};
+
+/// How the area of a pocket is cleared, see [`pocket`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum PocketStrategy {
+    /// Rings following the boundary of the pocket, each one stepover further
+    /// inside than the previous one.
+    #[default]
+    ContourParallel,
+    /// Parallel rows at `angle` radians from the x axis, traversed in
+    /// alternating directions, followed by a finishing pass along the
+    /// boundary.
+    Zigzag {
+        /// Angle of the rows in radians
+        angle: f64,
+    },
+}
+
+/// Options for [`pocket`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PocketOptions {
+    /// Radius of the tool.
+    pub tool_radius: f64,
+    /// Distance between neighbouring passes of the tool center, usually a
+    /// fraction of the tool diameter.
+    pub stepover: f64,
+    /// How the area is cleared, defaults to
+    /// [`PocketStrategy::ContourParallel`].
+    pub strategy: PocketStrategy,
+    /// Fill rule deciding which areas of the input paths are inside the
+    /// pocket, defaults to [`FillRule::NonZero`].
+    pub fill_rule: FillRule,
+}
+
+impl PocketOptions {
+    /// Create options for a tool radius and stepover with the default
+    /// strategy and fill rule.
+    pub fn new(tool_radius: f64, stepover: f64) -> Self {
+        Self {
+            tool_radius,
+            stepover,
+            strategy: PocketStrategy::default(),
+            fill_rule: FillRule::NonZero,
+        }
+    }
+
+    /// Set the strategy used to clear the area.
+    pub fn strategy(mut self, strategy: PocketStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Set the fill rule of the input paths.
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+}
+
+/// Generate the paths of the tool center that clear the area of a pocket,
+/// given as closed paths with holes for the islands to leave standing.
+///
+/// The pocket is first shrunk by the tool radius to the boundary that the
+/// tool center may reach. With [`PocketStrategy::ContourParallel`] the result
+/// holds closed rings from that boundary inward. With
+/// [`PocketStrategy::Zigzag`] it holds open zigzag paths, where neighbouring
+/// rows are only joined when the connecting move stays inside the boundary,
+/// followed by the boundary itself as closed rings for a finishing pass.
+///
+/// The rows of the zigzag strategy are spread evenly over the boundary, so
+/// the distance between them may be slightly smaller than the stepover.
+/// Returns no paths if the tool doesn't fit into the pocket or if the
+/// stepover is not a positive number, and
+/// [`ClipperError::TooManyHatchLines`] if the zigzag strategy would need
+/// more than [`MAX_HATCH_LINES`](crate::MAX_HATCH_LINES) rows.
+pub fn pocket<P: PointScaler>(
+    paths: &Paths<P>,
+    options: &PocketOptions,
+) -> Result<Paths<P>, ClipperError> {
+    if options.stepover.is_nan() || options.stepover <= 0.0 {
+        return Ok(Paths::default());
+    }
+
+    let boundary = paths.make_valid(options.fill_rule)?.inflate(
+        -options.tool_radius,
+        JoinType::Round,
+        EndType::Polygon,
+        0.0,
+    );
+
+    match options.strategy {
+        PocketStrategy::ContourParallel => Ok(contour_parallel(boundary, options.stepover)),
+        PocketStrategy::Zigzag { angle } => zigzag(boundary, options.stepover, angle),
+    }
+}
+
+fn contour_parallel<P: PointScaler>(boundary: Paths<P>, stepover: f64) -> Paths<P> {
+    let mut rings = Paths::default();
+    let mut current = boundary;
+
+    while !current.is_empty() {
+        let next = current.inflate(-stepover, JoinType::Round, EndType::Polygon, 0.0);
+        rings.push(current);
+        current = next;
+    }
+
+    rings
+}
+
+fn zigzag<P: PointScaler>(
+    boundary: Paths<P>,
+    stepover: f64,
+    angle: f64,
+) -> Result<Paths<P>, ClipperError> {
+    if boundary.is_empty() {
+        return Ok(boundary);
+    }
+
+    let (direction_x, direction_y) = (angle.cos(), angle.sin());
+    let (normal_x, normal_y) = (-direction_y, direction_x);
+    let across = |x: f64, y: f64| x * normal_x + y * normal_y;
+    let along = |x: f64, y: f64| x * direction_x + y * direction_y;
+
+    let (across_min, across_max) = boundary
+        .iter()
+        .flatten()
+        .map(|point| across(point.x(), point.y()))
+        .fold((f64::MAX, f64::MIN), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+    let rows = ((across_max - across_min) / stepover).ceil().max(1.0);
+    let spacing = (across_max - across_min) / rows;
+
+    if spacing <= 0.0 {
+        return Ok(boundary);
+    }
+
+    let phase = (across_min / spacing + 0.5).rem_euclid(1.0);
+    let lines = hatch_lines(&boundary, spacing, angle, phase, FillRule::NonZero)?;
+
+    let mut segments = lines
+        .iter()
+        .filter(|line| line.len() >= 2)
+        .map(|line| {
+            let (a, b) = (line[0], line[line.len() - 1]);
+            let middle = ((a.x() + b.x()) / 2.0, (a.y() + b.y()) / 2.0);
+            let row = (across(middle.0, middle.1) / spacing - phase).round() as i64;
+            let position = along(middle.0, middle.1);
+            let forward = along(b.x(), b.y()) >= along(a.x(), a.y());
+            let line = if forward == (row % 2 == 0) {
+                line.clone()
+            } else {
+                line.iter().rev().copied().collect()
+            };
+            (row, position, line)
+        })
+        .collect::<Vec<_>>();
+
+    segments.sort_by(|a, b| {
+        let direction = if a.0 % 2 == 0 { 1.0 } else { -1.0 };
+        a.0.cmp(&b.0)
+            .then((a.1 * direction).total_cmp(&(b.1 * direction)))
+    });
+
+    let mut result = Paths::default();
+    let mut current: Option<Path<P>> = None;
+
+    for (_, _, segment) in segments {
+        current = Some(match current {
+            Some(mut path) if stays_inside(&boundary, &path, &segment, spacing)? => {
+                path.append(segment);
+                path
+            }
+            Some(path) => {
+                result.push_open(path);
+                segment
+            }
+            None => segment,
+        });
+    }
+
+    if let Some(path) = current {
+        result.push_open(path);
+    }
+
+    result.push(boundary);
+    Ok(result)
+}
+
+/// Returns `true` if the move from the end of the path to the start of the
+/// segment stays inside the boundary, allowing for rounding along the edges.
+fn stays_inside<P: PointScaler>(
+    boundary: &Paths<P>,
+    path: &Path<P>,
+    segment: &Path<P>,
+    spacing: f64,
+) -> Result<bool, ClipperError> {
+    let (Some(from), Some(to)) = (path.iter().last(), segment.iter().next()) else {
+        return Ok(false);
+    };
+
+    if from.distance_to(to) > spacing * 2.0 {
+        return Ok(false);
+    }
+
+    let grown = boundary.inflate(spacing * 0.01, JoinType::Miter, EndType::Polygon, 2.0);
+    let outside = Clipper::new()
+        .add_open_subject(vec![*from, *to])
+        .add_clip(grown)
+        .difference(FillRule::NonZero)?
+        .open;
+
+    Ok(outside.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Centi;
+
+    use super::*;
+
+    fn square_with_island() -> Paths<Centi> {
+        vec![
+            Path::rectangle(0.0, 0.0, 20.0, 10.0),
+            Path::rectangle(8.0, 3.0, 4.0, 4.0)
+                .iter()
+                .rev()
+                .copied()
+                .collect(),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn test_contour_parallel() {
+        let square = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 10.0, 10.0));
+
+        let rings = pocket(&square, &PocketOptions::new(0.5, 1.0)).unwrap();
+
+        assert_eq!(rings.len(), 5);
+        assert_eq!(rings[0].signed_area(), 81.0);
+        assert!(!rings.has_open_paths());
+    }
+
+    #[test]
+    fn test_contour_parallel_keeps_clear_of_island() {
+        let shape = square_with_island();
+        let keep_out = Paths::<Centi>::from(Path::rectangle(8.0, 3.0, 4.0, 4.0)).inflate(
+            0.99,
+            JoinType::Round,
+            EndType::Polygon,
+            0.0,
+        );
+
+        let rings = pocket(&shape, &PocketOptions::new(1.0, 1.5)).unwrap();
+
+        assert!(!rings.is_empty());
+        for point in rings.iter().flatten() {
+            assert_eq!(
+                keep_out[0].is_point_inside(*point),
+                crate::PointInPolygonResult::IsOutside,
+                "{point} too close to the island"
+            );
+        }
+    }
+
+    #[test]
+    fn test_zigzag() {
+        let square = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 10.0, 10.0));
+
+        let paths = pocket(
+            &square,
+            &PocketOptions::new(0.5, 1.0).strategy(PocketStrategy::Zigzag { angle: 0.0 }),
+        )
+        .unwrap();
+
+        // A single zigzag covering all rows, then the finishing pass.
+        assert_eq!(paths.len(), 2);
+        assert!(paths.is_open(0));
+        assert!(!paths.is_open(1));
+        assert_eq!(paths[0].len(), 18);
+        assert_eq!(paths[1].signed_area(), 81.0);
+        assert!(paths[0]
+            .iter()
+            .all(|point| (0.5..=9.5).contains(&point.x()) && (0.5..=9.5).contains(&point.y())));
+    }
+
+    #[test]
+    fn test_zigzag_splits_around_island() {
+        let paths = pocket(
+            &square_with_island(),
+            &PocketOptions::new(1.0, 1.5).strategy(PocketStrategy::Zigzag { angle: 0.0 }),
+        )
+        .unwrap();
+
+        let (boundary, zigzags) = paths.partition_open();
+        assert_eq!(boundary.len(), 2);
+        assert!(zigzags.len() > 1);
+    }
+
+    #[test]
+    fn test_tool_too_large() {
+        let square = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 10.0, 10.0));
+
+        assert!(pocket(&square, &PocketOptions::new(6.0, 1.0))
+            .unwrap()
+            .is_empty());
+        assert!(pocket(&square, &PocketOptions::new(1.0, 0.0))
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -1,25 +1,30 @@
-use std::marker::PhantomData;
+use std::{cell::Cell, marker::PhantomData};
 
 use clipper2c_sys::{
     clipper_clipper64, clipper_clipper64_add_clip, clipper_clipper64_add_open_subject,
     clipper_clipper64_add_subject, clipper_clipper64_execute,
-    clipper_clipper64_execute_tree_with_open, clipper_clipper64_size,
-    clipper_delete_clipper64, clipper_delete_path64, clipper_delete_paths64,
-    clipper_delete_polytree64, clipper_path64_size, clipper_paths64, clipper_paths64_size,
-    clipper_polytree64, clipper_polytree64_area, clipper_polytree64_count,
-    clipper_polytree64_get_child, clipper_polytree64_is_hole, clipper_polytree64_parent,
-    clipper_polytree64_polygon, clipper_polytree64_size, clipper_polytree64_to_paths,
-    ClipperClipper64, ClipperPolyTree64,
+    clipper_clipper64_execute_tree_with_open, clipper_clipper64_size, clipper_path64_size,
+    clipper_pathd_size, clipper_paths64, clipper_paths64_area, clipper_paths64_size,
+    clipper_polytree64, clipper_polytree64_count, clipper_polytree64_get_child,
+    clipper_polytree64_is_hole, clipper_polytree64_polygon, clipper_polytree64_size,
+    clipper_polytreed_count, clipper_polytreed_get_child, clipper_polytreed_is_hole,
+    clipper_polytreed_polygon, ClipperClipper64, ClipperPaths64, ClipperPolyTree64,
+    ClipperPolyTreeD,
 };
 
-use crate::{malloc, Centi, ClipType, FillRule, Path, Paths, PointScaler};
+use crate::{
+    malloc,
+    memory::{delete_clipper64, delete_path64, delete_pathd, delete_paths64, delete_polytree64},
+    BooleanIterResult, Bounds, Centi, ClipType, FillRule, NativeBooleanTreeResult, NativePathsIter,
+    NativePolyTree, Path, Paths, PointScaler,
+};
 
 /// The result of a boolean operation containing both closed and open paths.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct BooleanResult<P: PointScaler = Centi> {
     /// Closed paths from the boolean operation
     pub closed: Paths<P>,
-    /// Open paths from the boolean operation
+    /// Open paths from the boolean operation, marked as open
     pub open: Paths<P>,
 }
 
@@ -28,14 +33,75 @@ impl<P: PointScaler> BooleanResult<P> {
     pub fn new(closed: Paths<P>, open: Paths<P>) -> Self {
         Self { closed, open }
     }
+
+    /// Remove the closed paths with an absolute area below the threshold,
+    /// keeping the open paths, see [`Paths::retain_by_area`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let a: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+    /// let b: Paths = Path::rectangle(0.01, 0.0, 20.0, 10.0).into();
+    ///
+    /// let result = a.to_clipper_subject().add_clip(b).difference(FillRule::default()).unwrap();
+    /// assert_eq!(result.closed.len(), 1);
+    ///
+    /// let result = result.filter_min_area(0.5);
+    /// assert!(result.closed.is_empty());
+    /// ```
+    pub fn filter_min_area(mut self, threshold: f64) -> Self {
+        self.closed.retain_by_area(threshold);
+        self
+    }
+
+    /// Returns the total number of points of the closed and open paths.
+    pub fn vertex_count(&self) -> usize {
+        self.closed.vertex_count() + self.open.vertex_count()
+    }
+
+    /// Returns the result with the closed and open paths each in a
+    /// deterministic order, see [`Paths::canonical_order`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let squares: Paths = (0..4)
+    ///     .map(|i| Path::rectangle(f64::from(3 - i) * 2.0, 0.0, 1.0, 1.0))
+    ///     .collect();
+    ///
+    /// let result = squares
+    ///     .to_clipper_subject()
+    ///     .add_clip(Paths::default())
+    ///     .union(FillRule::NonZero)
+    ///     .unwrap();
+    /// let sorted = result.sorted();
+    ///
+    /// let lefts = sorted.closed.iter().map(|path| path.bounds().min.x()).collect::<Vec<_>>();
+    /// assert_eq!(lefts, vec![0.0, 2.0, 4.0, 6.0]);
+    /// ```
+    pub fn sorted(mut self) -> Self {
+        self.closed = self.closed.canonical_order();
+        self.open = self.open.canonical_order();
+        self
+    }
+
+    /// Returns an estimate of the heap memory held by the closed and open
+    /// paths in bytes, see [`Paths::memory_estimate`].
+    pub fn memory_estimate(&self) -> usize {
+        self.closed.memory_estimate() + self.open.memory_estimate()
+    }
 }
 
 /// The result of a boolean operation containing a PolyTree with hierarchy and open paths.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct BooleanTreeResult<P: PointScaler = Centi> {
     /// PolyTree containing the closed paths with hierarchy information
     pub tree: PolyTree<P>,
-    /// Open paths from the boolean operation
+    /// Open paths from the boolean operation, marked as open
     pub open: Paths<P>,
 }
 
@@ -44,6 +110,17 @@ impl<P: PointScaler> BooleanTreeResult<P> {
     pub fn new(tree: PolyTree<P>, open: Paths<P>) -> Self {
         Self { tree, open }
     }
+
+    /// Returns the total number of points of the tree and the open paths.
+    pub fn vertex_count(&self) -> usize {
+        self.tree.vertex_count() + self.open.vertex_count()
+    }
+
+    /// Returns an estimate of the heap memory held by the tree and the open
+    /// paths in bytes, see [`Paths::memory_estimate`].
+    pub fn memory_estimate(&self) -> usize {
+        self.tree.memory_estimate() + self.open.memory_estimate()
+    }
 }
 
 /// The state of the Clipper struct.
@@ -64,15 +141,127 @@ impl ClipperState for WithSubjects {}
 pub struct WithClips {}
 impl ClipperState for WithClips {}
 
-/// The Clipper struct used as a builder for applying boolean operations to paths.
+/// A state indicating subjects and clips that stay loaded in the native
+/// engine across executions, see [`Clipper::execute_keeping_inputs`].
 #[derive(Debug)]
+pub struct KeepingInputs {}
+impl ClipperState for KeepingInputs {}
+
+/// The Clipper struct used as a builder for applying boolean operations to paths.
+///
+/// The [`Debug`] output reports the state and the paths added so far, see
+/// [`Clipper::summary`].
+///
+/// A clipper is [`Send`], so a configured builder can be moved to a worker
+/// thread and executed there. It isn't [`Sync`], as executing mutates the
+/// native engine.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let clipper = Clipper::<NoSubjects, Centi>::new()
+///     .add_subject(Path::rectangle(0.0, 0.0, 2.0, 2.0))
+///     .add_clip(Path::rectangle(1.0, 0.0, 2.0, 2.0));
+///
+/// let result = std::thread::spawn(move || clipper.union(FillRule::NonZero))
+///     .join()
+///     .unwrap()
+///     .unwrap();
+///
+/// assert_eq!(result.closed.signed_area(), 6.0);
+/// ```
 pub struct Clipper<S: ClipperState = NoSubjects, P: PointScaler = Centi> {
     ptr: *mut ClipperClipper64,
     keep_ptr_on_drop: bool,
+    inputs: ClipperSummary,
+    bounds: InputBounds<P>,
+    #[cfg(feature = "stats")]
+    input_time: std::time::Duration,
     _marker: PhantomData<P>,
     _state: S,
 }
 
+/// Bounds of the subjects and clips added to a [`Clipper`], tracked while
+/// adding them. Bounds without any points are [`Bounds::minmax`].
+#[derive(Clone, Copy)]
+struct InputBounds<P: PointScaler> {
+    subject: Bounds<P>,
+    clip: Bounds<P>,
+}
+
+impl<P: PointScaler> Default for InputBounds<P> {
+    fn default() -> Self {
+        Self {
+            subject: Bounds::minmax(),
+            clip: Bounds::minmax(),
+        }
+    }
+}
+
+/// Returns `None` for bounds without any points.
+fn non_empty<P: PointScaler>(bounds: Bounds<P>) -> Option<Bounds<P>> {
+    (bounds.min.x() <= bounds.max.x() && bounds.min.y() <= bounds.max.y()).then_some(bounds)
+}
+
+// The native engine owns copies of all paths added to it and has no global or
+// thread local state, so it can be used from any thread, as long as only one
+// thread at a time does. The engine is mutated through `&self` when executing
+// with `KeepingInputs`, so the clipper must not be `Sync`.
+unsafe impl<S: ClipperState + Send, P: PointScaler> Send for Clipper<S, P> {}
+
+/// Number of paths and vertices added to a [`Clipper`], see
+/// [`Clipper::summary`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ClipperSummary {
+    /// Number of closed subject paths
+    pub subject_paths: usize,
+    /// Number of vertices of the closed subject paths
+    pub subject_vertices: usize,
+    /// Number of open subject paths
+    pub open_subject_paths: usize,
+    /// Number of vertices of the open subject paths
+    pub open_subject_vertices: usize,
+    /// Number of clip paths
+    pub clip_paths: usize,
+    /// Number of vertices of the clip paths
+    pub clip_vertices: usize,
+}
+
+impl ClipperSummary {
+    fn add_subjects<P: PointScaler>(&mut self, paths: &Paths<P>) {
+        self.subject_paths += paths.len();
+        self.subject_vertices += paths.vertex_count();
+    }
+
+    fn add_open_subjects<P: PointScaler>(&mut self, paths: &Paths<P>) {
+        self.open_subject_paths += paths.len();
+        self.open_subject_vertices += paths.vertex_count();
+    }
+
+    fn add_clips<P: PointScaler>(&mut self, paths: &Paths<P>) {
+        self.clip_paths += paths.len();
+        self.clip_vertices += paths.vertex_count();
+    }
+}
+
+impl std::fmt::Display for ClipperSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} subject paths ({} vertices), {} open subject paths ({} vertices), \
+             {} clip paths ({} vertices)",
+            self.subject_paths,
+            self.subject_vertices,
+            self.open_subject_paths,
+            self.open_subject_vertices,
+            self.clip_paths,
+            self.clip_vertices
+        )
+    }
+}
+
 impl<P: PointScaler> Clipper<NoSubjects, P> {
     /// Creates a new empty Clipper instance.
     pub fn new() -> Clipper<NoSubjects, P> {
@@ -84,6 +273,10 @@ impl<P: PointScaler> Clipper<NoSubjects, P> {
         Clipper::<NoSubjects, P> {
             ptr,
             keep_ptr_on_drop: false,
+            inputs: ClipperSummary::default(),
+            bounds: InputBounds::default(),
+            #[cfg(feature = "stats")]
+            input_time: std::time::Duration::ZERO,
             _marker: PhantomData,
             _state: NoSubjects {},
         }
@@ -91,7 +284,8 @@ impl<P: PointScaler> Clipper<NoSubjects, P> {
 }
 
 impl<P: PointScaler> Clipper<NoSubjects, P> {
-    /// Adds a subject path to the Clipper instance.
+    /// Adds a subject path to the Clipper instance. Paths marked as open,
+    /// see [`Paths::push_open`], are added as open subjects.
     ///
     /// # Examples
     ///
@@ -108,6 +302,10 @@ impl<P: PointScaler> Clipper<NoSubjects, P> {
         let clipper = Clipper::<WithSubjects, P> {
             ptr: self.ptr,
             keep_ptr_on_drop: false,
+            inputs: self.inputs,
+            bounds: self.bounds,
+            #[cfg(feature = "stats")]
+            input_time: self.input_time,
             _marker: PhantomData,
             _state: WithSubjects {},
         };
@@ -134,6 +332,10 @@ impl<P: PointScaler> Clipper<NoSubjects, P> {
         let clipper = Clipper::<WithSubjects, P> {
             ptr: self.ptr,
             keep_ptr_on_drop: false,
+            inputs: self.inputs,
+            bounds: self.bounds,
+            #[cfg(feature = "stats")]
+            input_time: self.input_time,
             _marker: PhantomData,
             _state: WithSubjects {},
         };
@@ -142,10 +344,32 @@ impl<P: PointScaler> Clipper<NoSubjects, P> {
 
         clipper.add_open_subject(subject)
     }
+
+    /// Adds borrowed subject paths, reusing their cached native
+    /// representation instead of taking ownership of a copy.
+    pub(crate) fn add_subject_ref(mut self, subject: &Paths<P>) -> Clipper<WithSubjects, P> {
+        self.keep_ptr_on_drop = true;
+
+        let clipper = Clipper::<WithSubjects, P> {
+            ptr: self.ptr,
+            keep_ptr_on_drop: false,
+            inputs: self.inputs,
+            bounds: self.bounds,
+            #[cfg(feature = "stats")]
+            input_time: self.input_time,
+            _marker: PhantomData,
+            _state: WithSubjects {},
+        };
+
+        drop(self);
+
+        clipper.add_subject_ref(subject)
+    }
 }
 
 impl<P: PointScaler> Clipper<WithSubjects, P> {
-    /// Adds another subject path to the Clipper instance.
+    /// Adds another subject path to the Clipper instance. Paths marked as
+    /// open, see [`Paths::push_open`], are added as open subjects.
     ///
     /// # Examples
     ///
@@ -158,10 +382,27 @@ impl<P: PointScaler> Clipper<WithSubjects, P> {
     /// let clipper = Clipper::new().add_subject(path).add_subject(path2);
     /// ```
     pub fn add_subject(self, subject: impl Into<Paths<P>>) -> Self {
-        unsafe {
-            let subject_ptr = subject.into().to_clipperpaths64();
-            clipper_clipper64_add_subject(self.ptr, subject_ptr);
-            clipper_delete_paths64(subject_ptr);
+        self.add_subject_ref(&subject.into())
+    }
+
+    /// Adds more borrowed subject paths, reusing their cached native
+    /// representation instead of taking ownership of a copy.
+    pub(crate) fn add_subject_ref(mut self, subject: &Paths<P>) -> Self {
+        if subject.has_open_paths() {
+            let (closed, open) = subject.partition_open();
+            self.add_native(|ptr| unsafe {
+                clipper_clipper64_add_subject(ptr, closed.as_clipperpaths64());
+                clipper_clipper64_add_open_subject(ptr, open.as_clipperpaths64());
+            });
+            self.inputs.add_subjects(&closed);
+            self.inputs.add_open_subjects(&open);
+            self.bounds.subject = self.bounds.subject.union(&subject.bounds());
+        } else {
+            self.add_native(|ptr| unsafe {
+                clipper_clipper64_add_subject(ptr, subject.as_clipperpaths64());
+            });
+            self.inputs.add_subjects(subject);
+            self.bounds.subject = self.bounds.subject.union(&subject.bounds());
         }
 
         self
@@ -179,17 +420,19 @@ impl<P: PointScaler> Clipper<WithSubjects, P> {
     ///
     /// let clipper = Clipper::new().add_subject(path).add_open_subject(path2);
     /// ```
-    pub fn add_open_subject(self, subject: impl Into<Paths<P>>) -> Self {
-        unsafe {
-            let subject_ptr = subject.into().to_clipperpaths64();
-            clipper_clipper64_add_open_subject(self.ptr, subject_ptr);
-            clipper_delete_paths64(subject_ptr);
-        }
+    pub fn add_open_subject(mut self, subject: impl Into<Paths<P>>) -> Self {
+        let subject = subject.into();
+        self.add_native(|ptr| unsafe {
+            clipper_clipper64_add_open_subject(ptr, subject.as_clipperpaths64());
+        });
+        self.inputs.add_open_subjects(&subject);
+        self.bounds.subject = self.bounds.subject.union(&subject.bounds());
 
         self
     }
 
-    /// Adds a clip path to the Clipper instance.
+    /// Adds a clip path to the Clipper instance. Clip paths are always
+    /// closed, so open flags are ignored.
     ///
     /// # Examples
     ///
@@ -207,6 +450,10 @@ impl<P: PointScaler> Clipper<WithSubjects, P> {
         let clipper = Clipper::<WithClips, P> {
             ptr: self.ptr,
             keep_ptr_on_drop: false,
+            inputs: self.inputs,
+            bounds: self.bounds,
+            #[cfg(feature = "stats")]
+            input_time: self.input_time,
             _marker: PhantomData,
             _state: WithClips {},
         };
@@ -215,6 +462,29 @@ impl<P: PointScaler> Clipper<WithSubjects, P> {
 
         clipper.add_clip(clip)
     }
+
+    /// Adds borrowed clip paths, reusing their cached native representation
+    /// instead of taking ownership of a copy.
+    pub(crate) fn add_clip_ref(mut self, clip: &Paths<P>) -> Clipper<WithClips, P> {
+        self.add_native(|ptr| unsafe {
+            clipper_clipper64_add_clip(ptr, clip.as_clipperpaths64());
+        });
+        self.inputs.add_clips(clip);
+        self.bounds.clip = self.bounds.clip.union(&clip.bounds());
+
+        self.keep_ptr_on_drop = true;
+
+        Clipper::<WithClips, P> {
+            ptr: self.ptr,
+            keep_ptr_on_drop: false,
+            inputs: self.inputs,
+            bounds: self.bounds,
+            #[cfg(feature = "stats")]
+            input_time: self.input_time,
+            _marker: PhantomData,
+            _state: WithClips {},
+        }
+    }
 }
 
 impl<P: PointScaler> Clipper<WithClips, P> {
@@ -231,12 +501,13 @@ impl<P: PointScaler> Clipper<WithClips, P> {
     ///
     /// let clipper = Clipper::new().add_subject(path).add_clip(path2).add_clip(path3);
     /// ```
-    pub fn add_clip(self, clip: impl Into<Paths<P>>) -> Self {
-        unsafe {
-            let clip_ptr = clip.into().to_clipperpaths64();
-            clipper_clipper64_add_clip(self.ptr, clip_ptr);
-            clipper_delete_paths64(clip_ptr);
-        }
+    pub fn add_clip(mut self, clip: impl Into<Paths<P>>) -> Self {
+        let clip = clip.into();
+        self.add_native(|ptr| unsafe {
+            clipper_clipper64_add_clip(ptr, clip.as_clipperpaths64());
+        });
+        self.inputs.add_clips(&clip);
+        self.bounds.clip = self.bounds.clip.union(&clip.bounds());
 
         self
     }
@@ -321,40 +592,225 @@ impl<P: PointScaler> Clipper<WithClips, P> {
         self.boolean_operation(ClipType::Xor, fill_rule)
     }
 
-    fn boolean_operation(
+    /// Applies a union boolean operation and returns iterators that convert
+    /// the result paths one at a time, instead of collecting them all like
+    /// [`Clipper::union`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let squares: Paths = (0..100)
+    ///     .map(|i| Path::rectangle(i as f64 * 2.0, 0.0, 1.0, 1.0))
+    ///     .collect();
+    ///
+    /// let result = squares
+    ///     .to_clipper_subject()
+    ///     .add_clip(Paths::default())
+    ///     .union_iter(FillRule::default())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(result.closed.len(), 100);
+    /// let total = result.closed.map(|path| path.signed_area()).sum::<f64>();
+    /// assert_eq!(total, 100.0);
+    /// ```
+    pub fn union_iter(self, fill_rule: FillRule) -> Result<BooleanIterResult<P>, ClipperError> {
+        self.boolean_operation_iter(ClipType::Union, fill_rule)
+    }
+
+    /// Applies a difference boolean operation and returns iterators that
+    /// convert the result paths one at a time, see [`Clipper::union_iter`].
+    pub fn difference_iter(
+        self,
+        fill_rule: FillRule,
+    ) -> Result<BooleanIterResult<P>, ClipperError> {
+        self.boolean_operation_iter(ClipType::Difference, fill_rule)
+    }
+
+    /// Applies an intersection boolean operation and returns iterators that
+    /// convert the result paths one at a time, see [`Clipper::union_iter`].
+    pub fn intersect_iter(self, fill_rule: FillRule) -> Result<BooleanIterResult<P>, ClipperError> {
+        self.boolean_operation_iter(ClipType::Intersection, fill_rule)
+    }
+
+    /// Applies an xor boolean operation and returns iterators that convert
+    /// the result paths one at a time, see [`Clipper::union_iter`].
+    pub fn xor_iter(self, fill_rule: FillRule) -> Result<BooleanIterResult<P>, ClipperError> {
+        self.boolean_operation_iter(ClipType::Xor, fill_rule)
+    }
+
+    fn boolean_operation_iter(
         self,
         clip_type: ClipType,
         fill_rule: FillRule,
-    ) -> Result<BooleanResult<P>, ClipperError> {
-        let closed_path = unsafe { Paths::<P>::new(Vec::new()).to_clipperpaths64() };
-        let open_path = unsafe { Paths::<P>::new(Vec::new()).to_clipperpaths64() };
+    ) -> Result<BooleanIterResult<P>, ClipperError> {
+        unsafe {
+            // The iterators take ownership of the native paths, so the
+            // per-thread scratch paths can't be used here.
+            let closed = NativePathsIter::from_ptr(clipper_paths64(malloc(clipper_paths64_size())));
+            let open = NativePathsIter::from_ptr(clipper_paths64(malloc(clipper_paths64_size())));
 
-        let result = unsafe {
             let success = clipper_clipper64_execute(
                 self.ptr,
                 clip_type.into(),
                 fill_rule.into(),
-                closed_path,
-                open_path,
+                closed.ptr(),
+                open.ptr(),
             );
 
-            if success != 1 {
-                clipper_delete_paths64(closed_path);
-                clipper_delete_paths64(open_path);
-                return Err(ClipperError::FailedBooleanOperation);
+            if success == 1 {
+                Ok(BooleanIterResult {
+                    closed: closed.reset(),
+                    open: open.reset(),
+                })
+            } else {
+                Err(self.native_failure(clip_type, fill_rule, success))
             }
+        }
+    }
+
+    /// Applies a union boolean operation and writes the result into
+    /// `result`, replacing its contents.
+    ///
+    /// The allocations of the paths already in `result` are reused, which
+    /// avoids allocating fresh vectors when performing many small operations
+    /// in a loop. On failure `result` is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let mut result = BooleanResult::new(Paths::default(), Paths::default());
+    ///
+    /// for i in 0..10 {
+    ///     let offset = i as f64;
+    ///     Clipper::<NoSubjects, Centi>::new()
+    ///         .add_subject(Path::rectangle(offset, 0.0, 2.0, 2.0))
+    ///         .add_clip(Path::rectangle(offset + 1.0, 0.0, 2.0, 2.0))
+    ///         .union_into(FillRule::NonZero, &mut result)
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(result.closed.signed_area(), 6.0);
+    /// }
+    /// ```
+    pub fn union_into(
+        self,
+        fill_rule: FillRule,
+        result: &mut BooleanResult<P>,
+    ) -> Result<(), ClipperError> {
+        self.execute_into(ClipType::Union, fill_rule, result)
+    }
+
+    /// Applies a difference boolean operation and writes the result into
+    /// `result`, see [`Clipper::union_into`].
+    pub fn difference_into(
+        self,
+        fill_rule: FillRule,
+        result: &mut BooleanResult<P>,
+    ) -> Result<(), ClipperError> {
+        self.execute_into(ClipType::Difference, fill_rule, result)
+    }
+
+    /// Applies an intersection boolean operation and writes the result into
+    /// `result`, see [`Clipper::union_into`].
+    pub fn intersect_into(
+        self,
+        fill_rule: FillRule,
+        result: &mut BooleanResult<P>,
+    ) -> Result<(), ClipperError> {
+        self.execute_into(ClipType::Intersection, fill_rule, result)
+    }
+
+    /// Applies an xor boolean operation and writes the result into `result`,
+    /// see [`Clipper::union_into`].
+    pub fn xor_into(
+        self,
+        fill_rule: FillRule,
+        result: &mut BooleanResult<P>,
+    ) -> Result<(), ClipperError> {
+        self.execute_into(ClipType::Xor, fill_rule, result)
+    }
+
+    /// Switches to a state where the boolean operations leave the subjects
+    /// and clips loaded in the native engine, so more clips can be added and
+    /// the operation executed again without marshalling the subjects anew.
+    ///
+    /// This suits clips arriving over time, such as the cuts of an
+    /// interactive editor applied to a large subject.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let subject: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+    ///
+    /// let clipper = Clipper::new()
+    ///     .add_subject(subject)
+    ///     .add_clip(Path::rectangle(0.0, 0.0, 2.0, 2.0))
+    ///     .execute_keeping_inputs();
+    /// let first = clipper.difference(FillRule::NonZero).unwrap();
+    /// assert_eq!(first.closed.signed_area(), 96.0);
+    ///
+    /// let clipper = clipper.add_clip(Path::rectangle(8.0, 8.0, 2.0, 2.0));
+    /// let second = clipper.difference(FillRule::NonZero).unwrap();
+    /// assert_eq!(second.closed.signed_area(), 92.0);
+    /// ```
+    pub fn execute_keeping_inputs(mut self) -> Clipper<KeepingInputs, P> {
+        self.keep_ptr_on_drop = true;
 
-            let closed_result = Paths::from_clipperpaths64(closed_path);
-            let open_result = Paths::from_clipperpaths64(open_path);
-            clipper_delete_paths64(closed_path);
-            clipper_delete_paths64(open_path);
+        Clipper::<KeepingInputs, P> {
+            ptr: self.ptr,
+            keep_ptr_on_drop: false,
+            inputs: self.inputs,
+            bounds: self.bounds,
+            #[cfg(feature = "stats")]
+            input_time: self.input_time,
+            _marker: PhantomData,
+            _state: KeepingInputs {},
+        }
+    }
+
+    /// Applies a boolean operation and returns the signed area of the closed
+    /// result paths, computed by the native library without converting the
+    /// paths.
+    pub(crate) fn boolean_operation_area(
+        self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<f64, ClipperError> {
+        let scratch = ExecuteScratch::take();
 
-            Ok(BooleanResult::new(closed_result, open_result))
+        let (success, area) = unsafe {
+            let success = clipper_clipper64_execute(
+                self.ptr,
+                clip_type.into(),
+                fill_rule.into(),
+                scratch.closed,
+                scratch.open,
+            );
+            (success, clipper_paths64_area(scratch.closed))
         };
 
-        drop(self);
+        scratch.release();
+
+        if success == 1 {
+            Ok(area / (P::MULTIPLIER * P::MULTIPLIER))
+        } else {
+            Err(self.native_failure(clip_type, fill_rule, success))
+        }
+    }
 
-        result
+    pub(crate) fn boolean_operation(
+        self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<BooleanResult<P>, ClipperError> {
+        let mut result = BooleanResult::new(Paths::default(), Paths::default());
+        self.execute_into(clip_type, fill_rule, &mut result)?;
+        Ok(result)
     }
 
     /// Applies a boolean operation and returns a PolyTree with hierarchy information.
@@ -378,7 +834,10 @@ impl<P: PointScaler> Clipper<WithClips, P> {
     }
 
     /// Applies a difference boolean operation and returns a PolyTree with hierarchy information.
-    pub fn difference_tree(self, fill_rule: FillRule) -> Result<BooleanTreeResult<P>, ClipperError> {
+    pub fn difference_tree(
+        self,
+        fill_rule: FillRule,
+    ) -> Result<BooleanTreeResult<P>, ClipperError> {
         self.boolean_operation_tree(ClipType::Difference, fill_rule)
     }
 
@@ -392,16 +851,64 @@ impl<P: PointScaler> Clipper<WithClips, P> {
         self.boolean_operation_tree(ClipType::Xor, fill_rule)
     }
 
+    /// Applies a union boolean operation and returns the hierarchy as a
+    /// [`NativePolyTree`], which converts its nodes on demand instead of
+    /// copying the whole result like [`Clipper::union_tree`].
+    pub fn union_native_tree(
+        self,
+        fill_rule: FillRule,
+    ) -> Result<NativeBooleanTreeResult<P>, ClipperError> {
+        self.boolean_operation_native_tree(ClipType::Union, fill_rule)
+    }
+
+    /// Applies a difference boolean operation and returns the hierarchy as a
+    /// [`NativePolyTree`], see [`Clipper::union_native_tree`].
+    pub fn difference_native_tree(
+        self,
+        fill_rule: FillRule,
+    ) -> Result<NativeBooleanTreeResult<P>, ClipperError> {
+        self.boolean_operation_native_tree(ClipType::Difference, fill_rule)
+    }
+
+    /// Applies an intersection boolean operation and returns the hierarchy
+    /// as a [`NativePolyTree`], see [`Clipper::union_native_tree`].
+    pub fn intersect_native_tree(
+        self,
+        fill_rule: FillRule,
+    ) -> Result<NativeBooleanTreeResult<P>, ClipperError> {
+        self.boolean_operation_native_tree(ClipType::Intersection, fill_rule)
+    }
+
+    /// Applies an xor boolean operation and returns the hierarchy as a
+    /// [`NativePolyTree`], see [`Clipper::union_native_tree`].
+    pub fn xor_native_tree(
+        self,
+        fill_rule: FillRule,
+    ) -> Result<NativeBooleanTreeResult<P>, ClipperError> {
+        self.boolean_operation_native_tree(ClipType::Xor, fill_rule)
+    }
+
     fn boolean_operation_tree(
         self,
         clip_type: ClipType,
         fill_rule: FillRule,
     ) -> Result<BooleanTreeResult<P>, ClipperError> {
+        let NativeBooleanTreeResult { tree, open } =
+            self.boolean_operation_native_tree(clip_type, fill_rule)?;
+
+        Ok(BooleanTreeResult::new(tree.to_owned(), open))
+    }
+
+    fn boolean_operation_native_tree(
+        self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<NativeBooleanTreeResult<P>, ClipperError> {
         unsafe {
             // Allocate memory for PolyTree
             let tree_mem = malloc(clipper_polytree64_size());
             let tree_ptr = clipper_polytree64(tree_mem, std::ptr::null_mut());
-            
+
             // Allocate memory for open paths
             let open_path_mem = malloc(clipper_paths64_size());
             let open_path_ptr = clipper_paths64(open_path_mem);
@@ -415,21 +922,348 @@ impl<P: PointScaler> Clipper<WithClips, P> {
             );
 
             if success != 1 {
-                clipper_delete_polytree64(tree_ptr);
-                clipper_delete_paths64(open_path_ptr);
-                return Err(ClipperError::FailedBooleanOperation);
+                delete_polytree64(tree_ptr);
+                delete_paths64(open_path_ptr);
+                return Err(self.native_failure(clip_type, fill_rule, success));
             }
 
-            // Convert the raw pointer to a Rust PolyTree structure
-            let poly_tree = PolyTree::from_ptr(tree_ptr);
-            // Now we can delete the original PolyTree pointer since we've copied all data
-            clipper_delete_polytree64(tree_ptr);
-            
-            let open_paths = Paths::from_clipperpaths64(open_path_ptr);
+            // The native tree is deleted when the wrapper is dropped
+            let tree = NativePolyTree::from_ptr(tree_ptr);
+
+            let mut open = Paths::from_clipperpaths64(open_path_ptr);
+            open.set_all_open();
             // Clean up the open paths pointer
-            clipper_delete_paths64(open_path_ptr);
-            
-            Ok(BooleanTreeResult::new(poly_tree, open_paths))
+            delete_paths64(open_path_ptr);
+
+            Ok(NativeBooleanTreeResult { tree, open })
+        }
+    }
+}
+
+impl<P: PointScaler> Clipper<KeepingInputs, P> {
+    /// Adds another clip path, keeping the subjects and clips added before.
+    /// See [`Clipper::execute_keeping_inputs`].
+    pub fn add_clip(mut self, clip: impl Into<Paths<P>>) -> Self {
+        let clip = clip.into();
+        self.add_native(|ptr| unsafe {
+            clipper_clipper64_add_clip(ptr, clip.as_clipperpaths64());
+        });
+        self.inputs.add_clips(&clip);
+        self.bounds.clip = self.bounds.clip.union(&clip.bounds());
+
+        self
+    }
+
+    /// Applies a union boolean operation to all subjects and clips added so
+    /// far, keeping them loaded.
+    pub fn union(&self, fill_rule: FillRule) -> Result<BooleanResult<P>, ClipperError> {
+        self.execute_kept(ClipType::Union, fill_rule)
+    }
+
+    /// Applies a difference boolean operation to all subjects and clips
+    /// added so far, keeping them loaded.
+    pub fn difference(&self, fill_rule: FillRule) -> Result<BooleanResult<P>, ClipperError> {
+        self.execute_kept(ClipType::Difference, fill_rule)
+    }
+
+    /// Applies an intersection boolean operation to all subjects and clips
+    /// added so far, keeping them loaded.
+    pub fn intersect(&self, fill_rule: FillRule) -> Result<BooleanResult<P>, ClipperError> {
+        self.execute_kept(ClipType::Intersection, fill_rule)
+    }
+
+    /// Applies an xor boolean operation to all subjects and clips added so
+    /// far, keeping them loaded.
+    pub fn xor(&self, fill_rule: FillRule) -> Result<BooleanResult<P>, ClipperError> {
+        self.execute_kept(ClipType::Xor, fill_rule)
+    }
+
+    fn execute_kept(
+        &self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<BooleanResult<P>, ClipperError> {
+        let mut result = BooleanResult::new(Paths::default(), Paths::default());
+        self.execute_into(clip_type, fill_rule, &mut result)?;
+        Ok(result)
+    }
+}
+
+impl<S: ClipperState, P: PointScaler> Clipper<S, P> {
+    /// Returns the number of paths and vertices added so far.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let clipper = Clipper::<NoSubjects, Centi>::new()
+    ///     .add_subject(Path::rectangle(0.0, 0.0, 1.0, 1.0))
+    ///     .add_open_subject(vec![(0.0, 0.0), (1.0, 1.0)]);
+    ///
+    /// assert_eq!(
+    ///     clipper.summary().to_string(),
+    ///     "1 subject paths (4 vertices), 1 open subject paths (2 vertices), \
+    ///      0 clip paths (0 vertices)"
+    /// );
+    /// ```
+    pub fn summary(&self) -> ClipperSummary {
+        self.inputs
+    }
+
+    /// Returns the bounds of all subject paths added so far, or `None` if no
+    /// subject points have been added.
+    ///
+    /// The bounds are tracked while adding paths, so they can be used to
+    /// skip operations before executing them, such as an intersection of
+    /// subjects and clips whose bounds don't overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let clipper = Clipper::<NoSubjects, Centi>::new()
+    ///     .add_subject(Path::rectangle(0.0, 0.0, 1.0, 1.0))
+    ///     .add_clip(Path::rectangle(5.0, 5.0, 1.0, 1.0));
+    ///
+    /// let subject = clipper.subject_bounds().unwrap();
+    /// let clip = clipper.clip_bounds().unwrap();
+    ///
+    /// if !subject.overlaps(&clip) {
+    ///     // The intersection is empty, no need to execute it.
+    /// }
+    /// # assert!(!subject.overlaps(&clip));
+    /// ```
+    pub fn subject_bounds(&self) -> Option<Bounds<P>> {
+        non_empty(self.bounds.subject)
+    }
+
+    /// Returns the bounds of all clip paths added so far, or `None` if no
+    /// clip points have been added, see [`Clipper::subject_bounds`].
+    pub fn clip_bounds(&self) -> Option<Bounds<P>> {
+        non_empty(self.bounds.clip)
+    }
+
+    /// Add paths to the native engine, counting the time for the
+    /// [`ExecutionStats`](crate::ExecutionStats) with the `stats` feature.
+    fn add_native(&mut self, add: impl FnOnce(*mut ClipperClipper64)) {
+        #[cfg(feature = "stats")]
+        let start = std::time::Instant::now();
+
+        add(self.ptr);
+
+        #[cfg(feature = "stats")]
+        {
+            self.input_time += start.elapsed();
+        }
+    }
+
+    fn execute_into(
+        &self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+        result: &mut BooleanResult<P>,
+    ) -> Result<(), ClipperError> {
+        let mut scratch = ExecuteScratch::take();
+
+        let success = unsafe {
+            clipper_clipper64_execute(
+                self.ptr,
+                clip_type.into(),
+                fill_rule.into(),
+                scratch.closed,
+                scratch.open,
+            )
+        };
+
+        if success == 1 {
+            let ExecuteScratch {
+                closed,
+                open,
+                lengths,
+                buffers,
+            } = &mut scratch;
+            result.closed.read_clipperpaths64(*closed, lengths, buffers);
+            result.open.read_clipperpaths64(*open, lengths, buffers);
+            result.open.set_all_open();
+        }
+
+        scratch.release();
+
+        if success == 1 {
+            Ok(())
+        } else {
+            Err(self.native_failure(clip_type, fill_rule, success))
+        }
+    }
+
+    fn native_failure(&self, clip_type: ClipType, fill_rule: FillRule, code: i32) -> ClipperError {
+        ClipperError::NativeFailure {
+            operation: clip_type.name(),
+            fill_rule,
+            code,
+            subject_paths: self.inputs.subject_paths,
+            open_subject_paths: self.inputs.open_subject_paths,
+            clip_paths: self.inputs.clip_paths,
+        }
+    }
+}
+
+#[cfg(feature = "stats")]
+impl<P: PointScaler> Clipper<WithClips, P> {
+    /// Applies a boolean operation to the paths added so far and returns the
+    /// result together with [`ExecutionStats`](crate::ExecutionStats) on the
+    /// execution.
+    ///
+    /// The marshalling time covers converting the paths to the native
+    /// representation while adding them, and converting the result back.
+    /// Paths with a cached native representation, see [`Paths`], add
+    /// almost no marshalling time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let (result, stats) = Clipper::<NoSubjects, Centi>::new()
+    ///     .add_subject(Path::rectangle(0.0, 0.0, 2.0, 2.0))
+    ///     .add_clip(Path::rectangle(1.0, 0.0, 2.0, 2.0))
+    ///     .boolean_with_stats(ClipType::Union, FillRule::NonZero)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(result.closed.signed_area(), 6.0);
+    /// assert_eq!((stats.input_paths, stats.input_vertices), (2, 8));
+    /// assert_eq!((stats.output_paths, stats.output_vertices), (1, result.vertex_count()));
+    /// ```
+    pub fn boolean_with_stats(
+        self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<(BooleanResult<P>, crate::ExecutionStats), ClipperError> {
+        self.execute_with_stats(clip_type, fill_rule)
+    }
+}
+
+#[cfg(feature = "stats")]
+impl<P: PointScaler> Clipper<KeepingInputs, P> {
+    /// Applies a boolean operation to all subjects and clips added so far,
+    /// keeping them loaded, and returns the result together with
+    /// [`ExecutionStats`](crate::ExecutionStats) on the execution, see
+    /// [`Clipper::boolean_with_stats`].
+    pub fn boolean_with_stats(
+        &self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<(BooleanResult<P>, crate::ExecutionStats), ClipperError> {
+        self.execute_with_stats(clip_type, fill_rule)
+    }
+}
+
+#[cfg(feature = "stats")]
+impl<S: ClipperState, P: PointScaler> Clipper<S, P> {
+    fn execute_with_stats(
+        &self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<(BooleanResult<P>, crate::ExecutionStats), ClipperError> {
+        let mut scratch = ExecuteScratch::take();
+
+        let start = std::time::Instant::now();
+        let success = unsafe {
+            clipper_clipper64_execute(
+                self.ptr,
+                clip_type.into(),
+                fill_rule.into(),
+                scratch.closed,
+                scratch.open,
+            )
+        };
+        let engine = start.elapsed();
+
+        let start = std::time::Instant::now();
+        let mut result = BooleanResult::new(Paths::default(), Paths::default());
+        if success == 1 {
+            let ExecuteScratch {
+                closed,
+                open,
+                lengths,
+                buffers,
+            } = &mut scratch;
+            result.closed.read_clipperpaths64(*closed, lengths, buffers);
+            result.open.read_clipperpaths64(*open, lengths, buffers);
+            result.open.set_all_open();
+        }
+        let output_time = start.elapsed();
+
+        scratch.release();
+
+        if success != 1 {
+            return Err(self.native_failure(clip_type, fill_rule, success));
+        }
+
+        let inputs = self.inputs;
+        let stats = crate::ExecutionStats {
+            input_paths: inputs.subject_paths + inputs.open_subject_paths + inputs.clip_paths,
+            input_vertices: inputs.subject_vertices
+                + inputs.open_subject_vertices
+                + inputs.clip_vertices,
+            output_paths: result.closed.len() + result.open.len(),
+            output_vertices: result.vertex_count(),
+            marshalling: self.input_time + output_time,
+            engine,
+        };
+
+        Ok((result, stats))
+    }
+}
+
+/// Native output paths and conversion buffers kept per thread and reused by
+/// consecutive boolean operations, so each execution doesn't allocate them
+/// anew. The native library clears the output paths before writing to them.
+struct ExecuteScratch {
+    closed: *mut ClipperPaths64,
+    open: *mut ClipperPaths64,
+    lengths: Vec<usize>,
+    buffers: Vec<*mut libc::c_void>,
+}
+
+thread_local! {
+    static EXECUTE_SCRATCH: Cell<Option<ExecuteScratch>> = const { Cell::new(None) };
+}
+
+impl ExecuteScratch {
+    /// Take the scratch buffers of this thread, or allocate new ones if they
+    /// are in use or haven't been created yet.
+    fn take() -> Self {
+        EXECUTE_SCRATCH
+            .try_with(Cell::take)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| unsafe {
+                ExecuteScratch {
+                    closed: clipper_paths64(malloc(clipper_paths64_size())),
+                    open: clipper_paths64(malloc(clipper_paths64_size())),
+                    lengths: Vec::new(),
+                    buffers: Vec::new(),
+                }
+            })
+    }
+
+    /// Return the scratch buffers to this thread for the next execution.
+    fn release(mut self) {
+        self.buffers.clear();
+        // Ignore failures while the thread is being torn down, the buffers
+        // are dropped instead.
+        let _ = EXECUTE_SCRATCH.try_with(|cell| cell.set(Some(self)));
+    }
+}
+
+impl Drop for ExecuteScratch {
+    fn drop(&mut self) {
+        unsafe {
+            delete_paths64(self.closed);
+            delete_paths64(self.open);
         }
     }
 }
@@ -443,13 +1277,30 @@ impl Default for Clipper<NoSubjects, Centi> {
 impl<S: ClipperState, P: PointScaler> Drop for Clipper<S, P> {
     fn drop(&mut self) {
         if !self.keep_ptr_on_drop {
-            unsafe { clipper_delete_clipper64(self.ptr) }
+            unsafe { delete_clipper64(self.ptr) }
         }
     }
 }
 
+impl<S: ClipperState, P: PointScaler> std::fmt::Debug for Clipper<S, P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = std::any::type_name::<S>();
+        let state = state.rsplit("::").next().unwrap_or(state);
+
+        f.debug_struct("Clipper")
+            .field("state", &format_args!("{state}"))
+            .field("subject_paths", &self.inputs.subject_paths)
+            .field("subject_vertices", &self.inputs.subject_vertices)
+            .field("open_subject_paths", &self.inputs.open_subject_paths)
+            .field("open_subject_vertices", &self.inputs.open_subject_vertices)
+            .field("clip_paths", &self.inputs.clip_paths)
+            .field("clip_vertices", &self.inputs.clip_vertices)
+            .finish()
+    }
+}
+
 /// A PolyTree structure representing the result of a boolean operation with hierarchy.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PolyTree<P: PointScaler = Centi> {
     /// Child nodes of this PolyTree node
     pub(crate) children: Vec<PolyTree<P>>,
@@ -457,34 +1308,62 @@ pub struct PolyTree<P: PointScaler = Centi> {
     pub(crate) is_hole: bool,
     /// The polygon path of this node
     pub(crate) polygon: Path<P>,
+    /// The number of ancestors of this node
+    pub(crate) depth: usize,
 }
 
 impl<P: PointScaler> PolyTree<P> {
+    /// Convert a native double precision tree, whose polygons are already in
+    /// the units of the scaler.
+    pub(crate) unsafe fn from_ptr_d(ptr: *mut ClipperPolyTreeD, depth: usize) -> Self {
+        let is_hole = clipper_polytreed_is_hole(ptr) == 1;
+
+        let polygon_ptr = clipper_polytreed_polygon(malloc(clipper_pathd_size()), ptr);
+        let polygon = Path::from_clipperpathd(polygon_ptr);
+        delete_pathd(polygon_ptr);
+
+        let count = clipper_polytreed_count(ptr);
+        let children = (0..count)
+            .map(|i| {
+                let child_ptr = clipper_polytreed_get_child(ptr, i);
+                PolyTree::from_ptr_d(child_ptr as *mut ClipperPolyTreeD, depth + 1)
+            })
+            .collect();
+
+        Self {
+            children,
+            is_hole,
+            polygon,
+            depth,
+        }
+    }
+
     /// Create a PolyTree from a raw pointer. This is unsafe because the caller must ensure
     /// the pointer is valid and will be properly managed.
-    pub(crate) unsafe fn from_ptr(ptr: *mut ClipperPolyTree64) -> Self {
+    pub(crate) unsafe fn from_ptr(ptr: *mut ClipperPolyTree64, depth: usize) -> Self {
         let is_hole = clipper_polytree64_is_hole(ptr) == 1;
-        
+
         // Get polygon
         let mem = malloc(clipper_path64_size());
         let polygon_ptr = clipper_polytree64_polygon(mem, ptr);
         let polygon = Path::from_clipperpath64(polygon_ptr);
-        clipper_delete_path64(polygon_ptr);
-        
+        delete_path64(polygon_ptr);
+
         // Get children recursively
         let count = clipper_polytree64_count(ptr);
         let children = (0..count)
             .map(|i| {
                 let child_ptr = clipper_polytree64_get_child(ptr, i);
                 // The C function returns a const pointer, but we need a mutable pointer for conversion
-                PolyTree::from_ptr(child_ptr as *mut ClipperPolyTree64)
+                PolyTree::from_ptr(child_ptr as *mut ClipperPolyTree64, depth + 1)
             })
             .collect();
-        
+
         Self {
             children,
             is_hole,
             polygon,
+            depth,
         }
     }
 
@@ -508,6 +1387,16 @@ impl<P: PointScaler> PolyTree<P> {
         self.is_hole
     }
 
+    /// Get the depth of this node in the tree. The root node has depth 0,
+    /// outer polygons have odd depths and holes have even depths, matching
+    /// `Level()` in the C++ library.
+    ///
+    /// The depth is set when the tree is built and is not updated for nodes
+    /// moved around with [`PolyTree::children_mut`].
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
     /// Get the polygon path associated with this PolyTree node.
     pub fn polygon(&self) -> &Path<P> {
         &self.polygon
@@ -518,6 +1407,29 @@ impl<P: PointScaler> PolyTree<P> {
         self.polygon.signed_area()
     }
 
+    /// Get the total number of points of the polygons of this node and all
+    /// its descendants.
+    pub fn vertex_count(&self) -> usize {
+        self.polygon.len()
+            + self
+                .children
+                .iter()
+                .map(PolyTree::vertex_count)
+                .sum::<usize>()
+    }
+
+    /// Get an estimate of the heap memory held by this node and all its
+    /// descendants in bytes, see [`Paths::memory_estimate`].
+    pub fn memory_estimate(&self) -> usize {
+        self.polygon.memory_estimate()
+            + self.children.capacity() * std::mem::size_of::<PolyTree<P>>()
+            + self
+                .children
+                .iter()
+                .map(PolyTree::memory_estimate)
+                .sum::<usize>()
+    }
+
     /// Convert this PolyTree (and all its children) to Paths.
     pub fn to_paths(&self) -> Paths<P> {
         let mut paths = Vec::new();
@@ -561,11 +1473,373 @@ impl<P: PointScaler> PolyTree<P> {
     }
 }
 
-
 /// Errors that can occur during clipper operations.
+///
+/// New variants may be added in future versions, so matches need a wildcard
+/// arm.
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum ClipperError {
     /// Failed execute boolean operation.
+    ///
+    /// No longer returned, failed operations return
+    /// [`ClipperError::NativeFailure`] with the details of the failure.
+    #[deprecated(note = "failed operations return `ClipperError::NativeFailure`")]
     #[error("Failed boolean operation")]
     FailedBooleanOperation,
+    /// The native library failed to execute a boolean operation.
+    #[error(
+        "{operation} with fill rule {fill_rule:?} failed with native code {code} \
+         ({subject_paths} subject, {open_subject_paths} open subject and {clip_paths} clip paths)"
+    )]
+    NativeFailure {
+        /// The boolean operation, such as `"union"`
+        operation: &'static str,
+        /// The fill rule of the operation
+        fill_rule: FillRule,
+        /// The status code returned by the native library
+        code: i32,
+        /// Number of closed subject paths added to the clipper
+        subject_paths: usize,
+        /// Number of open subject paths added to the clipper
+        open_subject_paths: usize,
+        /// Number of clip paths added to the clipper
+        clip_paths: usize,
+    },
+    /// Scaling would move coordinates out of the range supported by the
+    /// native library, or a scale factor is zero or not finite.
+    #[error("Can not scale paths by {scale_x} x {scale_y}, the coordinates would be out of range")]
+    InvalidScale {
+        /// Scale factor along the x axis
+        scale_x: f64,
+        /// Scale factor along the y axis
+        scale_y: f64,
+    },
+    /// The number of decimal places for the double precision API is outside
+    /// of the range supported by the native library.
+    #[error("Precision of {precision} decimal places is out of the supported range of -8 to 8")]
+    InvalidPrecision {
+        /// The requested number of decimal places
+        precision: i32,
+    },
+    /// Coordinates passed to the double precision API are not finite or too
+    /// large to be scaled to the integers of the native library.
+    #[error("Coordinates are out of range at a precision of {precision} decimal places")]
+    CoordinatesOutOfRange {
+        /// The number of decimal places the coordinates were scaled by
+        precision: i32,
+    },
+    /// Hatching the paths would need more than
+    /// [`MAX_HATCH_LINES`](crate::MAX_HATCH_LINES) lines, because the spacing
+    /// is too small for the size of the paths.
+    #[error("Hatching with a spacing of {spacing} would need {lines} lines")]
+    TooManyHatchLines {
+        /// The spacing of the lines
+        spacing: f64,
+        /// The number of lines the spacing would need
+        lines: u64,
+    },
+    /// A [`ClipperEngine`] other than the native library failed.
+    ///
+    /// [`ClipperEngine`]: crate::ClipperEngine
+    #[error("{engine} engine failed: {message}")]
+    EngineFailure {
+        /// Name of the engine, see [`ClipperEngine::name`]
+        ///
+        /// [`ClipperEngine::name`]: crate::ClipperEngine::name
+        engine: String,
+        /// Description of the failure
+        message: String,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Point;
+
+    use super::*;
+
+    #[test]
+    fn test_into_matches_boolean_operation() {
+        let subject = Path::<Centi>::rectangle(0.0, 0.0, 4.0, 4.0);
+        let clip = Path::<Centi>::rectangle(2.0, 2.0, 4.0, 4.0);
+        let mut result = BooleanResult::new(Paths::default(), Paths::default());
+
+        Clipper::new()
+            .add_subject(subject.clone())
+            .add_clip(clip.clone())
+            .difference_into(FillRule::default(), &mut result)
+            .unwrap();
+
+        let expected = Clipper::new()
+            .add_subject(subject)
+            .add_clip(clip)
+            .difference(FillRule::default())
+            .unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_into_replaces_previous_result() {
+        let mut result = BooleanResult::<Centi>::new(
+            vec![
+                Path::rectangle(10.0, 10.0, 1.0, 1.0),
+                Path::rectangle(20.0, 20.0, 1.0, 1.0),
+            ]
+            .into(),
+            Path::rectangle(30.0, 30.0, 1.0, 1.0).into(),
+        );
+        let capacity = result.closed[0].points_mut().capacity();
+
+        Clipper::new()
+            .add_subject(Path::rectangle(0.0, 0.0, 2.0, 2.0))
+            .add_clip(Path::rectangle(1.0, 1.0, 2.0, 2.0))
+            .intersect_into(FillRule::default(), &mut result)
+            .unwrap();
+
+        assert_eq!(result.closed.len(), 1);
+        assert_eq!(result.closed.signed_area(), 1.0);
+        assert!(result.open.is_empty());
+        assert!(result.closed[0].points_mut().capacity() >= capacity);
+    }
+
+    #[test]
+    fn test_empty_and_degenerate_inputs() {
+        let square = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+        let degenerate = [
+            Paths::<Centi>::default(),
+            Paths::new(vec![Path::default()]),
+            vec![(0.0, 0.0), (1.0, 1.0)].into(),
+            vec![(1.0, 1.0), (1.0, 1.0), (1.0, 1.0)].into(),
+            vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)].into(),
+        ];
+
+        for input in degenerate {
+            let run = |subject: &Paths<Centi>, clip: &Paths<Centi>, clip_type| {
+                let result = subject
+                    .to_clipper_subject()
+                    .add_clip_ref(clip)
+                    .boolean_operation(clip_type, FillRule::NonZero)
+                    .unwrap();
+                assert!(result.closed.iter().all(|path| !path.is_empty()));
+                assert!(result.open.is_empty());
+                result.closed
+            };
+
+            for clip_type in [
+                ClipType::Union,
+                ClipType::Difference,
+                ClipType::Intersection,
+                ClipType::Xor,
+            ] {
+                assert!(run(&input, &input, clip_type).is_empty());
+            }
+
+            assert_eq!(run(&input, &square, ClipType::Union).signed_area(), 1.0);
+            assert!(run(&input, &square, ClipType::Difference).is_empty());
+            assert!(run(&input, &square, ClipType::Intersection).is_empty());
+            assert_eq!(run(&input, &square, ClipType::Xor).signed_area(), 1.0);
+
+            assert_eq!(run(&square, &input, ClipType::Union).signed_area(), 1.0);
+            assert_eq!(
+                run(&square, &input, ClipType::Difference).signed_area(),
+                1.0
+            );
+            assert!(run(&square, &input, ClipType::Intersection).is_empty());
+            assert_eq!(run(&square, &input, ClipType::Xor).signed_area(), 1.0);
+
+            let tree = input
+                .to_clipper_subject()
+                .add_clip_ref(&input)
+                .union_tree(FillRule::NonZero)
+                .unwrap();
+            assert!(tree.tree.children().is_empty());
+            assert_eq!(input.signed_area(), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_native_failure_message() {
+        let clipper = Clipper::<NoSubjects, Centi>::new()
+            .add_subject(vec![
+                Path::rectangle(0.0, 0.0, 1.0, 1.0),
+                Path::rectangle(2.0, 0.0, 1.0, 1.0),
+            ])
+            .add_open_subject(vec![(0.0, 0.0), (1.0, 1.0)])
+            .add_clip(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+
+        let error = clipper.native_failure(ClipType::Xor, FillRule::NonZero, 0);
+
+        assert_eq!(
+            error.to_string(),
+            "xor with fill rule NonZero failed with native code 0 \
+             (2 subject, 1 open subject and 1 clip paths)"
+        );
+    }
+
+    #[test]
+    fn test_execute_keeping_inputs() {
+        let subject = Paths::<Centi>::from(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(20.0, 0.0, 10.0, 10.0),
+        ]);
+        let cuts = [
+            Path::<Centi>::rectangle(5.0, 5.0, 20.0, 2.0),
+            Path::rectangle(-1.0, -1.0, 3.0, 3.0),
+            Path::rectangle(28.0, 0.0, 5.0, 10.0),
+        ];
+
+        let mut clipper = subject
+            .to_clipper_subject()
+            .add_clip(cuts[0].clone())
+            .execute_keeping_inputs();
+
+        for count in 1..=cuts.len() {
+            if count > 1 {
+                clipper = clipper.add_clip(cuts[count - 1].clone());
+            }
+
+            let expected = subject
+                .to_clipper_subject()
+                .add_clip(cuts[..count].to_vec())
+                .difference(FillRule::NonZero)
+                .unwrap();
+            let kept = clipper.difference(FillRule::NonZero).unwrap();
+            assert_eq!(kept.closed.signed_area(), expected.closed.signed_area());
+
+            // Executing again without new clips gives the same result.
+            assert_eq!(clipper.difference(FillRule::NonZero).unwrap(), kept);
+        }
+
+        assert_eq!(
+            clipper
+                .union(FillRule::NonZero)
+                .unwrap()
+                .closed
+                .signed_area(),
+            200.0 + 20.0 + 5.0 + 30.0
+        );
+        assert!(clipper.intersect(FillRule::NonZero).is_ok());
+        assert!(clipper.xor(FillRule::NonZero).is_ok());
+    }
+
+    #[test]
+    fn test_summary_and_debug() {
+        let clipper = Clipper::<NoSubjects, Centi>::new();
+        assert_eq!(clipper.summary(), ClipperSummary::default());
+        assert_eq!(
+            format!("{clipper:?}"),
+            "Clipper { state: NoSubjects, subject_paths: 0, subject_vertices: 0, \
+             open_subject_paths: 0, open_subject_vertices: 0, clip_paths: 0, clip_vertices: 0 }"
+        );
+
+        let mut subject = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+        subject.push_open(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]);
+        let clipper = subject
+            .to_clipper_subject()
+            .add_open_subject(vec![(0.0, 0.0), (1.0, 1.0)])
+            .add_clip(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)])
+            .execute_keeping_inputs()
+            .add_clip(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+
+        assert_eq!(
+            clipper.summary(),
+            ClipperSummary {
+                subject_paths: 1,
+                subject_vertices: 4,
+                open_subject_paths: 2,
+                open_subject_vertices: 5,
+                clip_paths: 2,
+                clip_vertices: 7,
+            }
+        );
+        assert!(format!("{clipper:?}").starts_with("Clipper { state: KeepingInputs, "));
+    }
+
+    #[test]
+    fn test_clipper_is_send() {
+        fn assert_send<T: Send>(_: &T) {}
+
+        let clipper = Clipper::<NoSubjects, Centi>::new();
+        assert_send(&clipper);
+
+        let handles = (0..4)
+            .map(|i| {
+                let clipper = clipper_for(i);
+                std::thread::spawn(move || clipper.difference(FillRule::NonZero).unwrap())
+            })
+            .collect::<Vec<_>>();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            let result = handle.join().unwrap();
+            assert_eq!(result.closed.signed_area(), 16.0 - i as f64);
+        }
+
+        fn clipper_for(i: usize) -> Clipper<WithClips, Centi> {
+            Clipper::new()
+                .add_subject(Path::rectangle(0.0, 0.0, 4.0, 4.0))
+                .add_clip(Path::rectangle(0.0, 0.0, 1.0, i as f64))
+        }
+    }
+
+    #[test]
+    fn test_vertex_count_and_memory_estimate() {
+        let subject = Paths::<Centi>::from(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(2.0, 2.0, 2.0, 2.0),
+        ]);
+        let clip = Paths::<Centi>::from(Path::rectangle(1.0, 1.0, 4.0, 4.0));
+
+        let result = subject
+            .to_clipper_subject()
+            .add_open_subject(vec![(0.0, 5.0), (20.0, 5.0)])
+            .add_clip(clip.clone())
+            .xor(FillRule::EvenOdd)
+            .unwrap();
+        assert_eq!(
+            result.vertex_count(),
+            result.closed.vertex_count() + result.open.vertex_count()
+        );
+        assert!(result.memory_estimate() >= result.vertex_count() * 16);
+
+        let tree = subject
+            .to_clipper_subject()
+            .add_clip(clip)
+            .xor_tree(FillRule::EvenOdd)
+            .unwrap();
+        assert_eq!(tree.vertex_count(), tree.tree.to_paths().vertex_count());
+        assert!(tree.memory_estimate() >= tree.vertex_count() * 16);
+    }
+
+    #[test]
+    fn test_input_bounds() {
+        let clipper = Clipper::<NoSubjects, Centi>::new();
+        assert_eq!(clipper.subject_bounds(), None);
+        assert_eq!(clipper.clip_bounds(), None);
+
+        let mut subject = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 2.0, 2.0));
+        subject.push_open(vec![(-1.0, 1.0), (1.0, 5.0)]);
+        let clipper = subject
+            .to_clipper_subject()
+            .add_open_subject(vec![(0.0, 0.0), (3.0, 0.0)])
+            .add_subject(Paths::default());
+        assert_eq!(
+            clipper.subject_bounds(),
+            Some(Bounds {
+                min: Point::new(-1.0, 0.0),
+                max: Point::new(3.0, 5.0)
+            })
+        );
+        assert_eq!(clipper.clip_bounds(), None);
+
+        let clipper = clipper
+            .add_clip(Path::rectangle(10.0, 10.0, 1.0, 1.0))
+            .execute_keeping_inputs()
+            .add_clip(Path::rectangle(4.0, 12.0, 1.0, 1.0));
+        let clip = clipper.clip_bounds().unwrap();
+        assert_eq!(clip.min, Point::new(4.0, 10.0));
+        assert_eq!(clip.max, Point::new(11.0, 13.0));
+        assert!(!clip.overlaps(&clipper.subject_bounds().unwrap()));
+    }
 }
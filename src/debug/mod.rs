@@ -0,0 +1,8 @@
+//! Helpers for inspecting intermediate geometry while debugging clipping
+//! code.
+
+pub mod svg;
+#[cfg(feature = "viewer")]
+pub mod viewer;
+
+pub use svg::render_operation_svg;
@@ -0,0 +1,508 @@
+//! Write one or more layers of paths to an SVG image.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use clipper2::*;
+//! use clipper2::debug::svg::{SvgLayer, SvgWriter};
+//!
+//! let subject: Paths = vec![(0.0, 0.0), (6.0, 0.0), (6.0, 6.0), (0.0, 6.0)].into();
+//! let clip: Paths = vec![(5.0, 5.0), (8.0, 5.0), (8.0, 8.0), (5.0, 8.0)].into();
+//! let result = difference(subject.clone(), clip.clone(), FillRule::default()).unwrap();
+//!
+//! let svg = SvgWriter::new()
+//!     .add_layer(SvgLayer::new(subject).fill("#4a90d9"))
+//!     .add_layer(SvgLayer::new(clip).fill("#d94a4a"))
+//!     .add_layer(SvgLayer::new(result.closed).fill("#4ad97a").show_vertices(true))
+//!     .to_svg_string();
+//!
+//! assert!(svg.starts_with("<svg"));
+//! ```
+
+use std::fmt::Write;
+
+use crate::{Bounds, FillRule, Paths, PointScaler, PolyTree};
+
+const DEFAULT_COLORS: [&str; 6] = [
+    "#4a90d9", "#d94a4a", "#4ad97a", "#d9a84a", "#9b4ad9", "#4ad9d3",
+];
+
+/// A set of paths drawn with a single style.
+#[derive(Debug, Clone)]
+pub struct SvgLayer<P: PointScaler> {
+    paths: Paths<P>,
+    fill: Option<String>,
+    stroke: Option<String>,
+    fill_opacity: f64,
+    fill_rule: FillRule,
+    open: bool,
+    show_vertices: bool,
+    label: Option<String>,
+}
+
+impl<P: PointScaler> SvgLayer<P> {
+    /// Create a new layer of closed paths using the default style.
+    pub fn new(paths: impl Into<Paths<P>>) -> Self {
+        Self {
+            paths: paths.into(),
+            fill: None,
+            stroke: None,
+            fill_opacity: 0.5,
+            fill_rule: FillRule::default(),
+            open: false,
+            show_vertices: false,
+            label: None,
+        }
+    }
+
+    /// Create a new layer from all the polygons in a [`PolyTree`].
+    pub fn from_polytree(tree: &PolyTree<P>) -> Self {
+        Self::new(tree.to_paths())
+    }
+
+    /// Set the fill color, any SVG color value such as `"red"` or `"#ff0000"`
+    /// is accepted. The stroke will use the same color unless set separately.
+    pub fn fill(mut self, color: impl Into<String>) -> Self {
+        self.fill = Some(color.into());
+        self
+    }
+
+    /// Set the stroke color.
+    pub fn stroke(mut self, color: impl Into<String>) -> Self {
+        self.stroke = Some(color.into());
+        self
+    }
+
+    /// Set the fill opacity between `0.0` and `1.0`, defaults to `0.5`.
+    pub fn fill_opacity(mut self, opacity: f64) -> Self {
+        self.fill_opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the fill rule used when filling the layer.
+    ///
+    /// SVG only supports the even-odd and non-zero rules, so
+    /// [`FillRule::Positive`] and [`FillRule::Negative`] are drawn as
+    /// non-zero.
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// Draw the paths as open polylines without fill.
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Draw a marker at every vertex.
+    pub fn show_vertices(mut self, show_vertices: bool) -> Self {
+        self.show_vertices = show_vertices;
+        self
+    }
+
+    /// Set the name of the layer shown in the legend, see
+    /// [`SvgWriter::legend`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// Builder that collects [`SvgLayer`]s and writes them to a single SVG
+/// image, with the view box computed from the bounds of all layers.
+#[derive(Debug, Clone)]
+pub struct SvgWriter<P: PointScaler> {
+    layers: Vec<SvgLayer<P>>,
+    margin: f64,
+    legend: bool,
+}
+
+impl<P: PointScaler> Default for SvgWriter<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: PointScaler> SvgWriter<P> {
+    /// Create a new writer without any layers.
+    pub fn new() -> Self {
+        Self {
+            layers: Vec::new(),
+            margin: 0.05,
+            legend: false,
+        }
+    }
+
+    /// Add a layer, layers are drawn in the order they are added.
+    pub fn add_layer(mut self, layer: SvgLayer<P>) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Set the margin around the drawing as a fraction of the largest side of
+    /// the bounds, defaults to `0.05`.
+    pub fn margin(mut self, margin: f64) -> Self {
+        self.margin = margin.max(0.0);
+        self
+    }
+
+    /// Draw a legend below the drawing with a swatch and the label of every
+    /// labeled layer, layers sharing a label are listed once.
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Returns the bounds covering all points in all layers.
+    pub fn bounds(&self) -> Bounds<P> {
+        let mut bounds = Bounds::minmax();
+
+        for layer in &self.layers {
+            if !layer.paths.contains_points() {
+                continue;
+            }
+
+            let b = layer.paths.bounds();
+            bounds.min = (b.min.x().min(bounds.min.x()), b.min.y().min(bounds.min.y())).into();
+            bounds.max = (b.max.x().max(bounds.max.x()), b.max.y().max(bounds.max.y())).into();
+        }
+
+        if bounds.min.x() > bounds.max.x() {
+            return Bounds::default();
+        }
+
+        bounds
+    }
+
+    /// Render all layers to an SVG document.
+    pub fn to_svg_string(&self) -> String {
+        let bounds = self.bounds();
+        let size = bounds.size();
+        let largest_side = size.x().max(size.y());
+        let margin = largest_side * self.margin;
+        let marker_radius = largest_side * 0.005;
+
+        let mut entries: Vec<(&str, usize)> = Vec::new();
+        if self.legend {
+            for (index, layer) in self.layers.iter().enumerate() {
+                if let Some(label) = layer.label.as_deref() {
+                    if !entries.iter().any(|(existing, _)| *existing == label) {
+                        entries.push((label, index));
+                    }
+                }
+            }
+        }
+
+        // Legend rows are sized relative to the drawing so they stay
+        // readable at any scale.
+        let font_size = if largest_side > 0.0 {
+            largest_side * 0.04
+        } else {
+            1.0
+        };
+        let row_height = font_size * 1.5;
+        let legend_height = row_height * entries.len() as f64;
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+            bounds.min.x() - margin,
+            bounds.min.y() - margin,
+            size.x() + margin * 2.0,
+            size.y() + margin * 2.0 + legend_height,
+        );
+
+        for (index, layer) in self.layers.iter().enumerate() {
+            let (fill, stroke) = self.colors(index);
+
+            let _ = writeln!(svg, r#"  <g id="layer-{index}">"#);
+
+            let data = path_data(&layer.paths, !layer.open);
+            if !data.is_empty() {
+                if layer.open {
+                    let _ = writeln!(
+                        svg,
+                        r#"    <path d="{data}" fill="none" stroke="{stroke}" stroke-width="1" vector-effect="non-scaling-stroke"/>"#,
+                    );
+                } else {
+                    let fill_rule = match layer.fill_rule {
+                        FillRule::EvenOdd => "evenodd",
+                        FillRule::NonZero | FillRule::Positive | FillRule::Negative => "nonzero",
+                    };
+                    let _ = writeln!(
+                        svg,
+                        r#"    <path d="{data}" fill="{fill}" fill-opacity="{}" fill-rule="{fill_rule}" stroke="{stroke}" stroke-width="1" vector-effect="non-scaling-stroke"/>"#,
+                        layer.fill_opacity,
+                    );
+                }
+            }
+
+            if layer.show_vertices {
+                for point in layer.paths.iter().flatten() {
+                    let _ = writeln!(
+                        svg,
+                        r#"    <circle cx="{}" cy="{}" r="{marker_radius}" fill="{stroke}"/>"#,
+                        point.x(),
+                        point.y(),
+                    );
+                }
+            }
+
+            let _ = writeln!(svg, "  </g>");
+        }
+
+        if !entries.is_empty() {
+            let _ = writeln!(svg, r#"  <g id="legend">"#);
+
+            for (row, (label, index)) in entries.into_iter().enumerate() {
+                let layer = &self.layers[index];
+                let (fill, stroke) = self.colors(index);
+                let (fill, fill_opacity) = if layer.open {
+                    ("none".to_string(), 0.0)
+                } else {
+                    (fill, layer.fill_opacity)
+                };
+                let top = bounds.max.y() + margin + row_height * row as f64;
+
+                let _ = writeln!(
+                    svg,
+                    r#"    <rect x="{}" y="{top}" width="{font_size}" height="{font_size}" fill="{fill}" fill-opacity="{fill_opacity}" stroke="{stroke}" stroke-width="1" vector-effect="non-scaling-stroke"/>"#,
+                    bounds.min.x(),
+                );
+                let _ = writeln!(
+                    svg,
+                    r#"    <text x="{}" y="{}" font-size="{font_size}" font-family="sans-serif">{}</text>"#,
+                    bounds.min.x() + font_size * 1.5,
+                    top + font_size * 0.85,
+                    escape(label),
+                );
+            }
+
+            let _ = writeln!(svg, "  </g>");
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Render all layers and write the SVG document to a file.
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_svg_string())
+    }
+
+    /// The fill and stroke color of the layer at `index`.
+    fn colors(&self, index: usize) -> (String, String) {
+        let layer = &self.layers[index];
+        let fill = layer
+            .fill
+            .clone()
+            .unwrap_or_else(|| DEFAULT_COLORS[index % DEFAULT_COLORS.len()].to_string());
+        let stroke = layer.stroke.clone().unwrap_or_else(|| fill.clone());
+        (fill, stroke)
+    }
+}
+
+/// Draw the inputs and the result of a boolean operation overlaid in one SVG
+/// image and write it to a file, with a legend naming the three layers.
+///
+/// Subjects are drawn in blue and clips in red, both faintly filled, with the
+/// result on top in green with its vertices marked. Open paths in any of the
+/// inputs are drawn as polylines in the color of their layer. When a boolean
+/// result looks wrong, this shows at a glance which input caused it.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use clipper2::*;
+///
+/// let subject: Paths = Path::rectangle(0.0, 0.0, 6.0, 6.0).into();
+/// let clip: Paths = Path::rectangle(4.0, 4.0, 4.0, 4.0).into();
+/// let result = difference(subject.clone(), clip.clone(), FillRule::default()).unwrap();
+///
+/// debug::render_operation_svg(&subject, &clip, &result.closed, "difference.svg").unwrap();
+/// ```
+pub fn render_operation_svg<P: PointScaler>(
+    subjects: &Paths<P>,
+    clips: &Paths<P>,
+    result: &Paths<P>,
+    path: impl AsRef<std::path::Path>,
+) -> std::io::Result<()> {
+    operation_writer(subjects, clips, result).save(path)
+}
+
+fn operation_writer<P: PointScaler>(
+    subjects: &Paths<P>,
+    clips: &Paths<P>,
+    result: &Paths<P>,
+) -> SvgWriter<P> {
+    let layers = [
+        (subjects, "subjects", DEFAULT_COLORS[0], 0.2, false),
+        (clips, "clips", DEFAULT_COLORS[1], 0.2, false),
+        (result, "result", DEFAULT_COLORS[2], 0.6, true),
+    ];
+
+    let mut writer = SvgWriter::new().legend(true);
+    for (paths, label, color, opacity, show_vertices) in layers {
+        let (closed, open) = paths.partition_open();
+        writer = writer
+            .add_layer(
+                SvgLayer::new(closed)
+                    .fill(color)
+                    .fill_opacity(opacity)
+                    .show_vertices(show_vertices)
+                    .label(label),
+            )
+            .add_layer(
+                SvgLayer::new(open)
+                    .fill(color)
+                    .open(true)
+                    .show_vertices(show_vertices)
+                    .label(label),
+            );
+    }
+
+    writer
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn path_data<P: PointScaler>(paths: &Paths<P>, closed: bool) -> String {
+    let mut data = String::new();
+
+    for path in paths.iter().filter(|path| !path.is_empty()) {
+        for (index, point) in path.iter().enumerate() {
+            let command = if index == 0 { 'M' } else { 'L' };
+            let _ = write!(data, "{command}{} {} ", point.x(), point.y());
+        }
+
+        if closed {
+            data.push_str("Z ");
+        }
+    }
+
+    data.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, Path};
+
+    use super::*;
+
+    #[test]
+    fn test_view_box_covers_all_layers() {
+        let svg = SvgWriter::<Centi>::new()
+            .margin(0.0)
+            .add_layer(SvgLayer::new(Path::rectangle(0.0, 0.0, 10.0, 5.0)))
+            .add_layer(SvgLayer::new(Path::rectangle(-2.0, 1.0, 4.0, 20.0)))
+            .to_svg_string();
+
+        assert!(svg.contains(r#"viewBox="-2 0 12 21""#), "{svg}");
+    }
+
+    #[test]
+    fn test_layer_styles() {
+        let svg = SvgWriter::<Centi>::new()
+            .add_layer(
+                SvgLayer::new(Path::rectangle(0.0, 0.0, 1.0, 1.0))
+                    .fill("red")
+                    .stroke("black")
+                    .fill_rule(FillRule::NonZero),
+            )
+            .add_layer(SvgLayer::new(vec![(0.0, 0.0), (2.0, 2.0)]).open(true))
+            .to_svg_string();
+
+        assert!(svg.contains(
+            r#"<path d="M0 0 L1 0 L1 1 L0 1 Z" fill="red" fill-opacity="0.5" fill-rule="nonzero" stroke="black""#
+        ));
+        assert!(svg.contains(r##"<path d="M0 0 L2 2" fill="none" stroke="#d94a4a""##));
+    }
+
+    #[test]
+    fn test_vertex_markers() {
+        let svg = SvgWriter::<Centi>::new()
+            .add_layer(SvgLayer::new(Path::rectangle(0.0, 0.0, 100.0, 100.0)).show_vertices(true))
+            .to_svg_string();
+
+        assert_eq!(svg.matches("<circle").count(), 4);
+        assert!(svg.contains(r#"<circle cx="100" cy="100" r="0.5""#));
+    }
+
+    #[test]
+    fn test_empty() {
+        let svg = SvgWriter::<Centi>::new()
+            .add_layer(SvgLayer::new(Paths::default()))
+            .to_svg_string();
+
+        assert!(svg.contains(r#"viewBox="0 0 0 0""#));
+        assert!(!svg.contains("<path"));
+    }
+
+    #[test]
+    fn test_legend() {
+        let writer = SvgWriter::<Centi>::new()
+            .margin(0.0)
+            .add_layer(SvgLayer::new(Path::rectangle(0.0, 0.0, 100.0, 50.0)).label("a & b"))
+            .add_layer(
+                SvgLayer::new(vec![(0.0, 0.0), (2.0, 2.0)])
+                    .open(true)
+                    .label("a & b"),
+            )
+            .add_layer(SvgLayer::new(Path::rectangle(0.0, 0.0, 1.0, 1.0)).label("lines"))
+            .add_layer(SvgLayer::new(Path::rectangle(0.0, 0.0, 1.0, 1.0)));
+
+        let svg = writer.clone().to_svg_string();
+        assert!(!svg.contains("legend"));
+
+        let svg = writer.legend(true).to_svg_string();
+        assert_eq!(svg.matches("<text").count(), 2);
+        assert!(svg.contains(">a &amp; b</text>"), "{svg}");
+        assert!(svg.contains(r#"viewBox="0 0 100 62""#), "{svg}");
+        assert!(svg.contains(
+            r##"<rect x="0" y="50" width="4" height="4" fill="#4a90d9" fill-opacity="0.5""##
+        ));
+        assert!(
+            svg.contains(r#"<text x="6" y="59.4" font-size="4""#),
+            "{svg}"
+        );
+    }
+
+    #[test]
+    fn test_render_operation() {
+        let subject: Paths<Centi> = Path::rectangle(0.0, 0.0, 6.0, 6.0).into();
+        let mut clip: Paths<Centi> = Path::rectangle(4.0, 4.0, 4.0, 4.0).into();
+        clip.push_open(vec![(0.0, 8.0), (8.0, 0.0)]);
+
+        let svg = operation_writer(&subject, &clip, &subject).to_svg_string();
+
+        assert_eq!(svg.matches("<text").count(), 3);
+        assert!(svg.contains(r##"fill="none" stroke="#d94a4a""##));
+        assert!(svg.contains(r##"fill="#4ad97a" fill-opacity="0.6""##));
+        assert_eq!(svg.matches("<circle").count(), 4);
+
+        let file = std::env::temp_dir().join("clipper2_debug_svg_test_render_operation.svg");
+        render_operation_svg(&subject, &clip, &subject, &file).unwrap();
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), svg);
+        std::fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    fn test_save() {
+        let file = std::env::temp_dir().join("clipper2_debug_svg_test_save.svg");
+        let writer =
+            SvgWriter::<Centi>::new().add_layer(SvgLayer::new(Path::rectangle(0.0, 0.0, 1.0, 1.0)));
+
+        writer.save(&file).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&file).unwrap(),
+            writer.to_svg_string()
+        );
+        std::fs::remove_file(file).unwrap();
+    }
+}
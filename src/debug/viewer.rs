@@ -0,0 +1,402 @@
+//! Show one or more layers of paths in an interactive window.
+//!
+//! Drag with the left mouse button to pan, scroll to zoom around the cursor,
+//! press `1`-`9` to toggle the layers, `F` to fit the view to the visible
+//! layers and `Escape` to close the window.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use clipper2::*;
+//! use clipper2::debug::viewer::{Viewer, ViewerLayer};
+//!
+//! let subject: Paths = vec![(0.0, 0.0), (6.0, 0.0), (6.0, 6.0), (0.0, 6.0)].into();
+//! let clip: Paths = vec![(5.0, 5.0), (8.0, 5.0), (8.0, 8.0), (5.0, 8.0)].into();
+//! let result = difference(subject.clone(), clip.clone(), FillRule::default()).unwrap();
+//!
+//! Viewer::new("Difference")
+//!     .add_layer(ViewerLayer::new("subject", subject))
+//!     .add_layer(ViewerLayer::new("clip", clip))
+//!     .add_layer(ViewerLayer::new("result", result.closed).show_vertices(true))
+//!     .show();
+//! ```
+
+use macroquad::prelude::{
+    clear_background, draw_circle, draw_line, draw_text, draw_triangle, is_key_down,
+    is_key_pressed, is_mouse_button_down, mouse_position, mouse_wheel, next_frame, screen_height,
+    screen_width, vec2, Color, KeyCode, MouseButton, BLACK, GRAY, WHITE,
+};
+use macroquad::Window;
+
+use crate::{Bounds, FillRule, Paths, Point, PointScaler, PolyTree, Triangulation};
+
+const DEFAULT_COLORS: [Color; 6] = [
+    Color::new(0.29, 0.56, 0.85, 1.0),
+    Color::new(0.85, 0.29, 0.29, 1.0),
+    Color::new(0.29, 0.85, 0.48, 1.0),
+    Color::new(0.85, 0.66, 0.29, 1.0),
+    Color::new(0.61, 0.29, 0.85, 1.0),
+    Color::new(0.29, 0.85, 0.83, 1.0),
+];
+
+/// A named set of paths drawn with a single color that can be toggled on
+/// and off in the [`Viewer`].
+#[derive(Debug, Clone)]
+pub struct ViewerLayer<P: PointScaler> {
+    name: String,
+    paths: Paths<P>,
+    color: Option<Color>,
+    fill_rule: FillRule,
+    open: bool,
+    show_vertices: bool,
+    visible: bool,
+    fill: Option<Triangulation<P>>,
+}
+
+impl<P: PointScaler> ViewerLayer<P> {
+    /// Create a new layer of closed paths using the default style.
+    pub fn new(name: impl Into<String>, paths: impl Into<Paths<P>>) -> Self {
+        Self {
+            name: name.into(),
+            paths: paths.into(),
+            color: None,
+            fill_rule: FillRule::default(),
+            open: false,
+            show_vertices: false,
+            visible: true,
+            fill: None,
+        }
+    }
+
+    /// Create a new layer from all the polygons in a [`PolyTree`].
+    pub fn from_polytree(name: impl Into<String>, tree: &PolyTree<P>) -> Self {
+        Self::new(name, tree.to_paths())
+    }
+
+    /// Set the color of the layer, the fill is drawn semi transparent.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the fill rule used when filling the layer.
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// Draw the paths as open polylines without fill.
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Draw a dot at each vertex.
+    pub fn show_vertices(mut self, show_vertices: bool) -> Self {
+        self.show_vertices = show_vertices;
+        self
+    }
+
+    /// Set whether the layer is visible when the viewer opens.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+}
+
+/// An interactive window showing layers of paths.
+#[derive(Debug, Clone)]
+pub struct Viewer<P: PointScaler> {
+    title: String,
+    layers: Vec<ViewerLayer<P>>,
+    view: View,
+}
+
+impl<P: PointScaler> Viewer<P> {
+    /// Create a new viewer without any layers.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            layers: Vec::new(),
+            view: View::default(),
+        }
+    }
+
+    /// Add a layer, layers are drawn in the order they are added.
+    pub fn add_layer(mut self, mut layer: ViewerLayer<P>) -> Self {
+        if layer.color.is_none() {
+            layer.color = Some(DEFAULT_COLORS[self.layers.len() % DEFAULT_COLORS.len()]);
+        }
+
+        if !layer.open {
+            layer.fill = layer.paths.triangulate(layer.fill_rule).ok();
+        }
+
+        self.layers.push(layer);
+        self
+    }
+
+    /// Open a window showing the layers and block until it is closed.
+    ///
+    /// This creates the window and event loop, so it has to be called from
+    /// the main thread of a program that is not already running a
+    /// `macroquad` window, use [`Viewer::run`] for that.
+    pub fn show(self)
+    where
+        P: 'static,
+    {
+        let title = self.title.clone();
+        Window::new(&title, self.run());
+    }
+
+    /// Show the layers in the current `macroquad` window until `Escape` is
+    /// pressed.
+    pub async fn run(mut self) {
+        self.fit();
+
+        while !is_key_pressed(KeyCode::Escape) {
+            self.handle_input();
+            self.draw();
+            next_frame().await;
+        }
+    }
+
+    fn fit(&mut self) {
+        let mut visible = self
+            .layers
+            .iter()
+            .filter(|layer| layer.visible)
+            .flat_map(|layer| layer.paths.iter().cloned())
+            .collect::<Paths<P>>();
+
+        if visible.is_empty() {
+            visible = self
+                .layers
+                .iter()
+                .flat_map(|layer| layer.paths.iter().cloned())
+                .collect();
+        }
+
+        self.view = View::fit(&visible.bounds(), screen_width(), screen_height());
+    }
+
+    fn handle_input(&mut self) {
+        let (mouse_x, mouse_y) = mouse_position();
+
+        if is_mouse_button_down(MouseButton::Left) {
+            if let Some((last_x, last_y)) = self.view.drag {
+                self.view.pan(mouse_x - last_x, mouse_y - last_y);
+            }
+            self.view.drag = Some((mouse_x, mouse_y));
+        } else {
+            self.view.drag = None;
+        }
+
+        let (_, wheel) = mouse_wheel();
+        if wheel != 0.0 {
+            self.view
+                .zoom_at(mouse_x, mouse_y, 1.1f32.powf(wheel.signum()));
+        }
+
+        if is_key_pressed(KeyCode::F) {
+            self.fit();
+        }
+
+        let digits = [
+            KeyCode::Key1,
+            KeyCode::Key2,
+            KeyCode::Key3,
+            KeyCode::Key4,
+            KeyCode::Key5,
+            KeyCode::Key6,
+            KeyCode::Key7,
+            KeyCode::Key8,
+            KeyCode::Key9,
+        ];
+
+        for (layer, key) in self.layers.iter_mut().zip(digits) {
+            if is_key_pressed(key) {
+                layer.visible = !layer.visible;
+            }
+        }
+    }
+
+    fn draw(&self) {
+        clear_background(BLACK);
+
+        for layer in self.layers.iter().filter(|layer| layer.visible) {
+            self.draw_layer(layer);
+        }
+
+        let hint = if is_key_down(KeyCode::H) {
+            "drag: pan, scroll: zoom, 1-9: toggle layer, F: fit, Esc: close"
+        } else {
+            "H: help"
+        };
+
+        for (index, layer) in self.layers.iter().enumerate() {
+            let color = if layer.visible {
+                layer.color.unwrap_or(WHITE)
+            } else {
+                GRAY
+            };
+            let label = format!("{} {}", index + 1, layer.name);
+            draw_text(&label, 10.0, 20.0 + index as f32 * 20.0, 20.0, color);
+        }
+
+        draw_text(hint, 10.0, screen_height() - 10.0, 20.0, GRAY);
+    }
+
+    fn draw_layer(&self, layer: &ViewerLayer<P>) {
+        let color = layer.color.unwrap_or(WHITE);
+
+        if let Some(fill) = &layer.fill {
+            let fill_color = Color { a: 0.4, ..color };
+            for &[a, b, c] in &fill.triangles {
+                let [a, b, c] = [a, b, c].map(|index| {
+                    let (x, y) = self.view.to_screen(&fill.vertices[index]);
+                    vec2(x, y)
+                });
+                draw_triangle(a, b, c, fill_color);
+            }
+        }
+
+        for path in layer.paths.iter() {
+            let points = path
+                .iter()
+                .map(|point| self.view.to_screen(point))
+                .collect::<Vec<_>>();
+
+            for segment in points.windows(2) {
+                draw_line(
+                    segment[0].0,
+                    segment[0].1,
+                    segment[1].0,
+                    segment[1].1,
+                    2.0,
+                    color,
+                );
+            }
+
+            if let (false, Some(first), Some(last)) = (layer.open, points.first(), points.last()) {
+                draw_line(last.0, last.1, first.0, first.1, 2.0, color);
+            }
+
+            if layer.show_vertices {
+                for &(x, y) in &points {
+                    draw_circle(x, y, 3.0, color);
+                }
+            }
+        }
+    }
+}
+
+/// Maps path coordinates to screen pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct View {
+    scale: f32,
+    offset_x: f32,
+    offset_y: f32,
+    drag: Option<(f32, f32)>,
+}
+
+impl Default for View {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            offset_x: 0.0,
+            offset_y: 0.0,
+            drag: None,
+        }
+    }
+}
+
+impl View {
+    fn fit<P: PointScaler>(bounds: &Bounds<P>, width: f32, height: f32) -> Self {
+        let size = bounds.size();
+        let center = bounds.center();
+        let margin = 0.9;
+
+        let scale_x = width / size.x() as f32;
+        let scale_y = height / size.y() as f32;
+        let scale = match (scale_x.is_finite(), scale_y.is_finite()) {
+            (true, true) => scale_x.min(scale_y) * margin,
+            (true, false) => scale_x * margin,
+            (false, true) => scale_y * margin,
+            (false, false) => 1.0,
+        };
+
+        Self {
+            scale,
+            offset_x: width / 2.0 - center.x() as f32 * scale,
+            offset_y: height / 2.0 - center.y() as f32 * scale,
+            drag: None,
+        }
+    }
+
+    fn to_screen<P: PointScaler>(self, point: &Point<P>) -> (f32, f32) {
+        (
+            point.x() as f32 * self.scale + self.offset_x,
+            point.y() as f32 * self.scale + self.offset_y,
+        )
+    }
+
+    fn pan(&mut self, dx: f32, dy: f32) {
+        self.offset_x += dx;
+        self.offset_y += dy;
+    }
+
+    fn zoom_at(&mut self, x: f32, y: f32, factor: f32) {
+        self.offset_x = x - (x - self.offset_x) * factor;
+        self.offset_y = y - (y - self.offset_y) * factor;
+        self.scale *= factor;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, Path};
+
+    use super::*;
+
+    #[test]
+    fn test_view_fit_centers_bounds() {
+        let paths: Paths<Centi> = Path::rectangle(0.0, 0.0, 10.0, 5.0).into();
+        let view = View::fit(&paths.bounds(), 200.0, 200.0);
+
+        assert_eq!(view.scale, 18.0);
+        assert_eq!(
+            view.to_screen(&Point::<Centi>::new(5.0, 2.5)),
+            (100.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn test_view_zoom_keeps_cursor_position() {
+        let mut view = View::fit(
+            &Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 1.0, 1.0)).bounds(),
+            100.0,
+            100.0,
+        );
+        let point = Point::<Centi>::new(0.25, 0.75);
+        let (x, y) = view.to_screen(&point);
+
+        view.zoom_at(x, y, 2.0);
+
+        let (zoomed_x, zoomed_y) = view.to_screen(&point);
+        assert!((zoomed_x - x).abs() < 1e-3);
+        assert!((zoomed_y - y).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_layers_get_default_colors_and_fill() {
+        let viewer = Viewer::<Centi>::new("test")
+            .add_layer(ViewerLayer::new("a", Path::rectangle(0.0, 0.0, 1.0, 1.0)))
+            .add_layer(ViewerLayer::new("b", Path::rectangle(0.0, 0.0, 1.0, 1.0)).open(true));
+
+        assert_eq!(viewer.layers[0].color, Some(DEFAULT_COLORS[0]));
+        assert_eq!(viewer.layers[1].color, Some(DEFAULT_COLORS[1]));
+        assert_eq!(viewer.layers[0].fill.as_ref().unwrap().triangles.len(), 2);
+        assert!(viewer.layers[1].fill.is_none());
+    }
+}
@@ -0,0 +1,575 @@
+//! The double precision API of the native library, as an alternative to the
+//! [`PointScaler`] integer conversion of the rest of this crate.
+//!
+//! Paths are plain vectors of `[x, y]` coordinates in user units, and each
+//! operation takes the number of decimal places to keep. The native library
+//! scales the coordinates to integers itself, runs the operation and scales
+//! the result back, like the `ClipperD` and `PathsD` API of Clipper2 does.
+//! This saves converting to and from [`Paths`] when the data is `f64` to
+//! begin with, and matches the results of code written against the double
+//! precision API of Clipper2 in other languages.
+//!
+//! The precision can be anywhere from -8 to 8 decimal places, and scaled
+//! coordinates must stay within the range of the native library, otherwise
+//! the operations return an error instead of passing the values on.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use clipper2::double::{inflate_d, ClipperD};
+//! use clipper2::{EndType, FillRule, JoinType};
+//!
+//! let a = vec![vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]];
+//! let b = vec![vec![[0.5, 0.5], [1.5, 0.5], [1.5, 1.5], [0.5, 1.5]]];
+//!
+//! let mut clipper = ClipperD::new(3).unwrap();
+//! clipper.add_subject(&a).unwrap().add_clip(&b).unwrap();
+//! let union = clipper.union(FillRule::NonZero).unwrap();
+//!
+//! assert_eq!(union.closed.len(), 1);
+//! assert_eq!(union.closed[0].len(), 8);
+//!
+//! let grown = inflate_d(&a, 0.25, JoinType::Miter, EndType::Polygon, 2.0, 3).unwrap();
+//! assert_eq!(grown[0].len(), 4);
+//! ```
+
+use clipper2c_sys::{
+    clipper_clipperd, clipper_clipperd_add_clip, clipper_clipperd_add_open_subject,
+    clipper_clipperd_add_subject, clipper_clipperd_clear, clipper_clipperd_execute,
+    clipper_clipperd_execute_tree_with_open, clipper_clipperd_get_preserve_collinear,
+    clipper_clipperd_get_reverse_solution, clipper_clipperd_set_preserve_collinear,
+    clipper_clipperd_set_reverse_solution, clipper_clipperd_size, clipper_pathd_length,
+    clipper_pathd_of_points, clipper_pathd_size, clipper_pathsd, clipper_pathsd_add_path,
+    clipper_pathsd_get_path, clipper_pathsd_inflate, clipper_pathsd_length,
+    clipper_pathsd_simplify, clipper_pathsd_size, clipper_polytreed, clipper_polytreed_size,
+    ClipperClipperD, ClipperPathsD,
+};
+
+use crate::{
+    ffi::clipper_pathd_to_points,
+    malloc,
+    memory::{delete_clipperd, delete_pathd, delete_pathsd, delete_polytreed},
+    pipeline::MAX_COORD,
+    BooleanTreeResult, ClipType, ClipperError, EndType, FillRule, JoinType, Paths, PointScaler,
+    PolyTree,
+};
+
+/// A path of `[x, y]` coordinates in user units.
+pub type PathD = Vec<[f64; 2]>;
+
+/// The largest number of decimal places supported by the native library, in
+/// either direction.
+const MAX_PRECISION: i32 = 8;
+
+/// The result of a boolean operation of a [`ClipperD`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BooleanResultD {
+    /// Closed paths from the boolean operation
+    pub closed: Vec<PathD>,
+    /// Open paths from the boolean operation
+    pub open: Vec<PathD>,
+}
+
+/// The double precision boolean engine of the native library, `ClipperD`.
+///
+/// Paths are added in user units and rounded to the precision of the
+/// clipper. The paths stay in the clipper after executing an operation, so
+/// several operations can be applied to the same input, until
+/// [`ClipperD::clear`] is called.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::double::ClipperD;
+/// use clipper2::FillRule;
+///
+/// let square = vec![vec![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]];
+/// let line = vec![vec![[-1.0, 1.0], [3.0, 1.0]]];
+///
+/// let mut clipper = ClipperD::new(2).unwrap();
+/// clipper.add_open_subject(&line).unwrap().add_clip(&square).unwrap();
+///
+/// let inside = clipper.intersect(FillRule::NonZero).unwrap();
+/// assert_eq!(inside.open, vec![vec![[0.0, 1.0], [2.0, 1.0]]]);
+///
+/// let outside = clipper.difference(FillRule::NonZero).unwrap();
+/// assert_eq!(outside.open.len(), 2);
+/// ```
+pub struct ClipperD {
+    ptr: *mut ClipperClipperD,
+    precision: i32,
+    subject_paths: usize,
+    open_subject_paths: usize,
+    clip_paths: usize,
+}
+
+// The native clipper owns copies of all paths added to it and is only used
+// through `&mut self`.
+unsafe impl Send for ClipperD {}
+
+impl ClipperD {
+    /// Create a clipper rounding coordinates to `precision` decimal places,
+    /// negative precisions rounding to tens, hundreds and so on.
+    ///
+    /// Returns [`ClipperError::InvalidPrecision`] for precisions outside of
+    /// -8 to 8.
+    pub fn new(precision: i32) -> Result<Self, ClipperError> {
+        check_precision(precision)?;
+
+        Ok(Self {
+            ptr: unsafe { clipper_clipperd(malloc(clipper_clipperd_size()), precision) },
+            precision,
+            subject_paths: 0,
+            open_subject_paths: 0,
+            clip_paths: 0,
+        })
+    }
+
+    /// Returns the number of decimal places coordinates are rounded to.
+    pub fn precision(&self) -> i32 {
+        self.precision
+    }
+
+    /// Returns `true` if collinear points are kept in the results.
+    pub fn preserve_collinear(&self) -> bool {
+        unsafe { clipper_clipperd_get_preserve_collinear(self.ptr) != 0 }
+    }
+
+    /// Keep collinear points in the results.
+    pub fn set_preserve_collinear(&mut self, preserve: bool) {
+        unsafe { clipper_clipperd_set_preserve_collinear(self.ptr, preserve.into()) }
+    }
+
+    /// Returns `true` if the orientation of the results is reversed.
+    pub fn reverse_solution(&self) -> bool {
+        unsafe { clipper_clipperd_get_reverse_solution(self.ptr) != 0 }
+    }
+
+    /// Reverse the orientation of the results, making outers negative and
+    /// holes positive.
+    pub fn set_reverse_solution(&mut self, reverse: bool) {
+        unsafe { clipper_clipperd_set_reverse_solution(self.ptr, reverse.into()) }
+    }
+
+    /// Add closed subject paths.
+    ///
+    /// Returns [`ClipperError::CoordinatesOutOfRange`] without adding any of
+    /// the paths if a coordinate can't be scaled to the precision.
+    pub fn add_subject(&mut self, paths: &[PathD]) -> Result<&mut Self, ClipperError> {
+        let native = NativePathsD::new(paths, self.precision)?;
+        unsafe { clipper_clipperd_add_subject(self.ptr, native.0) };
+        self.subject_paths += paths.len();
+        Ok(self)
+    }
+
+    /// Add open subject paths, see [`ClipperD::add_subject`].
+    pub fn add_open_subject(&mut self, paths: &[PathD]) -> Result<&mut Self, ClipperError> {
+        let native = NativePathsD::new(paths, self.precision)?;
+        unsafe { clipper_clipperd_add_open_subject(self.ptr, native.0) };
+        self.open_subject_paths += paths.len();
+        Ok(self)
+    }
+
+    /// Add clip paths, see [`ClipperD::add_subject`].
+    pub fn add_clip(&mut self, paths: &[PathD]) -> Result<&mut Self, ClipperError> {
+        let native = NativePathsD::new(paths, self.precision)?;
+        unsafe { clipper_clipperd_add_clip(self.ptr, native.0) };
+        self.clip_paths += paths.len();
+        Ok(self)
+    }
+
+    /// Remove all paths added so far.
+    pub fn clear(&mut self) {
+        unsafe { clipper_clipperd_clear(self.ptr) };
+        self.subject_paths = 0;
+        self.open_subject_paths = 0;
+        self.clip_paths = 0;
+    }
+
+    /// Join the subject and clip paths.
+    pub fn union(&mut self, fill_rule: FillRule) -> Result<BooleanResultD, ClipperError> {
+        self.boolean(ClipType::Union, fill_rule)
+    }
+
+    /// Subtract the clip paths from the subject paths.
+    pub fn difference(&mut self, fill_rule: FillRule) -> Result<BooleanResultD, ClipperError> {
+        self.boolean(ClipType::Difference, fill_rule)
+    }
+
+    /// Intersect the subject and clip paths.
+    pub fn intersect(&mut self, fill_rule: FillRule) -> Result<BooleanResultD, ClipperError> {
+        self.boolean(ClipType::Intersection, fill_rule)
+    }
+
+    /// Keep the areas covered by either the subject or the clip paths but not
+    /// both.
+    pub fn xor(&mut self, fill_rule: FillRule) -> Result<BooleanResultD, ClipperError> {
+        self.boolean(ClipType::Xor, fill_rule)
+    }
+
+    /// Apply a boolean operation to the paths added so far.
+    pub fn boolean(
+        &mut self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<BooleanResultD, ClipperError> {
+        let (closed, open) = (NativePathsD::empty(), NativePathsD::empty());
+        let code = unsafe {
+            clipper_clipperd_execute(
+                self.ptr,
+                clip_type.into(),
+                fill_rule.into(),
+                closed.0,
+                open.0,
+            )
+        };
+        if code != 1 {
+            return Err(self.failure(code, clip_type, fill_rule));
+        }
+
+        Ok(BooleanResultD {
+            closed: closed.to_vec(),
+            open: open.to_vec(),
+        })
+    }
+
+    /// Apply a boolean operation to the paths added so far and return the
+    /// closed result paths as a [`PolyTree`], built by the double precision
+    /// tree of the native library.
+    ///
+    /// The coordinates of the result are converted to the scaler `P`, so a
+    /// scaler with fewer decimal places than the clipper rounds them again.
+    pub fn boolean_tree<P: PointScaler>(
+        &mut self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<BooleanTreeResult<P>, ClipperError> {
+        let open = NativePathsD::empty();
+        unsafe {
+            let tree = clipper_polytreed(malloc(clipper_polytreed_size()), std::ptr::null_mut());
+            let code = clipper_clipperd_execute_tree_with_open(
+                self.ptr,
+                clip_type.into(),
+                fill_rule.into(),
+                tree,
+                open.0,
+            );
+            let result = (code == 1).then(|| PolyTree::from_ptr_d(tree, 0));
+            delete_polytreed(tree);
+
+            match result {
+                Some(tree) => {
+                    let mut open = Paths::from(open.to_vec());
+                    open.set_all_open();
+                    Ok(BooleanTreeResult::new(tree, open))
+                }
+                None => Err(self.failure(code, clip_type, fill_rule)),
+            }
+        }
+    }
+
+    fn failure(&self, code: i32, clip_type: ClipType, fill_rule: FillRule) -> ClipperError {
+        ClipperError::NativeFailure {
+            operation: clip_type.name(),
+            fill_rule,
+            code,
+            subject_paths: self.subject_paths,
+            open_subject_paths: self.open_subject_paths,
+            clip_paths: self.clip_paths,
+        }
+    }
+}
+
+impl std::fmt::Debug for ClipperD {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClipperD")
+            .field("precision", &self.precision)
+            .field("subject_paths", &self.subject_paths)
+            .field("open_subject_paths", &self.open_subject_paths)
+            .field("clip_paths", &self.clip_paths)
+            .finish()
+    }
+}
+
+impl Drop for ClipperD {
+    fn drop(&mut self) {
+        unsafe { delete_clipperd(self.ptr) }
+    }
+}
+
+/// This function offsets paths in user units by `delta`, rounding the
+/// coordinates to `precision` decimal places, like
+/// [`inflate`](crate::inflate) does for [`Paths`].
+///
+/// Returns [`ClipperError::InvalidPrecision`] for precisions outside of -8
+/// to 8, and [`ClipperError::CoordinatesOutOfRange`] if a coordinate can't be
+/// scaled to the precision.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::double::inflate_d;
+/// use clipper2::{EndType, JoinType};
+///
+/// let line = vec![vec![[0.0, 0.0], [10.0, 0.0]]];
+///
+/// let stroke = inflate_d(&line, 0.5, JoinType::Square, EndType::Butt, 2.0, 2).unwrap();
+///
+/// assert_eq!(stroke.len(), 1);
+/// assert_eq!(stroke[0].len(), 4);
+/// ```
+pub fn inflate_d(
+    paths: &[PathD],
+    delta: f64,
+    join_type: JoinType,
+    end_type: EndType,
+    miter_limit: f64,
+    precision: i32,
+) -> Result<Vec<PathD>, ClipperError> {
+    check_precision(precision)?;
+    let native = NativePathsD::new(paths, precision)?;
+
+    let result = unsafe {
+        NativePathsD(clipper_pathsd_inflate(
+            malloc(clipper_pathsd_size()),
+            native.0,
+            delta,
+            join_type.into(),
+            end_type.into(),
+            miter_limit,
+            precision,
+        ))
+    };
+    Ok(result.to_vec())
+}
+
+/// This function removes vertices closer than `epsilon` to the line through
+/// their neighbors from paths in user units, like
+/// [`simplify`](crate::simplify) does for [`Paths`]. The coordinates are not
+/// rounded.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::double::simplify_d;
+///
+/// let path = vec![vec![[0.0, 0.0], [1.0, 0.01], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]];
+///
+/// let simplified = simplify_d(&path, 0.1, false);
+///
+/// assert_eq!(simplified[0].len(), 4);
+/// ```
+pub fn simplify_d(paths: &[PathD], epsilon: f64, is_open: bool) -> Vec<PathD> {
+    let native = NativePathsD::unchecked(paths);
+    let result = unsafe {
+        NativePathsD(clipper_pathsd_simplify(
+            malloc(clipper_pathsd_size()),
+            native.0,
+            epsilon,
+            is_open.into(),
+        ))
+    };
+    result.to_vec()
+}
+
+fn check_precision(precision: i32) -> Result<(), ClipperError> {
+    if (-MAX_PRECISION..=MAX_PRECISION).contains(&precision) {
+        Ok(())
+    } else {
+        Err(ClipperError::InvalidPrecision { precision })
+    }
+}
+
+/// Native double precision paths, deleted when dropped.
+struct NativePathsD(*mut ClipperPathsD);
+
+impl NativePathsD {
+    fn empty() -> Self {
+        Self(unsafe { clipper_pathsd(malloc(clipper_pathsd_size())) })
+    }
+
+    /// Copy the paths after checking that the native library can scale them
+    /// to the precision, which it would otherwise throw on.
+    fn new(paths: &[PathD], precision: i32) -> Result<Self, ClipperError> {
+        // `ClipperD` scales by the power of two above the power of ten, which
+        // is the larger of the two scales the native library uses.
+        let scale = 2f64.powi(10f64.powi(precision).log2().floor() as i32 + 1);
+        let in_range = paths
+            .iter()
+            .flatten()
+            .flatten()
+            .all(|value| (value * scale).abs() <= MAX_COORD);
+
+        if in_range {
+            Ok(Self::unchecked(paths))
+        } else {
+            Err(ClipperError::CoordinatesOutOfRange { precision })
+        }
+    }
+
+    fn unchecked(paths: &[PathD]) -> Self {
+        let native = Self::empty();
+        for path in paths {
+            unsafe {
+                // `[f64; 2]` has the layout of the native point.
+                let path = clipper_pathd_of_points(
+                    malloc(clipper_pathd_size()),
+                    path.as_ptr().cast_mut().cast(),
+                    path.len(),
+                );
+                clipper_pathsd_add_path(native.0, path);
+                delete_pathd(path);
+            }
+        }
+        native
+    }
+
+    fn to_vec(&self) -> Vec<PathD> {
+        unsafe {
+            (0..clipper_pathsd_length(self.0))
+                .map(|index| {
+                    let path =
+                        clipper_pathsd_get_path(malloc(clipper_pathd_size()), self.0, index as _);
+                    let len = clipper_pathd_length(path);
+                    let mut points = PathD::with_capacity(len);
+                    clipper_pathd_to_points(points.as_mut_ptr().cast(), path);
+                    points.set_len(len);
+                    delete_pathd(path);
+                    points
+                })
+                .collect()
+        }
+    }
+}
+
+impl Drop for NativePathsD {
+    fn drop(&mut self) {
+        unsafe { delete_pathsd(self.0) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, Milli, Point};
+
+    use super::*;
+
+    fn square(x: f64, y: f64, size: f64) -> PathD {
+        vec![[x, y], [x + size, y], [x + size, y + size], [x, y + size]]
+    }
+
+    fn area(paths: &[PathD]) -> f64 {
+        paths
+            .iter()
+            .map(|path| {
+                (0..path.len())
+                    .map(|i| {
+                        let (a, b) = (path[i], path[(i + 1) % path.len()]);
+                        a[0] * b[1] - b[0] * a[1]
+                    })
+                    .sum::<f64>()
+                    / 2.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_boolean_operations_keep_inputs() {
+        let mut clipper = ClipperD::new(4).unwrap();
+        clipper
+            .add_subject(&[square(0.0, 0.0, 1.0)])
+            .unwrap()
+            .add_clip(&[square(0.5, 0.5, 1.0)])
+            .unwrap();
+
+        assert_eq!(
+            area(&clipper.union(FillRule::NonZero).unwrap().closed),
+            1.75
+        );
+        assert_eq!(
+            area(&clipper.difference(FillRule::NonZero).unwrap().closed),
+            0.75
+        );
+        assert_eq!(
+            area(&clipper.intersect(FillRule::NonZero).unwrap().closed),
+            0.25
+        );
+        assert_eq!(area(&clipper.xor(FillRule::NonZero).unwrap().closed), 1.5);
+
+        let tree = clipper
+            .boolean_tree::<Centi>(ClipType::Xor, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(tree.tree.total_net_area(), 1.5);
+
+        clipper.clear();
+        assert!(clipper.union(FillRule::NonZero).unwrap().closed.is_empty());
+    }
+
+    #[test]
+    fn test_boolean_tree_nesting_and_units() {
+        let mut clipper = ClipperD::new(3).unwrap();
+        clipper
+            .add_subject(&[square(0.0, 0.0, 1.0), square(0.25, 0.25, 0.5)])
+            .unwrap()
+            .add_clip(&[square(0.4, 0.4, 0.125)])
+            .unwrap();
+
+        let result = clipper
+            .boolean_tree::<Milli>(ClipType::Union, FillRule::EvenOdd)
+            .unwrap();
+
+        let outer = result.tree.get_child(0).unwrap();
+        let hole = outer.get_child(0).unwrap();
+        let island = hole.get_child(0).unwrap();
+        assert_eq!(outer.depth(), 1);
+        assert!(hole.is_hole());
+        assert_eq!(island.depth(), 3);
+        assert!(!island.is_hole());
+        assert_eq!(island.polygon().bounds().min, Point::new(0.4, 0.4));
+        assert_eq!(result.tree.total_net_area(), 1.0 - 0.25 + 0.125 * 0.125);
+        assert!(result.open.is_empty());
+    }
+
+    #[test]
+    fn test_precision_rounds_results() {
+        let thin = [vec![[0.0, 0.0], [1.0, 0.0], [1.0, 0.003], [0.0, 0.003]]];
+
+        let mut fine = ClipperD::new(3).unwrap();
+        fine.add_subject(&thin).unwrap();
+        assert_eq!(fine.union(FillRule::NonZero).unwrap().closed.len(), 1);
+
+        // Rounded to the precision of hundredths the path has no area left.
+        let mut coarse = ClipperD::new(2).unwrap();
+        coarse.add_subject(&thin).unwrap();
+        assert!(coarse.union(FillRule::NonZero).unwrap().closed.is_empty());
+
+        let grown = inflate_d(&thin, 1.0, JoinType::Miter, EndType::Polygon, 2.0, 0).unwrap();
+        assert_eq!(area(&grown), 3.0 * 2.0);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(matches!(
+            ClipperD::new(9),
+            Err(ClipperError::InvalidPrecision { precision: 9 })
+        ));
+        assert!(matches!(
+            inflate_d(&[], 1.0, JoinType::Round, EndType::Polygon, 2.0, -9),
+            Err(ClipperError::InvalidPrecision { precision: -9 })
+        ));
+
+        let mut clipper = ClipperD::new(8).unwrap();
+        for value in [1e12, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                clipper.add_subject(&[vec![[0.0, 0.0], [value, 0.0], [0.0, 1.0]]]),
+                Err(ClipperError::CoordinatesOutOfRange { precision: 8 })
+            ));
+        }
+        assert!(clipper.union(FillRule::NonZero).unwrap().closed.is_empty());
+
+        assert_eq!(
+            simplify_d(&[square(0.0, 0.0, 1.0)], 0.1, false),
+            vec![square(0.0, 0.0, 1.0)]
+        );
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{
+    inflate, BooleanResult, Centi, ClipType, Clipper, ClipperError, EndType, FillRule, JoinType,
+    Paths, PointScaler,
+};
+
+/// A backend executing boolean operations and offsets, so alternative
+/// implementations can be swapped in without changing the code using them.
+///
+/// [`NativeEngine`] runs the operations with the Clipper2 library, like the
+/// rest of this crate. Other engines, such as pure Rust or experimental
+/// implementations, implement the two required methods and get the named
+/// boolean operations for free. The trait is object safe, so an engine can
+/// be picked at runtime, from configuration or per call, and passed around
+/// as `&dyn ClipperEngine<P>` to compare the robustness and speed of engines
+/// on the same input.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// fn overlap(engine: &dyn ClipperEngine, a: &Paths, b: &Paths) -> f64 {
+///     engine
+///         .intersect(a, b, FillRule::NonZero)
+///         .unwrap()
+///         .closed
+///         .signed_area()
+/// }
+///
+/// let engines: Vec<Box<dyn ClipperEngine>> = vec![Box::new(NativeEngine)];
+/// let a: Paths = Path::rectangle(0.0, 0.0, 4.0, 4.0).into();
+/// let b: Paths = Path::rectangle(2.0, 2.0, 4.0, 4.0).into();
+///
+/// for engine in &engines {
+///     assert_eq!(overlap(engine.as_ref(), &a, &b), 4.0, "{}", engine.name());
+/// }
+/// ```
+pub trait ClipperEngine<P: PointScaler = Centi>: Send + Sync {
+    /// Name of the engine, to tell engines apart in reports and errors.
+    fn name(&self) -> &str;
+
+    /// Apply a boolean operation to closed subjects, open subjects and clips,
+    /// like [`Clipper`] does.
+    fn boolean(
+        &self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+        subjects: &Paths<P>,
+        open_subjects: &Paths<P>,
+        clips: &Paths<P>,
+    ) -> Result<BooleanResult<P>, ClipperError>;
+
+    /// Offset the paths by `delta`, like [`inflate`] does.
+    fn offset(
+        &self,
+        paths: &Paths<P>,
+        delta: f64,
+        join_type: JoinType,
+        end_type: EndType,
+        miter_limit: f64,
+    ) -> Result<Paths<P>, ClipperError>;
+
+    /// Join the subject and clip paths, see [`Clipper::union`].
+    fn union(
+        &self,
+        subject: &Paths<P>,
+        clip: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<BooleanResult<P>, ClipperError> {
+        self.boolean(ClipType::Union, fill_rule, subject, &Paths::default(), clip)
+    }
+
+    /// Subtract the clip paths from the subject, see [`Clipper::difference`].
+    fn difference(
+        &self,
+        subject: &Paths<P>,
+        clip: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<BooleanResult<P>, ClipperError> {
+        self.boolean(
+            ClipType::Difference,
+            fill_rule,
+            subject,
+            &Paths::default(),
+            clip,
+        )
+    }
+
+    /// Intersect the subject and clip paths, see [`Clipper::intersect`].
+    fn intersect(
+        &self,
+        subject: &Paths<P>,
+        clip: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<BooleanResult<P>, ClipperError> {
+        self.boolean(
+            ClipType::Intersection,
+            fill_rule,
+            subject,
+            &Paths::default(),
+            clip,
+        )
+    }
+
+    /// Keep the areas covered by either the subject or clip paths but not
+    /// both, see [`Clipper::xor`].
+    fn xor(
+        &self,
+        subject: &Paths<P>,
+        clip: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<BooleanResult<P>, ClipperError> {
+        self.boolean(ClipType::Xor, fill_rule, subject, &Paths::default(), clip)
+    }
+}
+
+/// The [`ClipperEngine`] backed by the Clipper2 library, giving the same
+/// results as [`Clipper`] and [`inflate`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NativeEngine;
+
+impl<P: PointScaler> ClipperEngine<P> for NativeEngine {
+    fn name(&self) -> &str {
+        "native"
+    }
+
+    fn boolean(
+        &self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+        subjects: &Paths<P>,
+        open_subjects: &Paths<P>,
+        clips: &Paths<P>,
+    ) -> Result<BooleanResult<P>, ClipperError> {
+        Clipper::new()
+            .add_subject_ref(subjects)
+            .add_open_subject(open_subjects.clone())
+            .add_clip_ref(clips)
+            .boolean_operation(clip_type, fill_rule)
+    }
+
+    fn offset(
+        &self,
+        paths: &Paths<P>,
+        delta: f64,
+        join_type: JoinType,
+        end_type: EndType,
+        miter_limit: f64,
+    ) -> Result<Paths<P>, ClipperError> {
+        Ok(inflate(
+            paths.clone(),
+            delta,
+            join_type,
+            end_type,
+            miter_limit,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::Path;
+
+    use super::*;
+
+    /// Counts the boolean operations and passes them on to the native
+    /// engine, failing offsets.
+    #[derive(Default)]
+    struct CountingEngine {
+        booleans: AtomicUsize,
+    }
+
+    impl ClipperEngine for CountingEngine {
+        fn name(&self) -> &str {
+            "counting"
+        }
+
+        fn boolean(
+            &self,
+            clip_type: ClipType,
+            fill_rule: FillRule,
+            subjects: &Paths,
+            open_subjects: &Paths,
+            clips: &Paths,
+        ) -> Result<BooleanResult, ClipperError> {
+            self.booleans.fetch_add(1, Ordering::Relaxed);
+            NativeEngine.boolean(clip_type, fill_rule, subjects, open_subjects, clips)
+        }
+
+        fn offset(
+            &self,
+            _paths: &Paths,
+            _delta: f64,
+            _join_type: JoinType,
+            _end_type: EndType,
+            _miter_limit: f64,
+        ) -> Result<Paths, ClipperError> {
+            Err(ClipperError::EngineFailure {
+                engine: "counting".to_owned(),
+                message: "offsets are not supported".to_owned(),
+            })
+        }
+    }
+
+    fn operands() -> (Paths<Centi>, Paths<Centi>) {
+        (
+            Path::rectangle(0.0, 0.0, 4.0, 4.0).into(),
+            Path::rectangle(2.0, 2.0, 4.0, 4.0).into(),
+        )
+    }
+
+    #[test]
+    fn test_native_engine_matches_clipper() {
+        let (subject, clip) = operands();
+        let engine: &dyn ClipperEngine = &NativeEngine;
+
+        for (result, expected) in [
+            (
+                engine.union(&subject, &clip, FillRule::NonZero),
+                subject
+                    .to_clipper_subject()
+                    .add_clip(clip.clone())
+                    .union(FillRule::NonZero),
+            ),
+            (
+                engine.difference(&subject, &clip, FillRule::NonZero),
+                subject
+                    .to_clipper_subject()
+                    .add_clip(clip.clone())
+                    .difference(FillRule::NonZero),
+            ),
+            (
+                engine.intersect(&subject, &clip, FillRule::NonZero),
+                subject
+                    .to_clipper_subject()
+                    .add_clip(clip.clone())
+                    .intersect(FillRule::NonZero),
+            ),
+            (
+                engine.xor(&subject, &clip, FillRule::NonZero),
+                subject
+                    .to_clipper_subject()
+                    .add_clip(clip.clone())
+                    .xor(FillRule::NonZero),
+            ),
+        ] {
+            assert_eq!(result.unwrap().closed, expected.unwrap().closed);
+        }
+
+        let offset = engine
+            .offset(&subject, 1.0, JoinType::Miter, EndType::Polygon, 2.0)
+            .unwrap();
+        assert_eq!(offset.signed_area(), 36.0);
+    }
+
+    #[test]
+    fn test_open_subjects() {
+        let (_, clip) = operands();
+        let line = Paths::<Centi>::from(vec![(0.0, 3.0), (10.0, 3.0)]);
+
+        let result = NativeEngine
+            .boolean(
+                ClipType::Intersection,
+                FillRule::NonZero,
+                &Paths::default(),
+                &line,
+                &clip,
+            )
+            .unwrap();
+
+        assert!(result.closed.is_empty());
+        assert_eq!(result.open.len(), 1);
+        assert_eq!(result.open[0].bounds().min.x(), 2.0);
+    }
+
+    #[test]
+    fn test_select_engine_at_runtime() {
+        let (subject, clip) = operands();
+        let counting = CountingEngine::default();
+
+        let engines: [&dyn ClipperEngine; 2] = [&NativeEngine, &counting];
+        let areas = engines
+            .iter()
+            .map(|engine| {
+                engine
+                    .union(&subject, &clip, FillRule::NonZero)
+                    .unwrap()
+                    .closed
+                    .signed_area()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(areas, vec![28.0, 28.0]);
+        assert_eq!(counting.booleans.load(Ordering::Relaxed), 1);
+
+        let error = engines[1]
+            .offset(&subject, 1.0, JoinType::Round, EndType::Polygon, 0.0)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "counting engine failed: offsets are not supported"
+        );
+    }
+}
@@ -0,0 +1,562 @@
+use std::{collections::HashMap, rc::Rc};
+
+use clipper2c_sys::{
+    clipper_clipper64, clipper_clipper64_add_clip, clipper_clipper64_add_subject,
+    clipper_clipper64_clear, clipper_clipper64_execute, clipper_clipper64_set_reverse_solution,
+    clipper_clipper64_size, clipper_paths64, clipper_paths64_length, clipper_paths64_size,
+    ClipperClipper64, ClipperPaths64,
+};
+
+use crate::{
+    malloc,
+    memory::{delete_clipper64, delete_paths64},
+    Centi, ClipType, ClipperError, FillRule, Path, Paths, PointScaler,
+};
+
+/// A lazy boolean expression over sets of paths, for CSG style compositions
+/// such as `(a ∪ b) - c`.
+///
+/// Building an expression only records the operations, they are executed
+/// when calling [`Expr::eval`]. Expressions are cheap to clone and a clone
+/// refers to the same node, so an expression used several times is only
+/// evaluated once.
+///
+/// The evaluation keeps all intermediate results in the native
+/// representation of the Clipper2 library and converts only the final result
+/// back to Rust paths. Nested unions, and differences subtracting several
+/// paths from the same subject, are merged into as few invocations of the
+/// clipping engine as the fill rule allows.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let a = Expr::from(Path::<Centi>::rectangle(0.0, 0.0, 4.0, 4.0));
+/// let b: Paths = Path::rectangle(2.0, 0.0, 4.0, 4.0).into();
+/// let c: Paths = Path::rectangle(1.0, 1.0, 4.0, 2.0).into();
+///
+/// let result = Expr::union(a, b).difference(c).eval(FillRule::NonZero).unwrap();
+///
+/// assert_eq!(result.signed_area(), 24.0 - 8.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Expr<P: PointScaler = Centi>(Rc<Node<P>>);
+
+#[derive(Debug)]
+enum Node<P: PointScaler> {
+    Paths(Paths<P>),
+    Operation {
+        clip_type: ClipType,
+        subject: Expr<P>,
+        clip: Expr<P>,
+    },
+}
+
+impl<P: PointScaler> Expr<P> {
+    /// Create an expression evaluating to the paths. Open flags are ignored,
+    /// all paths are treated as closed.
+    pub fn paths(paths: impl Into<Paths<P>>) -> Self {
+        Self(Rc::new(Node::Paths(paths.into())))
+    }
+
+    /// The union of this expression and another one.
+    pub fn union(self, other: impl Into<Expr<P>>) -> Self {
+        self.operation(ClipType::Union, other.into())
+    }
+
+    /// This expression with the area of another one removed.
+    pub fn difference(self, other: impl Into<Expr<P>>) -> Self {
+        self.operation(ClipType::Difference, other.into())
+    }
+
+    /// The intersection of this expression and another one.
+    pub fn intersect(self, other: impl Into<Expr<P>>) -> Self {
+        self.operation(ClipType::Intersection, other.into())
+    }
+
+    /// The exclusive or of this expression and another one.
+    pub fn xor(self, other: impl Into<Expr<P>>) -> Self {
+        self.operation(ClipType::Xor, other.into())
+    }
+
+    fn operation(self, clip_type: ClipType, clip: Expr<P>) -> Self {
+        Self(Rc::new(Node::Operation {
+            clip_type,
+            subject: self,
+            clip,
+        }))
+    }
+
+    /// Evaluate the expression and return the closed paths of the result.
+    ///
+    /// The fill rule applies to the paths the expression was built from.
+    /// Intermediate results count as filled with every fill rule.
+    pub fn eval(&self, fill_rule: FillRule) -> Result<Paths<P>, ClipperError> {
+        self.eval_counting(fill_rule).map(|(paths, _)| paths)
+    }
+
+    /// Evaluate the expression, also returning the number of invocations of
+    /// the clipping engine.
+    fn eval_counting(&self, fill_rule: FillRule) -> Result<(Paths<P>, usize), ClipperError> {
+        let mut evaluator = Evaluator::new(fill_rule);
+        let result = evaluator.evaluate(self)?;
+        let mut paths = Paths::from_clipperpaths64(result.ptr);
+        drop(result);
+
+        // Results are reversed for the negative fill rule, so they count as
+        // filled when used by the next operation.
+        if evaluator.reversed {
+            for path in paths.iter_mut() {
+                path.reverse();
+            }
+        }
+
+        Ok((paths, evaluator.invocations))
+    }
+}
+
+impl<P: PointScaler> From<Paths<P>> for Expr<P> {
+    fn from(paths: Paths<P>) -> Self {
+        Self::paths(paths)
+    }
+}
+
+impl<P: PointScaler> From<Path<P>> for Expr<P> {
+    fn from(path: Path<P>) -> Self {
+        Self::paths(path)
+    }
+}
+
+/// Native paths used as input of an engine invocation. Owned paths are
+/// deleted when the operand is dropped, also when an error ends the
+/// evaluation early.
+struct Operand {
+    ptr: *mut ClipperPaths64,
+    /// Whether the paths are the output of the engine, with a winding number
+    /// of exactly one (or minus one when reversed) inside.
+    normalized: bool,
+    /// Whether the paths are deleted once used, intermediate results that
+    /// are not cached.
+    owned: bool,
+}
+
+impl Drop for Operand {
+    fn drop(&mut self) {
+        if self.owned {
+            unsafe { delete_paths64(self.ptr) }
+        }
+    }
+}
+
+struct Evaluator<P: PointScaler> {
+    clipper: *mut ClipperClipper64,
+    open: *mut ClipperPaths64,
+    fill_rule: FillRule,
+    reversed: bool,
+    /// Results of expressions referenced more than once, by node address.
+    cache: HashMap<*const Node<P>, *mut ClipperPaths64>,
+    invocations: usize,
+}
+
+impl<P: PointScaler> Evaluator<P> {
+    fn new(fill_rule: FillRule) -> Self {
+        let reversed = fill_rule == FillRule::Negative;
+
+        unsafe {
+            let clipper = clipper_clipper64(malloc(clipper_clipper64_size()));
+            clipper_clipper64_set_reverse_solution(clipper, reversed.into());
+
+            Self {
+                clipper,
+                open: clipper_paths64(malloc(clipper_paths64_size())),
+                fill_rule,
+                reversed,
+                cache: HashMap::new(),
+                invocations: 0,
+            }
+        }
+    }
+
+    /// Whether several normalized operands can be added as one set of
+    /// subject or clip paths, which adds up their winding numbers.
+    fn can_merge(&self) -> bool {
+        self.fill_rule != FillRule::EvenOdd
+    }
+
+    fn evaluate(&mut self, expr: &Expr<P>) -> Result<Operand, ClipperError> {
+        let key = Rc::as_ptr(&expr.0);
+        if let Some(&ptr) = self.cache.get(&key) {
+            return Ok(Operand {
+                ptr,
+                normalized: true,
+                owned: false,
+            });
+        }
+
+        let result = match &*expr.0 {
+            Node::Paths(paths) => {
+                let operand = Self::leaf(paths);
+                self.execute(ClipType::Union, vec![operand], Vec::new())?
+            }
+            Node::Operation {
+                clip_type: ClipType::Union,
+                ..
+            } => {
+                let mut operands = Vec::new();
+                self.collect(expr, ClipType::Union, &mut operands)?;
+
+                let mut sets = self.sets(operands).into_iter();
+                let mut result = sets.next().unwrap_or_default();
+                for set in sets {
+                    result = vec![self.execute(ClipType::Union, result, set)?];
+                }
+
+                if result.len() == 1 && result[0].normalized {
+                    result.remove(0)
+                } else {
+                    self.execute(ClipType::Union, result, Vec::new())?
+                }
+            }
+            Node::Operation {
+                clip_type: ClipType::Difference,
+                subject,
+                clip,
+            } => {
+                let mut clips = Vec::new();
+                let mut subject = subject;
+                clips.push(self.operand(clip)?);
+                while let Node::Operation {
+                    clip_type: ClipType::Difference,
+                    subject: inner,
+                    clip,
+                } = &*subject.0
+                {
+                    if Self::is_shared(subject) {
+                        break;
+                    }
+                    clips.push(self.operand(clip)?);
+                    subject = inner;
+                }
+
+                let mut result = self.operand(subject)?;
+                for set in self.sets(clips) {
+                    result = self.execute(ClipType::Difference, vec![result], set)?;
+                }
+                result
+            }
+            Node::Operation {
+                clip_type,
+                subject,
+                clip,
+            } => {
+                let subject = self.operand(subject)?;
+                let clip = self.operand(clip)?;
+                self.execute(*clip_type, vec![subject], vec![clip])?
+            }
+        };
+
+        self.cache_shared(expr, result)
+    }
+
+    /// Cache the result of an expression referenced more than once.
+    fn cache_shared(
+        &mut self,
+        expr: &Expr<P>,
+        mut result: Operand,
+    ) -> Result<Operand, ClipperError> {
+        if Self::is_shared(expr) {
+            self.cache.insert(Rc::as_ptr(&expr.0), result.ptr);
+            result.owned = false;
+        }
+        Ok(result)
+    }
+
+    /// Whether the expression is referenced from more than one place, so
+    /// its result is cached instead of being merged into its parent.
+    fn is_shared(expr: &Expr<P>) -> bool {
+        Rc::strong_count(&expr.0) > 1
+    }
+
+    fn leaf(paths: &Paths<P>) -> Operand {
+        Operand {
+            ptr: paths.as_clipperpaths64(),
+            normalized: false,
+            owned: false,
+        }
+    }
+
+    /// Paths as operand of an operation, evaluating nested operations.
+    fn operand(&mut self, expr: &Expr<P>) -> Result<Operand, ClipperError> {
+        match &*expr.0 {
+            Node::Paths(paths) => Ok(Self::leaf(paths)),
+            Node::Operation { .. } => self.evaluate(expr),
+        }
+    }
+
+    /// Collect the operands of nested operations of the same associative
+    /// type.
+    fn collect(
+        &mut self,
+        expr: &Expr<P>,
+        clip_type: ClipType,
+        operands: &mut Vec<Operand>,
+    ) -> Result<(), ClipperError> {
+        match &*expr.0 {
+            Node::Operation {
+                clip_type: nested,
+                subject,
+                clip,
+            } if *nested == clip_type => {
+                for child in [subject, clip] {
+                    if Self::is_shared(child) {
+                        operands.push(self.operand(child)?);
+                    } else {
+                        self.collect(child, clip_type, operands)?;
+                    }
+                }
+                Ok(())
+            }
+            _ => {
+                operands.push(self.operand(expr)?);
+                Ok(())
+            }
+        }
+    }
+
+    /// Group operands into sets that can be added to the engine together,
+    /// starting with the merged normalized operands.
+    fn sets(&self, operands: Vec<Operand>) -> Vec<Vec<Operand>> {
+        if !self.can_merge() {
+            return operands.into_iter().map(|operand| vec![operand]).collect();
+        }
+
+        let (normalized, raw): (Vec<_>, Vec<_>) =
+            operands.into_iter().partition(|operand| operand.normalized);
+
+        let mut sets = Vec::with_capacity(raw.len() + 1);
+        if !normalized.is_empty() {
+            sets.push(normalized);
+        }
+        sets.extend(raw.into_iter().map(|operand| vec![operand]));
+        sets
+    }
+
+    fn execute(
+        &mut self,
+        clip_type: ClipType,
+        subjects: Vec<Operand>,
+        clips: Vec<Operand>,
+    ) -> Result<Operand, ClipperError> {
+        self.invocations += 1;
+
+        let (success, closed) = unsafe {
+            clipper_clipper64_clear(self.clipper);
+            for subject in &subjects {
+                clipper_clipper64_add_subject(self.clipper, subject.ptr);
+            }
+            for clip in &clips {
+                clipper_clipper64_add_clip(self.clipper, clip.ptr);
+            }
+
+            let closed = clipper_paths64(malloc(clipper_paths64_size()));
+            let success = clipper_clipper64_execute(
+                self.clipper,
+                clip_type.into(),
+                self.fill_rule.into(),
+                closed,
+                self.open,
+            );
+            (success, closed)
+        };
+
+        let count = |operands: &[Operand]| {
+            operands
+                .iter()
+                .map(|operand| unsafe { clipper_paths64_length(operand.ptr) })
+                .sum()
+        };
+        let error = (success != 1).then(|| ClipperError::NativeFailure {
+            operation: clip_type.name(),
+            fill_rule: self.fill_rule,
+            code: success,
+            subject_paths: count(&subjects),
+            open_subject_paths: 0,
+            clip_paths: count(&clips),
+        });
+
+        // The operands are dropped, deleting intermediate results.
+        drop((subjects, clips));
+
+        match error {
+            None => Ok(Operand {
+                ptr: closed,
+                normalized: true,
+                owned: true,
+            }),
+            Some(error) => {
+                unsafe { delete_paths64(closed) };
+                Err(error)
+            }
+        }
+    }
+}
+
+impl<P: PointScaler> Drop for Evaluator<P> {
+    fn drop(&mut self) {
+        unsafe {
+            for &ptr in self.cache.values() {
+                delete_paths64(ptr);
+            }
+            delete_paths64(self.open);
+            delete_clipper64(self.clipper);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn squares() -> Vec<Paths<Centi>> {
+        (0..4)
+            .map(|i| Path::rectangle(i as f64, 0.0, 2.0, 2.0).into())
+            .collect()
+    }
+
+    #[test]
+    fn test_nested_unions_use_fewer_invocations() {
+        let [a, b, c, d] = <[_; 4]>::try_from(squares()).unwrap();
+
+        let left = Expr::from(a.clone()).union(b.clone());
+        let right = Expr::from(c.clone()).union(d.clone());
+        let expr = left.union(right);
+
+        let (result, invocations) = expr.eval_counting(FillRule::NonZero).unwrap();
+        assert_eq!(result.signed_area(), 10.0);
+        assert_eq!(invocations, 3);
+
+        // Intermediate results are merged into one set of subjects, unless
+        // the fill rule is even-odd.
+        let squares = squares();
+        let unions = squares
+            .windows(2)
+            .map(|pair| Expr::from(pair[0].clone()).intersect(pair[1].clone()))
+            .reduce(Expr::union)
+            .unwrap();
+        let (result, invocations) = unions.eval_counting(FillRule::NonZero).unwrap();
+        assert_eq!(result.signed_area(), 6.0);
+        assert_eq!(invocations, 4);
+
+        let (result, invocations) = unions.eval_counting(FillRule::EvenOdd).unwrap();
+        assert_eq!(result.signed_area(), 6.0);
+        assert_eq!(invocations, 5);
+    }
+
+    #[test]
+    fn test_intersection_chain_stays_native() {
+        let squares = squares();
+        let expr = squares
+            .iter()
+            .cloned()
+            .map(Expr::from)
+            .reduce(Expr::intersect)
+            .unwrap();
+
+        let (result, invocations) = expr.eval_counting(FillRule::NonZero).unwrap();
+        assert_eq!(result.signed_area(), 0.0);
+        assert_eq!(invocations, 3);
+
+        let (result, invocations) = Expr::from(squares[0].clone())
+            .intersect(squares[1].clone())
+            .intersect(squares[1].clone())
+            .eval_counting(FillRule::NonZero)
+            .unwrap();
+        assert_eq!(result.signed_area(), 2.0);
+        assert_eq!(invocations, 2);
+    }
+
+    #[test]
+    fn test_shared_subexpressions_are_evaluated_once() {
+        let [a, b, c, _] = <[_; 4]>::try_from(squares()).unwrap();
+
+        let shared = Expr::from(a.clone()).intersect(b.clone());
+        let expr = shared.clone().union(c.clone()).xor(shared);
+
+        let (result, invocations) = expr.eval_counting(FillRule::NonZero).unwrap();
+        assert_eq!(invocations, 3);
+
+        let expected = a
+            .intersect_with(&b, FillRule::NonZero)
+            .unwrap()
+            .union_with(&c, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(result.signed_area(), expected.signed_area() - 2.0);
+    }
+
+    #[test]
+    fn test_difference_chain() {
+        let plate = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 10.0, 10.0));
+        let holes = (0..4)
+            .map(|i| Paths::from(Path::rectangle(i as f64 * 2.0 + 1.0, 1.0, 1.0, 1.0)))
+            .collect::<Vec<_>>();
+
+        let expr = holes
+            .iter()
+            .cloned()
+            .fold(Expr::from(plate.clone()), Expr::difference);
+
+        let (result, invocations) = expr.eval_counting(FillRule::NonZero).unwrap();
+        assert_eq!(result.signed_area(), 100.0 - 4.0);
+        assert_eq!(invocations, 4);
+
+        // Differences of intermediate results are merged into one set.
+        let expr = holes
+            .chunks(2)
+            .map(|pair| Expr::from(pair[0].clone()).union(pair[1].clone()))
+            .fold(Expr::from(plate), Expr::difference);
+        let (result, invocations) = expr.eval_counting(FillRule::NonZero).unwrap();
+        assert_eq!(result.signed_area(), 100.0 - 4.0);
+        assert_eq!(invocations, 3);
+    }
+
+    #[test]
+    fn test_fill_rules() {
+        let [a, b, c, d] = <[_; 4]>::try_from(squares()).unwrap();
+        let reversed = Paths::<Centi>::new(
+            d.iter()
+                .map(|path| path.iter().rev().copied().collect())
+                .collect(),
+        );
+        let expr = Expr::from(a.clone())
+            .union(b.clone())
+            .union(reversed.clone())
+            .difference(c.clone());
+
+        for fill_rule in [FillRule::EvenOdd, FillRule::NonZero, FillRule::Positive] {
+            let expected = a
+                .union_with(&b, fill_rule)
+                .unwrap()
+                .union_with(&reversed, fill_rule)
+                .unwrap()
+                .difference_with(&c, fill_rule)
+                .unwrap();
+
+            let result = expr.eval(fill_rule).unwrap();
+            assert_eq!(
+                result.signed_area(),
+                expected.signed_area(),
+                "{fill_rule:?}"
+            );
+        }
+
+        // Only the reversed square is filled, and the intermediate union
+        // still counts as filled when subtracting.
+        let result = expr.eval(FillRule::Negative).unwrap();
+        assert_eq!(result.signed_area(), 4.0);
+        assert_eq!(
+            result,
+            reversed.union_with(&reversed, FillRule::Negative).unwrap()
+        );
+    }
+}
@@ -0,0 +1,291 @@
+//! Linear extrusion of polygons into 3D triangle meshes.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use clipper2::*;
+//! use clipper2::extrude::extrude;
+//!
+//! let plate: Paths = vec![
+//!     Path::rectangle(0.0, 0.0, 10.0, 10.0),
+//!     Path::rectangle(4.0, 4.0, 2.0, 2.0),
+//! ]
+//! .into();
+//! let tree = PolyTree::from_paths(&plate, FillRule::EvenOdd);
+//!
+//! let mesh = extrude(&tree, 3.0);
+//!
+//! assert_eq!(mesh.volume(), (100.0 - 4.0) * 3.0);
+//!
+//! let mut stl = Vec::new();
+//! mesh.write_stl(&mut stl).unwrap();
+//! assert_eq!(stl.len(), 84 + 50 * mesh.triangles.len());
+//! ```
+
+use std::io::{self, Write};
+
+use crate::{Path, PointScaler, PolyTree, Triangulation};
+
+/// An indexed 3D triangle mesh, as produced by [`extrude`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Mesh {
+    /// The vertices of the mesh as x, y and z coordinates.
+    pub vertices: Vec<[f64; 3]>,
+    /// Triangles as indices into `vertices`, all wound counter-clockwise
+    /// when seen from outside of the mesh.
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl Mesh {
+    /// Returns the volume enclosed by the mesh, negative if the triangles
+    /// are wound the wrong way round.
+    pub fn volume(&self) -> f64 {
+        self.triangles
+            .iter()
+            .map(|&[a, b, c]| {
+                let (a, b, c) = (self.vertices[a], self.vertices[b], self.vertices[c]);
+                (a[0] * (b[1] * c[2] - b[2] * c[1]) - a[1] * (b[0] * c[2] - b[2] * c[0])
+                    + a[2] * (b[0] * c[1] - b[1] * c[0]))
+                    / 6.0
+            })
+            .sum()
+    }
+
+    /// Write the mesh as a binary STL file, with a facet normal computed for
+    /// each triangle.
+    ///
+    /// STL files have no units, the coordinates are written as they are.
+    pub fn write_stl(&self, mut writer: impl Write) -> io::Result<()> {
+        let count = u32::try_from(self.triangles.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "too many triangles"))?;
+
+        let mut header = [0u8; 80];
+        let title = b"clipper2 extrusion";
+        header[..title.len()].copy_from_slice(title);
+        writer.write_all(&header)?;
+        writer.write_all(&count.to_le_bytes())?;
+
+        for &[a, b, c] in &self.triangles {
+            let (a, b, c) = (self.vertices[a], self.vertices[b], self.vertices[c]);
+            let (u, v) = (
+                [b[0] - a[0], b[1] - a[1], b[2] - a[2]],
+                [c[0] - a[0], c[1] - a[1], c[2] - a[2]],
+            );
+            let normal = [
+                u[1] * v[2] - u[2] * v[1],
+                u[2] * v[0] - u[0] * v[2],
+                u[0] * v[1] - u[1] * v[0],
+            ];
+            let length = (normal[0].powi(2) + normal[1].powi(2) + normal[2].powi(2)).sqrt();
+            let normal = if length > 0.0 {
+                normal.map(|n| n / length)
+            } else {
+                [0.0; 3]
+            };
+
+            for vector in [normal, a, b, c] {
+                for value in vector {
+                    writer.write_all(&(value as f32).to_le_bytes())?;
+                }
+            }
+            writer.write_all(&[0, 0])?;
+        }
+
+        writer.flush()
+    }
+
+    /// Save the mesh as a binary STL file, see [`Mesh::write_stl`].
+    pub fn save_stl(&self, path: impl AsRef<std::path::Path>) -> io::Result<()> {
+        self.write_stl(io::BufWriter::new(std::fs::File::create(path)?))
+    }
+}
+
+/// This function extrudes the polygons of a tree straight up into a closed
+/// triangle mesh, as a quick way from a 2D design to a part for printing or
+/// milling.
+///
+/// Each outer polygon with its holes becomes a solid `height` tall, with the
+/// bottom at z = 0 and the top at z = `height`. Holes go all the way through
+/// and islands within holes become separate solids. A negative height
+/// extrudes downwards.
+///
+/// The bottom and top are triangulated like [`PolyTree::triangulate`] and
+/// share their vertices with the walls, so the mesh is watertight as long as
+/// the polygons are valid, such as the output of a boolean operation or
+/// [`PolyTree::from_paths`]. Points along straight edges are dropped.
+///
+/// Returns an empty mesh if `height` is zero or not a number.
+pub fn extrude<P: PointScaler>(tree: &PolyTree<P>, height: f64) -> Mesh {
+    let mut mesh = Mesh::default();
+    if height.is_nan() || height == 0.0 {
+        return mesh;
+    }
+
+    for (outer, holes) in tree.outers_with_holes() {
+        let outer = without_straight_points(&outer);
+        if outer.len() < 3 {
+            continue;
+        }
+        let holes = holes
+            .iter()
+            .map(without_straight_points)
+            .filter(|hole| hole.len() >= 3)
+            .collect::<Vec<_>>();
+
+        let mut caps = Triangulation::default();
+        caps.add_polygon(&outer, &holes);
+
+        // The bottom vertices come first, followed by the top vertices.
+        let offset = mesh.vertices.len();
+        let count = caps.vertices.len();
+        for z in [0.0, height] {
+            mesh.vertices
+                .extend(caps.vertices.iter().map(|point| [point.x(), point.y(), z]));
+        }
+
+        for &[a, b, c] in &caps.triangles {
+            mesh.triangles.push([offset + a, offset + c, offset + b]);
+            mesh.triangles
+                .push([offset + count + a, offset + count + b, offset + count + c]);
+        }
+
+        // The rings were added to the triangulation one after the other.
+        let mut start = offset;
+        for (ring, is_outer) in
+            std::iter::once((&outer, true)).chain(holes.iter().map(|h| (h, false)))
+        {
+            let len = ring.len();
+            // Walk the ring with the inside on the left, counter-clockwise
+            // around outers and clockwise around holes.
+            let reverse = (ring.signed_area() > 0.0) != is_outer;
+            for i in 0..len {
+                let (mut a, mut b) = (start + i, start + (i + 1) % len);
+                if reverse {
+                    (a, b) = (b, a);
+                }
+                mesh.triangles.push([a, b, b + count]);
+                mesh.triangles.push([a, b + count, a + count]);
+            }
+            start += len;
+        }
+    }
+
+    if height < 0.0 {
+        for triangle in &mut mesh.triangles {
+            triangle.swap(1, 2);
+        }
+    }
+
+    mesh
+}
+
+/// Remove repeated points and points in line with their neighbors, which
+/// the triangulation would leave out of the caps but the walls would use.
+fn without_straight_points<P: PointScaler>(path: &Path<P>) -> Path<P> {
+    let mut points = path.iter().copied().collect::<Vec<_>>();
+
+    let mut changed = true;
+    while changed && points.len() >= 3 {
+        changed = false;
+        let mut i = 0;
+        while i < points.len() && points.len() >= 3 {
+            let len = points.len();
+            let (prev, point, next) = (
+                points[(i + len - 1) % len],
+                points[i],
+                points[(i + 1) % len],
+            );
+            let cross = (point.x_scaled() - prev.x_scaled()) as i128
+                * (next.y_scaled() - point.y_scaled()) as i128
+                - (point.y_scaled() - prev.y_scaled()) as i128
+                    * (next.x_scaled() - point.x_scaled()) as i128;
+            if cross == 0 {
+                points.remove(i);
+                changed = true;
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    points.into()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::{Centi, FillRule, Paths};
+
+    use super::*;
+
+    /// Every edge is used once in each direction.
+    fn is_watertight(mesh: &Mesh) -> bool {
+        let mut edges = HashMap::new();
+        for &[a, b, c] in &mesh.triangles {
+            for edge in [(a, b), (b, c), (c, a)] {
+                *edges.entry(edge).or_insert(0) += 1;
+            }
+        }
+        edges
+            .iter()
+            .all(|(&(a, b), &count)| count == 1 && edges.get(&(b, a)) == Some(&1))
+    }
+
+    fn tree(paths: Vec<Path<Centi>>) -> PolyTree<Centi> {
+        PolyTree::from_paths(&Paths::new(paths), FillRule::EvenOdd)
+    }
+
+    #[test]
+    fn test_extrude_box() {
+        let mesh = extrude(&tree(vec![Path::rectangle(0.0, 0.0, 2.0, 3.0)]), 4.0);
+
+        assert_eq!(mesh.vertices.len(), 8);
+        assert_eq!(mesh.triangles.len(), 12);
+        assert_eq!(mesh.volume(), 24.0);
+        assert!(is_watertight(&mesh));
+    }
+
+    #[test]
+    fn test_holes_islands_and_straight_points() {
+        let mesh = extrude(
+            &tree(vec![
+                vec![
+                    (0.0, 0.0),
+                    (5.0, 0.0),
+                    (10.0, 0.0),
+                    (10.0, 10.0),
+                    (0.0, 10.0),
+                ]
+                .into(),
+                Path::rectangle(2.0, 2.0, 6.0, 6.0),
+                Path::rectangle(4.0, 4.0, 2.0, 2.0),
+            ]),
+            -2.0,
+        );
+
+        assert_eq!(mesh.vertices.len(), 2 * (4 + 4 + 4));
+        assert!((mesh.volume() - (100.0 - 36.0 + 4.0) * 2.0).abs() < 1e-9);
+        assert!(mesh.vertices.iter().all(|vertex| vertex[2] <= 0.0));
+        assert!(is_watertight(&mesh));
+    }
+
+    #[test]
+    fn test_write_stl() {
+        let mesh = extrude(&tree(vec![Path::rectangle(0.0, 0.0, 1.0, 1.0)]), 1.0);
+
+        let mut stl = Vec::new();
+        mesh.write_stl(&mut stl).unwrap();
+
+        assert_eq!(stl.len(), 84 + 12 * 50);
+        assert_eq!(u32::from_le_bytes(stl[80..84].try_into().unwrap()), 12);
+        // The first triangle is part of the bottom, facing down.
+        let normal_z = f32::from_le_bytes(stl[92..96].try_into().unwrap());
+        assert_eq!(normal_z, -1.0);
+
+        assert_eq!(
+            extrude(&tree(vec![Path::rectangle(0.0, 0.0, 1.0, 1.0)]), 0.0),
+            Mesh::default()
+        );
+    }
+}
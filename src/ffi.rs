@@ -0,0 +1,321 @@
+//! Functions exported by the `clipper2c` library that `clipper2c-sys` does
+//! not generate bindings for. The library is linked through `clipper2c-sys`.
+
+use clipper2c_sys::{
+    ClipperClipType, ClipperClipper64, ClipperClipperD, ClipperClipperOffset, ClipperFillRule,
+    ClipperPath64, ClipperPathD, ClipperPaths64, ClipperPathsD, ClipperPoint64, ClipperPointD,
+    ClipperPolyTree64, ClipperPolyTreeD,
+};
+
+/// A rectangle in scaled coordinates, laid out like the native `Rect64`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClipperRect64 {
+    /// Smallest x coordinate
+    pub left: i64,
+    /// Smallest y coordinate
+    pub top: i64,
+    /// Largest x coordinate
+    pub right: i64,
+    /// Largest y coordinate
+    pub bottom: i64,
+}
+
+extern "C" {
+    /// Copy all points of the path into `mem`, which must have room for
+    /// `clipper_path64_length(path)` points.
+    pub(crate) fn clipper_path64_to_points(
+        mem: *mut libc::c_void,
+        path: *mut ClipperPath64,
+    ) -> *mut ClipperPoint64;
+
+    /// Write the length of each path into `mem`, which must have room for
+    /// `clipper_paths64_length(paths)` values.
+    pub(crate) fn clipper_paths64_lengths(
+        mem: *mut libc::c_void,
+        paths: *mut ClipperPaths64,
+    ) -> *mut usize;
+
+    /// Copy all points of each path `i` into `mem[i]`, which must have room
+    /// for the length of that path.
+    pub(crate) fn clipper_paths64_to_points(
+        mem: *mut *mut libc::c_void,
+        paths: *mut ClipperPaths64,
+    ) -> *mut *mut ClipperPoint64;
+
+    /// Run the destructor of the path without freeing its memory.
+    pub(crate) fn clipper_destruct_path64(p: *mut ClipperPath64);
+
+    /// Run the destructor of the paths without freeing their memory.
+    pub(crate) fn clipper_destruct_paths64(p: *mut ClipperPaths64);
+
+    /// Run the destructor of the polytree, which frees its child nodes, without
+    /// freeing the memory of the root node.
+    pub(crate) fn clipper_destruct_polytree64(p: *mut ClipperPolyTree64);
+
+    /// Run the destructor of the clipper without freeing its memory.
+    pub(crate) fn clipper_destruct_clipper64(p: *mut ClipperClipper64);
+
+    /// Copy all points of the double precision path into `mem`, which must
+    /// have room for `clipper_pathd_length(path)` points.
+    pub(crate) fn clipper_pathd_to_points(
+        mem: *mut libc::c_void,
+        path: *mut ClipperPathD,
+    ) -> *mut ClipperPointD;
+
+    /// Run the destructor of the double precision path without freeing its
+    /// memory.
+    pub(crate) fn clipper_destruct_pathd(p: *mut ClipperPathD);
+
+    /// Run the destructor of the double precision paths without freeing
+    /// their memory.
+    pub(crate) fn clipper_destruct_pathsd(p: *mut ClipperPathsD);
+
+    /// Run the destructor of the double precision clipper without freeing
+    /// its memory.
+    pub(crate) fn clipper_destruct_clipperd(p: *mut ClipperClipperD);
+
+    /// Run the destructor of the double precision polytree, which frees its
+    /// child nodes, without freeing the memory of the root node.
+    pub(crate) fn clipper_destruct_polytreed(p: *mut ClipperPolyTreeD);
+
+    /// Clip closed paths to the rectangle, writing the result into `mem`,
+    /// which must have room for `clipper_paths64_size()` bytes.
+    pub(crate) fn clipper_paths64_rect_clip(
+        mem: *mut libc::c_void,
+        rect: *mut ClipperRect64,
+        paths: *mut ClipperPaths64,
+    ) -> *mut ClipperPaths64;
+
+    /// Write the bounds of all paths into `mem`. Empty paths have inverted
+    /// bounds from `i64::MAX` to `i64::MIN`.
+    pub(crate) fn clipper_paths64_bounds(
+        mem: *mut libc::c_void,
+        paths: *mut ClipperPaths64,
+    ) -> *mut ClipperRect64;
+
+    /// Translate the paths by whole scaled units, writing the result into
+    /// `mem`, which must have room for `clipper_paths64_size()` bytes.
+    pub(crate) fn clipper_paths64_translate(
+        mem: *mut libc::c_void,
+        paths: *mut ClipperPaths64,
+        dx: i64,
+        dy: i64,
+    ) -> *mut ClipperPaths64;
+
+    /// Scale the paths around the origin, writing the result into `mem`,
+    /// which must have room for `clipper_paths64_size()` bytes. The factors
+    /// must be non-zero and keep all coordinates in range, as the native
+    /// library throws otherwise.
+    pub(crate) fn clipper_paths64_scale(
+        mem: *mut libc::c_void,
+        paths: *mut ClipperPaths64,
+        sx: f64,
+        sy: f64,
+        error_code: *mut libc::c_int,
+    ) -> *mut ClipperPaths64;
+
+    /// Clip open paths to the rectangle, writing the result into `mem`, which
+    /// must have room for `clipper_paths64_size()` bytes.
+    pub(crate) fn clipper_paths64_rect_clip_lines(
+        mem: *mut libc::c_void,
+        rect: *mut ClipperRect64,
+        paths: *mut ClipperPaths64,
+    ) -> *mut ClipperPaths64;
+
+    /// Run the destructor of the offsetter without freeing its memory.
+    pub(crate) fn clipper_destruct_clipperoffset(p: *mut ClipperClipperOffset);
+
+    /// Apply a boolean operation to closed subjects and clips, writing the
+    /// result into `mem`, which must have room for `clipper_paths64_size()`
+    /// bytes.
+    pub(crate) fn clipper_paths64_boolean_op(
+        mem: *mut libc::c_void,
+        cliptype: ClipperClipType,
+        fillrule: ClipperFillRule,
+        subjects: *mut ClipperPaths64,
+        clips: *mut ClipperPaths64,
+    ) -> *mut ClipperPaths64;
+
+    /// Apply a boolean operation to closed subjects and clips, writing the
+    /// result into an existing polytree.
+    pub(crate) fn clipper_paths64_boolean_op_tree(
+        cliptype: ClipperClipType,
+        fillrule: ClipperFillRule,
+        subjects: *mut ClipperPaths64,
+        clips: *mut ClipperPaths64,
+        solution: *mut ClipperPolyTree64,
+    );
+
+    /// Write the bounds of the path into `mem`.
+    pub(crate) fn clipper_path64_bounds(
+        mem: *mut libc::c_void,
+        path: *mut ClipperPath64,
+    ) -> *mut ClipperRect64;
+
+    /// Clip a closed path to the rectangle, writing the result into `mem`.
+    pub(crate) fn clipper_path64_rect_clip(
+        mem: *mut libc::c_void,
+        rect: *mut ClipperRect64,
+        path: *mut ClipperPath64,
+    ) -> *mut ClipperPaths64;
+
+    /// Clip an open path to the rectangle, writing the result into `mem`.
+    pub(crate) fn clipper_path64_rect_clip_line(
+        mem: *mut libc::c_void,
+        rect: *mut ClipperRect64,
+        path: *mut ClipperPath64,
+    ) -> *mut ClipperPaths64;
+
+    /// Reserve room for `size` points in the path.
+    pub(crate) fn clipper_path64_reserve(path: *mut ClipperPath64, size: usize);
+
+    /// Reserve room for `size` paths.
+    pub(crate) fn clipper_paths64_reserve(paths: *mut ClipperPaths64, size: usize);
+
+    /// Build an ellipse around `center` into `mem`. With `steps` of zero the
+    /// number of points is derived from the radii.
+    pub(crate) fn clipper_path64_ellipse(
+        mem: *mut libc::c_void,
+        center: ClipperPoint64,
+        radius_x: f64,
+        radius_y: f64,
+        steps: libc::c_int,
+    ) -> *mut ClipperPath64;
+
+    /// Translate the path by whole scaled units, writing the result into
+    /// `mem`.
+    pub(crate) fn clipper_path64_translate(
+        mem: *mut libc::c_void,
+        path: *mut ClipperPath64,
+        dx: i64,
+        dy: i64,
+    ) -> *mut ClipperPath64;
+
+    /// Scale the path around the origin, writing the result into `mem`. See
+    /// `clipper_paths64_scale` for the requirements on the factors.
+    pub(crate) fn clipper_path64_scale(
+        mem: *mut libc::c_void,
+        path: *mut ClipperPath64,
+        sx: f64,
+        sy: f64,
+        error_code: *mut libc::c_int,
+    ) -> *mut ClipperPath64;
+
+    /// Remove collinear points from the path, writing the result into `mem`.
+    pub(crate) fn clipper_path64_trim_collinear(
+        mem: *mut libc::c_void,
+        path: *mut ClipperPath64,
+        is_open_path: libc::c_int,
+    ) -> *mut ClipperPath64;
+
+    /// Simplify the path with the Ramer-Douglas-Peucker algorithm, writing
+    /// the result into `mem`.
+    pub(crate) fn clipper_path64_ramer_douglas_peucker(
+        mem: *mut libc::c_void,
+        path: *mut ClipperPath64,
+        epsilon: f64,
+    ) -> *mut ClipperPath64;
+
+    /// Simplify the paths with the Ramer-Douglas-Peucker algorithm, writing
+    /// the result into `mem`.
+    pub(crate) fn clipper_paths64_ramer_douglas_peucker(
+        mem: *mut libc::c_void,
+        paths: *mut ClipperPaths64,
+        epsilon: f64,
+    ) -> *mut ClipperPaths64;
+
+    /// Remove points closer than the square root of `max_dist_sqrd` to the
+    /// previous point, writing the result into `mem`.
+    pub(crate) fn clipper_path64_strip_near_equal(
+        mem: *mut libc::c_void,
+        path: *mut ClipperPath64,
+        max_dist_sqrd: f64,
+        is_closed_path: libc::c_int,
+    ) -> *mut ClipperPath64;
+
+    /// Remove points closer than the square root of `max_dist_sqrd` to the
+    /// previous point from each path, writing the result into `mem`.
+    pub(crate) fn clipper_paths64_strip_near_equal(
+        mem: *mut libc::c_void,
+        paths: *mut ClipperPaths64,
+        max_dist_sqrd: f64,
+        is_closed_paths: libc::c_int,
+    ) -> *mut ClipperPaths64;
+
+    /// Remove repeated points from the path in place.
+    pub(crate) fn clipper_path64_strip_duplicates(
+        path: *mut ClipperPath64,
+        is_closed_path: libc::c_int,
+    );
+
+    /// Remove repeated points from each path in place.
+    pub(crate) fn clipper_paths64_strip_duplicates(
+        paths: *mut ClipperPaths64,
+        is_closed_paths: libc::c_int,
+    );
+
+    /// Minkowski sum of the pattern along the path, writing the result into
+    /// `mem`.
+    pub(crate) fn clipper_path64_minkowski_sum(
+        mem: *mut libc::c_void,
+        pattern: *mut ClipperPath64,
+        path: *mut ClipperPath64,
+        is_closed: libc::c_int,
+    ) -> *mut ClipperPaths64;
+
+    /// Minkowski difference of the pattern along the path, writing the result
+    /// into `mem`.
+    pub(crate) fn clipper_path64_minkowski_diff(
+        mem: *mut libc::c_void,
+        pattern: *mut ClipperPath64,
+        path: *mut ClipperPath64,
+        is_closed: libc::c_int,
+    ) -> *mut ClipperPaths64;
+
+    /// Minkowski sum of the pattern along each path, joined with the fill
+    /// rule, writing the result into `mem`.
+    pub(crate) fn clipper_paths64_minkowski_sum(
+        mem: *mut libc::c_void,
+        pattern: *mut ClipperPath64,
+        paths: *mut ClipperPaths64,
+        is_closed: libc::c_int,
+        fillrule: ClipperFillRule,
+    ) -> *mut ClipperPaths64;
+
+    /// Minkowski difference of the pattern along each path, joined with the
+    /// fill rule, writing the result into `mem`.
+    pub(crate) fn clipper_paths64_minkowski_diff(
+        mem: *mut libc::c_void,
+        pattern: *mut ClipperPath64,
+        paths: *mut ClipperPaths64,
+        is_closed: libc::c_int,
+        fillrule: ClipperFillRule,
+    ) -> *mut ClipperPaths64;
+
+    /// Distance between two points.
+    pub(crate) fn clipper_point64_distance(a: ClipperPoint64, b: ClipperPoint64) -> f64;
+
+    /// Whether the angle at `b` is within the angle with the given squared
+    /// sine of a straight line.
+    pub(crate) fn clipper_point64_near_collinear(
+        a: ClipperPoint64,
+        b: ClipperPoint64,
+        c: ClipperPoint64,
+        sin_sqrd_min_angle_rads: f64,
+    ) -> libc::c_int;
+
+    /// Whether the path has a positive area.
+    pub(crate) fn clipper_path64_is_positive(path: *mut ClipperPath64) -> libc::c_int;
+
+    /// Remove all children of the polytree node.
+    pub(crate) fn clipper_polytree64_clear(pt: *mut ClipperPolyTree64);
+
+    /// Depth of the polytree node, zero for the root.
+    pub(crate) fn clipper_polytree64_level(pt: *mut ClipperPolyTree64) -> libc::c_int;
+
+    /// Whether the polygons of all descendants lie inside their parents.
+    pub(crate) fn clipper_polytree64_fully_contains_children(
+        pt: *mut ClipperPolyTree64,
+    ) -> libc::c_int;
+}
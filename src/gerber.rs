@@ -0,0 +1,239 @@
+use std::fmt::Write;
+
+use crate::{Path, Paths, PointScaler, PolyTree};
+
+/// The unit that path coordinates are given in when exporting to Gerber.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum GerberUnit {
+    /// Coordinates are in millimeters
+    #[default]
+    Millimeters,
+    /// Coordinates are in inches
+    Inches,
+}
+
+/// Options for the Gerber (RS-274X) export.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GerberOptions {
+    /// The unit of the path coordinates, defaults to millimeters.
+    pub unit: GerberUnit,
+    /// Number of decimal digits in the coordinate format, defaults to 6.
+    /// The Gerber specification allows 5 or 6.
+    pub decimal_digits: u8,
+}
+
+impl Default for GerberOptions {
+    fn default() -> Self {
+        Self {
+            unit: GerberUnit::Millimeters,
+            decimal_digits: 6,
+        }
+    }
+}
+
+impl<P: PointScaler> Paths<P> {
+    /// Export the closed paths as a Gerber (RS-274X) file of filled regions.
+    ///
+    /// Paths with a positive signed area are written as dark regions first,
+    /// then paths with a negative signed area are written with clear polarity
+    /// to cut them out as holes. Islands nested inside holes can not be
+    /// represented this way, use [`PolyTree::to_gerber`] for those.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 5.0), (0.0, 5.0)].into();
+    /// let gerber = paths.to_gerber(&GerberOptions::default());
+    ///
+    /// assert!(gerber.contains("G36*\nX0Y0D02*\nX10000000Y0D01*"));
+    /// ```
+    pub fn to_gerber(&self, options: &GerberOptions) -> String {
+        let mut writer = GerberWriter::new(options);
+
+        let (outers, holes): (Vec<_>, Vec<_>) =
+            self.iter().partition(|path| path.signed_area() >= 0.0);
+
+        for path in outers {
+            writer.region(path, true);
+        }
+
+        for path in holes {
+            writer.region(path, false);
+        }
+
+        writer.finish()
+    }
+}
+
+impl<P: PointScaler> PolyTree<P> {
+    /// Export the tree as a Gerber (RS-274X) file of filled regions.
+    ///
+    /// The tree is written depth first so that each outer polygon is drawn
+    /// with dark polarity, its holes are then cut out with clear polarity,
+    /// and islands inside the holes are drawn dark again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let outer: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+    /// let hole: Paths = Path::rectangle(2.0, 2.0, 6.0, 6.0).into();
+    ///
+    /// let tree = outer
+    ///     .to_clipper_subject()
+    ///     .add_clip(hole)
+    ///     .difference_tree(FillRule::default())
+    ///     .unwrap()
+    ///     .tree;
+    /// let gerber = tree.to_gerber(&GerberOptions::default());
+    ///
+    /// assert!(gerber.contains("%LPC*%"));
+    /// ```
+    pub fn to_gerber(&self, options: &GerberOptions) -> String {
+        let mut writer = GerberWriter::new(options);
+        self.write_gerber_regions(&mut writer);
+        writer.finish()
+    }
+
+    fn write_gerber_regions(&self, writer: &mut GerberWriter) {
+        writer.region(&self.polygon, !self.is_hole);
+
+        for child in &self.children {
+            child.write_gerber_regions(writer);
+        }
+    }
+}
+
+struct GerberWriter {
+    output: String,
+    multiplier: f64,
+    dark: bool,
+}
+
+impl GerberWriter {
+    fn new(options: &GerberOptions) -> Self {
+        let decimal_digits = options.decimal_digits.clamp(5, 6);
+        let unit = match options.unit {
+            GerberUnit::Millimeters => "MM",
+            GerberUnit::Inches => "IN",
+        };
+
+        let mut output = String::new();
+        let _ = writeln!(output, "G04 Generated by clipper2*");
+        let _ = writeln!(output, "%FSLAX6{decimal_digits}Y6{decimal_digits}*%");
+        let _ = writeln!(output, "%MO{unit}*%");
+        let _ = writeln!(output, "%LPD*%");
+        let _ = writeln!(output, "G01*");
+
+        Self {
+            output,
+            multiplier: 10f64.powi(decimal_digits.into()),
+            dark: true,
+        }
+    }
+
+    fn region<P: PointScaler>(&mut self, path: &Path<P>, dark: bool) {
+        if path.len() < 3 {
+            return;
+        }
+
+        if dark != self.dark {
+            self.output
+                .push_str(if dark { "%LPD*%\n" } else { "%LPC*%\n" });
+            self.dark = dark;
+        }
+
+        self.output.push_str("G36*\n");
+
+        for (index, point) in path.iter().chain(path.iter().take(1)).enumerate() {
+            let operation = if index == 0 { "D02" } else { "D01" };
+            let _ = writeln!(
+                self.output,
+                "X{}Y{}{operation}*",
+                (point.x() * self.multiplier).round() as i64,
+                (point.y() * self.multiplier).round() as i64,
+            );
+        }
+
+        self.output.push_str("G37*\n");
+    }
+
+    fn finish(mut self) -> String {
+        self.output.push_str("M02*\n");
+        self.output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, FillRule};
+
+    use super::*;
+
+    #[test]
+    fn test_paths_region() {
+        let paths: Paths<Centi> = Path::rectangle(0.0, 0.0, 1.5, 2.0).into();
+        let gerber = paths.to_gerber(&GerberOptions {
+            unit: GerberUnit::Inches,
+            decimal_digits: 5,
+        });
+
+        assert_eq!(
+            gerber,
+            "G04 Generated by clipper2*\n\
+             %FSLAX65Y65*%\n\
+             %MOIN*%\n\
+             %LPD*%\n\
+             G01*\n\
+             G36*\n\
+             X0Y0D02*\n\
+             X150000Y0D01*\n\
+             X150000Y200000D01*\n\
+             X0Y200000D01*\n\
+             X0Y0D01*\n\
+             G37*\n\
+             M02*\n"
+        );
+    }
+
+    #[test]
+    fn test_paths_holes_are_written_last_with_clear_polarity() {
+        let hole = Path::<Centi>::rectangle(2.0, 2.0, 2.0, -1.0);
+        let outer = Path::<Centi>::rectangle(0.0, 0.0, 10.0, 10.0);
+        let gerber = Paths::new(vec![hole, outer]).to_gerber(&GerberOptions::default());
+
+        let dark_region = gerber.find("X10000000Y0D01*").unwrap();
+        let clear = gerber.find("%LPC*%").unwrap();
+        let hole_region = gerber.find("X4000000Y2000000D01*").unwrap();
+        assert!(dark_region < clear);
+        assert!(clear < hole_region);
+    }
+
+    #[test]
+    fn test_polytree_polarity() {
+        let paths = Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(8.0, 2.0, -6.0, 6.0),
+            Path::rectangle(4.0, 4.0, 2.0, 2.0),
+        ]);
+
+        let tree = paths
+            .to_clipper_subject()
+            .add_clip(Paths::default())
+            .union_tree(FillRule::NonZero)
+            .unwrap()
+            .tree;
+        let gerber = tree.to_gerber(&GerberOptions::default());
+
+        let polarities = gerber
+            .lines()
+            .filter(|line| line.starts_with("%LP"))
+            .collect::<Vec<_>>();
+        assert_eq!(polarities, vec!["%LPD*%", "%LPC*%", "%LPD*%"]);
+        assert_eq!(gerber.matches("G36*").count(), 3);
+        assert!(gerber.ends_with("M02*\n"));
+    }
+}
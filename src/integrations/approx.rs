@@ -0,0 +1,133 @@
+use approx::{AbsDiffEq, RelativeEq};
+
+use crate::{Path, Paths, Point, PointScaler};
+
+/// Points are compared by their coordinates in user units.
+impl<P: PointScaler> AbsDiffEq for Point<P> {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.x().abs_diff_eq(&other.x(), epsilon) && self.y().abs_diff_eq(&other.y(), epsilon)
+    }
+}
+
+impl<P: PointScaler> RelativeEq for Point<P> {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.x().relative_eq(&other.x(), epsilon, max_relative)
+            && self.y().relative_eq(&other.y(), epsilon, max_relative)
+    }
+}
+
+/// Paths are equal when they have the same number of points and each point is
+/// approximately equal to the point at the same position in the other path.
+impl<P: PointScaler> AbsDiffEq for Path<P> {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl<P: PointScaler> RelativeEq for Path<P> {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl<P: PointScaler> AbsDiffEq for Paths<P> {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl<P: PointScaler> RelativeEq for Paths<P> {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use approx::{assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq};
+
+    use crate::{Centi, Milli};
+
+    use super::*;
+
+    #[test]
+    fn test_point() {
+        let a = Point::<Milli>::new(1.0, 2.0);
+        let b = Point::<Milli>::new(1.001, 2.0);
+
+        assert_abs_diff_eq!(a, b, epsilon = 0.0011);
+        assert_abs_diff_ne!(a, b);
+        assert_relative_eq!(a, b, max_relative = 0.001);
+    }
+
+    #[test]
+    fn test_paths() {
+        let a = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+        let b = Paths::<Centi>::from(Path::rectangle(0.01, 0.0, 1.0, 1.0));
+
+        assert_abs_diff_eq!(a, b, epsilon = 0.011);
+        assert_abs_diff_ne!(a, b, epsilon = 0.001);
+        assert_abs_diff_ne!(a, Paths::default(), epsilon = 1.0);
+    }
+}
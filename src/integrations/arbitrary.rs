@@ -0,0 +1,77 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{Path, Paths, Point, PointScaler};
+
+use super::star_polygon;
+
+/// Coordinates are generated in thousandths of a unit within this range, so
+/// they stay exact for the provided scalers and far from the `i64` limits.
+const COORDINATE_RANGE: std::ops::RangeInclusive<i32> = -1_000_000..=1_000_000;
+
+/// Points are generated with both coordinates within -1000.0 and 1000.0.
+impl<'a, P: PointScaler> Arbitrary<'a> for Point<P> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let x = u.int_in_range(COORDINATE_RANGE)?;
+        let y = u.int_in_range(COORDINATE_RANGE)?;
+        Ok(Point::new(x as f64 / 1000.0, y as f64 / 1000.0))
+    }
+}
+
+/// Paths are generated as star shaped polygons of 3 to 16 points with a
+/// positive area and a radius of up to 100.0 around an arbitrary center, so
+/// they are valid polygons apart from rounding to the scaler.
+impl<'a, P: PointScaler> Arbitrary<'a> for Path<P> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let center = Point::arbitrary(u)?;
+        let len = u.int_in_range(3..=16)?;
+        let vertices = (0..len)
+            .map(|_| {
+                let radius = u.int_in_range(1_000..=100_000)? as f64 / 1000.0;
+                let step = u.int_in_range(1..=8)? as f64;
+                Ok((radius, step))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(star_polygon(center, &vertices))
+    }
+}
+
+/// Paths are generated as up to 8 closed paths, see [`Path`].
+impl<'a, P: PointScaler> Arbitrary<'a> for Paths<P> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.int_in_range(0..=8)?;
+        (0..len).map(|_| Path::arbitrary(u)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{union, Centi, FillRule};
+
+    use super::*;
+
+    #[test]
+    fn test_generated_paths_are_valid_polygons() {
+        let data = (0..4096u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+            .collect::<Vec<_>>();
+        let mut u = Unstructured::new(&data);
+
+        for _ in 0..20 {
+            let a = Paths::<Centi>::arbitrary(&mut u).unwrap();
+            let b = Paths::<Centi>::arbitrary(&mut u).unwrap();
+
+            assert!(a
+                .iter()
+                .all(|path| path.len() >= 3 && path.signed_area() > 0.0));
+
+            let max_area = a
+                .iter()
+                .chain(b.iter())
+                .map(Path::signed_area)
+                .fold(0.0, f64::max);
+            let union = union(a, b, FillRule::NonZero).unwrap().closed;
+            assert!(union.signed_area() >= max_area - 1e-6);
+        }
+    }
+}
@@ -0,0 +1,434 @@
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    LineTrait, MultiLineStringTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait, UnimplementedGeometryCollection, UnimplementedLine, UnimplementedLineString,
+    UnimplementedMultiLineString, UnimplementedMultiPoint, UnimplementedMultiPolygon,
+    UnimplementedPoint, UnimplementedPolygon, UnimplementedRect, UnimplementedTriangle,
+};
+
+use crate::{Path, Paths, Point, PointScaler, PolyTree};
+
+/// A [`Path`] borrowed as a `geo-traits` line string. Rings of closed paths
+/// end with their first point again, as in most geometry formats, while
+/// open paths are returned as they are.
+#[derive(Debug, Clone, Copy)]
+pub struct GeoRing<'a, P: PointScaler> {
+    path: &'a Path<P>,
+    closed: bool,
+}
+
+impl<'a, P: PointScaler> GeoRing<'a, P> {
+    /// Returns the borrowed path.
+    pub fn path(&self) -> &'a Path<P> {
+        self.path
+    }
+}
+
+/// An outer node of a [`PolyTree`] borrowed as a `geo-traits` polygon, with
+/// the holes directly inside it as interiors.
+#[derive(Debug, Clone, Copy)]
+pub struct GeoPolygon<'a, P: PointScaler> {
+    node: &'a PolyTree<P>,
+}
+
+impl<'a, P: PointScaler> GeoPolygon<'a, P> {
+    /// Returns the borrowed tree node.
+    pub fn node(&self) -> &'a PolyTree<P> {
+        self.node
+    }
+
+    fn holes(&self) -> impl Iterator<Item = &'a PolyTree<P>> {
+        self.node.children().iter().filter(|child| child.is_hole())
+    }
+}
+
+/// Implement `GeometryTrait` for a type that is a single kind of geometry,
+/// with all other geometry types unimplemented.
+macro_rules! geometry_trait {
+    (@type $kind:ident, $kind_ty:ident, $unimplemented:ident) => {
+        geometry_trait!(@pick $kind, $kind_ty, $unimplemented)
+    };
+    (@pick Point, PointType, $unimplemented:ident) => { Self };
+    (@pick LineString, LineStringType, $unimplemented:ident) => { Self };
+    (@pick Polygon, PolygonType, $unimplemented:ident) => { Self };
+    (@pick MultiLineString, MultiLineStringType, $unimplemented:ident) => { Self };
+    (@pick MultiPolygon, MultiPolygonType, $unimplemented:ident) => { Self };
+    (@pick $kind:ident, $kind_ty:ident, $unimplemented:ident) => { $unimplemented<f64> };
+    ([$($generics:tt)*] $ty:ty, $kind:ident) => {
+        impl<$($generics)*> GeometryTrait for $ty {
+            type T = f64;
+            type PointType<'b> = geometry_trait!(@type $kind, PointType, UnimplementedPoint) where Self: 'b;
+            type LineStringType<'b> = geometry_trait!(@type $kind, LineStringType, UnimplementedLineString) where Self: 'b;
+            type PolygonType<'b> = geometry_trait!(@type $kind, PolygonType, UnimplementedPolygon) where Self: 'b;
+            type MultiPointType<'b> = UnimplementedMultiPoint<f64> where Self: 'b;
+            type MultiLineStringType<'b> = geometry_trait!(@type $kind, MultiLineStringType, UnimplementedMultiLineString) where Self: 'b;
+            type MultiPolygonType<'b> = geometry_trait!(@type $kind, MultiPolygonType, UnimplementedMultiPolygon) where Self: 'b;
+            type GeometryCollectionType<'b> = UnimplementedGeometryCollection<f64> where Self: 'b;
+            type RectType<'b> = UnimplementedRect<f64> where Self: 'b;
+            type TriangleType<'b> = UnimplementedTriangle<f64> where Self: 'b;
+            type LineType<'b> = UnimplementedLine<f64> where Self: 'b;
+
+            fn dim(&self) -> Dimensions {
+                Dimensions::Xy
+            }
+
+            #[allow(clippy::type_complexity)]
+            fn as_type(
+                &self,
+            ) -> GeometryType<
+                '_,
+                Self::PointType<'_>,
+                Self::LineStringType<'_>,
+                Self::PolygonType<'_>,
+                Self::MultiPointType<'_>,
+                Self::MultiLineStringType<'_>,
+                Self::MultiPolygonType<'_>,
+                Self::GeometryCollectionType<'_>,
+                Self::RectType<'_>,
+                Self::TriangleType<'_>,
+                Self::LineType<'_>,
+            > {
+                GeometryType::$kind(self)
+            }
+        }
+    };
+}
+
+geometry_trait!([P: PointScaler] Point<P>, Point);
+geometry_trait!([P: PointScaler] Path<P>, Polygon);
+geometry_trait!([P: PointScaler] Paths<P>, MultiLineString);
+geometry_trait!([P: PointScaler] PolyTree<P>, MultiPolygon);
+geometry_trait!(['a, P: PointScaler] GeoRing<'a, P>, LineString);
+geometry_trait!(['a, P: PointScaler] GeoPolygon<'a, P>, Polygon);
+
+impl<P: PointScaler> CoordTrait for Point<P> {
+    type T = f64;
+
+    fn dim(&self) -> Dimensions {
+        Dimensions::Xy
+    }
+
+    fn x(&self) -> f64 {
+        Point::x(self)
+    }
+
+    fn y(&self) -> f64 {
+        Point::y(self)
+    }
+
+    fn nth_or_panic(&self, n: usize) -> f64 {
+        match n {
+            0 => Point::x(self),
+            1 => Point::y(self),
+            _ => panic!("Point only supports 2 dimensions"),
+        }
+    }
+}
+
+impl<P: PointScaler> PointTrait for Point<P> {
+    type CoordType<'a>
+        = Point<P>
+    where
+        Self: 'a;
+
+    fn coord(&self) -> Option<Self::CoordType<'_>> {
+        Some(*self)
+    }
+}
+
+impl<'a, P: PointScaler> LineStringTrait for GeoRing<'a, P> {
+    type CoordType<'b>
+        = Point<P>
+    where
+        Self: 'b;
+
+    fn num_coords(&self) -> usize {
+        let len = self.path.len();
+        let repeat_first = self.closed && len > 0 && self.path[0] != self.path[len - 1];
+        len + usize::from(repeat_first)
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.path[i % self.path.len()]
+    }
+}
+
+/// A path is a polygon without holes.
+impl<P: PointScaler> PolygonTrait for Path<P> {
+    type RingType<'a>
+        = GeoRing<'a, P>
+    where
+        Self: 'a;
+
+    fn exterior(&self) -> Option<Self::RingType<'_>> {
+        (!self.is_empty()).then_some(GeoRing {
+            path: self,
+            closed: true,
+        })
+    }
+
+    fn num_interiors(&self) -> usize {
+        0
+    }
+
+    unsafe fn interior_unchecked(&self, _i: usize) -> Self::RingType<'_> {
+        unreachable!("a path has no interiors")
+    }
+}
+
+/// Paths carry no hierarchy, so they are line strings, with the ring of
+/// closed paths ending at their first point. Convert them into a
+/// [`PolyTree`] for polygons with holes.
+impl<P: PointScaler> MultiLineStringTrait for Paths<P> {
+    type InnerLineStringType<'a>
+        = GeoRing<'a, P>
+    where
+        Self: 'a;
+
+    fn num_line_strings(&self) -> usize {
+        self.len()
+    }
+
+    unsafe fn line_string_unchecked(&self, i: usize) -> Self::InnerLineStringType<'_> {
+        GeoRing {
+            path: &self[i],
+            closed: !self.is_open(i),
+        }
+    }
+}
+
+impl<'a, P: PointScaler> PolygonTrait for GeoPolygon<'a, P> {
+    type RingType<'b>
+        = GeoRing<'a, P>
+    where
+        Self: 'b;
+
+    fn exterior(&self) -> Option<Self::RingType<'_>> {
+        (!self.node.polygon().is_empty()).then_some(GeoRing {
+            path: self.node.polygon(),
+            closed: true,
+        })
+    }
+
+    fn num_interiors(&self) -> usize {
+        self.holes().count()
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+        GeoRing {
+            path: self.holes().nth(i).unwrap().polygon(),
+            closed: true,
+        }
+    }
+}
+
+/// Every outer node of the tree is a polygon, including islands inside
+/// holes. Looking up a polygon by index walks the tree, iterate over
+/// `polygons()` to visit all of them.
+impl<P: PointScaler> MultiPolygonTrait for PolyTree<P> {
+    type InnerPolygonType<'a>
+        = GeoPolygon<'a, P>
+    where
+        Self: 'a;
+
+    fn polygons(
+        &self,
+    ) -> impl DoubleEndedIterator + ExactSizeIterator<Item = Self::InnerPolygonType<'_>> {
+        self.outers().collect::<Vec<_>>().into_iter()
+    }
+
+    fn num_polygons(&self) -> usize {
+        self.outers().count()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::InnerPolygonType<'_> {
+        self.outers().nth(i).unwrap()
+    }
+}
+
+impl<P: PointScaler> PolyTree<P> {
+    fn outers(&self) -> impl Iterator<Item = GeoPolygon<'_, P>> {
+        self.iter()
+            .filter(|node| !node.is_hole() && !node.polygon().is_empty())
+            .map(|node| GeoPolygon { node })
+    }
+}
+
+impl<P: PointScaler> Paths<P> {
+    /// Read any geometry implementing the `geo-traits` traits, such as the
+    /// types of `geo-types`, `geoarrow` or `wkb`, without converting it into
+    /// an intermediate type first.
+    ///
+    /// Polygon rings, rectangles and triangles become closed paths, dropping
+    /// the repeated first point that closes a ring. Line strings and lines
+    /// become open paths. Multi geometries and collections are flattened,
+    /// and points are skipped as they enclose no area.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let square = Path::<Centi>::rectangle(0.0, 0.0, 2.0, 2.0);
+    /// let paths = Paths::<Centi>::from_geometry(&square);
+    ///
+    /// assert_eq!(paths, square.into());
+    /// ```
+    pub fn from_geometry<T: Into<f64>>(geometry: &impl GeometryTrait<T = T>) -> Self {
+        let mut paths = Self::default();
+        add_geometry(&mut paths, geometry);
+        paths
+    }
+}
+
+fn add_geometry<P: PointScaler, T: Into<f64>>(
+    paths: &mut Paths<P>,
+    geometry: &impl GeometryTrait<T = T>,
+) {
+    match geometry.as_type() {
+        GeometryType::Point(_) | GeometryType::MultiPoint(_) => {}
+        GeometryType::LineString(line) => paths.push_open(to_path(line.coords())),
+        GeometryType::Polygon(polygon) => add_polygon(paths, polygon),
+        GeometryType::MultiLineString(lines) => {
+            for line in lines.line_strings() {
+                paths.push_open(to_path(line.coords()));
+            }
+        }
+        GeometryType::MultiPolygon(polygons) => {
+            for polygon in polygons.polygons() {
+                add_polygon(paths, &polygon);
+            }
+        }
+        GeometryType::GeometryCollection(collection) => {
+            for geometry in collection.geometries() {
+                add_geometry(paths, &geometry);
+            }
+        }
+        GeometryType::Rect(rect) => {
+            let (min, max) = (to_point::<P, _>(&rect.min()), to_point::<P, _>(&rect.max()));
+            paths.push_closed(Path::rectangle(
+                min.x(),
+                min.y(),
+                max.x() - min.x(),
+                max.y() - min.y(),
+            ));
+        }
+        GeometryType::Triangle(triangle) => paths.push_closed(to_path(triangle.coords())),
+        GeometryType::Line(line) => paths.push_open(to_path(line.coords())),
+    }
+}
+
+fn add_polygon<P: PointScaler, T: Into<f64>>(
+    paths: &mut Paths<P>,
+    polygon: &impl PolygonTrait<T = T>,
+) {
+    for ring in polygon.exterior().into_iter().chain(polygon.interiors()) {
+        let mut path = to_path::<P, _>(ring.coords());
+        if path.len() > 1 && path[0] == path[path.len() - 1] {
+            path = path.iter().take(path.len() - 1).copied().collect();
+        }
+        paths.push_closed(path);
+    }
+}
+
+fn to_path<P: PointScaler, C: CoordTrait<T: Into<f64>>>(
+    coords: impl IntoIterator<Item = C>,
+) -> Path<P> {
+    coords.into_iter().map(|coord| to_point(&coord)).collect()
+}
+
+fn to_point<P: PointScaler, C: CoordTrait<T: Into<f64>>>(coord: &C) -> Point<P> {
+    Point::new(coord.x().into(), coord.y().into())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, FillRule};
+
+    use super::*;
+
+    fn nested_tree() -> PolyTree<Centi> {
+        PolyTree::from_paths(
+            &Paths::new(vec![
+                Path::rectangle(0.0, 0.0, 10.0, 10.0),
+                Path::rectangle(2.0, 2.0, 6.0, 6.0),
+                Path::rectangle(4.0, 4.0, 2.0, 2.0),
+                Path::rectangle(20.0, 0.0, 1.0, 1.0),
+            ]),
+            FillRule::EvenOdd,
+        )
+    }
+
+    #[test]
+    fn test_path_as_polygon() {
+        let path = Path::<Centi>::rectangle(0.0, 0.0, 2.0, 1.0);
+
+        let ring = PolygonTrait::exterior(&path).unwrap();
+        assert_eq!(ring.num_coords(), 5);
+        assert_eq!(ring.coord(4), ring.coord(0));
+        assert_eq!(ring.coord(2).unwrap().x_y(), (2.0, 1.0));
+        assert!(ring.coord(5).is_none());
+        assert_eq!(path.num_interiors(), 0);
+        assert!(PolygonTrait::exterior(&Path::<Centi>::default()).is_none());
+        assert_eq!(Paths::from_geometry(&path), path.into());
+    }
+
+    #[test]
+    fn test_paths_as_line_strings() {
+        let mut paths = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+        paths.push_open(vec![(0.0, 0.0), (3.0, 3.0)]);
+
+        let counts = paths
+            .line_strings()
+            .map(|line| line.num_coords())
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![5, 2]);
+
+        let read = Paths::<Centi>::from_geometry(&paths);
+        assert_eq!(read.len(), 2);
+        assert!(read.is_open(0) && read.is_open(1));
+        assert_eq!(read[1], paths[1]);
+    }
+
+    #[test]
+    fn test_polytree_as_multi_polygon() {
+        let tree = nested_tree();
+
+        assert_eq!(tree.num_polygons(), 3);
+        let interiors = tree
+            .polygons()
+            .map(|polygon| polygon.num_interiors())
+            .collect::<Vec<_>>();
+        assert_eq!(interiors, vec![1, 0, 0]);
+
+        let outer = MultiPolygonTrait::polygon(&tree, 0).unwrap();
+        assert_eq!(outer.interior(0).unwrap().num_coords(), 5);
+        assert!(MultiPolygonTrait::polygon(&tree, 3).is_none());
+
+        let read = Paths::<Centi>::from_geometry(&tree);
+        assert_eq!(read.len(), 4);
+        assert_eq!(read.signed_area(), 100.0 - 36.0 + 4.0 + 1.0);
+    }
+
+    #[test]
+    fn test_from_geo_types() {
+        let polygon = geo_types::Polygon::new(
+            vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)].into(),
+            vec![vec![(1.0, 1.0), (1.0, 2.0), (2.0, 2.0), (2.0, 1.0)].into()],
+        );
+        let collection =
+            geo_types::Geometry::GeometryCollection(geo_types::GeometryCollection(vec![
+                polygon.into(),
+                geo_types::Point::new(1.0, 1.0).into(),
+                geo_types::Rect::new((10.0, 10.0), (12.0, 11.0)).into(),
+                geo_types::LineString::from(vec![(0.0, 0.0), (5.0, 5.0)]).into(),
+            ]));
+
+        let paths = Paths::<Centi>::from_geometry(&collection);
+
+        assert_eq!(paths.len(), 4);
+        assert_eq!(paths[0].len(), 4);
+        assert_eq!(paths[2], Path::rectangle(10.0, 10.0, 2.0, 1.0));
+        assert!(paths.is_open(3) && !paths.is_open(2));
+    }
+}
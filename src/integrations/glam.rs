@@ -0,0 +1,163 @@
+use glam::{DVec2, Vec2};
+
+use crate::{Path, Paths, Point, PointScaler};
+
+impl<P: PointScaler> From<DVec2> for Point<P> {
+    fn from(vec: DVec2) -> Self {
+        Self::new(vec.x, vec.y)
+    }
+}
+
+impl<P: PointScaler> From<&DVec2> for Point<P> {
+    fn from(vec: &DVec2) -> Self {
+        Self::new(vec.x, vec.y)
+    }
+}
+
+impl<P: PointScaler> From<Vec2> for Point<P> {
+    fn from(vec: Vec2) -> Self {
+        Self::new(vec.x as f64, vec.y as f64)
+    }
+}
+
+impl<P: PointScaler> From<&Vec2> for Point<P> {
+    fn from(vec: &Vec2) -> Self {
+        Self::new(vec.x as f64, vec.y as f64)
+    }
+}
+
+impl<P: PointScaler> From<Point<P>> for DVec2 {
+    fn from(point: Point<P>) -> Self {
+        DVec2::new(point.x(), point.y())
+    }
+}
+
+impl<P: PointScaler> From<Point<P>> for Vec2 {
+    fn from(point: Point<P>) -> Self {
+        Vec2::new(point.x() as f32, point.y() as f32)
+    }
+}
+
+impl<P: PointScaler> FromIterator<DVec2> for Path<P> {
+    fn from_iter<T: IntoIterator<Item = DVec2>>(iter: T) -> Self {
+        iter.into_iter().map(Point::<P>::from).collect()
+    }
+}
+
+impl<P: PointScaler> FromIterator<Vec2> for Path<P> {
+    fn from_iter<T: IntoIterator<Item = Vec2>>(iter: T) -> Self {
+        iter.into_iter().map(Point::<P>::from).collect()
+    }
+}
+
+impl<P: PointScaler> From<Vec<DVec2>> for Path<P> {
+    fn from(points: Vec<DVec2>) -> Self {
+        points.into_iter().collect()
+    }
+}
+
+impl<P: PointScaler> From<Vec<Vec2>> for Path<P> {
+    fn from(points: Vec<Vec2>) -> Self {
+        points.into_iter().collect()
+    }
+}
+
+impl<P: PointScaler> From<Path<P>> for Vec<DVec2> {
+    fn from(path: Path<P>) -> Self {
+        path.into_iter().map(DVec2::from).collect()
+    }
+}
+
+impl<P: PointScaler> From<Path<P>> for Vec<Vec2> {
+    fn from(path: Path<P>) -> Self {
+        path.into_iter().map(Vec2::from).collect()
+    }
+}
+
+impl<P: PointScaler> From<Vec<DVec2>> for Paths<P> {
+    fn from(points: Vec<DVec2>) -> Self {
+        Paths::new(vec![points.into()])
+    }
+}
+
+impl<P: PointScaler> From<Vec<Vec2>> for Paths<P> {
+    fn from(points: Vec<Vec2>) -> Self {
+        Paths::new(vec![points.into()])
+    }
+}
+
+impl<P: PointScaler> From<Vec<Vec<DVec2>>> for Paths<P> {
+    fn from(paths: Vec<Vec<DVec2>>) -> Self {
+        Paths::new(paths.into_iter().map(|path| path.into()).collect())
+    }
+}
+
+impl<P: PointScaler> From<Vec<Vec<Vec2>>> for Paths<P> {
+    fn from(paths: Vec<Vec<Vec2>>) -> Self {
+        Paths::new(paths.into_iter().map(|path| path.into()).collect())
+    }
+}
+
+impl<P: PointScaler> From<Paths<P>> for Vec<Vec<DVec2>> {
+    fn from(paths: Paths<P>) -> Self {
+        paths.into_iter().map(|path| path.into()).collect()
+    }
+}
+
+impl<P: PointScaler> From<Paths<P>> for Vec<Vec<Vec2>> {
+    fn from(paths: Paths<P>) -> Self {
+        paths.into_iter().map(|path| path.into()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Centi;
+
+    use super::*;
+
+    #[test]
+    fn test_point_from_dvec2() {
+        let point = Point::<Centi>::from(DVec2::new(1.0, 2.5));
+        assert_eq!(point.x_scaled(), 100);
+        assert_eq!(point.y_scaled(), 250);
+        assert_eq!(DVec2::from(point), DVec2::new(1.0, 2.5));
+    }
+
+    #[test]
+    fn test_point_from_vec2() {
+        let point = Point::<Centi>::from(Vec2::new(1.0, 2.5));
+        assert_eq!(point.x_scaled(), 100);
+        assert_eq!(point.y_scaled(), 250);
+        assert_eq!(Vec2::from(point), Vec2::new(1.0, 2.5));
+    }
+
+    #[test]
+    fn test_path_from_iterator() {
+        let path: Path<Centi> = [Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)]
+            .into_iter()
+            .collect();
+        assert_eq!(path, Path::from(vec![(0.0, 0.0), (1.0, 1.0)]));
+
+        let output: Vec<DVec2> = path.into();
+        assert_eq!(output, vec![DVec2::new(0.0, 0.0), DVec2::new(1.0, 1.0)]);
+    }
+
+    #[test]
+    fn test_paths_from_vecs() {
+        let paths = Paths::<Centi>::from(vec![
+            vec![DVec2::new(0.0, 0.0), DVec2::new(1.0, 1.0)],
+            vec![DVec2::new(2.0, 2.0), DVec2::new(3.0, 3.0)],
+        ]);
+        assert_eq!(
+            paths,
+            Paths::from(vec![
+                vec![(0.0, 0.0), (1.0, 1.0)],
+                vec![(2.0, 2.0), (3.0, 3.0)]
+            ])
+        );
+
+        let output: Vec<Vec<Vec2>> = paths.into();
+        assert_eq!(output[1], vec![Vec2::new(2.0, 2.0), Vec2::new(3.0, 3.0)]);
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use image::{GenericImageView, Pixel};
+
+use crate::{Path, Paths, Point, PointScaler};
+
+impl<P: PointScaler> Paths<P> {
+    /// Trace the outlines of the set pixels in a `width` by `height` mask
+    /// into closed paths.
+    ///
+    /// Each pixel `(x, y)` covers the unit square from `(x, y)` to
+    /// `(x + 1, y + 1)`, so the contours follow the pixel edges exactly.
+    /// Outer contours have a positive signed area and hole contours a
+    /// negative one, and diagonally touching pixels are kept apart. Use
+    /// [`Paths::simplify`] to smooth out the staircase edges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let mask = [
+    ///     [1, 1, 1],
+    ///     [1, 0, 1],
+    ///     [1, 1, 1],
+    /// ];
+    /// let paths: Paths = Paths::from_mask(3, 3, |x, y| mask[y as usize][x as usize] == 1);
+    ///
+    /// assert_eq!(paths.len(), 2);
+    /// assert_eq!(paths.signed_area(), 8.0);
+    /// ```
+    pub fn from_mask(width: u32, height: u32, is_set: impl Fn(u32, u32) -> bool) -> Self {
+        let is_set = |x: i64, y: i64| {
+            x >= 0 && y >= 0 && x < width as i64 && y < height as i64 && is_set(x as u32, y as u32)
+        };
+
+        let mut edges = Vec::new();
+        for y in 0..height as i64 {
+            for x in 0..width as i64 {
+                if !is_set(x, y) {
+                    continue;
+                }
+
+                if !is_set(x, y - 1) {
+                    edges.push(((x, y), (x + 1, y)));
+                }
+                if !is_set(x + 1, y) {
+                    edges.push(((x + 1, y), (x + 1, y + 1)));
+                }
+                if !is_set(x, y + 1) {
+                    edges.push(((x + 1, y + 1), (x, y + 1)));
+                }
+                if !is_set(x - 1, y) {
+                    edges.push(((x, y + 1), (x, y)));
+                }
+            }
+        }
+
+        let mut outgoing: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+        for (index, (start, _)) in edges.iter().enumerate() {
+            outgoing.entry(*start).or_default().push(index);
+        }
+
+        let mut used = vec![false; edges.len()];
+        let mut paths = Vec::new();
+
+        for first in 0..edges.len() {
+            if used[first] {
+                continue;
+            }
+
+            let mut contour = Vec::new();
+            let mut current = first;
+
+            loop {
+                used[current] = true;
+                let (start, end) = edges[current];
+                contour.push(start);
+
+                // Two outgoing edges only happen where pixels touch
+                // diagonally, turning left keeps following the same pixel.
+                let direction = (end.0 - start.0, end.1 - start.1);
+                current = *outgoing[&end]
+                    .iter()
+                    .find(|&&next| {
+                        let (next_start, next_end) = edges[next];
+                        let next_direction = (next_end.0 - next_start.0, next_end.1 - next_start.1);
+                        outgoing[&end].len() == 1
+                            || direction.0 * next_direction.1 - direction.1 * next_direction.0 > 0
+                    })
+                    .unwrap_or(&first);
+
+                if current == first {
+                    break;
+                }
+            }
+
+            paths.push(remove_collinear(&contour));
+        }
+
+        Paths::new(paths)
+    }
+
+    /// Trace the outlines of the pixels in an image whose luminance is at
+    /// least `threshold`, see [`Paths::from_mask`].
+    pub fn from_image_threshold<I>(image: &I, threshold: u8) -> Self
+    where
+        I: GenericImageView,
+        I::Pixel: Pixel<Subpixel = u8>,
+    {
+        Self::from_mask(image.width(), image.height(), |x, y| {
+            image.get_pixel(x, y).to_luma()[0] >= threshold
+        })
+    }
+}
+
+fn remove_collinear<P: PointScaler>(contour: &[(i64, i64)]) -> Path<P> {
+    let len = contour.len();
+
+    contour
+        .iter()
+        .enumerate()
+        .filter(|&(index, point)| {
+            let prev = contour[(index + len - 1) % len];
+            let next = contour[(index + 1) % len];
+            (point.0 - prev.0) * (next.1 - point.1) != (point.1 - prev.1) * (next.0 - point.0)
+        })
+        .map(|(_, &(x, y))| Point::new(x as f64, y as f64))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use image::{GrayImage, Luma};
+
+    use crate::Centi;
+
+    use super::*;
+
+    fn mask(rows: &[&str]) -> Paths<Centi> {
+        Paths::from_mask(rows[0].len() as u32, rows.len() as u32, |x, y| {
+            rows[y as usize].as_bytes()[x as usize] == b'#'
+        })
+    }
+
+    #[test]
+    fn test_single_pixel() {
+        let paths = mask(&["#"]);
+
+        assert_eq!(
+            paths,
+            Paths::new(vec![
+                vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].into()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_holes_have_negative_area() {
+        let paths = mask(&["####", "#..#", "####"]);
+
+        assert_eq!(paths.len(), 2);
+        let mut areas = paths
+            .iter()
+            .map(|path| path.signed_area())
+            .collect::<Vec<_>>();
+        areas.sort_by(f64::total_cmp);
+        assert_eq!(areas, vec![-2.0, 12.0]);
+    }
+
+    #[test]
+    fn test_diagonal_pixels_are_separate() {
+        let paths = mask(&["#.", ".#"]);
+
+        assert_eq!(paths.len(), 2);
+        assert!(paths.iter().all(|path| path.len() == 4));
+        assert_eq!(paths.signed_area(), 2.0);
+    }
+
+    #[test]
+    fn test_image_threshold() {
+        let image = GrayImage::from_fn(4, 4, |x, y| {
+            Luma([if (1..3).contains(&x) && (1..3).contains(&y) {
+                200
+            } else {
+                10
+            }])
+        });
+        let paths = Paths::<Centi>::from_image_threshold(&image, 128);
+
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths.signed_area(), 4.0);
+        assert_eq!(paths.bounds().min, Point::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_empty_mask() {
+        assert!(mask(&["..", ".."]).is_empty());
+    }
+}
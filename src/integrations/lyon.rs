@@ -0,0 +1,201 @@
+use lyon_tessellation::{
+    geom::point, path::Path as LyonPath, BuffersBuilder, FillOptions, FillTessellator, FillVertex,
+    StrokeOptions, StrokeTessellator, StrokeVertex, TessellationError, VertexBuffers,
+};
+
+use crate::{BooleanResult, Paths, PointScaler, PolyTree};
+
+/// Vertex and index buffers produced by the lyon tessellation adapters, with
+/// one `[x, y]` position per vertex and three indices per triangle.
+pub type TessellationBuffers = VertexBuffers<[f32; 2], u32>;
+
+impl<P: PointScaler> Paths<P> {
+    /// Convert the paths into a `lyon` path, with each path as a sub path
+    /// that is closed if `closed` is set.
+    pub fn to_lyon_path(&self, closed: bool) -> LyonPath {
+        let mut builder = LyonPath::builder();
+
+        for path in self.iter().filter(|path| path.len() > 1) {
+            let mut points = path.iter().map(|p| point(p.x() as f32, p.y() as f32));
+
+            if let Some(first) = points.next() {
+                builder.begin(first);
+                points.for_each(|p| {
+                    builder.line_to(p);
+                });
+                builder.end(closed);
+            }
+        }
+
+        builder.build()
+    }
+
+    /// Tessellate the area covered by the closed paths into triangles with
+    /// the `lyon` fill tessellator. The fill rule is taken from `options`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    /// use lyon_tessellation::FillOptions;
+    ///
+    /// let paths: Paths = Path::rectangle(0.0, 0.0, 4.0, 2.0).into();
+    /// let buffers = paths.tessellate_fill(&FillOptions::default()).unwrap();
+    ///
+    /// assert_eq!(buffers.vertices.len(), 4);
+    /// assert_eq!(buffers.indices.len(), 6);
+    /// ```
+    pub fn tessellate_fill(
+        &self,
+        options: &FillOptions,
+    ) -> Result<TessellationBuffers, TessellationError> {
+        let mut buffers = TessellationBuffers::new();
+
+        FillTessellator::new().tessellate_path(
+            &self.to_lyon_path(true),
+            options,
+            &mut BuffersBuilder::new(&mut buffers, |vertex: FillVertex| {
+                vertex.position().to_array()
+            }),
+        )?;
+
+        Ok(buffers)
+    }
+
+    /// Tessellate the outlines of the paths into triangles with the `lyon`
+    /// stroke tessellator, treating the paths as closed if `closed` is set.
+    pub fn tessellate_stroke(
+        &self,
+        closed: bool,
+        options: &StrokeOptions,
+    ) -> Result<TessellationBuffers, TessellationError> {
+        let mut buffers = TessellationBuffers::new();
+        self.tessellate_stroke_into(closed, options, &mut buffers)?;
+        Ok(buffers)
+    }
+
+    fn tessellate_stroke_into(
+        &self,
+        closed: bool,
+        options: &StrokeOptions,
+        buffers: &mut TessellationBuffers,
+    ) -> Result<(), TessellationError> {
+        StrokeTessellator::new().tessellate_path(
+            &self.to_lyon_path(closed),
+            options,
+            &mut BuffersBuilder::new(buffers, |vertex: StrokeVertex| vertex.position().to_array()),
+        )
+    }
+}
+
+impl<P: PointScaler> BooleanResult<P> {
+    /// Tessellate the closed result paths into triangles, see
+    /// [`Paths::tessellate_fill`]. Open paths have no area and are ignored.
+    pub fn tessellate_fill(
+        &self,
+        options: &FillOptions,
+    ) -> Result<TessellationBuffers, TessellationError> {
+        self.closed.tessellate_fill(options)
+    }
+
+    /// Tessellate the outlines of both the closed and the open result paths
+    /// into a single set of buffers, see [`Paths::tessellate_stroke`].
+    pub fn tessellate_stroke(
+        &self,
+        options: &StrokeOptions,
+    ) -> Result<TessellationBuffers, TessellationError> {
+        let mut buffers = TessellationBuffers::new();
+        self.closed
+            .tessellate_stroke_into(true, options, &mut buffers)?;
+        self.open
+            .tessellate_stroke_into(false, options, &mut buffers)?;
+        Ok(buffers)
+    }
+}
+
+impl<P: PointScaler> PolyTree<P> {
+    /// Tessellate the polygons of the tree into triangles, see
+    /// [`Paths::tessellate_fill`].
+    pub fn tessellate_fill(
+        &self,
+        options: &FillOptions,
+    ) -> Result<TessellationBuffers, TessellationError> {
+        self.to_paths().tessellate_fill(options)
+    }
+
+    /// Tessellate the outlines of the polygons in the tree, see
+    /// [`Paths::tessellate_stroke`].
+    pub fn tessellate_stroke(
+        &self,
+        options: &StrokeOptions,
+    ) -> Result<TessellationBuffers, TessellationError> {
+        self.to_paths().tessellate_stroke(true, options)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use lyon_tessellation::FillRule as LyonFillRule;
+
+    use crate::{Centi, FillRule, Path};
+
+    use super::*;
+
+    fn area(buffers: &TessellationBuffers) -> f32 {
+        buffers
+            .indices
+            .chunks(3)
+            .map(|triangle| {
+                let [a, b, c] = [0, 1, 2].map(|i| buffers.vertices[triangle[i] as usize]);
+                ((b[0] - a[0]) * (c[1] - a[1]) - (c[0] - a[0]) * (b[1] - a[1])).abs() / 2.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_lyon_path_sub_paths() {
+        let paths = Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 1.0, 1.0),
+            Path::rectangle(2.0, 2.0, 1.0, 1.0),
+            Path::default(),
+        ]);
+        let path = paths.to_lyon_path(true);
+
+        assert_eq!(path.iter().count(), 2 * (1 + 3 + 1));
+    }
+
+    #[test]
+    fn test_polytree_fill_leaves_holes_empty() {
+        let tree = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 10.0, 10.0))
+            .to_clipper_subject()
+            .add_clip(Path::rectangle(2.0, 2.0, 6.0, 6.0))
+            .difference_tree(FillRule::default())
+            .unwrap()
+            .tree;
+
+        let buffers = tree
+            .tessellate_fill(&FillOptions::default().with_fill_rule(LyonFillRule::NonZero))
+            .unwrap();
+        assert!((area(&buffers) - 64.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_boolean_result_stroke_includes_open_paths() {
+        let result = BooleanResult::new(
+            Path::<Centi>::rectangle(0.0, 0.0, 1.0, 1.0).into(),
+            vec![(0.0, 2.0), (5.0, 2.0)].into(),
+        );
+
+        let closed_only = result
+            .closed
+            .tessellate_stroke(true, &StrokeOptions::default())
+            .unwrap();
+        let both = result.tessellate_stroke(&StrokeOptions::default()).unwrap();
+
+        assert!(both.indices.len() > closed_only.indices.len());
+        assert!(both
+            .vertices
+            .iter()
+            .any(|vertex| (vertex[0] - 5.0).abs() < 1.0 && vertex[1] > 1.5));
+    }
+}
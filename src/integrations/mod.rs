@@ -0,0 +1,47 @@
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "geo-traits")]
+mod geo_traits;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "image")]
+mod image;
+#[cfg(feature = "lyon")]
+mod lyon;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "rstar")]
+mod rstar;
+
+#[cfg(feature = "geo-traits")]
+pub use self::geo_traits::*;
+#[cfg(feature = "lyon")]
+pub use self::lyon::*;
+
+/// Build a star shaped polygon around `center` from `(radius, angle step)`
+/// pairs. The angle steps are relative weights that are scaled to add up to
+/// a full turn, so the vertices go around the center once counter-clockwise
+/// and the polygon doesn't self-intersect before rounding to the scaler.
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+fn star_polygon<P: crate::PointScaler>(
+    center: crate::Point<P>,
+    vertices: &[(f64, f64)],
+) -> crate::Path<P> {
+    let total = vertices.iter().map(|(_, step)| step).sum::<f64>();
+    let mut angle = 0.0;
+
+    vertices
+        .iter()
+        .map(|&(radius, step)| {
+            angle += step / total * std::f64::consts::TAU;
+            (
+                center.x() + radius * angle.cos(),
+                center.y() + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
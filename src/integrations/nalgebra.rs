@@ -0,0 +1,138 @@
+use nalgebra::{Point2, Vector2};
+
+use crate::{Path, Paths, Point, PointScaler};
+
+impl<P: PointScaler> From<Point2<f64>> for Point<P> {
+    fn from(point: Point2<f64>) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+impl<P: PointScaler> From<&Point2<f64>> for Point<P> {
+    fn from(point: &Point2<f64>) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+impl<P: PointScaler> From<Vector2<f64>> for Point<P> {
+    fn from(vector: Vector2<f64>) -> Self {
+        Self::new(vector.x, vector.y)
+    }
+}
+
+impl<P: PointScaler> From<&Vector2<f64>> for Point<P> {
+    fn from(vector: &Vector2<f64>) -> Self {
+        Self::new(vector.x, vector.y)
+    }
+}
+
+impl<P: PointScaler> From<Point<P>> for Point2<f64> {
+    fn from(point: Point<P>) -> Self {
+        Point2::new(point.x(), point.y())
+    }
+}
+
+impl<P: PointScaler> From<Point<P>> for Vector2<f64> {
+    fn from(point: Point<P>) -> Self {
+        Vector2::new(point.x(), point.y())
+    }
+}
+
+impl<P: PointScaler> FromIterator<Point2<f64>> for Path<P> {
+    fn from_iter<T: IntoIterator<Item = Point2<f64>>>(iter: T) -> Self {
+        iter.into_iter().map(Point::<P>::from).collect()
+    }
+}
+
+impl<P: PointScaler> FromIterator<Vector2<f64>> for Path<P> {
+    fn from_iter<T: IntoIterator<Item = Vector2<f64>>>(iter: T) -> Self {
+        iter.into_iter().map(Point::<P>::from).collect()
+    }
+}
+
+impl<P: PointScaler> From<Vec<Point2<f64>>> for Path<P> {
+    fn from(points: Vec<Point2<f64>>) -> Self {
+        points.into_iter().collect()
+    }
+}
+
+impl<P: PointScaler> From<Vec<Vector2<f64>>> for Path<P> {
+    fn from(points: Vec<Vector2<f64>>) -> Self {
+        points.into_iter().collect()
+    }
+}
+
+impl<P: PointScaler> From<Path<P>> for Vec<Point2<f64>> {
+    fn from(path: Path<P>) -> Self {
+        path.into_iter().map(Point2::from).collect()
+    }
+}
+
+impl<P: PointScaler> From<Path<P>> for Vec<Vector2<f64>> {
+    fn from(path: Path<P>) -> Self {
+        path.into_iter().map(Vector2::from).collect()
+    }
+}
+
+impl<P: PointScaler> From<Vec<Point2<f64>>> for Paths<P> {
+    fn from(points: Vec<Point2<f64>>) -> Self {
+        Paths::new(vec![points.into()])
+    }
+}
+
+impl<P: PointScaler> From<Vec<Vec<Point2<f64>>>> for Paths<P> {
+    fn from(paths: Vec<Vec<Point2<f64>>>) -> Self {
+        Paths::new(paths.into_iter().map(|path| path.into()).collect())
+    }
+}
+
+impl<P: PointScaler> From<Paths<P>> for Vec<Vec<Point2<f64>>> {
+    fn from(paths: Paths<P>) -> Self {
+        paths.into_iter().map(|path| path.into()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Centi;
+
+    use super::*;
+
+    #[test]
+    fn test_point_from_point2() {
+        let point = Point::<Centi>::from(Point2::new(1.0, 2.5));
+        assert_eq!(point.x_scaled(), 100);
+        assert_eq!(point.y_scaled(), 250);
+        assert_eq!(Point2::from(point), Point2::new(1.0, 2.5));
+    }
+
+    #[test]
+    fn test_point_from_vector2() {
+        let point = Point::<Centi>::from(Vector2::new(1.0, 2.5));
+        assert_eq!(point.x_scaled(), 100);
+        assert_eq!(point.y_scaled(), 250);
+        assert_eq!(Vector2::from(point), Vector2::new(1.0, 2.5));
+    }
+
+    #[test]
+    fn test_path_round_trip() {
+        let points = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0)];
+        let path = Path::<Centi>::from(points.clone());
+        assert_eq!(path, Path::from(vec![(0.0, 0.0), (1.0, 1.0)]));
+
+        let output: Vec<Point2<f64>> = path.into();
+        assert_eq!(output, points);
+    }
+
+    #[test]
+    fn test_paths_from_vecs() {
+        let paths = Paths::<Centi>::from(vec![vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0)]]);
+        assert_eq!(paths, Paths::from(vec![vec![(0.0, 0.0), (1.0, 1.0)]]));
+
+        let output: Vec<Vec<Point2<f64>>> = paths.into();
+        assert_eq!(
+            output,
+            vec![vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0)]]
+        );
+    }
+}
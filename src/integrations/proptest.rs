@@ -0,0 +1,70 @@
+use std::fmt::Debug;
+
+use proptest::{
+    arbitrary::Arbitrary,
+    collection::vec,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::{Path, Paths, Point, PointScaler};
+
+use super::star_polygon;
+
+/// Points are generated with both coordinates within -1000.0 and 1000.0.
+impl<P: PointScaler + Debug + 'static> Arbitrary for Point<P> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (-1000.0..=1000.0, -1000.0..=1000.0)
+            .prop_map(|(x, y)| Point::new(x, y))
+            .boxed()
+    }
+}
+
+/// Paths are generated as star shaped polygons of 3 to 16 points with a
+/// positive area and a radius of up to 100.0 around an arbitrary center, so
+/// they are valid polygons apart from rounding to the scaler.
+impl<P: PointScaler + Debug + 'static> Arbitrary for Path<P> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (Point::arbitrary(), vec((1.0..=100.0, 1.0..=8.0), 3..=16))
+            .prop_map(|(center, vertices)| star_polygon(center, &vertices))
+            .boxed()
+    }
+}
+
+/// Paths are generated as up to 8 closed paths, see [`Path`].
+impl<P: PointScaler + Debug + 'static> Arbitrary for Paths<P> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        vec(Path::arbitrary(), 0..=8).prop_map(Paths::new).boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::{prelude::*, proptest};
+
+    use crate::{union, Centi, FillRule};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_union_area_covers_inputs(a: Paths<Centi>, b: Paths<Centi>) {
+            let max_area = a
+                .iter()
+                .chain(b.iter())
+                .map(Path::signed_area)
+                .fold(0.0, f64::max);
+            let union = union(a, b, FillRule::NonZero).unwrap().closed;
+
+            prop_assert!(union.signed_area() >= max_area - 1e-6);
+        }
+    }
+}
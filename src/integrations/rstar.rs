@@ -0,0 +1,98 @@
+use rstar::{PointDistance, RTreeObject, AABB};
+
+use crate::{Path, Point, PointInPolygonResult, PointScaler};
+
+impl<P: PointScaler> RTreeObject for Path<P> {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_points(
+            self.iter()
+                .map(|point| [point.x(), point.y()])
+                .collect::<Vec<_>>()
+                .iter(),
+        )
+    }
+}
+
+impl<P: PointScaler> PointDistance for Path<P> {
+    /// Returns the squared distance from the point to the closed polygon,
+    /// which is zero for points inside or on the polygon.
+    fn distance_2(&self, point: &[f64; 2]) -> f64 {
+        if self.is_empty() {
+            return f64::MAX;
+        }
+
+        if self.is_point_inside(Point::new(point[0], point[1])) != PointInPolygonResult::IsOutside {
+            return 0.0;
+        }
+
+        let points = self.iter().map(|p| (p.x(), p.y())).collect::<Vec<_>>();
+        let mut closest = f64::MAX;
+
+        for (index, &start) in points.iter().enumerate() {
+            let end = points[(index + 1) % points.len()];
+            closest = closest.min(segment_distance_2(start, end, (point[0], point[1])));
+        }
+
+        closest
+    }
+}
+
+fn segment_distance_2(start: (f64, f64), end: (f64, f64), point: (f64, f64)) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length_2 = dx * dx + dy * dy;
+
+    let t = if length_2 == 0.0 {
+        0.0
+    } else {
+        (((point.0 - start.0) * dx + (point.1 - start.1) * dy) / length_2).clamp(0.0, 1.0)
+    };
+
+    let (x, y) = (start.0 + t * dx - point.0, start.1 + t * dy - point.1);
+    x * x + y * y
+}
+
+#[cfg(test)]
+mod test {
+    use rstar::RTree;
+
+    use crate::Centi;
+
+    use super::*;
+
+    #[test]
+    fn test_envelope() {
+        let path = Path::<Centi>::rectangle(-1.0, 2.0, 3.0, 4.0);
+        assert_eq!(path.envelope(), AABB::from_corners([-1.0, 2.0], [2.0, 6.0]));
+    }
+
+    #[test]
+    fn test_distance_2() {
+        let path = Path::<Centi>::rectangle(0.0, 0.0, 2.0, 2.0);
+        assert_eq!(path.distance_2(&[1.0, 1.0]), 0.0);
+        assert_eq!(path.distance_2(&[2.0, 1.0]), 0.0);
+        assert_eq!(path.distance_2(&[4.0, 1.0]), 4.0);
+        assert_eq!(path.distance_2(&[5.0, 6.0]), 25.0);
+    }
+
+    #[test]
+    fn test_rtree_queries() {
+        let tree = RTree::bulk_load(vec![
+            Path::<Centi>::rectangle(0.0, 0.0, 1.0, 1.0),
+            Path::<Centi>::rectangle(10.0, 0.0, 1.0, 1.0),
+            Path::<Centi>::rectangle(20.0, 0.0, 1.0, 1.0),
+        ]);
+
+        let found = tree.locate_all_at_point([10.5, 0.5]).collect::<Vec<_>>();
+        assert_eq!(found, vec![&Path::rectangle(10.0, 0.0, 1.0, 1.0)]);
+
+        let nearest = tree.nearest_neighbor([18.0, 0.5]).unwrap();
+        assert_eq!(nearest, &Path::rectangle(20.0, 0.0, 1.0, 1.0));
+
+        let in_envelope = tree
+            .locate_in_envelope_intersecting(AABB::from_corners([0.5, 0.5], [10.5, 0.5]))
+            .count();
+        assert_eq!(in_envelope, 2);
+    }
+}
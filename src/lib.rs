@@ -23,12 +23,24 @@
 //!     * [`Paths::to_clipper_open_subject`] similar but adds the current set
 //!       of paths as an open "line" rather than a closed path/polygon.
 //! 2. Via the plain functions:
+//!     * [`clip_lines_inside`] / [`clip_lines_outside`]
+//!     * [`concave_hull`]
 //!     * [`difference`]
+//!     * [`difference_all`]
+//!     * [`hatch`]
 //!     * [`inflate`]
 //!     * [`intersect`]
+//!     * [`intersection_area`]
+//!     * [`offset_one_side`]
+//!     * [`overlay_layers`]
 //!     * [`point_in_polygon`]
 //!     * [`simplify`]
+//!     * [`skeleton`]
+//!     * [`spiral_from_rings`]
+//!     * [`split_into_bands`]
+//!     * [`stroke_tapered`]
 //!     * [`union`]
+//!     * [`union_all`]
 //!     * [`xor`]
 //!
 //! The [`Path`]/[`Paths`] structs also thas some transformation methods such
@@ -39,6 +51,23 @@
 //! * [`Path::rotate`] / [`Paths::rotate`] for rotating a path in by x radians
 //! * [`Path::scale`] / [`Paths::scale`] for scaling a path by multiplier
 //!
+//! # Empty and degenerate input
+//!
+//! Boolean operations never fail because of empty or degenerate input. Empty
+//! [`Paths`], empty paths and closed paths that enclose no area, such as
+//! paths with fewer than three distinct points or with all points on one
+//! line, are treated as covering nothing:
+//!
+//! * a union or xor with such input returns the other operand
+//! * a difference returns the subject, or nothing if the subject is
+//!   degenerate
+//! * an intersection returns nothing
+//!
+//! Results never contain empty paths or paths without area. Offsetting is
+//! the exception, [`Paths::inflate`] grows a single point or a two point path
+//! into a shape around it, matching the Clipper2 library, while empty paths
+//! produce no output. [`Paths::simplify`] returns degenerate paths unchanged.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -52,6 +81,7 @@
 //!     .add_clip(path_b)
 //!     .difference(FillRule::default())
 //!     .expect("Failed difference operation")
+//!     .closed
 //!     .into();
 //!
 //! dbg!(output);
@@ -62,25 +92,125 @@
 //! More examples can be found in the
 //! [examples](https://github.com/tirithen/clipper2/tree/main/examples)
 //! directory.
+//!
+//! # Cargo features
+//!
+//! * `approx` - `approx::AbsDiffEq` and `approx::RelativeEq` for [`Point`],
+//!   [`Path`] and [`Paths`] to compare geometry within a tolerance.
+//! * `arbitrary` - `arbitrary::Arbitrary` for [`Point`], [`Path`] and
+//!   [`Paths`], generating star shaped polygons for fuzzing.
+//! * `serde` - serialize and deserialize [`Point`], [`Path`] and [`Paths`].
+//! * `geo-traits` - the `geo-traits` geometry traits for [`Point`], [`Path`],
+//!   [`Paths`] and [`PolyTree`], and [`Paths::from_geometry`] reading any
+//!   geometry implementing them, for zero-copy interop with crates such as
+//!   `geoarrow` and `geozero`.
+//! * `glam` - conversions between [`Point`]/[`Path`]/[`Paths`] and
+//!   `glam::Vec2`/`glam::DVec2`.
+//! * `image` - [`Paths::from_mask`] and [`Paths::from_image_threshold`] for
+//!   tracing the outlines of bitmap masks into paths.
+//! * `lyon` - [`Paths::tessellate_fill`] and [`Paths::tessellate_stroke`],
+//!   also on [`BooleanResult`] and [`PolyTree`], for turning paths into
+//!   vertex/index buffers with `lyon_tessellation`.
+//! * `nalgebra` - conversions between [`Point`]/[`Path`]/[`Paths`] and
+//!   `nalgebra::Point2<f64>`/`nalgebra::Vector2<f64>`.
+//! * `proptest` - `proptest::arbitrary::Arbitrary` for [`Point`], [`Path`]
+//!   and [`Paths`], generating the same kind of polygons for property tests.
+//! * `rayon` - [`union_all_par`] and [`pairwise_clip_par`], which join many
+//!   inputs and clip many pairs of inputs in parallel.
+//! * `rstar` - `rstar::RTreeObject` and `rstar::PointDistance` for [`Path`]
+//!   so paths can be stored in an `rstar::RTree`.
+//! * `shapefile` - [`read_shp`], [`write_shp`], [`load_shapefile`] and
+//!   [`save_shapefile`] for reading and writing polygon and polyline
+//!   shapefiles.
+//! * `stats` - [`Clipper::boolean_with_stats`] and [`inflate_with_stats`],
+//!   returning [`ExecutionStats`] with vertex counts and the time spent
+//!   converting paths and in the native engine.
+//! * `test-utils` - [`test_utils`], helpers for golden snapshot tests that
+//!   compare paths rendered to SVG against reviewed files.
+//! * `viewer` - [`debug::viewer::Viewer`], an interactive window for
+//!   inspecting layers of paths with pan, zoom and layer toggles.
+//! * `wkb` - [`Paths::from_wkb`], [`Paths::to_wkb`] and the PostGIS EWKB
+//!   variants with an SRID, also for [`PolyTree`], for reading and writing
+//!   Well-Known Binary geometry.
+//! * `wkt` - [`Paths::from_wkt`] and [`Paths::to_wkt`] for reading and
+//!   writing Well-Known Text geometry.
 
 mod bounds;
+mod cache;
+pub mod cam;
 mod clipper;
+pub mod debug;
+pub mod double;
+mod engine;
+mod expr;
+pub mod extrude;
+mod ffi;
+mod gerber;
+mod integrations;
+mod memory;
+mod native_paths;
+mod native_polytree;
+mod openscad;
 mod operations;
 mod options;
+mod overlay;
 mod path;
 mod paths;
+mod pipeline;
 mod point;
-
-use clipper2c_sys::clipper_allocate;
+mod polytree;
+mod quantization;
+pub mod raster;
+pub mod raw;
+mod rect_clip;
+#[cfg(feature = "shapefile")]
+mod shapefile;
+pub mod spatial;
+#[cfg(feature = "stats")]
+mod stats;
+mod stream;
+mod svg_path;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+mod triangulate;
+mod units;
+mod validate;
+#[cfg(feature = "wkb")]
+mod wkb;
+#[cfg(feature = "wkt")]
+mod wkt;
 
 pub use crate::bounds::*;
+pub use crate::cache::*;
 pub use crate::clipper::*;
+pub use crate::engine::*;
+pub use crate::expr::*;
+pub use crate::gerber::*;
+#[cfg(any(feature = "geo-traits", feature = "lyon"))]
+pub use crate::integrations::*;
+pub use crate::memory::*;
+pub use crate::native_paths::*;
+pub use crate::native_polytree::*;
 pub use crate::operations::*;
 pub use crate::options::*;
+pub use crate::overlay::*;
 pub use crate::path::*;
 pub use crate::paths::*;
+pub use crate::pipeline::*;
 pub use crate::point::*;
-
-pub(crate) unsafe fn malloc(size: usize) -> *mut libc::c_void {
-    clipper_allocate(size)
-}
+pub use crate::polytree::*;
+pub use crate::quantization::*;
+pub use crate::rect_clip::*;
+#[cfg(feature = "shapefile")]
+pub use crate::shapefile::*;
+#[cfg(feature = "stats")]
+pub use crate::stats::*;
+pub use crate::stream::*;
+pub use crate::svg_path::*;
+pub use crate::triangulate::*;
+pub use crate::units::*;
+pub use crate::validate::*;
+#[cfg(feature = "wkb")]
+pub use crate::wkb::*;
+#[cfg(feature = "wkt")]
+pub use crate::wkt::*;
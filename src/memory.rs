@@ -0,0 +1,347 @@
+//! Allocation of the memory backing the native Clipper2 objects.
+//!
+//! Every clipper, path, paths and polytree object handed to the native
+//! library lives in a block allocated here. The blocks come in a handful of
+//! fixed sizes, so freed blocks are kept in a per-thread pool and handed out
+//! again by the next allocation of the same size instead of going back to
+//! the allocator.
+//!
+//! The blocks come from the global Rust allocator unless another allocator
+//! is installed with [`set_native_allocator`].
+
+use std::{
+    alloc::{self, GlobalAlloc, Layout},
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        OnceLock,
+    },
+};
+
+use clipper2c_sys::{
+    clipper_clipper64_size, clipper_clipperd_size, clipper_path64_size, clipper_pathd_size,
+    clipper_paths64_size, clipper_pathsd_size, clipper_polytree64_size, clipper_polytreed_size,
+    ClipperClipper64, ClipperClipperD, ClipperPath64, ClipperPathD, ClipperPaths64, ClipperPathsD,
+    ClipperPolyTree64, ClipperPolyTreeD,
+};
+
+use crate::ffi::{
+    clipper_destruct_clipper64, clipper_destruct_clipperd, clipper_destruct_path64,
+    clipper_destruct_pathd, clipper_destruct_paths64, clipper_destruct_pathsd,
+    clipper_destruct_polytree64, clipper_destruct_polytreed,
+};
+
+/// Alignment of all blocks, matching what `operator new` guarantees.
+const BLOCK_ALIGN: usize = 16;
+
+/// Default number of freed blocks kept per block size and thread.
+const DEFAULT_POOL_LIMIT: usize = 64;
+
+static POOL_LIMIT: AtomicUsize = AtomicUsize::new(DEFAULT_POOL_LIMIT);
+
+/// Allocator of all blocks, fixed by the first allocation.
+static ALLOCATOR: OnceLock<&'static (dyn GlobalAlloc + Sync)> = OnceLock::new();
+
+/// Forwards to the global Rust allocator, the default for native objects.
+struct Global;
+
+unsafe impl GlobalAlloc for Global {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        alloc::alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        alloc::dealloc(ptr, layout)
+    }
+}
+
+/// Errors from installing an allocator with [`set_native_allocator`].
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum AllocatorError {
+    /// Native objects have already been allocated, or another allocator has
+    /// been installed before.
+    #[error("The native allocator can only be set before the first native object is allocated")]
+    AlreadyInUse,
+}
+
+/// Route the blocks backing the native Clipper2 objects through a custom
+/// allocator, such as a tracking or arena allocator of a game engine.
+///
+/// This covers the objects allocated by this crate. The buffers the native
+/// library allocates inside those objects, such as the point storage of
+/// paths, still come from the C++ allocator.
+///
+/// The allocator is global and must be installed before the first native
+/// object is allocated, which happens with the first clipping, offsetting or
+/// other native operation of the process. Blocks freed by the native objects
+/// are still pooled per thread as described in
+/// [`set_allocation_pool_limit`], set the limit to `0` to see every
+/// allocation in the allocator. Returns an error if native objects have
+/// already been allocated.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::alloc::{GlobalAlloc, Layout, System};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// use clipper2::*;
+///
+/// struct Counting(AtomicUsize);
+///
+/// unsafe impl GlobalAlloc for Counting {
+///     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///         System.alloc(layout)
+///     }
+///
+///     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+///         System.dealloc(ptr, layout)
+///     }
+/// }
+///
+/// static COUNTING: Counting = Counting(AtomicUsize::new(0));
+///
+/// set_native_allocator(&COUNTING).unwrap();
+///
+/// let square: Paths = Path::rectangle(0.0, 0.0, 1.0, 1.0).into();
+/// square.inflate(1.0, JoinType::Round, EndType::Polygon, 2.0);
+///
+/// assert!(COUNTING.0.load(Ordering::Relaxed) > 0);
+/// assert_eq!(
+///     set_native_allocator(&COUNTING),
+///     Err(AllocatorError::AlreadyInUse)
+/// );
+/// ```
+pub fn set_native_allocator(
+    allocator: &'static (dyn GlobalAlloc + Sync),
+) -> Result<(), AllocatorError> {
+    ALLOCATOR
+        .set(allocator)
+        .map_err(|_| AllocatorError::AlreadyInUse)
+}
+
+fn allocator() -> &'static (dyn GlobalAlloc + Sync) {
+    *ALLOCATOR.get_or_init(|| &Global)
+}
+
+/// Freed blocks of the current thread, grouped by block size.
+#[derive(Default)]
+struct BlockPool {
+    free: Vec<(usize, Vec<*mut u8>)>,
+}
+
+impl BlockPool {
+    fn blocks(&mut self, size: usize) -> &mut Vec<*mut u8> {
+        let index = match self.free.iter().position(|(s, _)| *s == size) {
+            Some(index) => index,
+            None => {
+                self.free.push((size, Vec::new()));
+                self.free.len() - 1
+            }
+        };
+        &mut self.free[index].1
+    }
+
+    fn clear(&mut self) {
+        for (size, blocks) in self.free.drain(..) {
+            for block in blocks {
+                unsafe { allocator().dealloc(block, layout(size)) };
+            }
+        }
+    }
+}
+
+impl Drop for BlockPool {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+thread_local! {
+    static POOL: RefCell<BlockPool> = RefCell::new(BlockPool::default());
+}
+
+/// Set the maximum number of freed blocks that each thread keeps per block
+/// size for reuse by later operations. Setting the limit to `0` disables
+/// pooling, so every native object is allocated and freed individually.
+///
+/// The default limit is 64, which covers the handful of objects alive at
+/// once during any single operation.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// set_allocation_pool_limit(256);
+/// assert_eq!(allocation_pool_limit(), 256);
+/// # set_allocation_pool_limit(64);
+/// ```
+pub fn set_allocation_pool_limit(limit: usize) {
+    POOL_LIMIT.store(limit, Ordering::Relaxed);
+}
+
+/// Get the maximum number of freed blocks kept per block size and thread,
+/// see [`set_allocation_pool_limit`].
+pub fn allocation_pool_limit() -> usize {
+    POOL_LIMIT.load(Ordering::Relaxed)
+}
+
+/// Free all blocks kept for reuse by the current thread.
+pub fn clear_allocation_pool() {
+    let _ = POOL.try_with(|pool| pool.borrow_mut().clear());
+}
+
+fn layout(size: usize) -> Layout {
+    Layout::from_size_align(size.max(1), BLOCK_ALIGN).expect("invalid native object size")
+}
+
+/// Allocate a block of `size` bytes for a native object, reusing a pooled
+/// block of the same size if there is one.
+pub(crate) unsafe fn malloc(size: usize) -> *mut libc::c_void {
+    let pooled = POOL
+        .try_with(|pool| pool.borrow_mut().blocks(size).pop())
+        .ok()
+        .flatten();
+
+    let block = match pooled {
+        Some(block) => block,
+        None => {
+            let layout = layout(size);
+            let block = allocator().alloc(layout);
+            if block.is_null() {
+                alloc::handle_alloc_error(layout);
+            }
+            block
+        }
+    };
+
+    block.cast()
+}
+
+/// Return a block allocated with [`malloc`] to the pool, or free it if the
+/// pool of this thread is full.
+pub(crate) unsafe fn free(block: *mut libc::c_void, size: usize) {
+    let block = block.cast::<u8>();
+    let limit = allocation_pool_limit();
+
+    let pooled = POOL
+        .try_with(|pool| {
+            let mut pool = pool.borrow_mut();
+            let blocks = pool.blocks(size);
+            if blocks.len() < limit {
+                blocks.push(block);
+                true
+            } else {
+                false
+            }
+        })
+        .unwrap_or(false);
+
+    if !pooled {
+        allocator().dealloc(block, layout(size));
+    }
+}
+
+/// Destruct a native path and free its block.
+pub(crate) unsafe fn delete_path64(ptr: *mut ClipperPath64) {
+    clipper_destruct_path64(ptr);
+    free(ptr.cast(), clipper_path64_size());
+}
+
+/// Destruct a native paths object and free its block.
+pub(crate) unsafe fn delete_paths64(ptr: *mut ClipperPaths64) {
+    clipper_destruct_paths64(ptr);
+    free(ptr.cast(), clipper_paths64_size());
+}
+
+/// Destruct a native clipper and free its block.
+pub(crate) unsafe fn delete_clipper64(ptr: *mut ClipperClipper64) {
+    clipper_destruct_clipper64(ptr);
+    free(ptr.cast(), clipper_clipper64_size());
+}
+
+/// Destruct a native polytree and free its block.
+pub(crate) unsafe fn delete_polytree64(ptr: *mut ClipperPolyTree64) {
+    clipper_destruct_polytree64(ptr);
+    free(ptr.cast(), clipper_polytree64_size());
+}
+
+/// Destruct a native double precision path and free its block.
+pub(crate) unsafe fn delete_pathd(ptr: *mut ClipperPathD) {
+    clipper_destruct_pathd(ptr);
+    free(ptr.cast(), clipper_pathd_size());
+}
+
+/// Destruct native double precision paths and free their block.
+pub(crate) unsafe fn delete_pathsd(ptr: *mut ClipperPathsD) {
+    clipper_destruct_pathsd(ptr);
+    free(ptr.cast(), clipper_pathsd_size());
+}
+
+/// Destruct a native double precision clipper and free its block.
+pub(crate) unsafe fn delete_clipperd(ptr: *mut ClipperClipperD) {
+    clipper_destruct_clipperd(ptr);
+    free(ptr.cast(), clipper_clipperd_size());
+}
+
+/// Destruct a native double precision polytree and free its block.
+pub(crate) unsafe fn delete_polytreed(ptr: *mut ClipperPolyTreeD) {
+    clipper_destruct_polytreed(ptr);
+    free(ptr.cast(), clipper_polytreed_size());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_freed_blocks_are_reused() {
+        clear_allocation_pool();
+
+        unsafe {
+            let block = malloc(48);
+            free(block, 48);
+
+            assert_eq!(malloc(48), block);
+
+            let other = malloc(48);
+            assert_ne!(other, block);
+
+            free(block, 48);
+            free(other, 48);
+        }
+
+        clear_allocation_pool();
+    }
+
+    #[test]
+    fn test_allocator_is_fixed_by_first_allocation() {
+        unsafe { free(malloc(16), 16) };
+
+        assert_eq!(
+            set_native_allocator(&Global),
+            Err(AllocatorError::AlreadyInUse)
+        );
+    }
+
+    #[test]
+    fn test_native_objects_round_trip_through_pool() {
+        use crate::{Centi, EndType, FillRule, JoinType, Path, Paths};
+
+        let paths = Paths::<Centi>::new(vec![Path::rectangle(0.0, 0.0, 2.0, 1.0)]);
+
+        for _ in 0..10 {
+            let inflated = paths.inflate(1.0, JoinType::Miter, EndType::Polygon, 2.0);
+            assert_eq!(inflated.signed_area(), 12.0);
+
+            let union = paths
+                .to_clipper_subject()
+                .add_clip(inflated)
+                .union_tree(FillRule::NonZero)
+                .unwrap();
+            assert_eq!(union.tree.total_net_area(), 12.0);
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::marker::PhantomData;
+
+use clipper2c_sys::{
+    clipper_path64_size, clipper_paths64_get_path, clipper_paths64_length, ClipperPaths64,
+};
+
+use crate::{
+    malloc,
+    memory::{delete_path64, delete_paths64},
+    Centi, Path, PointScaler,
+};
+
+/// Iterator over paths kept in the native representation of the Clipper2
+/// library, converting one path at a time as it's advanced, returned by
+/// [`Clipper::union_iter`] and the other `*_iter` operations.
+///
+/// Collecting a boolean result into [`Paths`] converts all paths at once.
+/// This iterator keeps the native result alive until it's dropped instead,
+/// so huge results can be filtered or serialized on the fly while only one
+/// converted path is held in memory at a time.
+///
+/// [`Clipper::union_iter`]: crate::Clipper::union_iter
+/// [`Paths`]: crate::Paths
+#[derive(Debug)]
+pub struct NativePathsIter<P: PointScaler = Centi> {
+    ptr: *mut ClipperPaths64,
+    next: usize,
+    len: usize,
+    _marker: PhantomData<P>,
+}
+
+// The native paths are owned exclusively by the iterator, so it can be moved
+// to another thread.
+unsafe impl<P: PointScaler> Send for NativePathsIter<P> {}
+
+impl<P: PointScaler> NativePathsIter<P> {
+    /// Take ownership of native paths. The caller must ensure the pointer is
+    /// valid and allocated with [`malloc`], it's deleted when the iterator
+    /// is dropped.
+    pub(crate) unsafe fn from_ptr(ptr: *mut ClipperPaths64) -> Self {
+        Self {
+            ptr,
+            next: 0,
+            len: clipper_paths64_length(ptr),
+            _marker: PhantomData,
+        }
+    }
+
+    pub(crate) fn ptr(&self) -> *mut ClipperPaths64 {
+        self.ptr
+    }
+
+    /// Start over at the first path, rereading the number of paths after the
+    /// native paths have been written to.
+    pub(crate) fn reset(mut self) -> Self {
+        self.next = 0;
+        self.len = unsafe { clipper_paths64_length(self.ptr) };
+        self
+    }
+}
+
+impl<P: PointScaler> Iterator for NativePathsIter<P> {
+    type Item = Path<P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.len {
+            return None;
+        }
+
+        let path = unsafe {
+            let ptr =
+                clipper_paths64_get_path(malloc(clipper_path64_size()), self.ptr, self.next as _);
+            let path = Path::from_clipperpath64(ptr);
+            delete_path64(ptr);
+            path
+        };
+        self.next += 1;
+
+        Some(path)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.next;
+        (remaining, Some(remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // Skipped paths are never converted.
+        self.next = self.next.saturating_add(n).min(self.len);
+        self.next()
+    }
+}
+
+impl<P: PointScaler> ExactSizeIterator for NativePathsIter<P> {}
+
+impl<P: PointScaler> Drop for NativePathsIter<P> {
+    fn drop(&mut self) {
+        unsafe { delete_paths64(self.ptr) }
+    }
+}
+
+/// The result of a boolean operation with iterators converting the closed
+/// and open paths one at a time, see [`NativePathsIter`].
+#[derive(Debug)]
+pub struct BooleanIterResult<P: PointScaler = Centi> {
+    /// Closed paths from the boolean operation
+    pub closed: NativePathsIter<P>,
+    /// Open paths from the boolean operation
+    pub open: NativePathsIter<P>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{FillRule, Paths};
+
+    use super::*;
+
+    #[test]
+    fn test_iter_matches_collected_result() {
+        let mut subject = Paths::<Centi>::new(
+            (0..10)
+                .map(|i| Path::rectangle(i as f64 * 3.0, 0.0, 2.0, 2.0))
+                .collect(),
+        );
+        subject.push_open(vec![(-1.0, 1.0), (40.0, 1.0)]);
+        let clip = Paths::<Centi>::from(Path::rectangle(1.0, -1.0, 20.0, 4.0));
+
+        let expected = subject
+            .to_clipper_subject()
+            .add_clip(clip.clone())
+            .difference(FillRule::NonZero)
+            .unwrap();
+        let result = subject
+            .to_clipper_subject()
+            .add_clip(clip)
+            .difference_iter(FillRule::NonZero)
+            .unwrap();
+
+        assert_eq!(result.closed.len(), expected.closed.len());
+        assert_eq!(result.closed.collect::<Paths<Centi>>(), expected.closed);
+        assert_eq!(
+            result.open.collect::<Vec<_>>(),
+            expected.open.iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_iter_skips_and_filters() {
+        let subject = Paths::<Centi>::new(
+            (1..=5)
+                .map(|i| Path::rectangle(i as f64 * 10.0, 0.0, i as f64, i as f64))
+                .collect(),
+        );
+
+        let mut closed = subject
+            .to_clipper_subject()
+            .add_clip(Paths::default())
+            .union_iter(FillRule::NonZero)
+            .unwrap()
+            .closed;
+
+        assert_eq!(closed.size_hint(), (5, Some(5)));
+        assert!(closed.nth(1).is_some());
+        assert_eq!(closed.len(), 3);
+        assert!(closed.nth(5).is_none());
+        assert!(closed.next().is_none());
+
+        let large = subject
+            .to_clipper_subject()
+            .add_clip(Path::rectangle(0.0, 0.0, 100.0, 100.0))
+            .intersect_iter(FillRule::NonZero)
+            .unwrap()
+            .closed
+            .filter(|path| path.signed_area() > 10.0)
+            .count();
+        assert_eq!(large, 2);
+    }
+}
@@ -0,0 +1,284 @@
+use std::marker::PhantomData;
+
+use clipper2c_sys::{
+    clipper_path64_size, clipper_paths64_size, clipper_polytree64_area, clipper_polytree64_count,
+    clipper_polytree64_get_child, clipper_polytree64_is_hole, clipper_polytree64_polygon,
+    clipper_polytree64_to_paths, ClipperPolyTree64,
+};
+
+use crate::{
+    malloc,
+    memory::{delete_path64, delete_paths64, delete_polytree64},
+    Centi, Path, Paths, PointScaler, PolyTree,
+};
+
+/// A [`PolyTree`] kept in the native representation of the Clipper2
+/// library, returned by [`Clipper::union_native_tree`] and the other
+/// `*_native_tree` operations.
+///
+/// Building a [`PolyTree`] copies every polygon of the result into Rust.
+/// This tree instead keeps the native tree alive until it's dropped, and only
+/// converts the nodes that are visited, so results with millions of vertices
+/// can be inspected or filtered without copying all of them. Use
+/// [`NativePolyTree::to_owned`] to convert the whole tree.
+///
+/// [`Clipper::union_native_tree`]: crate::Clipper::union_native_tree
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let outer: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+/// let hole: Paths = Path::rectangle(2.0, 2.0, 6.0, 6.0).into();
+///
+/// let result = outer
+///     .to_clipper_subject()
+///     .add_clip(hole)
+///     .difference_native_tree(FillRule::default())
+///     .unwrap();
+///
+/// let outer = result.tree.get_child(0).unwrap();
+/// assert!(!outer.is_hole());
+/// assert_eq!(outer.net_area(), 64.0);
+/// assert!(outer.get_child(0).unwrap().is_hole());
+///
+/// assert_eq!(result.tree.to_paths().len(), 2);
+/// assert_eq!(result.tree.to_owned().child_count(), 1);
+/// ```
+#[derive(Debug)]
+pub struct NativePolyTree<P: PointScaler = Centi> {
+    ptr: *mut ClipperPolyTree64,
+    _marker: PhantomData<P>,
+}
+
+// The native tree is owned exclusively by this struct and only read after it
+// has been built, reading a node allocates new native paths instead of
+// touching the tree, so it can be moved and shared between threads.
+unsafe impl<P: PointScaler> Send for NativePolyTree<P> {}
+unsafe impl<P: PointScaler> Sync for NativePolyTree<P> {}
+
+impl<P: PointScaler> NativePolyTree<P> {
+    /// Take ownership of a native tree. The caller must ensure the pointer
+    /// is the root of a valid tree allocated with [`malloc`], it's deleted
+    /// when the returned struct is dropped.
+    pub(crate) unsafe fn from_ptr(ptr: *mut ClipperPolyTree64) -> Self {
+        Self {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the root node of the tree, which has no polygon.
+    pub fn root(&self) -> NativePolyNode<'_, P> {
+        NativePolyNode {
+            ptr: self.ptr,
+            depth: 0,
+            _tree: PhantomData,
+        }
+    }
+
+    /// Get the number of direct children of the root node.
+    pub fn child_count(&self) -> usize {
+        self.root().child_count()
+    }
+
+    /// Get a child of the root node at the given index.
+    pub fn get_child(&self, index: usize) -> Option<NativePolyNode<'_, P>> {
+        self.root().get_child(index)
+    }
+
+    /// Returns an iterator over the children of the root node.
+    pub fn children(&self) -> impl Iterator<Item = NativePolyNode<'_, P>> {
+        self.root().children()
+    }
+
+    /// Convert the whole tree into a [`PolyTree`].
+    pub fn to_owned(&self) -> PolyTree<P> {
+        self.root().to_owned()
+    }
+
+    /// Convert the polygons of the whole tree into [`Paths`], without
+    /// building the hierarchy.
+    pub fn to_paths(&self) -> Paths<P> {
+        self.root().to_paths()
+    }
+}
+
+impl<P: PointScaler> Drop for NativePolyTree<P> {
+    fn drop(&mut self) {
+        unsafe { delete_polytree64(self.ptr) }
+    }
+}
+
+/// A node of a [`NativePolyTree`], borrowed from the tree. Nothing is
+/// converted until one of the methods reading the polygon is called.
+#[derive(Debug, Clone, Copy)]
+pub struct NativePolyNode<'a, P: PointScaler = Centi> {
+    ptr: *mut ClipperPolyTree64,
+    depth: usize,
+    _tree: PhantomData<&'a NativePolyTree<P>>,
+}
+
+impl<'a, P: PointScaler> NativePolyNode<'a, P> {
+    /// Get the number of direct children of this node.
+    pub fn child_count(&self) -> usize {
+        unsafe { clipper_polytree64_count(self.ptr) }
+    }
+
+    /// Get a child of this node at the given index.
+    pub fn get_child(&self, index: usize) -> Option<NativePolyNode<'a, P>> {
+        (index < self.child_count()).then(|| NativePolyNode {
+            // The C function returns a const pointer, the node is only read.
+            ptr: unsafe { clipper_polytree64_get_child(self.ptr, index) as *mut _ },
+            depth: self.depth + 1,
+            _tree: PhantomData,
+        })
+    }
+
+    /// Returns an iterator over the direct children of this node.
+    pub fn children(&self) -> impl Iterator<Item = NativePolyNode<'a, P>> {
+        let node = *self;
+        (0..self.child_count()).filter_map(move |index| node.get_child(index))
+    }
+
+    /// Check if this node represents a hole.
+    pub fn is_hole(&self) -> bool {
+        unsafe { clipper_polytree64_is_hole(self.ptr) == 1 }
+    }
+
+    /// Get the depth of this node in the tree, see [`PolyTree::depth`].
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Convert the polygon of this node into a [`Path`]. The root node has
+    /// an empty polygon.
+    pub fn polygon(&self) -> Path<P> {
+        unsafe {
+            let ptr = clipper_polytree64_polygon(malloc(clipper_path64_size()), self.ptr);
+            let polygon = Path::from_clipperpath64(ptr);
+            delete_path64(ptr);
+            polygon
+        }
+    }
+
+    /// Get the sum of the signed areas of the polygons of this node and all
+    /// its descendants, computed by the native library without converting
+    /// them. As holes are oriented opposite to their outers, this is the
+    /// filled area of an outer node, see [`PolyTree::net_area`].
+    pub fn net_area(&self) -> f64 {
+        unsafe { clipper_polytree64_area(self.ptr) / (P::MULTIPLIER * P::MULTIPLIER) }
+    }
+
+    /// Convert this node and all its descendants into a [`PolyTree`].
+    pub fn to_owned(&self) -> PolyTree<P> {
+        unsafe { PolyTree::from_ptr(self.ptr, self.depth) }
+    }
+
+    /// Convert the polygons of this node and all its descendants into
+    /// [`Paths`], without building the hierarchy.
+    pub fn to_paths(&self) -> Paths<P> {
+        unsafe {
+            let ptr = clipper_polytree64_to_paths(malloc(clipper_paths64_size()), self.ptr);
+            let paths = Paths::from_clipperpaths64(ptr);
+            delete_paths64(ptr);
+            paths
+        }
+    }
+}
+
+/// The result of a boolean operation containing a [`NativePolyTree`] and
+/// open paths.
+#[derive(Debug)]
+pub struct NativeBooleanTreeResult<P: PointScaler = Centi> {
+    /// Native tree containing the closed paths with hierarchy information
+    pub tree: NativePolyTree<P>,
+    /// Open paths from the boolean operation, marked as open
+    pub open: Paths<P>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{FillRule, Paths};
+
+    use super::*;
+
+    fn nested() -> (Paths<Centi>, Paths<Centi>) {
+        let subject = Paths::new(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(20.0, 0.0, 5.0, 5.0),
+            Path::rectangle(4.0, 4.0, 2.0, 2.0),
+        ]);
+        let clip = Paths::from(Path::rectangle(2.0, 2.0, 6.0, 6.0));
+        (subject, clip)
+    }
+
+    #[test]
+    fn test_native_tree_matches_owned_tree() {
+        let (subject, clip) = nested();
+
+        let owned = subject
+            .to_clipper_subject()
+            .add_clip(clip.clone())
+            .xor_tree(FillRule::EvenOdd)
+            .unwrap()
+            .tree;
+        let native = subject
+            .to_clipper_subject()
+            .add_clip(clip)
+            .xor_native_tree(FillRule::EvenOdd)
+            .unwrap()
+            .tree;
+
+        assert_eq!(native.to_owned(), owned);
+        // The owned tree also returns the empty polygon of the root.
+        assert_eq!(
+            native.to_paths(),
+            owned
+                .to_paths()
+                .iter()
+                .filter(|path| !path.is_empty())
+                .cloned()
+                .collect()
+        );
+        assert_eq!(native.child_count(), owned.child_count());
+
+        let outer = native
+            .children()
+            .find(|node| node.child_count() == 1)
+            .unwrap();
+        let hole = outer.get_child(0).unwrap();
+        let island = hole.get_child(0).unwrap();
+
+        assert_eq!((outer.depth(), hole.depth(), island.depth()), (1, 2, 3));
+        assert!(hole.is_hole() && !island.is_hole());
+        assert_eq!(hole.polygon().signed_area().abs(), 36.0);
+        assert_eq!(outer.net_area(), 100.0 - 36.0 + 4.0);
+        assert_eq!(island.to_owned(), *owned_island(&owned));
+        assert!(outer.get_child(1).is_none());
+        assert!(native.root().polygon().is_empty());
+    }
+
+    fn owned_island(tree: &PolyTree<Centi>) -> &PolyTree<Centi> {
+        tree.iter().find(|node| node.depth() == 3).unwrap()
+    }
+
+    #[test]
+    fn test_native_tree_open_paths_and_threads() {
+        let mut subject = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 4.0, 4.0));
+        subject.push_open(vec![(-1.0, 2.0), (5.0, 2.0)]);
+
+        let result = subject
+            .to_clipper_subject()
+            .add_clip(Path::rectangle(1.0, 0.0, 2.0, 4.0))
+            .intersect_native_tree(FillRule::NonZero)
+            .unwrap();
+
+        assert_eq!(result.open.len(), 1);
+        let area = std::thread::spawn(move || result.tree.get_child(0).unwrap().net_area())
+            .join()
+            .unwrap();
+        assert_eq!(area, 8.0);
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt::Write;
+
+use crate::{Path, Paths, PointScaler, PolyTree};
+
+impl<P: PointScaler> Paths<P> {
+    /// Export the paths as an OpenSCAD `polygon()` statement.
+    ///
+    /// All paths are written to the same polygon, which OpenSCAD fills with
+    /// the even-odd rule, so holes are cut out of the paths surrounding them.
+    /// Paths that overlap without being holes should be unioned first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 5.0)].into();
+    ///
+    /// assert_eq!(
+    ///     paths.to_openscad(),
+    ///     "polygon(points=[[0,0],[10,0],[10,5]], paths=[[0,1,2]]);\n",
+    /// );
+    /// ```
+    pub fn to_openscad(&self) -> String {
+        openscad_polygon(self.iter())
+    }
+}
+
+impl<P: PointScaler> PolyTree<P> {
+    /// Export the polygons of the tree as an OpenSCAD `polygon()` statement,
+    /// see [`Paths::to_openscad`].
+    pub fn to_openscad(&self) -> String {
+        openscad_polygon(self.to_paths().iter())
+    }
+}
+
+fn openscad_polygon<'a, P: PointScaler + 'a>(paths: impl Iterator<Item = &'a Path<P>>) -> String {
+    let mut points = String::new();
+    let mut indices = String::new();
+    let mut index = 0;
+
+    for path in paths.filter(|path| path.len() >= 3) {
+        if !indices.is_empty() {
+            indices.push(',');
+        }
+        indices.push('[');
+
+        for (i, point) in path.iter().enumerate() {
+            if index > 0 {
+                points.push(',');
+            }
+            if i > 0 {
+                indices.push(',');
+            }
+
+            let _ = write!(points, "[{},{}]", point.x(), point.y());
+            let _ = write!(indices, "{index}");
+            index += 1;
+        }
+
+        indices.push(']');
+    }
+
+    format!("polygon(points=[{points}], paths=[{indices}]);\n")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, FillRule};
+
+    use super::*;
+
+    #[test]
+    fn test_paths_with_hole() {
+        let paths = Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 4.0, 4.0),
+            Path::rectangle(1.0, 1.0, 1.5, 2.0),
+        ]);
+
+        assert_eq!(
+            paths.to_openscad(),
+            "polygon(points=[[0,0],[4,0],[4,4],[0,4],[1,1],[2.5,1],[2.5,3],[1,3]], \
+             paths=[[0,1,2,3],[4,5,6,7]]);\n"
+        );
+    }
+
+    #[test]
+    fn test_polytree_skips_root() {
+        let tree = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 4.0, 4.0))
+            .to_clipper_subject()
+            .add_clip(Path::rectangle(1.0, 1.0, 2.0, 2.0))
+            .difference_tree(FillRule::default())
+            .unwrap()
+            .tree;
+        let scad = tree.to_openscad();
+
+        assert_eq!(scad.matches("],[").count(), 8);
+        assert!(scad.contains("paths=[[0,1,2,3],[4,5,6,7]]"));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(
+            Paths::<Centi>::default().to_openscad(),
+            "polygon(points=[], paths=[]);\n"
+        );
+    }
+}
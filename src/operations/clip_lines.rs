@@ -0,0 +1,146 @@
+use crate::{Clipper, ClipperError, FillRule, Paths, PointScaler};
+
+/// This function cuts polylines to the parts inside the area covered by the
+/// polygons.
+///
+/// All `lines` are treated as open paths, even if their last point equals
+/// the first one, and the result holds the open paths of the intersection,
+/// marked as open. Passing `None` as the fill rule uses
+/// [`FillRule::default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let lines: Paths = vec![vec![(-1.0, 1.0), (3.0, 1.0)]].into();
+/// let square: Paths = Path::rectangle(0.0, 0.0, 2.0, 2.0).into();
+///
+/// let inside = clip_lines_inside(lines, square, FillRule::default()).unwrap();
+///
+/// assert_eq!(inside.len(), 1);
+/// assert_eq!(inside[0].len(), 2);
+/// ```
+pub fn clip_lines_inside<P: PointScaler>(
+    lines: impl Into<Paths<P>>,
+    polygons: impl Into<Paths<P>>,
+    fill_rule: impl Into<Option<FillRule>>,
+) -> Result<Paths<P>, ClipperError> {
+    let fill_rule = fill_rule.into().unwrap_or_default();
+
+    Ok(Clipper::new()
+        .add_open_subject(lines)
+        .add_clip(polygons)
+        .intersect(fill_rule)?
+        .open)
+}
+
+/// This function cuts polylines to the parts outside the area covered by the
+/// polygons.
+///
+/// All `lines` are treated as open paths, even if their last point equals
+/// the first one, and the result holds the open paths of the difference,
+/// marked as open. Passing `None` as the fill rule uses
+/// [`FillRule::default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let lines: Paths = vec![vec![(-1.0, 1.0), (3.0, 1.0)]].into();
+/// let square: Paths = Path::rectangle(0.0, 0.0, 2.0, 2.0).into();
+///
+/// let outside = clip_lines_outside(lines, square, FillRule::default()).unwrap();
+///
+/// assert_eq!(outside.len(), 2);
+/// ```
+pub fn clip_lines_outside<P: PointScaler>(
+    lines: impl Into<Paths<P>>,
+    polygons: impl Into<Paths<P>>,
+    fill_rule: impl Into<Option<FillRule>>,
+) -> Result<Paths<P>, ClipperError> {
+    let fill_rule = fill_rule.into().unwrap_or_default();
+
+    Ok(Clipper::new()
+        .add_open_subject(lines)
+        .add_clip(polygons)
+        .difference(fill_rule)?
+        .open)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, Path};
+
+    use super::*;
+
+    fn length(paths: &Paths<Centi>) -> f64 {
+        paths
+            .iter()
+            .flat_map(|path| path.windows(2))
+            .map(|pair| pair[0].distance_to(&pair[1]))
+            .sum()
+    }
+
+    #[test]
+    fn test_clip_lines_inside_and_outside() {
+        let lines = Paths::<Centi>::from(vec![
+            vec![(-1.0, 1.0), (11.0, 1.0)],
+            vec![(5.0, -5.0), (5.0, 15.0)],
+        ]);
+        let polygons = Paths::<Centi>::from(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(2.5, 2.5, 5.0, 5.0),
+        ]);
+
+        let inside = clip_lines_inside(lines.clone(), polygons.clone(), FillRule::EvenOdd).unwrap();
+        let outside = clip_lines_outside(lines, polygons, FillRule::EvenOdd).unwrap();
+
+        assert_eq!(inside.len(), 3);
+        assert_eq!(outside.len(), 5);
+        assert!((length(&inside) - 15.0).abs() < 1e-9);
+        assert!((length(&outside) - 17.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_clipped_lines_are_open() {
+        let lines = Paths::<Centi>::from(vec![vec![(-1.0, 1.0), (11.0, 1.0)]]);
+        let square = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 10.0, 10.0));
+        let hole = Paths::<Centi>::from(Path::rectangle(4.0, 0.0, 2.0, 2.0));
+
+        let inside = clip_lines_inside(lines, square, None).unwrap();
+        assert!(inside.is_open(0));
+
+        let outside = clip_lines_outside(inside, hole.clone(), None).unwrap();
+        assert_eq!(outside.len(), 2);
+        assert!(outside.is_open(0) && outside.is_open(1));
+        assert!((length(&outside) - 8.0).abs() < 1e-9);
+
+        // Passed on as subjects, the lines are not closed into polygons.
+        let result = outside
+            .to_clipper_subject()
+            .add_clip(hole)
+            .union(FillRule::NonZero)
+            .unwrap();
+        assert_eq!(result.closed.signed_area(), 4.0);
+        assert_eq!(result.open.len(), 2);
+    }
+
+    #[test]
+    fn test_clip_closed_looking_line() {
+        let ring = Paths::<Centi>::from(vec![vec![
+            (-1.0, -1.0),
+            (1.0, -1.0),
+            (1.0, 1.0),
+            (-1.0, 1.0),
+            (-1.0, -1.0),
+        ]]);
+        let half = Paths::<Centi>::from(Path::rectangle(0.0, -2.0, 2.0, 4.0));
+
+        let inside = clip_lines_inside(ring, half, None).unwrap();
+
+        assert_eq!(inside.len(), 1);
+        assert!((length(&inside) - 4.0).abs() < 1e-9);
+    }
+}
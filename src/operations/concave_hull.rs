@@ -0,0 +1,285 @@
+use crate::{union_all, ClipperError, FillRule, Path, Paths, Point, PointScaler};
+
+/// How tightly [`concave_hull`] wraps the points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Concavity {
+    /// Alpha shape with the given radius: the outline only bridges gaps that
+    /// a circle of this radius can't pass through. Smaller radii follow the
+    /// points more closely, an infinite radius gives the convex hull.
+    Alpha(f64),
+    /// Bridge gaps up to the distance of the `k`-th nearest neighbor of the
+    /// points on either side, which adapts to the local density of the
+    /// points. Smaller counts follow the points more closely.
+    Neighbors(usize),
+}
+
+/// This function outlines a set of points with a tight, non-convex polygon,
+/// as used to turn scanned point clouds into boundaries for boolean
+/// operations.
+///
+/// The vertices of all `paths`, open or closed, are used as the points and
+/// the paths themselves are otherwise ignored. The points are connected by
+/// their Delaunay triangulation, and the triangles with an edge longer than
+/// the [`Concavity`] allows are dropped. The result is the union of the
+/// remaining triangles, so distant clusters of points get separate outlines
+/// and empty areas within the points become holes.
+///
+/// Returns no paths for fewer than three points, points along one line or
+/// an alpha radius that isn't a positive number. Triangulating takes time
+/// roughly proportional to the number of points to the power of 1.5, and
+/// [`Concavity::Neighbors`] additionally compares each point with all
+/// others, so very large point clouds are better thinned out first.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// // A 4 x 4 grid of points with a gap in the middle of the top row.
+/// let points: Paths = (0..16)
+///     .map(|i| (f64::from(i % 4), f64::from(i / 4)))
+///     .filter(|&(x, y)| !(y == 3.0 && x > 0.0 && x < 3.0))
+///     .collect::<Vec<_>>()
+///     .into();
+///
+/// let convex = concave_hull(points.clone(), Concavity::Alpha(f64::INFINITY)).unwrap();
+/// let concave = concave_hull(points, Concavity::Alpha(1.0)).unwrap();
+///
+/// assert_eq!(convex.signed_area(), 9.0);
+/// assert!(concave.signed_area() < 9.0);
+/// ```
+pub fn concave_hull<P: PointScaler>(
+    paths: impl Into<Paths<P>>,
+    concavity: Concavity,
+) -> Result<Paths<P>, ClipperError> {
+    let mut points = paths
+        .into()
+        .iter()
+        .flat_map(|path| path.iter().map(|p| (p.x(), p.y())))
+        .collect::<Vec<_>>();
+    points.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    points.dedup();
+
+    if let Concavity::Alpha(radius) = concavity {
+        if radius.is_nan() || radius <= 0.0 {
+            return Ok(Paths::default());
+        }
+    }
+
+    let triangles = delaunay(&points);
+    let max_length = match concavity {
+        Concavity::Alpha(_) => Vec::new(),
+        Concavity::Neighbors(k) => neighbor_distances(&points, k),
+    };
+
+    let distance =
+        |a: usize, b: usize| (points[a].0 - points[b].0).hypot(points[a].1 - points[b].1);
+
+    let kept = triangles
+        .iter()
+        .filter(|triangle| match concavity {
+            Concavity::Alpha(radius) => triangle.radius_squared <= radius * radius,
+            Concavity::Neighbors(_) => (0..3).all(|i| {
+                let (a, b) = (triangle.vertices[i], triangle.vertices[(i + 1) % 3]);
+                distance(a, b) <= max_length[a].max(max_length[b])
+            }),
+        })
+        .map(|triangle| {
+            let [a, b, c] = triangle.vertices;
+            let mut path = [a, b, c]
+                .iter()
+                .map(|&i| Point::new(points[i].0, points[i].1))
+                .collect::<Path<P>>();
+            if path.signed_area() < 0.0 {
+                path.reverse();
+            }
+            path
+        })
+        .collect::<Paths<P>>();
+
+    union_all([kept], FillRule::NonZero)
+}
+
+/// A triangle of the Delaunay triangulation with its circumcircle.
+#[derive(Debug, Clone, Copy)]
+struct Triangle {
+    vertices: [usize; 3],
+    center: (f64, f64),
+    radius_squared: f64,
+}
+
+impl Triangle {
+    fn new(vertices: [usize; 3], points: &[(f64, f64)]) -> Self {
+        let [a, b, c] = vertices.map(|i| points[i]);
+        let (bx, by) = (b.0 - a.0, b.1 - a.1);
+        let (cx, cy) = (c.0 - a.0, c.1 - a.1);
+        let d = 2.0 * (bx * cy - by * cx);
+        let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+        let (ux, uy) = ((cy * b2 - by * c2) / d, (bx * c2 - cx * b2) / d);
+
+        Self {
+            vertices,
+            center: (a.0 + ux, a.1 + uy),
+            radius_squared: ux * ux + uy * uy,
+        }
+    }
+
+    fn contains(&self, point: (f64, f64)) -> bool {
+        let (dx, dy) = (point.0 - self.center.0, point.1 - self.center.1);
+        dx * dx + dy * dy < self.radius_squared
+    }
+}
+
+/// Bowyer-Watson triangulation of points sorted by x. Triangles whose
+/// circumcircle lies left of the current point can't change anymore and are
+/// set aside, which keeps the number of triangles to check small.
+fn delaunay(sorted: &[(f64, f64)]) -> Vec<Triangle> {
+    let count = sorted.len();
+    if count < 3 {
+        return Vec::new();
+    }
+
+    let (mut min_y, mut max_y) = (f64::INFINITY, f64::NEG_INFINITY);
+    for &(_, y) in sorted {
+        min_y = min_y.min(y);
+        max_y = max_y.max(y);
+    }
+    let (min_x, max_x) = (sorted[0].0, sorted[count - 1].0);
+    let size = (max_x - min_x).max(max_y - min_y).max(1.0);
+    let (mid_x, mid_y) = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+
+    // A triangle around all points, removed again at the end.
+    let mut points = sorted.to_vec();
+    points.push((mid_x - 20.0 * size, mid_y - size));
+    points.push((mid_x, mid_y + 20.0 * size));
+    points.push((mid_x + 20.0 * size, mid_y - size));
+
+    let mut open = vec![Triangle::new([count, count + 1, count + 2], &points)];
+    let mut done = Vec::new();
+    let mut edges = Vec::new();
+
+    for (index, &point) in sorted.iter().enumerate() {
+        edges.clear();
+        open.retain(|triangle| {
+            let dx = point.0 - triangle.center.0;
+            if dx > 0.0 && dx * dx > triangle.radius_squared {
+                done.push(*triangle);
+                return false;
+            }
+            if !triangle.contains(point) {
+                return true;
+            }
+            let [a, b, c] = triangle.vertices;
+            edges.extend([(a, b), (b, c), (c, a)]);
+            false
+        });
+
+        // The edges of exactly one removed triangle form the boundary of
+        // the hole around the point.
+        let mut normalized = edges
+            .iter()
+            .map(|&(a, b)| (a.min(b), a.max(b)))
+            .collect::<Vec<_>>();
+        normalized.sort_unstable();
+        for &(a, b) in &edges {
+            let key = (a.min(b), a.max(b));
+            let start = normalized.partition_point(|&edge| edge < key);
+            if normalized.get(start + 1) != Some(&key) {
+                open.push(Triangle::new([a, b, index], &points));
+            }
+        }
+    }
+
+    done.extend(open);
+    done.retain(|triangle| triangle.vertices.iter().all(|&i| i < count));
+    done
+}
+
+/// The distance from each point to its `k`-th nearest neighbor, or to the
+/// farthest point if there are fewer than `k` other points.
+fn neighbor_distances(points: &[(f64, f64)], k: usize) -> Vec<f64> {
+    if k == 0 {
+        return vec![0.0; points.len()];
+    }
+
+    points
+        .iter()
+        .map(|&(x, y)| {
+            let mut distances = points
+                .iter()
+                .map(|&(other_x, other_y)| (other_x - x).hypot(other_y - y))
+                .collect::<Vec<_>>();
+            // The point itself is at index 0 after sorting.
+            let k = k.min(distances.len() - 1);
+            *distances.select_nth_unstable_by(k, f64::total_cmp).1
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Centi;
+
+    use super::*;
+
+    fn grid(range_x: std::ops::Range<i32>, range_y: std::ops::Range<i32>) -> Vec<(f64, f64)> {
+        range_x
+            .flat_map(|x| range_y.clone().map(move |y| (f64::from(x), f64::from(y))))
+            .collect()
+    }
+
+    #[test]
+    fn test_separate_clusters() {
+        let mut points = grid(0..5, 0..5);
+        points.extend(grid(9..14, 0..5));
+        let points = Paths::<Centi>::from(points);
+
+        let convex = concave_hull(points.clone(), Concavity::Alpha(f64::INFINITY)).unwrap();
+        assert_eq!(convex.len(), 1);
+        assert_eq!(convex.signed_area(), 13.0 * 4.0);
+
+        for concavity in [Concavity::Alpha(1.0), Concavity::Neighbors(8)] {
+            let hull = concave_hull(points.clone(), concavity).unwrap();
+            assert_eq!(hull.len(), 2);
+            assert_eq!(hull.signed_area(), 2.0 * 16.0);
+        }
+    }
+
+    #[test]
+    fn test_holes() {
+        let ring = grid(0..11, 0..11)
+            .into_iter()
+            .filter(|&(x, y)| x <= 2.0 || x >= 8.0 || y <= 2.0 || y >= 8.0)
+            .collect::<Vec<_>>();
+
+        let hull = concave_hull(Paths::<Centi>::from(ring), Concavity::Alpha(1.0)).unwrap();
+
+        // The corners of the hole are cut off by the triangles between the
+        // points next to them.
+        assert_eq!(hull.len(), 2);
+        assert!(hull.signed_area() > 100.0 - 36.0);
+        assert!(hull.signed_area() <= 100.0 - 36.0 + 4.0 * 0.5);
+    }
+
+    #[test]
+    fn test_degenerate_input() {
+        let line = Paths::<Centi>::from(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]);
+        assert!(concave_hull(line, Concavity::Alpha(10.0))
+            .unwrap()
+            .is_empty());
+
+        let triangle = Paths::<Centi>::from(vec![(0.0, 0.0), (4.0, 0.0), (0.0, 3.0), (0.0, 0.0)]);
+        assert_eq!(
+            concave_hull(triangle.clone(), Concavity::Neighbors(2))
+                .unwrap()
+                .signed_area(),
+            6.0
+        );
+        assert!(concave_hull(triangle.clone(), Concavity::Alpha(0.0))
+            .unwrap()
+            .is_empty());
+        assert!(concave_hull(triangle, Concavity::Neighbors(0))
+            .unwrap()
+            .is_empty());
+    }
+}
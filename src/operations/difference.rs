@@ -2,6 +2,8 @@ use crate::{BooleanResult, Clipper, ClipperError, FillRule, Paths, PointScaler};
 
 /// This function differences closed subject paths from clip paths.
 ///
+/// Passing `None` as the fill rule uses [`FillRule::default`].
+///
 /// # Examples
 ///
 /// ```rust
@@ -24,17 +26,62 @@ use crate::{BooleanResult, Clipper, ClipperError, FillRule, Paths, PointScaler};
 pub fn difference<P: PointScaler>(
     subject: impl Into<Paths<P>>,
     clip: impl Into<Paths<P>>,
-    fill_rule: FillRule,
+    fill_rule: impl Into<Option<FillRule>>,
 ) -> Result<BooleanResult<P>, ClipperError> {
+    let fill_rule = fill_rule.into().unwrap_or_default();
+
     Clipper::new()
         .add_subject(subject)
         .add_clip(clip)
         .difference(fill_rule)
 }
 
+/// This function differences closed subject paths from any number of sets
+/// of clip paths in a single operation.
+///
+/// All clips are loaded into the same engine instance before it executes
+/// once, which is much faster than subtracting the clips one at a time and
+/// avoids accumulating rounding from the intermediate results. As all clips
+/// share the fill rule, overlapping clips only all get subtracted with a fill
+/// rule such as [`FillRule::NonZero`].
+///
+/// Passing `None` as the fill rule uses [`FillRule::default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let subject = Path::<Centi>::rectangle(0.0, 0.0, 10.0, 10.0);
+/// let holes = (0..4).map(|i| Path::<Centi>::rectangle(1.0 + i as f64 * 2.0, 1.0, 1.0, 1.0));
+///
+/// let result = difference_all(subject, holes, FillRule::NonZero).unwrap();
+///
+/// assert_eq!(result.closed.signed_area(), 96.0);
+/// ```
+///
+/// For more details see the original [difference](https://www.angusj.com/clipper2/Docs/Units/Clipper/Functions/Difference.htm) docs.
+pub fn difference_all<P: PointScaler>(
+    subject: impl Into<Paths<P>>,
+    clips: impl IntoIterator<Item = impl Into<Paths<P>>>,
+    fill_rule: impl Into<Option<FillRule>>,
+) -> Result<BooleanResult<P>, ClipperError> {
+    let fill_rule = fill_rule.into().unwrap_or_default();
+
+    clips
+        .into_iter()
+        .fold(
+            Clipper::new()
+                .add_subject(subject)
+                .add_clip(Paths::default()),
+            |clipper, clip| clipper.add_clip(clip),
+        )
+        .difference(fill_rule)
+}
+
 #[cfg(test)]
 mod test {
-    use crate::Centi;
+    use crate::{Centi, Path};
 
     use super::*;
 
@@ -55,4 +102,32 @@ mod test {
         let output: Vec<Vec<(f64, f64)>> = result.closed.into();
         assert_eq!(output, expected_output);
     }
+
+    #[test]
+    fn test_difference_all() {
+        let subject = Path::<Centi>::rectangle(0.0, 0.0, 10.0, 10.0);
+        let clips = vec![
+            Path::<Centi>::rectangle(-1.0, -1.0, 3.0, 3.0),
+            Path::<Centi>::rectangle(1.0, 1.0, 2.0, 2.0),
+            Path::<Centi>::rectangle(5.0, 5.0, 2.0, 2.0),
+        ];
+
+        let all = difference_all(subject.clone(), clips.clone(), FillRule::NonZero).unwrap();
+        let one_by_one = clips
+            .into_iter()
+            .try_fold(Paths::from(subject.clone()), |acc, clip| {
+                difference(acc, clip, FillRule::NonZero).map(|result| result.closed)
+            })
+            .unwrap();
+
+        assert_eq!(all.closed.signed_area(), 100.0 - 7.0 - 4.0);
+        assert_eq!(all.closed.signed_area(), one_by_one.signed_area());
+        assert_eq!(
+            difference_all(subject, Vec::<Paths<Centi>>::new(), FillRule::NonZero)
+                .unwrap()
+                .closed
+                .signed_area(),
+            100.0
+        );
+    }
 }
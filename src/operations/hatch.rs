@@ -0,0 +1,194 @@
+use crate::{Clipper, ClipperError, FillRule, Path, Paths, PointScaler};
+
+/// The largest number of lines [`hatch`] generates before clipping, which
+/// keeps a spacing far too small for the size of the paths from allocating
+/// without bound.
+pub const MAX_HATCH_LINES: usize = 1_000_000;
+
+/// This function fills closed paths with parallel lines, as used for slicer
+/// infill, plotter fills and cross-hatched drawings.
+///
+/// The lines are `spacing` apart and run at `angle` radians from the x axis.
+/// They are placed half a spacing off the whole multiples of the spacing
+/// from the origin, so neighbouring shapes hatched with the same settings get
+/// continuous lines, and lines don't run along the edges of shapes aligned to
+/// the spacing.
+/// Each line is clipped to the area covered by the paths, so the result is a
+/// set of open paths, marked as open, with one path per inside segment of
+/// each line.
+///
+/// Returns no lines if `spacing` is not a positive number, and
+/// [`ClipperError::TooManyHatchLines`] if the bounds of the paths would need
+/// more than [`MAX_HATCH_LINES`] lines. Passing `None` as the fill rule uses
+/// [`FillRule::default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let square: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+///
+/// let lines = hatch(square, 1.0, 0.0, FillRule::default()).unwrap();
+///
+/// assert_eq!(lines.len(), 10);
+/// assert!(lines.iter().all(|line| line.len() == 2));
+/// assert!(lines.is_open(0));
+/// ```
+pub fn hatch<P: PointScaler>(
+    paths: impl Into<Paths<P>>,
+    spacing: f64,
+    angle: f64,
+    fill_rule: impl Into<Option<FillRule>>,
+) -> Result<Paths<P>, ClipperError> {
+    let fill_rule = fill_rule.into().unwrap_or_default();
+
+    hatch_lines(&paths.into(), spacing, angle, 0.5, fill_rule)
+}
+
+/// Clip parallel lines to the paths. The lines are placed at `spacing` times
+/// a whole number plus `phase` from the origin.
+pub(crate) fn hatch_lines<P: PointScaler>(
+    paths: &Paths<P>,
+    spacing: f64,
+    angle: f64,
+    phase: f64,
+    fill_rule: FillRule,
+) -> Result<Paths<P>, ClipperError> {
+    if spacing.is_nan() || spacing <= 0.0 || !paths.contains_points() {
+        return Ok(Paths::default());
+    }
+
+    let (direction_x, direction_y) = (angle.cos(), angle.sin());
+    let (normal_x, normal_y) = (-direction_y, direction_x);
+
+    let bounds = paths.bounds();
+    let corners = [
+        (bounds.min.x(), bounds.min.y()),
+        (bounds.max.x(), bounds.min.y()),
+        (bounds.max.x(), bounds.max.y()),
+        (bounds.min.x(), bounds.max.y()),
+    ];
+    let range = |x: f64, y: f64| {
+        corners
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(min, max), corner| {
+                let value = corner.0 * x + corner.1 * y;
+                (min.min(value), max.max(value))
+            })
+    };
+
+    // Extend the lines past the bounds so rounding never cuts them short.
+    let (along_min, along_max) = range(direction_x, direction_y);
+    let (along_min, along_max) = (along_min - spacing, along_max + spacing);
+    let (across_min, across_max) = range(normal_x, normal_y);
+
+    let first = (across_min / spacing - phase).ceil();
+    let last = (across_max / spacing - phase).floor();
+    if last - first >= MAX_HATCH_LINES as f64 {
+        return Err(ClipperError::TooManyHatchLines {
+            spacing,
+            lines: (last - first + 1.0) as u64,
+        });
+    }
+    let (first, last) = (first as i64, last as i64);
+
+    let lines = (first..=last)
+        .map(|step| {
+            let offset = (step as f64 + phase) * spacing;
+            let point = |along: f64| {
+                (
+                    normal_x * offset + direction_x * along,
+                    normal_y * offset + direction_y * along,
+                )
+            };
+            Path::from(vec![point(along_min), point(along_max)])
+        })
+        .collect::<Paths<P>>();
+
+    Ok(Clipper::new()
+        .add_open_subject(lines)
+        .add_clip_ref(paths)
+        .intersect(fill_rule)?
+        .open)
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::FRAC_PI_4;
+
+    use crate::Centi;
+
+    use super::*;
+
+    #[test]
+    fn test_hatch_square_with_hole() {
+        let paths = Paths::<Centi>::from(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(2.5, 2.5, 5.0, 5.0),
+        ]);
+
+        let lines = hatch(paths, 2.0, 0.0, FillRule::EvenOdd).unwrap();
+
+        // Lines at y = 1 and 9 cross the full width, y = 3, 5 and 7 are split
+        // by the hole.
+        assert_eq!(lines.len(), 8);
+        let length = lines
+            .iter()
+            .map(|line| line[0].distance_to(&line[1]))
+            .sum::<f64>();
+        assert!((length - (10.0 * 2.0 + 5.0 * 3.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hatch_angle() {
+        let paths = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 10.0, 10.0));
+
+        let lines = hatch(paths, 1.0, FRAC_PI_4, None).unwrap();
+
+        assert!(lines.len() > 10);
+        for line in &lines {
+            let delta = line[1] - line[0];
+            assert!((delta.x().abs() - delta.y().abs()).abs() <= 0.02);
+        }
+        assert!(lines.iter().flatten().all(|point| {
+            (0.0..=10.0).contains(&point.x()) && (0.0..=10.0).contains(&point.y())
+        }));
+    }
+
+    #[test]
+    fn test_hatch_lines_are_open() {
+        let paths = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 10.0, 10.0));
+
+        let lines = hatch(paths.clone(), 2.0, 0.0, None).unwrap();
+
+        assert_eq!(lines.len(), 5);
+        assert!((0..lines.len()).all(|index| lines.is_open(index)));
+
+        // Clipped again, the lines stay lines instead of becoming polygons.
+        let clipped = lines
+            .to_clipper_subject()
+            .add_clip(paths)
+            .intersect(FillRule::NonZero)
+            .unwrap();
+        assert!(clipped.closed.is_empty());
+        assert_eq!(clipped.open.len(), 5);
+    }
+
+    #[test]
+    fn test_hatch_invalid_spacing() {
+        let paths = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 10.0, 10.0));
+
+        assert!(hatch(paths.clone(), 0.0, 0.0, None).unwrap().is_empty());
+        assert!(hatch(paths.clone(), f64::NAN, 0.0, None)
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            hatch(paths, 1e-6, 0.0, None),
+            Err(ClipperError::TooManyHatchLines { lines, .. }) if lines > MAX_HATCH_LINES as u64
+        ));
+        assert!(hatch(Paths::<Centi>::default(), 1.0, 0.0, None)
+            .unwrap()
+            .is_empty());
+    }
+}
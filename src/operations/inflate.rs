@@ -1,6 +1,6 @@
-use clipper2c_sys::{clipper_delete_paths64, clipper_paths64_inflate, clipper_paths64_size};
+use clipper2c_sys::{clipper_paths64_inflate, clipper_paths64_size};
 
-use crate::{malloc, EndType, JoinType, Paths, PointScaler};
+use crate::{malloc, memory::delete_paths64, EndType, JoinType, Paths, PointScaler};
 
 /// This function performs both closed path and open path offsetting.
 ///
@@ -40,18 +40,16 @@ pub fn inflate<P: PointScaler>(
 
     unsafe {
         let mem = malloc(clipper_paths64_size());
-        let paths_ptr = paths.to_clipperpaths64();
         let result_ptr = clipper_paths64_inflate(
             mem,
-            paths_ptr,
+            paths.as_clipperpaths64(),
             delta,
             join_type.into(),
             end_type.into(),
             miter_limit,
         );
-        clipper_delete_paths64(paths_ptr);
         let result = Paths::from_clipperpaths64(result_ptr);
-        clipper_delete_paths64(result_ptr);
+        delete_paths64(result_ptr);
         result
     }
 }
@@ -1,7 +1,18 @@
-use crate::{BooleanResult, Clipper, ClipperError, FillRule, Paths, PointScaler};
+use crate::{
+    rect_clip::as_rectangle, BooleanResult, Clipper, ClipperError, FillRule, Paths, PointScaler,
+    RectClipper,
+};
 
 /// This function intersects closed subject paths with clip paths.
 ///
+/// Passing `None` as the fill rule uses [`FillRule::default`].
+///
+/// When either operand is a single axis aligned rectangle, as with map tiles,
+/// the other operand is first cut down to the rectangle with the fast
+/// [`RectClipper`], so the general intersection only handles the points
+/// inside the tile. The result covers the same area as without the
+/// shortcut, though its paths may start at different vertices.
+///
 /// # Examples
 ///
 /// ```rust
@@ -23,8 +34,17 @@ use crate::{BooleanResult, Clipper, ClipperError, FillRule, Paths, PointScaler};
 pub fn intersect<P: PointScaler>(
     subject: impl Into<Paths<P>>,
     clip: impl Into<Paths<P>>,
-    fill_rule: FillRule,
+    fill_rule: impl Into<Option<FillRule>>,
 ) -> Result<BooleanResult<P>, ClipperError> {
+    let fill_rule = fill_rule.into().unwrap_or_default();
+    let (subject, clip) = (subject.into(), clip.into());
+
+    let (subject, clip) = match (as_rectangle(&subject), as_rectangle(&clip)) {
+        (_, Some(rect)) => (RectClipper::new(rect).reduce(&subject), clip),
+        (Some(rect), None) => (subject, RectClipper::new(rect).clip(&clip)),
+        (None, None) => (subject, clip),
+    };
+
     Clipper::new()
         .add_subject(subject)
         .add_clip(clip)
@@ -33,7 +53,7 @@ pub fn intersect<P: PointScaler>(
 
 #[cfg(test)]
 mod test {
-    use crate::Centi;
+    use crate::{Centi, Path};
 
     use super::*;
 
@@ -47,4 +67,64 @@ mod test {
         let output: Vec<Vec<(f64, f64)>> = result.closed.into();
         assert_eq!(output, expected_output);
     }
+
+    #[test]
+    fn test_intersect_rectangle_shortcut() {
+        let tile = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 10.0, 10.0));
+        let mut features = Paths::<Centi>::from(vec![
+            Path::rectangle(-5.0, -5.0, 30.0, 30.0),
+            // A hole covering the whole tile.
+            Path::rectangle(-2.0, -2.0, 14.0, 14.0)
+                .iter()
+                .rev()
+                .copied()
+                .collect(),
+            Path::rectangle(5.0, 5.0, 10.0, 10.0),
+            Path::rectangle(6.0, 6.0, 10.0, 10.0),
+            // A self intersecting bow tie.
+            Path::from(vec![(-5.0, 2.0), (15.0, 8.0), (15.0, 2.0), (-5.0, 8.0)]),
+        ]);
+        features.push_open(vec![(-5.0, 1.0), (15.0, 1.0)]);
+
+        for fill_rule in [FillRule::NonZero, FillRule::EvenOdd, FillRule::Positive] {
+            let general = Clipper::new()
+                .add_subject(features.clone())
+                .add_clip(tile.clone())
+                .intersect(fill_rule)
+                .unwrap();
+
+            for result in [
+                intersect(features.clone(), tile.clone(), fill_rule).unwrap(),
+                intersect(tile.clone(), features.partition_open().0, fill_rule).unwrap(),
+            ] {
+                assert!((result.closed.signed_area() - general.closed.signed_area()).abs() < 1e-6);
+            }
+
+            let result = intersect(features.clone(), tile.clone(), fill_rule).unwrap();
+            assert_eq!(result.open, general.open);
+        }
+    }
+
+    #[test]
+    fn test_rectangle_detection() {
+        let rect = |points: Vec<(f64, f64)>| as_rectangle(&Paths::<Centi>::from(points));
+
+        assert!(rect(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)]).is_some());
+        assert!(rect(vec![
+            (0.0, 0.0),
+            (0.0, 1.0),
+            (2.0, 1.0),
+            (2.0, 0.0),
+            (0.0, 0.0)
+        ])
+        .is_some());
+        assert!(rect(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.1, 1.0)]).is_none());
+        assert!(rect(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 0.0), (0.0, 0.0)]).is_none());
+        assert!(rect(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 1.0)]).is_none());
+        assert!(as_rectangle(&Paths::<Centi>::from(vec![
+            Path::rectangle(0.0, 0.0, 1.0, 1.0),
+            Path::rectangle(2.0, 0.0, 1.0, 1.0),
+        ]))
+        .is_none());
+    }
 }
@@ -0,0 +1,90 @@
+use crate::{ClipperError, Expr, FillRule, Paths, PointScaler};
+
+/// This function intersects any number of sets of closed paths, returning
+/// the area covered by all of them.
+///
+/// An intersection of several inputs can't be expressed as one operation of
+/// the engine, so the inputs are intersected one after the other. The
+/// intermediate results stay in the native representation of the Clipper2
+/// library, only the final result is converted back, see [`Expr`] for
+/// composing other operations the same way. The fill rule applies to each
+/// input on its own, and intersecting no inputs gives no paths.
+///
+/// Passing `None` as the fill rule uses [`FillRule::default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let squares = (0..3).map(|i| Path::<Centi>::rectangle(i as f64, 0.0, 4.0, 4.0));
+///
+/// let result = intersect_all(squares, FillRule::NonZero).unwrap();
+///
+/// assert_eq!(result.signed_area(), 8.0);
+/// ```
+///
+/// For more details see the original [intersect](https://www.angusj.com/clipper2/Docs/Units/Clipper/Functions/Intersect.htm) docs.
+pub fn intersect_all<P: PointScaler>(
+    paths: impl IntoIterator<Item = impl Into<Paths<P>>>,
+    fill_rule: impl Into<Option<FillRule>>,
+) -> Result<Paths<P>, ClipperError> {
+    let fill_rule = fill_rule.into().unwrap_or_default();
+
+    paths
+        .into_iter()
+        .map(|paths| Expr::paths(paths))
+        .reduce(Expr::intersect)
+        .map_or_else(|| Ok(Paths::default()), |expr| expr.eval(fill_rule))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, Path};
+
+    use super::*;
+
+    #[test]
+    fn test_intersect_all_matches_pairwise() {
+        let shapes: Vec<Paths<Centi>> = vec![
+            Path::rectangle(0.0, 0.0, 6.0, 6.0).into(),
+            vec![
+                Path::rectangle(1.0, 1.0, 2.0, 4.0),
+                Path::rectangle(4.0, 1.0, 2.0, 4.0),
+            ]
+            .into(),
+            Path::rectangle(2.0, 2.0, 6.0, 2.0).into(),
+        ];
+
+        let result = intersect_all(shapes.clone(), FillRule::NonZero).unwrap();
+        let pairwise = shapes[0]
+            .intersect_with(&shapes[1], FillRule::NonZero)
+            .unwrap()
+            .intersect_with(&shapes[2], FillRule::NonZero)
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result.signed_area(), 6.0);
+        assert_eq!(result.signed_area(), pairwise.signed_area());
+    }
+
+    #[test]
+    fn test_intersect_all_single_and_empty() {
+        let overlapping: Paths<Centi> = vec![
+            Path::rectangle(0.0, 0.0, 2.0, 2.0),
+            Path::rectangle(1.0, 0.0, 2.0, 2.0),
+        ]
+        .into();
+
+        let single = intersect_all([overlapping.clone()], FillRule::NonZero).unwrap();
+        assert_eq!(single.len(), 1);
+        assert_eq!(single.signed_area(), 6.0);
+
+        let even_odd = intersect_all([overlapping], FillRule::EvenOdd).unwrap();
+        assert_eq!(even_odd.signed_area(), 4.0);
+
+        assert!(intersect_all(Vec::<Paths<Centi>>::new(), None)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{ClipType, Clipper, ClipperError, FillRule, Paths, PointScaler};
+
+/// This function returns the area of the intersection of two sets of closed
+/// paths.
+///
+/// The area is computed by the native library, so the result paths are never
+/// converted to Rust paths. This makes it considerably cheaper than
+/// [`intersect`](crate::intersect) followed by
+/// [`Paths::signed_area`] when only the number is needed, such as when
+/// computing coverage statistics over large datasets.
+///
+/// Passing `None` as the fill rule uses [`FillRule::default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let path_a: Paths = Path::rectangle(0.0, 0.0, 4.0, 4.0).into();
+/// let path_b: Paths = Path::rectangle(2.0, 1.0, 4.0, 2.0).into();
+///
+/// let area = intersection_area(path_a, path_b, FillRule::default()).unwrap();
+///
+/// assert_eq!(area, 4.0);
+/// ```
+pub fn intersection_area<P: PointScaler>(
+    a: impl Into<Paths<P>>,
+    b: impl Into<Paths<P>>,
+    fill_rule: impl Into<Option<FillRule>>,
+) -> Result<f64, ClipperError> {
+    let fill_rule = fill_rule.into().unwrap_or_default();
+
+    Clipper::new()
+        .add_subject(a)
+        .add_clip(b)
+        .boolean_operation_area(ClipType::Intersection, fill_rule)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, Path};
+
+    use super::*;
+
+    #[test]
+    fn test_intersection_area_matches_intersect() {
+        let a = Paths::<Centi>::from(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(2.0, 2.0, 2.0, 2.0)
+                .iter()
+                .rev()
+                .copied()
+                .collect(),
+        ]);
+        let b = Paths::<Centi>::from(vec![
+            vec![(1.0, 1.0), (12.0, 3.0), (5.0, 12.0)],
+            vec![(20.0, 20.0), (21.0, 20.0), (21.0, 21.0)],
+        ]);
+
+        for fill_rule in [FillRule::EvenOdd, FillRule::NonZero, FillRule::Positive] {
+            let expected = a.intersect_with(&b, fill_rule).unwrap().signed_area();
+            let area = intersection_area(a.clone(), b.clone(), fill_rule).unwrap();
+
+            assert!((area - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_intersection_area_disjoint_and_empty() {
+        let square = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+        let far = Paths::<Centi>::from(Path::rectangle(5.0, 5.0, 1.0, 1.0));
+
+        assert_eq!(intersection_area(square.clone(), far, None).unwrap(), 0.0);
+        assert_eq!(
+            intersection_area(square, Paths::default(), None).unwrap(),
+            0.0
+        );
+    }
+}
@@ -1,15 +1,41 @@
+mod clip_lines;
+mod concave_hull;
 mod difference;
+mod hatch;
 mod inflate;
 mod intersect;
+mod intersect_all;
+mod intersection_area;
+mod offset_one_side;
+mod overlay_layers;
+mod pairwise_clip;
 mod pointinpolygon;
 mod simplify;
+mod skeleton;
+mod spiral_from_rings;
+mod split_into_bands;
+mod stroke_tapered;
 mod union;
+mod union_all;
 mod xor;
 
+pub use clip_lines::*;
+pub use concave_hull::*;
 pub use difference::*;
+pub use hatch::*;
 pub use inflate::*;
 pub use intersect::*;
+pub use intersect_all::*;
+pub use intersection_area::*;
+pub use offset_one_side::*;
+pub use overlay_layers::*;
+pub use pairwise_clip::*;
 pub use pointinpolygon::*;
 pub use simplify::*;
+pub use skeleton::*;
+pub use spiral_from_rings::*;
+pub use split_into_bands::*;
+pub use stroke_tapered::*;
 pub use union::*;
+pub use union_all::*;
 pub use xor::*;
@@ -0,0 +1,273 @@
+use std::f64::consts::PI;
+
+use crate::{union_all, ClipperError, FillRule, JoinType, Path, Paths, Point, PointScaler};
+
+/// The side of a polyline, seen when walking along it from its first point,
+/// see [`offset_one_side`].
+///
+/// The sides assume the y axis points up. With the y axis pointing down, as
+/// in screen coordinates, they are swapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The left side, where the cross product of the direction and the
+    /// offset is positive
+    Left,
+    /// The right side, where the cross product of the direction and the
+    /// offset is negative
+    Right,
+}
+
+impl Side {
+    /// Returns the other side.
+    pub fn opposite(self) -> Self {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+/// Miter limit of [`JoinType::Miter`] joins, the default of the Clipper2
+/// library. Longer miters are squared off.
+const MITER_LIMIT: f64 = 2.0;
+
+/// Maximum distance of round joins from the true arc, as a fraction of the
+/// offset, the default of the Clipper2 library.
+pub(super) const ARC_TOLERANCE: f64 = 0.002;
+
+/// This function buffers open polylines to one side only, as needed for road
+/// casings, riverbanks or weld bead outlines.
+///
+/// The result covers the area within `delta` of each line on the chosen
+/// side, with butt ends at the first and last point. The `join_type` shapes
+/// the corners where the line turns away from that side, while corners
+/// turning towards it are filled in as with the symmetric [`inflate`]. A
+/// negative `delta` offsets to the opposite side.
+///
+/// All `lines` are treated as open paths and the result holds the closed
+/// paths of the buffer, joined with [`FillRule::NonZero`].
+///
+/// [`inflate`]: crate::inflate
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let road: Paths = vec![(0.0, 0.0), (10.0, 0.0), (10.0, -10.0)].into();
+///
+/// let left = offset_one_side(road.clone(), 1.0, Side::Left, JoinType::Miter).unwrap();
+/// let right = offset_one_side(road, 1.0, Side::Right, JoinType::Miter).unwrap();
+///
+/// assert_eq!(left.signed_area(), 21.0);
+/// assert_eq!(right.signed_area(), 19.0);
+/// ```
+pub fn offset_one_side<P: PointScaler>(
+    lines: impl Into<Paths<P>>,
+    delta: f64,
+    side: Side,
+    join_type: JoinType,
+) -> Result<Paths<P>, ClipperError> {
+    let (delta, side) = if delta < 0.0 {
+        (-delta, side.opposite())
+    } else {
+        (delta, side)
+    };
+    let sign = match side {
+        Side::Left => 1.0,
+        Side::Right => -1.0,
+    };
+
+    let mut pieces = Vec::new();
+    if delta > 0.0 {
+        for line in lines.into().iter() {
+            line_pieces(line, delta * sign, join_type, &mut pieces);
+        }
+    }
+
+    for piece in &mut pieces {
+        if piece.signed_area() < 0.0 {
+            piece.reverse();
+        }
+    }
+
+    union_all([Paths::new(pieces)], FillRule::NonZero)
+}
+
+/// Add a rectangle on the offset side of each segment of the line, and a
+/// join at each corner turning away from that side. The offset is negative
+/// for the right side.
+fn line_pieces<P: PointScaler>(
+    line: &Path<P>,
+    offset: f64,
+    join_type: JoinType,
+    pieces: &mut Vec<Path<P>>,
+) {
+    let mut points = line.iter().map(|p| (p.x(), p.y())).collect::<Vec<_>>();
+    points.dedup();
+
+    // Unit direction of each segment.
+    let directions = points
+        .windows(2)
+        .map(|segment| {
+            let (dx, dy) = (segment[1].0 - segment[0].0, segment[1].1 - segment[0].1);
+            let length = dx.hypot(dy);
+            (dx / length, dy / length)
+        })
+        .collect::<Vec<_>>();
+
+    let shift = |(x, y): (f64, f64), (dx, dy): (f64, f64)| (x - dy * offset, y + dx * offset);
+
+    for (segment, &direction) in points.windows(2).zip(&directions) {
+        pieces.push(to_path(&[
+            segment[0],
+            segment[1],
+            shift(segment[1], direction),
+            shift(segment[0], direction),
+        ]));
+    }
+
+    for (index, pair) in directions.windows(2).enumerate() {
+        let (before, after) = (pair[0], pair[1]);
+        let turn = before.0 * after.1 - before.1 * after.0;
+        if turn * offset >= 0.0 {
+            // The corner turns towards the offset side, the rectangles of
+            // the segments overlap.
+            continue;
+        }
+
+        let vertex = points[index + 1];
+        let join = corner(vertex, before, after, offset, join_type, shift);
+        pieces.push(to_path(&join));
+    }
+}
+
+/// The points of the join at a corner turning away from the offset side,
+/// starting at the corner itself.
+pub(super) fn corner(
+    vertex: (f64, f64),
+    before: (f64, f64),
+    after: (f64, f64),
+    offset: f64,
+    join_type: JoinType,
+    shift: impl Fn((f64, f64), (f64, f64)) -> (f64, f64),
+) -> Vec<(f64, f64)> {
+    let (start, end) = (shift(vertex, before), shift(vertex, after));
+    let radius = offset.abs();
+
+    // Angle between the offset directions of the segments, in (0, PI].
+    let cos = (before.0 * after.0 + before.1 * after.1).clamp(-1.0, 1.0);
+    let angle = cos.acos();
+
+    let mut join = vec![vertex, start];
+    match join_type {
+        JoinType::Bevel => {}
+        JoinType::Round => {
+            let step = 2.0 * (1.0 - ARC_TOLERANCE).acos();
+            let steps = (angle / step).ceil().max(1.0) as usize;
+            let (sx, sy) = (start.0 - vertex.0, start.1 - vertex.1);
+            // Rotate from the start towards the end, clockwise on the left
+            // side and counterclockwise on the right side.
+            let direction = -offset.signum();
+            for i in 1..steps {
+                let a = direction * angle * i as f64 / steps as f64;
+                let (sin, cos) = a.sin_cos();
+                join.push((
+                    vertex.0 + sx * cos - sy * sin,
+                    vertex.1 + sx * sin + sy * cos,
+                ));
+            }
+        }
+        JoinType::Miter if angle < PI && 1.0 / (angle / 2.0).cos() <= MITER_LIMIT => {
+            // The miter lies on the bisector of the offset directions.
+            let length = radius / (angle / 2.0).cos();
+            let (bx, by) = (
+                start.0 + end.0 - 2.0 * vertex.0,
+                start.1 + end.1 - 2.0 * vertex.1,
+            );
+            let norm = bx.hypot(by);
+            join.push((vertex.0 + bx / norm * length, vertex.1 + by / norm * length));
+        }
+        JoinType::Miter | JoinType::Square => {
+            let extend = radius * (angle / 4.0).tan();
+            join.push((start.0 + before.0 * extend, start.1 + before.1 * extend));
+            join.push((end.0 - after.0 * extend, end.1 - after.1 * extend));
+        }
+    }
+    join.push(end);
+
+    join
+}
+
+pub(super) fn to_path<P: PointScaler>(points: &[(f64, f64)]) -> Path<P> {
+    points.iter().map(|&(x, y)| Point::new(x, y)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Centi;
+
+    use super::*;
+
+    fn corner_line() -> Paths<Centi> {
+        vec![(0.0, 0.0), (10.0, 0.0), (10.0, -10.0)].into()
+    }
+
+    #[test]
+    fn test_straight_line_sides() {
+        let line: Paths<Centi> = vec![(0.0, 0.0), (10.0, 0.0)].into();
+
+        let left = offset_one_side(line.clone(), 1.0, Side::Left, JoinType::Round).unwrap();
+        assert_eq!(left.signed_area(), 10.0);
+        assert_eq!(left.bounds().min.y(), 0.0);
+        assert_eq!(left.bounds().max.y(), 1.0);
+
+        let right = offset_one_side(line.clone(), 1.0, Side::Right, JoinType::Round).unwrap();
+        assert_eq!(right.bounds().min.y(), -1.0);
+        assert_eq!(right.bounds().max.y(), 0.0);
+
+        let negative = offset_one_side(line.clone(), -1.0, Side::Left, JoinType::Round).unwrap();
+        assert_eq!(negative, right);
+
+        assert!(offset_one_side(line, 0.0, Side::Left, JoinType::Round)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_join_types() {
+        let area = |side, join_type| {
+            offset_one_side(corner_line(), 1.0, side, join_type)
+                .unwrap()
+                .signed_area()
+        };
+
+        assert_eq!(area(Side::Left, JoinType::Miter), 21.0);
+        assert_eq!(area(Side::Left, JoinType::Bevel), 20.5);
+        assert!(
+            (area(Side::Left, JoinType::Square) - (21.0 - (2f64.sqrt() - 1.0).powi(2))).abs()
+                < 0.01
+        );
+        assert!((area(Side::Left, JoinType::Round) - (20.0 + PI / 4.0)).abs() < 0.01);
+
+        for join_type in [
+            JoinType::Miter,
+            JoinType::Bevel,
+            JoinType::Square,
+            JoinType::Round,
+        ] {
+            assert_eq!(area(Side::Right, join_type), 19.0);
+        }
+    }
+
+    #[test]
+    fn test_sharp_corner_squares_long_miters() {
+        let line: Paths<Centi> = vec![(0.0, 0.0), (10.0, 0.0), (0.0, -1.0)].into();
+
+        let miter = offset_one_side(line.clone(), 1.0, Side::Left, JoinType::Miter).unwrap();
+        let square = offset_one_side(line, 1.0, Side::Left, JoinType::Square).unwrap();
+
+        assert_eq!(miter, square);
+        assert!(miter.bounds().max.x() < 11.5);
+    }
+}
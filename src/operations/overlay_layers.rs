@@ -0,0 +1,108 @@
+use crate::{ClipperError, FillRule, Overlay, Paths, PointScaler};
+
+/// A region of an [`overlay_layers`] result, covered by exactly the same
+/// layers everywhere.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerRegion<P: PointScaler> {
+    /// Closed paths of the region, it may consist of several parts.
+    pub paths: Paths<P>,
+    /// Indices of the layers covering the region, in ascending order.
+    pub layers: Vec<usize>,
+}
+
+/// This function splits the area covered by any of the layers into regions
+/// covered by exactly the same combination of layers, the union overlay of
+/// GIS tools applied to N inputs.
+///
+/// Only combinations that actually occur are returned, so the cost grows
+/// with the number of regions rather than with all 2^N combinations. The
+/// fill rule applies to each layer separately, regions without area are
+/// dropped and the regions are sorted by their lists of layer indices. See
+/// [`Overlay`] for attaching payloads to the inputs instead of indices.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let forest: Paths = Path::rectangle(0.0, 0.0, 2.0, 2.0).into();
+/// let protected: Paths = Path::rectangle(1.0, 0.0, 2.0, 2.0).into();
+///
+/// let regions = overlay_layers(&[forest, protected], FillRule::default()).unwrap();
+///
+/// let layers = regions.iter().map(|region| region.layers.clone()).collect::<Vec<_>>();
+/// assert_eq!(layers, vec![vec![0], vec![0, 1], vec![1]]);
+/// assert_eq!(regions[1].paths.signed_area(), 2.0);
+/// ```
+pub fn overlay_layers<P: PointScaler>(
+    layers: &[Paths<P>],
+    fill_rule: FillRule,
+) -> Result<Vec<LayerRegion<P>>, ClipperError> {
+    let mut overlay = Overlay::new();
+    for layer in layers {
+        overlay.add(layer.clone(), ());
+    }
+
+    Ok(overlay
+        .execute(fill_rule)?
+        .into_iter()
+        .map(|region| LayerRegion {
+            paths: region.paths,
+            layers: region.inputs,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, Path};
+
+    use super::*;
+
+    #[test]
+    fn test_overlay_layers_partitions_covered_area() {
+        let layers = [
+            Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 6.0, 6.0)),
+            Paths::from(vec![
+                Path::rectangle(3.0, 0.0, 6.0, 6.0),
+                Path::rectangle(20.0, 0.0, 1.0, 1.0),
+            ]),
+            Paths::from(Path::rectangle(0.0, 2.0, 9.0, 2.0)),
+            Paths::default(),
+        ];
+
+        let regions = overlay_layers(&layers, FillRule::NonZero).unwrap();
+
+        let area = |combination: &[usize]| {
+            regions
+                .iter()
+                .find(|region| region.layers == combination)
+                .map_or(0.0, |region| region.paths.signed_area())
+        };
+        assert_eq!(area(&[0]), 12.0);
+        assert_eq!(area(&[1]), 12.0 + 1.0);
+        assert_eq!(area(&[0, 1]), 12.0);
+        assert_eq!(area(&[0, 2]), 6.0);
+        assert_eq!(area(&[1, 2]), 6.0);
+        assert_eq!(area(&[0, 1, 2]), 6.0);
+        assert_eq!(area(&[2]), 0.0);
+        assert_eq!(regions.len(), 6);
+        assert!(regions
+            .windows(2)
+            .all(|pair| pair[0].layers < pair[1].layers));
+
+        let total = regions
+            .iter()
+            .map(|region| region.paths.signed_area())
+            .sum::<f64>();
+        let union = crate::union_all(layers.iter().cloned(), FillRule::NonZero).unwrap();
+        assert_eq!(total, union.signed_area());
+    }
+
+    #[test]
+    fn test_overlay_layers_empty() {
+        assert!(overlay_layers::<Centi>(&[], FillRule::NonZero)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    spatial::RTree, BooleanResult, Centi, ClipType, Clipper, ClipperError, FillRule, Paths,
+    PointScaler,
+};
+
+/// The result of one subject and clip pair of [`pairwise_clip`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PairwiseResult<P: PointScaler = Centi> {
+    /// Index of the subject paths
+    pub subject: usize,
+    /// Index of the clip paths
+    pub clip: usize,
+    /// Result of the operation on the pair
+    pub result: BooleanResult<P>,
+}
+
+/// This function applies a boolean operation to every pair of subject and
+/// clip paths whose bounds overlap, such as when cutting each part of a
+/// layout by each of a set of regions.
+///
+/// The bounds of all inputs are computed once and the clips are indexed in
+/// an R-tree, see [`spatial`](crate::spatial), so the engine only runs for
+/// the candidate pairs with overlapping bounds. Pairs without any shared
+/// area are skipped and not part of the result. Their intersection would be
+/// empty, while for the other operations the result of a skipped pair is
+/// just the two inputs. The results are ordered by subject and then clip
+/// index.
+///
+/// See [`pairwise_clip_par`] for clipping the pairs in parallel.
+///
+/// Passing `None` as the fill rule uses [`FillRule::default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let parts: Vec<Paths> = (0..10)
+///     .map(|i| Path::rectangle(i as f64 * 3.0, 0.0, 2.0, 2.0).into())
+///     .collect();
+/// let regions: Vec<Paths> = vec![Path::rectangle(1.0, 1.0, 4.0, 4.0).into()];
+///
+/// let results = pairwise_clip(&parts, &regions, ClipType::Intersection, FillRule::NonZero).unwrap();
+///
+/// assert_eq!(results.len(), 2);
+/// assert_eq!((results[0].subject, results[1].subject), (0, 1));
+/// assert_eq!(results[0].result.closed.signed_area(), 1.0);
+/// ```
+pub fn pairwise_clip<P: PointScaler>(
+    subjects: &[Paths<P>],
+    clips: &[Paths<P>],
+    clip_type: ClipType,
+    fill_rule: impl Into<Option<FillRule>>,
+) -> Result<Vec<PairwiseResult<P>>, ClipperError> {
+    let fill_rule = fill_rule.into().unwrap_or_default();
+
+    candidate_pairs(subjects, clips)
+        .iter()
+        .map(|&(subject, clip)| clip_pair(subjects, clips, subject, clip, clip_type, fill_rule))
+        .collect()
+}
+
+/// This function applies a boolean operation to every pair of subject and
+/// clip paths whose bounds overlap in parallel, see [`pairwise_clip`].
+///
+/// Passing `None` as the fill rule uses [`FillRule::default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let parts: Vec<Paths> = (0..10)
+///     .map(|i| Path::rectangle(i as f64 * 3.0, 0.0, 2.0, 2.0).into())
+///     .collect();
+/// let regions: Vec<Paths> = vec![Path::rectangle(1.0, 1.0, 4.0, 4.0).into()];
+///
+/// let results =
+///     pairwise_clip_par(&parts, &regions, ClipType::Intersection, FillRule::NonZero).unwrap();
+///
+/// assert_eq!(results.len(), 2);
+/// assert_eq!(results[0].result.closed.signed_area(), 1.0);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn pairwise_clip_par<P: PointScaler + Send + Sync>(
+    subjects: &[Paths<P>],
+    clips: &[Paths<P>],
+    clip_type: ClipType,
+    fill_rule: impl Into<Option<FillRule>>,
+) -> Result<Vec<PairwiseResult<P>>, ClipperError> {
+    use rayon::prelude::*;
+
+    let fill_rule = fill_rule.into().unwrap_or_default();
+
+    candidate_pairs(subjects, clips)
+        .par_iter()
+        .map(|&(subject, clip)| clip_pair(subjects, clips, subject, clip, clip_type, fill_rule))
+        .collect()
+}
+
+fn clip_pair<P: PointScaler>(
+    subjects: &[Paths<P>],
+    clips: &[Paths<P>],
+    subject: usize,
+    clip: usize,
+    clip_type: ClipType,
+    fill_rule: FillRule,
+) -> Result<PairwiseResult<P>, ClipperError> {
+    Clipper::new()
+        .add_subject_ref(&subjects[subject])
+        .add_clip_ref(&clips[clip])
+        .boolean_operation(clip_type, fill_rule)
+        .map(|result| PairwiseResult {
+            subject,
+            clip,
+            result,
+        })
+}
+
+/// Pairs of subject and clip indices with overlapping bounds, ordered by
+/// subject and clip index, found with an R-tree over the clip bounds.
+fn candidate_pairs<P: PointScaler>(
+    subjects: &[Paths<P>],
+    clips: &[Paths<P>],
+) -> Vec<(usize, usize)> {
+    let tree = RTree::new(clips.iter().map(Paths::bounds));
+
+    let mut pairs = Vec::new();
+    for (subject, bounds) in subjects.iter().map(Paths::bounds).enumerate() {
+        let start = pairs.len();
+        tree.query(&bounds, |clip| pairs.push((subject, clip)));
+        pairs[start..].sort_unstable();
+    }
+
+    pairs
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Path;
+
+    use super::*;
+
+    fn grid(count: usize, size: f64) -> Vec<Paths<Centi>> {
+        (0..count * count)
+            .map(|i| {
+                let (x, y) = ((i % count) as f64 * 3.0, (i / count) as f64 * 3.0);
+                Path::rectangle(x, y, size, size).into()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_pairwise_clip_matches_all_pairs() {
+        let subjects = grid(4, 2.0);
+        let clips: Vec<Paths<Centi>> = vec![
+            Path::rectangle(1.0, 1.0, 3.0, 3.0).into(),
+            Path::rectangle(7.0, 0.0, 1.0, 12.0).into(),
+            Path::rectangle(-5.0, -5.0, 1.0, 1.0).into(),
+        ];
+
+        let results =
+            pairwise_clip(&subjects, &clips, ClipType::Intersection, FillRule::NonZero).unwrap();
+
+        let mut expected = Vec::new();
+        for (s, subject) in subjects.iter().enumerate() {
+            for (c, clip) in clips.iter().enumerate() {
+                let closed = subject.intersect_with(clip, FillRule::NonZero).unwrap();
+                if !closed.is_empty() {
+                    expected.push((s, c, closed.signed_area()));
+                }
+            }
+        }
+        let actual = results
+            .iter()
+            .map(|pair| (pair.subject, pair.clip, pair.result.closed.signed_area()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+        assert_eq!(results.len(), 4 + 4);
+    }
+
+    #[test]
+    fn test_pairwise_clip_skips_disjoint_pairs() {
+        let subjects = grid(3, 2.0);
+        let clips = vec![
+            Paths::<Centi>::from(Path::rectangle(2.0, 2.0, 1.0, 1.0)),
+            Paths::default(),
+        ];
+
+        // The clip touches four subjects at their corners only.
+        let results =
+            pairwise_clip(&subjects, &clips, ClipType::Difference, FillRule::NonZero).unwrap();
+        assert_eq!(
+            results.iter().map(|pair| pair.subject).collect::<Vec<_>>(),
+            vec![0, 1, 3, 4]
+        );
+        assert!(results.iter().all(|pair| pair.clip == 0));
+        assert_eq!(results[0].result.closed.signed_area(), 4.0);
+
+        assert!(pairwise_clip(&[], &clips, ClipType::Union, None)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_pairwise_clip_par_matches_sequential() {
+        let subjects = grid(6, 2.5);
+        let clips = grid(4, 4.0);
+
+        for clip_type in [ClipType::Intersection, ClipType::Xor] {
+            assert_eq!(
+                pairwise_clip_par(&subjects, &clips, clip_type, FillRule::NonZero).unwrap(),
+                pairwise_clip(&subjects, &clips, clip_type, FillRule::NonZero).unwrap()
+            );
+        }
+    }
+}
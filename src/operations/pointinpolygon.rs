@@ -1,6 +1,6 @@
-use clipper2c_sys::{clipper_delete_path64, clipper_point_in_path64};
+use clipper2c_sys::clipper_point_in_path64;
 
-use crate::{Path, Point, PointInPolygonResult, PointScaler};
+use crate::{memory::delete_path64, Path, Point, PointInPolygonResult, PointScaler};
 
 /// The function result indicates whether the point is inside, or outside, or on
 /// one of the specified polygon's edges.
@@ -22,7 +22,7 @@ pub fn point_in_polygon<P: PointScaler>(point: Point<P>, path: &Path<P>) -> Poin
     let point_ptr = point.as_clipperpoint64();
     let path_ptr = unsafe { path.to_clipperpath64() };
     let result = unsafe { clipper_point_in_path64(path_ptr, *point_ptr) };
-    unsafe { clipper_delete_path64(path_ptr) };
+    unsafe { delete_path64(path_ptr) };
     result.into()
 }
 
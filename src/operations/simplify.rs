@@ -1,6 +1,6 @@
-use clipper2c_sys::{clipper_delete_paths64, clipper_paths64_simplify, clipper_paths64_size};
+use clipper2c_sys::{clipper_paths64_simplify, clipper_paths64_size};
 
-use crate::{malloc, Paths, PointScaler};
+use crate::{malloc, memory::delete_paths64, Paths, PointScaler};
 
 /// This function removes points that are less than the specified epsilon
 /// distance from an imaginary line that passes through its 2 adjacent points.
@@ -37,11 +37,11 @@ pub fn simplify<P: PointScaler>(
 
     unsafe {
         let mem = malloc(clipper_paths64_size());
-        let paths_ptr = paths.into().to_clipperpaths64();
-        let result_ptr = clipper_paths64_simplify(mem, paths_ptr, epsilon, is_open.into());
-        clipper_delete_paths64(paths_ptr);
+        let paths = paths.into();
+        let result_ptr =
+            clipper_paths64_simplify(mem, paths.as_clipperpaths64(), epsilon, is_open.into());
         let result = Paths::from_clipperpaths64(result_ptr);
-        clipper_delete_paths64(result_ptr);
+        delete_paths64(result_ptr);
         result
     }
 }
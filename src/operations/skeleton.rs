@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use crate::{ClipperError, EndType, FillRule, JoinType, Path, Paths, Point, PointScaler};
+
+/// Miter limit of the insets, high enough that corners are never squared off
+/// so every inset vertex stays on the skeleton.
+const MITER_LIMIT: f64 = 1000.0;
+
+/// Number of times the inset distance is halved to locate where a ring
+/// collapses.
+const REFINEMENTS: u32 = 3;
+
+/// This function approximates the straight skeleton of closed paths by
+/// insetting them step by step and linking the vertices of each inset to the
+/// vertices of the next one.
+///
+/// Insets with mitered corners keep their vertices on the bisectors of the
+/// corners they come from, so each vertex is linked to the vertex of the next
+/// inset closest to where its bisector leads. When a ring is about to vanish
+/// the step is halved a few times to locate the collapse more closely, and
+/// the remaining ring is linked to its center. The links are then joined into
+/// open polylines.
+///
+/// Every point of the result is within one `step` of the straight skeleton,
+/// and within an eighth of a step where rings collapse. Features of the
+/// skeleton shorter than a step may be missing, such as where a ring splits
+/// into two. The input is first joined with [`FillRule::NonZero`], so it
+/// doesn't need to be well formed. Returns no paths if `step` is not a
+/// positive number.
+///
+/// This is the base for roof generation, centerline engraving and single
+/// line fonts.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let rectangle: Paths = Path::rectangle(0.0, 0.0, 10.0, 4.0).into();
+///
+/// let skeleton = skeleton(rectangle, 0.5).unwrap();
+///
+/// assert!(!skeleton.is_empty());
+/// assert!(skeleton.iter().flatten().all(|point| {
+///     (0.0..=10.0).contains(&point.x()) && (0.0..=4.0).contains(&point.y())
+/// }));
+/// ```
+pub fn skeleton<P: PointScaler>(
+    paths: impl Into<Paths<P>>,
+    step: f64,
+) -> Result<Paths<P>, ClipperError> {
+    if step.is_nan() || step <= 0.0 {
+        return Ok(Paths::default());
+    }
+
+    let mut links = Vec::new();
+    let mut rings = paths
+        .into()
+        .make_valid(FillRule::NonZero)?
+        .into_iter()
+        .map(|ring| (ring, step))
+        .collect::<Vec<_>>();
+
+    while let Some((ring, delta)) = rings.pop() {
+        let inset = Paths::from(ring.clone()).inflate(
+            -delta,
+            JoinType::Miter,
+            EndType::Polygon,
+            MITER_LIMIT,
+        );
+
+        if inset.is_empty() {
+            if delta > step / 2f64.powi(REFINEMENTS as i32) {
+                rings.push((ring, delta / 2.0));
+            } else {
+                let center = collapse_point(&ring);
+                links.extend(ring.iter().map(|point| (*point, center)));
+            }
+            continue;
+        }
+
+        let targets = inset.iter().flatten().copied().collect::<Vec<_>>();
+        for (index, point) in ring.iter().enumerate() {
+            let expected = bisector_target(&ring, index, delta);
+            let closest = targets.iter().min_by(|a, b| {
+                a.distance_to(&expected)
+                    .total_cmp(&b.distance_to(&expected))
+            });
+
+            if let Some(closest) = closest {
+                if closest.distance_to(&expected) <= delta {
+                    links.push((*point, *closest));
+                }
+            }
+        }
+
+        rings.extend(inset.into_iter().map(|ring| (ring, delta)));
+    }
+
+    Ok(join_links(links))
+}
+
+/// Returns where the vertex at `index` moves to when the ring is inset by
+/// `delta`, following the bisector of its corner.
+fn bisector_target<P: PointScaler>(ring: &Path<P>, index: usize, delta: f64) -> Point<P> {
+    let len = ring.len();
+    let point = ring[index];
+    let previous = ring[(index + len - 1) % len];
+    let next = ring[(index + 1) % len];
+
+    // The inside of the rings returned by a union is to the left of each
+    // edge, for outer paths as well as for holes.
+    let normal = |from: Point<P>, to: Point<P>| {
+        let (dx, dy) = (to.x() - from.x(), to.y() - from.y());
+        let length = dx.hypot(dy).max(f64::EPSILON);
+        (-dy / length, dx / length)
+    };
+    let (ax, ay) = normal(previous, point);
+    let (bx, by) = normal(point, next);
+
+    let (sx, sy) = (ax + bx, ay + by);
+    let length = sx.hypot(sy);
+    if length < 1e-9 {
+        return point;
+    }
+
+    let (ux, uy) = (sx / length, sy / length);
+    let cos_half = (ux * ax + uy * ay).max(1.0 / MITER_LIMIT);
+    let distance = delta / cos_half;
+
+    Point::new(point.x() + ux * distance, point.y() + uy * distance)
+}
+
+/// Returns the point a nearly vanished ring collapses to, its centroid if
+/// that is inside the ring and otherwise the vertex closest to the centroid.
+fn collapse_point<P: PointScaler>(ring: &Path<P>) -> Point<P> {
+    let (mut x, mut y) = (0.0, 0.0);
+    for point in ring.iter() {
+        x += point.x();
+        y += point.y();
+    }
+    let count = ring.len().max(1) as f64;
+    let centroid = Point::new(x / count, y / count);
+
+    if ring.is_point_inside(centroid) != crate::PointInPolygonResult::IsOutside {
+        return centroid;
+    }
+
+    ring.iter()
+        .copied()
+        .min_by(|a, b| {
+            a.distance_to(&centroid)
+                .total_cmp(&b.distance_to(&centroid))
+        })
+        .unwrap_or(centroid)
+}
+
+/// Join links sharing end points into polylines, breaking them at points
+/// where more or less than two links meet.
+fn join_links<P: PointScaler>(links: Vec<(Point<P>, Point<P>)>) -> Paths<P> {
+    let key = |point: &Point<P>| (point.x_scaled(), point.y_scaled());
+
+    let mut links = links
+        .into_iter()
+        .filter(|(a, b)| key(a) != key(b))
+        .map(|(a, b)| if key(&a) <= key(&b) { (a, b) } else { (b, a) })
+        .collect::<Vec<_>>();
+    links.sort_by_key(|(a, b)| (key(a), key(b)));
+    links.dedup_by_key(|(a, b)| (key(a), key(b)));
+
+    let mut adjacent: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+    for (index, (a, b)) in links.iter().enumerate() {
+        adjacent.entry(key(a)).or_default().push(index);
+        adjacent.entry(key(b)).or_default().push(index);
+    }
+
+    let mut used = vec![false; links.len()];
+    let mut result = Paths::default();
+
+    let starts = (0..links.len())
+        .flat_map(|index| [(index, false), (index, true)])
+        .filter(|&(index, reversed)| {
+            let (a, b) = links[index];
+            let start = if reversed { b } else { a };
+            adjacent[&key(&start)].len() != 2
+        })
+        .chain((0..links.len()).map(|index| (index, false)));
+
+    for (index, reversed) in starts {
+        if used[index] {
+            continue;
+        }
+
+        let (a, b) = links[index];
+        let (start, mut end) = if reversed { (b, a) } else { (a, b) };
+        let mut path = Path::new(vec![start, end]);
+        used[index] = true;
+
+        loop {
+            let connected = &adjacent[&key(&end)];
+            if connected.len() != 2 {
+                break;
+            }
+
+            let Some(&next) = connected.iter().find(|&&next| !used[next]) else {
+                break;
+            };
+
+            used[next] = true;
+            let (a, b) = links[next];
+            end = if key(&a) == key(&end) { b } else { a };
+            path.push(end);
+        }
+
+        result.push_open(path);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Centi;
+
+    use super::*;
+
+    #[test]
+    fn test_skeleton_of_rectangle() {
+        let rectangle = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 10.0, 4.0));
+
+        let skeleton = skeleton(rectangle, 0.5).unwrap();
+
+        assert!(skeleton.iter().all(|path| path.len() >= 2));
+        assert!((0..skeleton.len()).all(|index| skeleton.is_open(index)));
+
+        // The straight skeleton is the segment from (2, 2) to (8, 2) and the
+        // diagonals from the corners to its ends.
+        let distance = |point: &Point<Centi>| {
+            let (x, y) = (point.x(), point.y());
+            let spine = ((x.clamp(2.0, 8.0) - x).powi(2) + (2.0 - y).powi(2)).sqrt();
+            let diagonal = |cx: f64, cy: f64, ex: f64| {
+                let t = (((x - cx) * (ex - cx) + (y - cy) * (2.0 - cy))
+                    / ((ex - cx).powi(2) + (2.0 - cy).powi(2)))
+                .clamp(0.0, 1.0);
+                let (px, py) = (cx + t * (ex - cx), cy + t * (2.0 - cy));
+                ((x - px).powi(2) + (y - py).powi(2)).sqrt()
+            };
+            spine
+                .min(diagonal(0.0, 0.0, 2.0))
+                .min(diagonal(0.0, 4.0, 2.0))
+                .min(diagonal(10.0, 0.0, 8.0))
+                .min(diagonal(10.0, 4.0, 8.0))
+        };
+
+        for point in skeleton.iter().flatten() {
+            assert!(distance(point) <= 0.5, "{point} is off the skeleton");
+        }
+
+        for corner in [(0.0, 0.0), (10.0, 0.0), (10.0, 4.0), (0.0, 4.0)] {
+            let corner = Point::<Centi>::from(corner);
+            assert!(skeleton.iter().flatten().any(|point| *point == corner));
+        }
+    }
+
+    #[test]
+    fn test_skeleton_invalid_step() {
+        let square = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+
+        assert!(skeleton(square.clone(), 0.0).unwrap().is_empty());
+        assert!(skeleton(Paths::<Centi>::default(), 1.0).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{Path, Paths, Point, PointScaler};
+
+/// Longest transition move between two rings, as a multiple of the stepover.
+/// Consecutive rings of a pocket are one stepover apart, but the closest
+/// point can be further away at sharp corners.
+const MAX_TRANSITION: f64 = 2.0;
+
+/// This function links concentric rings, such as the inset rings of
+/// [`cam::pocket`], into continuous open toolpaths.
+///
+/// Each ring is traversed once in full, starting and ending at the same
+/// point, followed by a straight transition move to the closest point of the
+/// next ring, where the traversal of that ring starts. The next ring is the
+/// remaining ring closest to the current position, so rings splitting into
+/// several islands are visited one branch after another.
+///
+/// A transition is only made if it is at most twice the `stepover` long,
+/// otherwise a new path starts at the first remaining ring. All rings are
+/// treated as closed, empty rings are skipped.
+///
+/// [`cam::pocket`]: crate::cam::pocket
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let rings: Paths = vec![
+///     Path::rectangle(0.0, 0.0, 10.0, 10.0),
+///     Path::rectangle(1.0, 1.0, 8.0, 8.0),
+///     Path::rectangle(2.0, 2.0, 6.0, 6.0),
+/// ]
+/// .into();
+///
+/// let spiral = spiral_from_rings(rings, 1.0);
+///
+/// assert_eq!(spiral.len(), 1);
+/// assert!(spiral.is_open(0));
+/// assert_eq!(spiral[0].len(), 3 * 5);
+/// ```
+pub fn spiral_from_rings<P: PointScaler>(rings: impl Into<Paths<P>>, stepover: f64) -> Paths<P> {
+    let mut remaining = rings
+        .into()
+        .iter()
+        .filter(|ring| !ring.is_empty())
+        .cloned()
+        .collect::<Vec<_>>();
+    let max_transition = stepover.abs() * MAX_TRANSITION;
+
+    let mut result = Paths::default();
+    let mut current: Vec<Point<P>> = Vec::new();
+
+    while !remaining.is_empty() {
+        let next = current.last().and_then(|&position| {
+            remaining
+                .iter()
+                .enumerate()
+                .map(|(index, ring)| (index, closest_point(ring, position)))
+                .filter(|(_, (_, _, distance))| *distance <= max_transition)
+                .min_by(|a, b| a.1 .2.total_cmp(&b.1 .2))
+        });
+
+        let (ring, entry) = match next {
+            Some((index, (segment, point, _))) => (remaining.remove(index), (segment, point)),
+            None => {
+                if !current.is_empty() {
+                    result.push_open(Path::from(std::mem::take(&mut current)));
+                }
+                (remaining.remove(0), (0, None))
+            }
+        };
+
+        current.extend(traverse_from(&ring, entry.0, entry.1));
+    }
+
+    if !current.is_empty() {
+        result.push_open(Path::from(current));
+    }
+
+    result
+}
+
+/// Find the closest point of a closed ring, returning the index of the
+/// segment starting at that vertex, the point if it lies inside the segment,
+/// and the distance.
+fn closest_point<P: PointScaler>(
+    ring: &Path<P>,
+    position: Point<P>,
+) -> (usize, Option<Point<P>>, f64) {
+    let mut best = (0, None, f64::MAX);
+
+    for index in 0..ring.len() {
+        let (start, end) = (ring[index], ring[(index + 1) % ring.len()]);
+        let edge = end - start;
+        let length_2 = edge.dot(&edge);
+        let t = if length_2 == 0.0 {
+            0.0
+        } else {
+            ((position - start).dot(&edge) / length_2).clamp(0.0, 1.0)
+        };
+
+        let point = Point::new(start.x() + t * edge.x(), start.y() + t * edge.y());
+        let distance = point.distance_to(&position);
+        if distance < best.2 {
+            best = if point == start {
+                (index, None, distance)
+            } else if point == end {
+                ((index + 1) % ring.len(), None, distance)
+            } else {
+                (index, Some(point), distance)
+            };
+        }
+    }
+
+    best
+}
+
+/// The points of a full loop around the ring, starting and ending at the
+/// vertex `index`, or at `point` on the segment starting at that vertex.
+fn traverse_from<P: PointScaler>(
+    ring: &Path<P>,
+    index: usize,
+    point: Option<Point<P>>,
+) -> Vec<Point<P>> {
+    let len = ring.len();
+    let mut points = Vec::with_capacity(len + 2);
+
+    match point {
+        Some(point) => {
+            points.push(point);
+            points.extend((1..=len).map(|offset| ring[(index + offset) % len]));
+            points.push(point);
+        }
+        None => points.extend((0..=len).map(|offset| ring[(index + offset) % len])),
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        cam::{pocket, PocketOptions},
+        Centi,
+    };
+
+    use super::*;
+
+    fn transitions(path: &Path<Centi>) -> Vec<f64> {
+        // Moves between rings are the segments leaving a point visited
+        // before, where the loop around a ring closes.
+        (1..path.len() - 1)
+            .filter(|&i| path.iter().take(i).any(|point| *point == path[i]))
+            .map(|i| path[i].distance_to(&path[i + 1]))
+            .collect()
+    }
+
+    #[test]
+    fn test_spiral_from_pocket_rings() {
+        let square: Paths<Centi> = Path::rectangle(0.0, 0.0, 20.0, 20.0).into();
+        let rings = pocket(&square, &PocketOptions::new(1.0, 1.5)).unwrap();
+        assert!(rings.len() > 3);
+
+        let spiral = spiral_from_rings(rings.clone(), 1.5);
+
+        assert_eq!(spiral.len(), 1);
+        assert!(spiral.is_open(0));
+        assert_eq!(
+            spiral[0].len(),
+            rings.iter().map(|ring| ring.len() + 1).sum::<usize>()
+        );
+
+        let moves = transitions(&spiral[0]);
+        assert_eq!(moves.len(), rings.len() - 1);
+        assert!(moves.iter().all(|&length| length <= 1.5 * 2.0));
+    }
+
+    #[test]
+    fn test_spiral_enters_at_closest_point() {
+        let rings: Paths<Centi> = vec![
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![(9.0, 3.0), (9.0, 9.0), (1.0, 9.0), (1.0, 1.0), (8.0, 1.0)],
+        ]
+        .into();
+
+        let spiral = spiral_from_rings(rings, 1.0);
+
+        assert_eq!(spiral[0][5], Point::new(1.0, 1.0));
+        assert_eq!(spiral[0].iter().last(), Some(&Point::new(1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_spiral_splits_distant_rings() {
+        let rings: Paths<Centi> = vec![
+            Path::rectangle(0.0, 0.0, 4.0, 4.0),
+            Path::rectangle(1.0, 1.0, 2.0, 2.0),
+            Path::rectangle(20.0, 0.0, 4.0, 4.0),
+            Path::default(),
+            Path::rectangle(21.0, 1.0, 2.0, 2.0),
+        ]
+        .into();
+
+        let spiral = spiral_from_rings(rings, 1.0);
+
+        assert_eq!(spiral.len(), 2);
+        assert!(spiral.is_open(0) && spiral.is_open(1));
+        assert_eq!(spiral[0].len(), 10);
+        assert_eq!(spiral[1][0], Point::new(20.0, 0.0));
+        assert!(spiral_from_rings(Paths::<Centi>::default(), 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_spiral_enters_mid_segment() {
+        let rings: Paths<Centi> = vec![
+            vec![(5.0, 0.0), (6.0, 0.0), (6.0, 1.0), (5.0, 1.0)],
+            vec![(0.0, 2.0), (10.0, 2.0), (10.0, 4.0), (0.0, 4.0)],
+        ]
+        .into();
+
+        let spiral = spiral_from_rings(rings, 1.0);
+
+        assert_eq!(spiral.len(), 1);
+        assert_eq!(spiral[0][5], Point::new(5.0, 2.0));
+        assert_eq!(spiral[0][6], Point::new(10.0, 2.0));
+        assert_eq!(spiral[0].iter().last(), Some(&Point::new(5.0, 2.0)));
+    }
+}
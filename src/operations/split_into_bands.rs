@@ -0,0 +1,140 @@
+use crate::{Bounds, Paths, Point, PointScaler, RectClipper};
+
+/// This function slices closed paths into parallel strips, as used for
+/// striped fills, roughing levels and assigning regions of a print bed.
+///
+/// Each strip is `band_height` across and runs at `angle` radians from the x
+/// axis. The edges of the strips lie at whole multiples of the height from the origin,
+/// so shapes split with the same settings share the same bands. The result
+/// holds one [`Paths`] per band, ordered along the normal of the bands (up
+/// for an `angle` of zero), from the band holding the lowest point of the
+/// paths to the band holding the highest. Bands the paths pass without
+/// covering, such as between two shapes, are kept as empty [`Paths`].
+///
+/// Each band is cut out with the fast rectangle clipping of
+/// [`RectClipper`], so like there the paths are clipped one by one and holes
+/// are clipped like any other path. For angles other than zero the paths are
+/// rotated so the bands become horizontal and the cut pieces rotated back,
+/// which rounds their points to the precision of the scaler twice.
+///
+/// Returns no bands if `band_height` is not a positive number.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let square: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+///
+/// let bands = split_into_bands(square, 4.0, 0.0);
+///
+/// assert_eq!(bands.len(), 3);
+/// assert_eq!(bands[0].signed_area(), 40.0);
+/// assert_eq!(bands[2].signed_area(), 20.0);
+/// ```
+pub fn split_into_bands<P: PointScaler>(
+    paths: impl Into<Paths<P>>,
+    band_height: f64,
+    angle: f64,
+) -> Vec<Paths<P>> {
+    let paths = paths.into();
+    if band_height.is_nan() || band_height <= 0.0 || !paths.contains_points() {
+        return Vec::new();
+    }
+
+    let (sin, cos) = angle.sin_cos();
+    let rotated = angle != 0.0;
+    let rotate = |paths: &Paths<P>, sin: f64| {
+        let mut paths = paths.clone();
+        paths.map_points_in_place(|point| {
+            Point::new(
+                point.x() * cos - point.y() * sin,
+                point.x() * sin + point.y() * cos,
+            )
+        });
+        paths
+    };
+
+    let horizontal = if rotated { rotate(&paths, -sin) } else { paths };
+
+    let bounds = horizontal.bounds();
+    let first = (bounds.min.y() / band_height).floor() as i64;
+    // A band starting exactly at the highest point would be empty.
+    let last = ((bounds.max.y() / band_height).ceil() as i64 - 1).max(first);
+
+    (first..=last)
+        .map(|band| {
+            let clipper = RectClipper::new(Bounds {
+                min: Point::new(bounds.min.x(), band as f64 * band_height),
+                max: Point::new(bounds.max.x(), (band + 1) as f64 * band_height),
+            });
+            let pieces = clipper.clip(&horizontal);
+
+            if rotated {
+                rotate(&pieces, sin)
+            } else {
+                pieces
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::{Centi, Path};
+
+    use super::*;
+
+    #[test]
+    fn test_bands_align_to_origin() {
+        let paths = Paths::<Centi>::from(vec![
+            Path::rectangle(0.0, -3.0, 10.0, 4.0),
+            Path::rectangle(0.0, 9.0, 2.0, 2.0),
+        ]);
+
+        let bands = split_into_bands(paths, 2.0, 0.0);
+
+        // Bands from y = -4 to 12.
+        let areas = bands.iter().map(Paths::signed_area).collect::<Vec<_>>();
+        assert_eq!(areas, vec![10.0, 20.0, 10.0, 0.0, 0.0, 0.0, 2.0, 2.0]);
+        assert!(bands[3].is_empty());
+        assert!(bands[1]
+            .iter()
+            .flatten()
+            .all(|point| (-2.0..=0.0).contains(&point.y())));
+    }
+
+    #[test]
+    fn test_rotated_bands() {
+        let paths = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 6.0, 10.0));
+
+        let bands = split_into_bands(paths, 2.0, FRAC_PI_2);
+
+        // Vertical bands ordered along the normal, which points towards
+        // negative x.
+        assert_eq!(bands.len(), 3);
+        for (band, min_x) in bands.iter().zip([4.0, 2.0, 0.0]) {
+            assert!((band.signed_area() - 20.0).abs() < 0.1);
+            assert!((band.bounds().min.x() - min_x).abs() <= 0.01);
+        }
+    }
+
+    #[test]
+    fn test_holes_and_invalid_height() {
+        let paths = Paths::<Centi>::from(vec![
+            Path::rectangle(0.0, 0.0, 4.0, 4.0),
+            Path::from(vec![(1.0, 1.0), (1.0, 3.0), (3.0, 3.0), (3.0, 1.0)]),
+        ]);
+
+        let bands = split_into_bands(paths.clone(), 2.0, 0.0);
+        assert_eq!(bands.len(), 2);
+        assert_eq!(bands[0].signed_area(), 8.0 - 2.0);
+        assert_eq!(bands[0].len(), 2);
+
+        assert!(split_into_bands(paths.clone(), 0.0, 0.0).is_empty());
+        assert!(split_into_bands(paths, f64::NAN, 0.0).is_empty());
+        assert!(split_into_bands(Paths::<Centi>::default(), 1.0, 0.0).is_empty());
+    }
+}
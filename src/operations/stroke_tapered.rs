@@ -0,0 +1,272 @@
+use std::f64::consts::PI;
+
+use crate::{
+    operations::offset_one_side::{corner, to_path, ARC_TOLERANCE},
+    union_all, ClipperError, EndType, FillRule, JoinType, Path, Paths, PointScaler,
+};
+
+/// This function strokes an open polyline with a width changing linearly
+/// along its length, as needed for calligraphic plotter strokes and traces
+/// narrowing towards a pad.
+///
+/// The stroke is `start_width` across at the first point of the line and
+/// `end_width` across at the last point, with the width in between following
+/// the arc length of the line rather than the number of vertices. The
+/// `join_type` shapes the outside of each corner, sized to the width at that
+/// corner. The `cap` shapes both ends like the end types of [`inflate`]:
+///
+/// * [`EndType::Butt`] ends the stroke at the first and last point
+/// * [`EndType::Square`] extends each end by half of its width
+/// * [`EndType::Round`] rounds each end off with half of its width
+/// * [`EndType::Joined`] and [`EndType::Polygon`] close the line back to its
+///   first point and join the ends like any other corner
+///
+/// Negative widths are treated as zero and a line without any segments has
+/// no stroke. The result holds the closed paths of the stroke, joined with
+/// [`FillRule::NonZero`], so a line crossing itself is filled where it
+/// overlaps.
+///
+/// [`inflate`]: crate::inflate
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let line: Path = vec![(0.0, 0.0), (10.0, 0.0)].into();
+///
+/// let stroke = stroke_tapered(line, 4.0, 0.0, JoinType::Round, EndType::Butt).unwrap();
+///
+/// assert_eq!(stroke.signed_area(), 20.0);
+/// assert_eq!(stroke.bounds().max.y(), 2.0);
+/// ```
+pub fn stroke_tapered<P: PointScaler>(
+    line: impl Into<Path<P>>,
+    start_width: f64,
+    end_width: f64,
+    join_type: JoinType,
+    cap: EndType,
+) -> Result<Paths<P>, ClipperError> {
+    let mut points = line
+        .into()
+        .iter()
+        .map(|p| (p.x(), p.y()))
+        .collect::<Vec<_>>();
+    points.dedup();
+
+    let closed = matches!(cap, EndType::Joined | EndType::Polygon);
+    if closed && points.len() > 2 && points.first() != points.last() {
+        points.push(points[0]);
+    }
+
+    let start = start_width.max(0.0) / 2.0;
+    let end = end_width.max(0.0) / 2.0;
+    if points.len() < 2 || (start == 0.0 && end == 0.0) {
+        return Ok(Paths::default());
+    }
+
+    // Unit direction and length of each segment.
+    let segments = points
+        .windows(2)
+        .map(|segment| {
+            let (dx, dy) = (segment[1].0 - segment[0].0, segment[1].1 - segment[0].1);
+            let length = dx.hypot(dy);
+            ((dx / length, dy / length), length)
+        })
+        .collect::<Vec<_>>();
+
+    // Half of the width at each point, by the arc length up to the point.
+    let total = segments.iter().map(|&(_, length)| length).sum::<f64>();
+    let mut travelled = 0.0;
+    let mut half_widths = vec![start];
+    for &(_, length) in &segments {
+        travelled += length;
+        half_widths.push(start + (end - start) * travelled / total);
+    }
+
+    let shift =
+        |(x, y): (f64, f64), (dx, dy): (f64, f64), offset: f64| (x - dy * offset, y + dx * offset);
+
+    let mut pieces = Vec::new();
+    for (index, &(direction, _)) in segments.iter().enumerate() {
+        let (from, to) = (points[index], points[index + 1]);
+        let (from_half, to_half) = (half_widths[index], half_widths[index + 1]);
+        pieces.push(to_path(&[
+            shift(from, direction, -from_half),
+            shift(to, direction, -to_half),
+            shift(to, direction, to_half),
+            shift(from, direction, from_half),
+        ]));
+    }
+
+    // Corners as the point and the segments before and after it.
+    let mut corners = (1..segments.len())
+        .map(|index| (index, index - 1, index, half_widths[index]))
+        .collect::<Vec<_>>();
+    if closed && segments.len() > 2 {
+        // The ends meet at the first point, with the end width before and
+        // the start width after it.
+        corners.push((0, segments.len() - 1, 0, start.max(end)));
+    }
+    for (vertex, before, after, half) in corners {
+        let (before, after) = (segments[before].0, segments[after].0);
+        let turn = before.0 * after.1 - before.1 * after.0;
+        if half == 0.0 || turn == 0.0 && before.0 * after.0 + before.1 * after.1 > 0.0 {
+            // No join is needed where the line goes straight on.
+            continue;
+        }
+
+        // The join goes on the outside of the corner, opposite to the turn.
+        let offset = if turn > 0.0 { -half } else { half };
+        pieces.push(to_path(&corner(
+            points[vertex],
+            before,
+            after,
+            offset,
+            join_type,
+            |point, direction| shift(point, direction, offset),
+        )));
+    }
+
+    if !closed {
+        let first = segments[0].0;
+        let last = segments[segments.len() - 1].0;
+        let reversed = (-first.0, -first.1);
+        for (point, direction, half) in [
+            (points[0], reversed, start),
+            (points[points.len() - 1], last, end),
+        ] {
+            if half > 0.0 {
+                if let Some(piece) = end_cap(point, direction, half, cap) {
+                    pieces.push(to_path(&piece));
+                }
+            }
+        }
+    }
+
+    for piece in &mut pieces {
+        if piece.signed_area() < 0.0 {
+            piece.reverse();
+        }
+    }
+
+    union_all([Paths::new(pieces)], FillRule::NonZero)
+}
+
+/// The points of the cap at an end of the line, where the `direction` points
+/// away from the line.
+fn end_cap(
+    point: (f64, f64),
+    direction: (f64, f64),
+    half: f64,
+    cap: EndType,
+) -> Option<Vec<(f64, f64)>> {
+    let (x, y) = point;
+    let (dx, dy) = (direction.0 * half, direction.1 * half);
+    match cap {
+        EndType::Square => Some(vec![
+            (x + dy, y - dx),
+            (x + dy + dx, y - dx + dy),
+            (x - dy + dx, y + dx + dy),
+            (x - dy, y + dx),
+        ]),
+        EndType::Round => {
+            let step = 2.0 * (1.0 - ARC_TOLERANCE).acos();
+            let steps = (2.0 * PI / step).ceil().max(4.0) as usize;
+            Some(
+                (0..steps)
+                    .map(|i| {
+                        let (sin, cos) = (2.0 * PI * i as f64 / steps as f64).sin_cos();
+                        (x + half * cos, y + half * sin)
+                    })
+                    .collect(),
+            )
+        }
+        EndType::Butt | EndType::Joined | EndType::Polygon => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, Point, PointInPolygonResult};
+
+    use super::*;
+
+    fn straight() -> Path<Centi> {
+        vec![(0.0, 0.0), (10.0, 0.0)].into()
+    }
+
+    #[test]
+    fn test_taper_and_caps() {
+        let area = |start, end, cap| {
+            stroke_tapered(straight(), start, end, JoinType::Round, cap)
+                .unwrap()
+                .signed_area()
+        };
+
+        assert_eq!(area(2.0, 2.0, EndType::Butt), 20.0);
+        assert_eq!(area(2.0, 6.0, EndType::Butt), 40.0);
+        assert_eq!(area(2.0, 2.0, EndType::Square), 24.0);
+        assert!((area(2.0, 2.0, EndType::Round) - (20.0 + PI)).abs() < 0.05);
+        assert!((area(0.0, 2.0, EndType::Round) - (10.0 + PI / 2.0)).abs() < 0.05);
+
+        let stroke = stroke_tapered(straight(), 4.0, 2.0, JoinType::Round, EndType::Butt).unwrap();
+        assert_eq!(stroke.bounds().min.y(), -2.0);
+        assert_eq!(stroke.bounds().max.y(), 2.0);
+
+        assert!(
+            stroke_tapered(straight(), -1.0, 0.0, JoinType::Round, EndType::Round)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(stroke_tapered(
+            Path::<Centi>::from(vec![(1.0, 1.0)]),
+            1.0,
+            1.0,
+            JoinType::Round,
+            EndType::Round
+        )
+        .unwrap()
+        .is_empty());
+    }
+
+    #[test]
+    fn test_width_follows_arc_length() {
+        // Most of the length is in the second segment, so the width at the
+        // corner is close to the start width.
+        let line: Path<Centi> = vec![(0.0, 0.0), (2.0, 0.0), (2.0, 18.0)].into();
+
+        let stroke = stroke_tapered(line, 4.0, 0.0, JoinType::Bevel, EndType::Butt).unwrap();
+
+        let bounds = stroke.bounds();
+        assert_eq!(bounds.min.y(), -2.0);
+        assert!((bounds.max.x() - 3.8).abs() <= 0.01);
+    }
+
+    #[test]
+    fn test_corner_joins() {
+        let line: Path<Centi> = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)].into();
+        let contains = |join_type, x, y| {
+            let stroke = stroke_tapered(line.clone(), 4.0, 0.0, join_type, EndType::Butt).unwrap();
+            assert_eq!(stroke.len(), 1);
+            stroke[0].is_point_inside(Point::new(x, y)) == PointInPolygonResult::IsInside
+        };
+
+        // The width is 2 at the corner, which turns left so the join is below
+        // and to the right of it.
+        assert!(contains(JoinType::Miter, 10.9, -0.9));
+        assert!(!contains(JoinType::Round, 10.9, -0.9));
+        assert!(contains(JoinType::Round, 10.6, -0.6));
+        assert!(!contains(JoinType::Bevel, 10.6, -0.6));
+    }
+
+    #[test]
+    fn test_joined_ends() {
+        let square: Path<Centi> = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)].into();
+
+        let ring = stroke_tapered(square, 2.0, 2.0, JoinType::Miter, EndType::Joined).unwrap();
+
+        assert_eq!(ring.len(), 2);
+        assert_eq!(ring.signed_area(), 144.0 - 64.0);
+    }
+}
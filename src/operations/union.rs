@@ -3,6 +3,8 @@ use crate::{BooleanResult, Clipper, ClipperError, FillRule, Paths, PointScaler};
 /// This function joins a set of closed subject paths, with and without clip
 /// paths.
 ///
+/// Passing `None` as the fill rule uses [`FillRule::default`].
+///
 /// # Examples
 ///
 /// ```rust
@@ -24,8 +26,10 @@ use crate::{BooleanResult, Clipper, ClipperError, FillRule, Paths, PointScaler};
 pub fn union<P: PointScaler>(
     subject: impl Into<Paths<P>>,
     clip: impl Into<Paths<P>>,
-    fill_rule: FillRule,
+    fill_rule: impl Into<Option<FillRule>>,
 ) -> Result<BooleanResult<P>, ClipperError> {
+    let fill_rule = fill_rule.into().unwrap_or_default();
+
     Clipper::new()
         .add_subject(subject)
         .add_clip(clip)
@@ -34,7 +38,7 @@ pub fn union<P: PointScaler>(
 
 #[cfg(test)]
 mod test {
-    use crate::Centi;
+    use crate::{Centi, Path};
 
     use super::*;
 
@@ -57,4 +61,15 @@ mod test {
         let output: Vec<Vec<(f64, f64)>> = result.closed.into();
         assert_eq!(output, expected_output);
     }
+
+    #[test]
+    fn test_union_default_fill_rule() {
+        let path1 = Path::<Centi>::rectangle(0.0, 0.0, 2.0, 2.0);
+        let path2 = Path::<Centi>::rectangle(1.0, 0.0, 2.0, 2.0);
+
+        assert_eq!(
+            union(path1.clone(), path2.clone(), None).unwrap(),
+            union(path1, path2, FillRule::default()).unwrap()
+        );
+    }
 }
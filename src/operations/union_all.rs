@@ -0,0 +1,255 @@
+use std::borrow::Borrow;
+
+use crate::{Clipper, ClipperError, FillRule, Paths, PointScaler};
+
+/// This function joins any number of sets of closed paths into one set.
+///
+/// All inputs are added as subjects to a single engine instance and joined
+/// in one operation. The fill rule therefore applies to all inputs together,
+/// so overlapping inputs are only joined with a fill rule such as
+/// [`FillRule::NonZero`], while [`FillRule::EvenOdd`] cuts out the regions
+/// where an even number of inputs overlap.
+///
+/// Passing `None` as the fill rule uses [`FillRule::default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let squares = (0..100).map(|i| Path::<Centi>::rectangle(i as f64, 0.0, 2.0, 2.0));
+///
+/// let result = union_all(squares, FillRule::NonZero).unwrap();
+///
+/// assert_eq!(result.len(), 1);
+/// assert_eq!(result.signed_area(), 202.0);
+/// ```
+///
+/// For more details see the original [union](https://www.angusj.com/clipper2/Docs/Units/Clipper/Functions/Union.htm) docs.
+pub fn union_all<P: PointScaler>(
+    paths: impl IntoIterator<Item = impl Into<Paths<P>>>,
+    fill_rule: impl Into<Option<FillRule>>,
+) -> Result<Paths<P>, ClipperError> {
+    union_once(
+        paths.into_iter().map(Into::into),
+        fill_rule.into().unwrap_or_default(),
+    )
+}
+
+/// This function joins any number of sets of closed paths into one set in
+/// parallel, see [`union_all`].
+///
+/// The inputs are split into one chunk per thread and the chunks are joined
+/// in parallel. The fill rule only applies within each chunk, the partial
+/// results are then merged pairwise in a balanced tree with
+/// [`FillRule::NonZero`]. The result is the same as with [`union_all`] for
+/// inputs with consistent orientation joined with [`FillRule::NonZero`],
+/// [`FillRule::Positive`] or [`FillRule::Negative`], but may differ for
+/// inputs with mixed orientation, such as holes given as separate inputs,
+/// as they are only cut out of inputs in the same chunk.
+/// [`FillRule::EvenOdd`] depends on how many inputs overlap, so it always
+/// joins all inputs in one operation.
+///
+/// Passing `None` as the fill rule uses [`FillRule::default`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let squares = (0..100).map(|i| Path::<Centi>::rectangle(i as f64, 0.0, 2.0, 2.0));
+///
+/// let result = union_all_par(squares, FillRule::NonZero).unwrap();
+///
+/// assert_eq!(result.len(), 1);
+/// assert_eq!(result.signed_area(), 202.0);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn union_all_par<P: PointScaler + Send + Sync>(
+    paths: impl IntoIterator<Item = impl Into<Paths<P>>>,
+    fill_rule: impl Into<Option<FillRule>>,
+) -> Result<Paths<P>, ClipperError> {
+    use rayon::prelude::*;
+
+    let fill_rule = fill_rule.into().unwrap_or_default();
+    if fill_rule == FillRule::EvenOdd {
+        return union_once(paths.into_iter().map(Into::into), fill_rule);
+    }
+
+    let paths = paths.into_iter().map(Into::into).collect::<Vec<Paths<P>>>();
+    let chunk_size = paths.len().div_ceil(rayon::current_num_threads()).max(1);
+
+    // Joined paths are positively oriented and don't overlap, so they are
+    // merged with the non-zero rule whatever the caller's rule.
+    let mut level = paths
+        .par_chunks(chunk_size)
+        .map(|chunk| union_once(chunk, fill_rule))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    while level.len() > 1 {
+        level = level
+            .par_chunks(2)
+            .map(|pair| match pair {
+                [a, b] => Ok(a
+                    .to_clipper_subject()
+                    .add_clip_ref(b)
+                    .union(FillRule::NonZero)?
+                    .closed),
+                _ => Ok(pair[0].clone()),
+            })
+            .collect::<Result<Vec<_>, ClipperError>>()?;
+    }
+
+    Ok(level.pop().unwrap_or_default())
+}
+
+/// Join all paths with a single engine instance and execution.
+fn union_once<P: PointScaler>(
+    paths: impl IntoIterator<Item = impl Borrow<Paths<P>>>,
+    fill_rule: FillRule,
+) -> Result<Paths<P>, ClipperError> {
+    let mut paths = paths.into_iter();
+
+    let Some(first) = paths.next() else {
+        return Ok(Paths::default());
+    };
+
+    let clipper = paths.fold(
+        Clipper::new().add_subject_ref(first.borrow()),
+        |clipper, paths| clipper.add_subject_ref(paths.borrow()),
+    );
+
+    Ok(clipper.add_clip(Paths::default()).union(fill_rule)?.closed)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{union, Centi, Path};
+
+    use super::*;
+
+    #[test]
+    fn test_union_all() {
+        let squares = (0..9)
+            .map(|i| Path::<Centi>::rectangle((i % 3) as f64 * 2.0, (i / 3) as f64 * 2.0, 3.0, 3.0))
+            .collect::<Vec<_>>();
+
+        let result = union_all(squares.clone(), FillRule::NonZero).unwrap();
+        let sequential = squares
+            .into_iter()
+            .try_fold(Paths::default(), |acc, square| {
+                union(acc, square, FillRule::NonZero).map(|result| result.closed)
+            })
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result.signed_area(), 49.0);
+        assert_eq!(result.signed_area(), sequential.signed_area());
+    }
+
+    #[test]
+    fn test_union_all_single_and_empty() {
+        let single = union_all(
+            [Paths::<Centi>::from(vec![
+                Path::rectangle(0.0, 0.0, 2.0, 2.0),
+                Path::rectangle(1.0, 0.0, 2.0, 2.0),
+            ])],
+            FillRule::NonZero,
+        )
+        .unwrap();
+
+        assert_eq!(single.len(), 1);
+        assert_eq!(single.signed_area(), 6.0);
+        assert!(union_all(Vec::<Paths<Centi>>::new(), FillRule::default())
+            .unwrap()
+            .is_empty());
+    }
+
+    /// Join with [`union_all`] and, with the `rayon` feature, with
+    /// [`union_all_par`] on several threads so the inputs are split.
+    fn union_alls(paths: &[Path<Centi>], fill_rule: FillRule) -> Vec<Paths<Centi>> {
+        let results = vec![union_all(paths.to_vec(), fill_rule).unwrap()];
+
+        #[cfg(feature = "rayon")]
+        let results = [
+            results,
+            vec![rayon::ThreadPoolBuilder::new()
+                .num_threads(4)
+                .build()
+                .unwrap()
+                .install(|| union_all_par(paths.to_vec(), fill_rule))
+                .unwrap()],
+        ]
+        .concat();
+
+        results
+    }
+
+    #[test]
+    fn test_union_all_fill_rule_spans_inputs() {
+        let squares = [
+            Path::<Centi>::rectangle(0.0, 0.0, 2.0, 2.0),
+            Path::<Centi>::rectangle(1.0, 0.0, 2.0, 2.0),
+        ];
+
+        for result in union_alls(&squares, FillRule::EvenOdd) {
+            assert_eq!(result.len(), 2);
+            assert_eq!(result.signed_area(), 4.0);
+        }
+    }
+
+    #[test]
+    fn test_union_all_negative_fill_rule() {
+        let clockwise = (0..64)
+            .map(|i| {
+                Path::<Centi>::rectangle((i % 8) as f64 * 3.0, (i / 8) as f64 * 3.0, 2.0, 2.0)
+                    .iter()
+                    .rev()
+                    .copied()
+                    .collect::<Path<Centi>>()
+            })
+            .collect::<Vec<_>>();
+
+        let single = union(
+            Paths::new(clockwise.clone()),
+            Paths::default(),
+            FillRule::Negative,
+        )
+        .unwrap()
+        .closed;
+
+        for result in union_alls(&clockwise, FillRule::Negative) {
+            assert_eq!(result.len(), 64);
+            assert_eq!(result.len(), single.len());
+            assert_eq!(result.signed_area(), single.signed_area());
+        }
+
+        // Counter-clockwise inputs are not filled with the negative rule.
+        let positive = clockwise
+            .iter()
+            .map(|path| path.iter().rev().copied().collect::<Path<Centi>>())
+            .collect::<Vec<_>>();
+        for result in union_alls(&positive, FillRule::Negative) {
+            assert!(result.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_union_all_holes_across_inputs() {
+        // A clockwise ring inside a counter-clockwise one is a hole however
+        // many inputs there are in between.
+        let mut paths = vec![Path::<Centi>::rectangle(0.0, 0.0, 10.0, 10.0)];
+        paths.extend((0..6).map(|i| Path::rectangle(20.0 + i as f64 * 3.0, 0.0, 2.0, 2.0)));
+        paths.push(
+            Path::<Centi>::rectangle(2.0, 2.0, 2.0, 2.0)
+                .iter()
+                .rev()
+                .copied()
+                .collect(),
+        );
+
+        let result = union_all(paths, FillRule::NonZero).unwrap();
+
+        assert_eq!(result.signed_area(), 100.0 - 4.0 + 6.0 * 4.0);
+    }
+}
@@ -2,6 +2,8 @@ use crate::{BooleanResult, Clipper, ClipperError, FillRule, Paths, PointScaler};
 
 /// This function 'XORs' closed subject paths and clip paths.
 ///
+/// Passing `None` as the fill rule uses [`FillRule::default`].
+///
 /// # Examples
 ///
 /// ```rust
@@ -23,8 +25,10 @@ use crate::{BooleanResult, Clipper, ClipperError, FillRule, Paths, PointScaler};
 pub fn xor<P: PointScaler>(
     subject: impl Into<Paths<P>>,
     clip: impl Into<Paths<P>>,
-    fill_rule: FillRule,
+    fill_rule: impl Into<Option<FillRule>>,
 ) -> Result<BooleanResult<P>, ClipperError> {
+    let fill_rule = fill_rule.into().unwrap_or_default();
+
     Clipper::new()
         .add_subject(subject)
         .add_clip(clip)
@@ -38,7 +38,7 @@ use clipper2c_sys::{
 /// * Negative: Only sub-regions with winding counts < 0 are filled
 ///
 /// For more details see [FillRule](https://www.angusj.com/clipper2/Docs/Units/Clipper/Types/FillRule.htm).
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FillRule {
     /// Even-Odd filling rule
     #[default]
@@ -64,13 +64,17 @@ pub enum FillRule {
 /// difference operations (and as long as subject paths are closed).
 ///
 /// For more details see [ClipType](https://www.angusj.com/clipper2/Docs/Units/Clipper/Types/ClipType.htm).
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub(crate) enum ClipType {
-    #[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClipType {
+    /// No operation, the result is empty
     None,
+    /// AND (intersection)
     Intersection,
+    /// OR (union)
     Union,
+    /// NOT (difference)
     Difference,
+    /// XOR (exclusive or)
     Xor,
 }
 
@@ -143,6 +147,31 @@ pub enum PointInPolygonResult {
     IsOutside,
 }
 
+impl FillRule {
+    /// Returns `true` if a region with the winding number is filled.
+    pub(crate) fn is_filled(self, winding: i64) -> bool {
+        match self {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+            FillRule::Positive => winding > 0,
+            FillRule::Negative => winding < 0,
+        }
+    }
+}
+
+impl ClipType {
+    /// Name of the operation, used in error messages.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            ClipType::None => "no-op",
+            ClipType::Intersection => "intersection",
+            ClipType::Union => "union",
+            ClipType::Difference => "difference",
+            ClipType::Xor => "xor",
+        }
+    }
+}
+
 impl From<ClipType> for ClipperClipType {
     fn from(value: ClipType) -> Self {
         match value {
@@ -0,0 +1,214 @@
+use crate::{ClipperError, FillRule, Paths, PointScaler};
+
+/// Builder for an overlay of polygons that each carry a payload, such as a
+/// feature ID or attribute struct of a GIS layer.
+///
+/// Executing the overlay splits the area covered by the inputs into regions
+/// that are each covered by the same set of inputs, the classic union overlay
+/// with attributes. Every region reports the payloads of the inputs covering
+/// it, so attributes can be combined per region.
+///
+/// The regions are computed with repeated intersections and differences, so
+/// the cost grows with the number of inputs times the number of regions.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let mut overlay = Overlay::new();
+/// overlay.add(Path::<Centi>::rectangle(0.0, 0.0, 2.0, 2.0), "forest");
+/// overlay.add(Path::rectangle(1.0, 0.0, 2.0, 2.0), "protected");
+///
+/// let regions = overlay.execute(FillRule::default()).unwrap();
+///
+/// assert_eq!(regions.len(), 3);
+/// let both = regions.iter().find(|region| region.inputs.len() == 2).unwrap();
+/// assert_eq!(both.paths.signed_area(), 2.0);
+/// assert_eq!(both.payloads, vec![&"forest", &"protected"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Overlay<P: PointScaler, T> {
+    inputs: Vec<(Paths<P>, T)>,
+}
+
+/// A region of an [`Overlay`] result, covered by exactly the same inputs
+/// everywhere.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayRegion<'a, P: PointScaler, T> {
+    /// Closed paths of the region, it may consist of several parts.
+    pub paths: Paths<P>,
+    /// Indices of the inputs covering the region, in the order they were
+    /// added.
+    pub inputs: Vec<usize>,
+    /// Payloads of the inputs covering the region, in the same order as
+    /// `inputs`.
+    pub payloads: Vec<&'a T>,
+}
+
+impl<P: PointScaler, T> Default for Overlay<P, T> {
+    fn default() -> Self {
+        Self { inputs: Vec::new() }
+    }
+}
+
+impl<P: PointScaler, T> Overlay<P, T> {
+    /// Create an empty overlay.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an input polygon with its payload, returning the index of the
+    /// input as reported by [`OverlayRegion::inputs`].
+    pub fn add(&mut self, paths: impl Into<Paths<P>>, payload: T) -> usize {
+        self.inputs.push((paths.into(), payload));
+        self.inputs.len() - 1
+    }
+
+    /// Returns the number of inputs.
+    pub fn len(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Returns `true` if no inputs have been added.
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+
+    /// Returns the payload of an input.
+    pub fn payload(&self, input: usize) -> Option<&T> {
+        self.inputs.get(input).map(|(_, payload)| payload)
+    }
+
+    /// Split the area covered by the inputs into regions covered by the same
+    /// inputs. The fill rule applies to each input separately.
+    ///
+    /// Regions covered by no input are not returned, and regions without
+    /// area are dropped. The regions are sorted by their lists of input
+    /// indices.
+    pub fn execute(
+        &self,
+        fill_rule: FillRule,
+    ) -> Result<Vec<OverlayRegion<'_, P, T>>, ClipperError> {
+        let mut regions: Vec<(Paths<P>, Vec<usize>)> = Vec::new();
+        let mut covered = Paths::default();
+
+        for (index, (paths, _)) in self.inputs.iter().enumerate() {
+            let input = paths.make_valid(fill_rule)?;
+            if input.is_empty() {
+                continue;
+            }
+
+            let mut next = Vec::with_capacity(regions.len() * 2 + 1);
+            for (region, inputs) in regions {
+                let inside = region.intersect_with(&input, FillRule::NonZero)?;
+                if inside.is_empty() {
+                    next.push((region, inputs));
+                    continue;
+                }
+
+                let outside = region.difference_with(&input, FillRule::NonZero)?;
+                if !outside.is_empty() {
+                    next.push((outside, inputs.clone()));
+                }
+
+                let mut inputs = inputs;
+                inputs.push(index);
+                next.push((inside, inputs));
+            }
+
+            let uncovered = input.difference_with(&covered, FillRule::NonZero)?;
+            if !uncovered.is_empty() {
+                next.push((uncovered, vec![index]));
+            }
+
+            covered = covered.union_with(&input, FillRule::NonZero)?;
+            regions = next;
+        }
+
+        regions.sort_by(|a, b| a.1.cmp(&b.1));
+
+        Ok(regions
+            .into_iter()
+            .map(|(paths, inputs)| OverlayRegion {
+                paths,
+                payloads: inputs.iter().map(|&index| &self.inputs[index].1).collect(),
+                inputs,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, Path};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Parcel {
+        id: u32,
+    }
+
+    #[test]
+    fn test_overlay_three_inputs() {
+        let mut overlay = Overlay::<Centi, Parcel>::new();
+        overlay.add(Path::rectangle(0.0, 0.0, 4.0, 4.0), Parcel { id: 1 });
+        overlay.add(Path::rectangle(2.0, 0.0, 4.0, 4.0), Parcel { id: 2 });
+        overlay.add(Path::rectangle(1.0, 1.0, 4.0, 2.0), Parcel { id: 3 });
+
+        let regions = overlay.execute(FillRule::NonZero).unwrap();
+
+        let area = |inputs: &[usize]| {
+            regions
+                .iter()
+                .find(|region| region.inputs == inputs)
+                .map_or(0.0, |region| region.paths.signed_area())
+        };
+        assert_eq!(area(&[0]), 8.0 - 2.0);
+        assert_eq!(area(&[1]), 8.0 - 2.0);
+        assert_eq!(area(&[0, 1]), 8.0 - 4.0);
+        assert_eq!(area(&[0, 2]), 2.0);
+        assert_eq!(area(&[1, 2]), 2.0);
+        assert_eq!(area(&[0, 1, 2]), 4.0);
+        assert_eq!(area(&[2]), 0.0);
+        assert_eq!(regions.len(), 6);
+
+        let total = regions
+            .iter()
+            .map(|region| region.paths.signed_area())
+            .sum::<f64>();
+        assert_eq!(total, 24.0);
+
+        let all = regions
+            .iter()
+            .find(|region| region.inputs.len() == 3)
+            .unwrap();
+        assert_eq!(
+            all.payloads
+                .iter()
+                .map(|parcel| parcel.id)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_overlay_disjoint_and_empty_inputs() {
+        let mut overlay = Overlay::<Centi, &str>::new();
+        overlay.add(Path::rectangle(0.0, 0.0, 1.0, 1.0), "a");
+        overlay.add(Paths::default(), "empty");
+        overlay.add(Path::rectangle(5.0, 0.0, 1.0, 1.0), "b");
+
+        let regions = overlay.execute(FillRule::NonZero).unwrap();
+
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0].inputs, vec![0]);
+        assert_eq!(regions[1].inputs, vec![2]);
+        assert_eq!(overlay.payload(1), Some(&"empty"));
+        assert!(Overlay::<Centi, ()>::new()
+            .execute(FillRule::NonZero)
+            .unwrap()
+            .is_empty());
+    }
+}
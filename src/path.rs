@@ -1,13 +1,16 @@
-use std::ops;
+use std::{fmt, ops, slice::SliceIndex};
 
 use clipper2c_sys::{
-    clipper_delete_path64, clipper_path64_area, clipper_path64_get_point, clipper_path64_length,
-    clipper_path64_of_points, clipper_path64_simplify, clipper_path64_size, ClipperPath64,
-    ClipperPoint64,
+    clipper_path64_area, clipper_path64_length, clipper_path64_of_points, clipper_path64_simplify,
+    clipper_path64_size, clipper_pathd_length, ClipperPath64, ClipperPathD, ClipperPoint64,
+    ClipperPointD,
 };
 
 use crate::{
-    inflate, malloc, point_in_polygon, Bounds, Centi, EndType, JoinType, Paths, Point,
+    ffi::{clipper_path64_is_positive, clipper_path64_to_points, clipper_pathd_to_points},
+    inflate, malloc,
+    memory::delete_path64,
+    point_in_polygon, Bounds, Centi, Coordinate, EndType, JoinType, Paths, Point,
     PointInPolygonResult, PointScaler,
 };
 
@@ -25,39 +28,105 @@ use crate::{
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(bound = "P: PointScaler")
+    serde(bound = "P: PointScaler, C: Coordinate")
 )]
-pub struct Path<P: PointScaler = Centi>(Vec<Point<P>>);
+pub struct Path<P: PointScaler = Centi, C: Coordinate = i64>(Vec<Point<P, C>>);
 
-impl<P: PointScaler> Eq for Path<P> {}
+impl<P: PointScaler, C: Coordinate + Eq> Eq for Path<P, C> {}
 
-impl<P: PointScaler> ops::Index<usize> for Path<P> {
-    type Output = Point<P>;
+impl<P: PointScaler, C: Coordinate, I: SliceIndex<[Point<P, C>]>> ops::Index<I> for Path<P, C> {
+    type Output = I::Output;
 
-    fn index(&self, index: usize) -> &Self::Output {
+    fn index(&self, index: I) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<P: PointScaler> ops::IndexMut<usize> for Path<P> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+impl<P: PointScaler, C: Coordinate, I: SliceIndex<[Point<P, C>]>> ops::IndexMut<I> for Path<P, C> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<P: PointScaler> Path<P> {
+/// Formats the path as a parenthesized, comma separated list of points, like
+/// a WKT ring. The precision of the formatter applies to all coordinates.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let path: Path = vec![(0.0, 0.0), (1.25, 0.0), (1.25, 1.0)].into();
+///
+/// assert_eq!(path.to_string(), "(0 0, 1.25 0, 1.25 1)");
+/// assert_eq!(format!("{path:.1}"), "(0.0 0.0, 1.2 0.0, 1.2 1.0)");
+/// ```
+impl<P: PointScaler, C: Coordinate> fmt::Display for Path<P, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        for (i, point) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Display::fmt(point, f)?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> ops::Deref for Path<P, C> {
+    type Target = [Point<P, C>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> ops::DerefMut for Path<P, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> AsRef<[Point<P, C>]> for Path<P, C> {
+    fn as_ref(&self) -> &[Point<P, C>] {
+        &self.0
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> Path<P, C> {
     /// Create a new path from a vector of points.
-    pub fn new(points: Vec<Point<P>>) -> Self {
+    pub fn new(points: Vec<Point<P, C>>) -> Self {
         Path(points)
     }
 
+    /// Create a new empty path with room for at least `capacity` points.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Path(Vec::with_capacity(capacity))
+    }
+
+    /// Returns the number of points the path can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Reserve room for at least `additional` more points.
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    /// Shrink the capacity of the path as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+
     /// In place push point onto this path.
-    pub fn push(&mut self, point: impl Into<Point<P>>) {
+    pub fn push(&mut self, point: impl Into<Point<P, C>>) {
         self.0.push(point.into());
     }
 
     /// Append another path onto this one, cloning the other path.
-    pub fn append(&mut self, path: impl Into<Vec<Point<P>>>) {
+    pub fn append(&mut self, path: impl Into<Vec<Point<P, C>>>) {
         let mut points = path.into();
         self.0.append(&mut points);
     }
@@ -72,6 +141,12 @@ impl<P: PointScaler> Path<P> {
         self.0.is_empty()
     }
 
+    /// Returns an estimate of the heap memory held by the path in bytes,
+    /// based on the capacity of its point buffer.
+    pub fn memory_estimate(&self) -> usize {
+        self.0.capacity() * std::mem::size_of::<Point<P, C>>()
+    }
+
     /// Returns `true` if the path contains at least one point
     pub fn contains_points(&self) -> bool {
         self.is_empty()
@@ -89,10 +164,63 @@ impl<P: PointScaler> Path<P> {
     }
 
     /// Returns an iterator over the points in the path.
-    pub fn iter(&self) -> std::slice::Iter<'_, Point<P>> {
+    pub fn iter(&self) -> std::slice::Iter<'_, Point<P, C>> {
         self.0.iter()
     }
 
+    /// Returns an iterator that allows modifying each point in the path.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Point<P, C>> {
+        self.0.iter_mut()
+    }
+
+    /// Replace each point in the path with the result of the given function,
+    /// without reallocating the path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let mut path: Path = vec![(0.004, 0.0), (1.0, 0.996)].into();
+    /// path.map_points_in_place(|point| Point::new(point.x().round(), point.y().round()));
+    ///
+    /// assert_eq!(path, vec![(0.0, 0.0), (1.0, 1.0)].into());
+    /// ```
+    pub fn map_points_in_place(&mut self, mut f: impl FnMut(Point<P, C>) -> Point<P, C>) {
+        for point in self.0.iter_mut() {
+            *point = f(*point);
+        }
+    }
+
+    /// Convert the points to another [`Coordinate`] type, see
+    /// [`Point::cast`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// // Pixel coordinates of a display.
+    /// let pixels = Path::<One, i32>::from_iter([(0.0, 0.0), (640.0, 0.0), (640.0, 480.0)]);
+    /// let path: Path<One> = pixels.cast();
+    ///
+    /// assert_eq!(path.signed_area(), 640.0 * 480.0 / 2.0);
+    /// ```
+    pub fn cast<T: Coordinate>(&self) -> Path<P, T> {
+        self.0.iter().map(|point| point.cast()).collect()
+    }
+}
+
+impl<P: PointScaler> Path<P> {
+    /// Create a new path from integer coordinates, stored exactly without a
+    /// round-trip through `f64`, see [`Point::from_i64`].
+    pub fn from_i64<T: Into<i64>>(points: impl IntoIterator<Item = (T, T)>) -> Self {
+        points
+            .into_iter()
+            .map(|(x, y)| Point::from_i64(x, y))
+            .collect()
+    }
+
     /// Construct a clone with each point offset by a x/y distance
     pub fn translate(&self, x: f64, y: f64) -> Self {
         Self::new(
@@ -190,30 +318,20 @@ impl<P: PointScaler> Path<P> {
 
     /// Returns the bounds for this path
     pub fn bounds(&self) -> Bounds<P> {
-        let mut bounds = Bounds::minmax();
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (i64::MAX, i64::MAX, i64::MIN, i64::MIN);
 
         for p in &self.0 {
-            let x = p.x();
-            let y = p.y();
-
-            if x < bounds.min.x() {
-                bounds.min = Point::new(x, bounds.min.y());
-            }
-
-            if y < bounds.min.y() {
-                bounds.min = Point::new(bounds.min.x(), y);
-            }
-
-            if x > bounds.max.x() {
-                bounds.max = Point::new(x, bounds.max.y());
-            }
-
-            if y > bounds.max.y() {
-                bounds.max = Point::new(bounds.max.x(), y);
-            }
+            let (x, y) = (p.x_scaled(), p.y_scaled());
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
         }
 
-        bounds
+        Bounds {
+            min: Point::from_scaled(min_x, min_y),
+            max: Point::from_scaled(max_x, max_y),
+        }
     }
 
     /// Construct a paths offset from this one by a delta distance.
@@ -270,9 +388,9 @@ impl<P: PointScaler> Path<P> {
             let mem = malloc(clipper_path64_size());
             let paths_ptr = self.to_clipperpath64();
             let result_ptr = clipper_path64_simplify(mem, paths_ptr, epsilon, is_open.into());
-            clipper_delete_path64(paths_ptr);
+            delete_path64(paths_ptr);
             let result = Path::from_clipperpath64(result_ptr);
-            clipper_delete_path64(result_ptr);
+            delete_path64(result_ptr);
             result
         }
     }
@@ -349,7 +467,39 @@ impl<P: PointScaler> Path<P> {
     /// ```
     ///
     pub fn signed_area(&self) -> f64 {
-        unsafe { clipper_path64_area(self.to_clipperpath64()) / (P::MULTIPLIER * P::MULTIPLIER) }
+        unsafe {
+            let path = self.to_clipperpath64();
+            let area = clipper_path64_area(path);
+            delete_path64(path);
+            area / (P::MULTIPLIER * P::MULTIPLIER)
+        }
+    }
+
+    /// Returns `true` if the path has a positive signed area, meaning its
+    /// points run counter-clockwise with the y axis pointing up. Paths
+    /// without area count as positive.
+    ///
+    /// The orientation is computed by the native library on the scaled
+    /// integer coordinates, so it is exact even for paths so large that
+    /// their area in user units loses precision.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let path: Path = vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)].into();
+    ///
+    /// assert!(path.is_positive());
+    /// assert!(!path.iter().rev().copied().collect::<Path>().is_positive());
+    /// ```
+    pub fn is_positive(&self) -> bool {
+        unsafe {
+            let path = self.to_clipperpath64();
+            let positive = clipper_path64_is_positive(path) != 0;
+            delete_path64(path);
+            positive
+        }
     }
 
     /// Returns the closest point on the path to a given point
@@ -399,36 +549,94 @@ impl<P: PointScaler> Path<P> {
         })
     }
 
+    /// Returns the closed path in a canonical form, so that paths describing
+    /// the same polygon compare and hash equal.
+    ///
+    /// Repeated points, including a last point equal to the first one, are
+    /// removed. The points are reversed if the signed area is negative, and
+    /// rotated so the path starts at the lowest point ordered by x and then
+    /// y. Paths with zero area keep their direction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use clipper2::*;
+    ///
+    /// let a: Path = vec![(1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)].into();
+    /// let b: Path = vec![(0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0), (0.0, 1.0)].into();
+    ///
+    /// let unique: HashSet<Path> = [a, b].iter().map(Path::canonicalize).collect();
+    /// assert_eq!(unique.len(), 1);
+    /// ```
+    pub fn canonicalize(&self) -> Self {
+        let mut points = self.0.clone();
+        points.dedup();
+        while points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+
+        let mut path = Self::new(points);
+        if crate::validate::doubled_area(&path) < 0 {
+            path.0.reverse();
+        }
+
+        let keys = path
+            .iter()
+            .map(|point| (point.x_scaled(), point.y_scaled()))
+            .collect::<Vec<_>>();
+        path.0.rotate_left(least_rotation(&keys));
+
+        path
+    }
+
     pub(crate) fn from_clipperpath64(ptr: *mut ClipperPath64) -> Self {
-        let paths = unsafe {
-            let len: i32 = clipper_path64_length(ptr).try_into().unwrap();
-            (0..len)
-                .map(|i| clipper_path64_get_point(ptr, i).into())
-                .collect()
+        // `Point` is a transparent wrapper around `ClipperPoint64`, so the
+        // points can be copied straight into the vector in one call.
+        let points = unsafe {
+            let len = clipper_path64_length(ptr);
+            let mut points = Vec::<Point<P>>::with_capacity(len);
+            clipper_path64_to_points(points.as_mut_ptr().cast(), ptr);
+            points.set_len(len);
+            points
         };
-        Self::new(paths)
+        Self::new(points)
+    }
+
+    /// Convert a native double precision path, whose coordinates are in the
+    /// units of the scaler.
+    pub(crate) fn from_clipperpathd(ptr: *mut ClipperPathD) -> Self {
+        let points = unsafe {
+            let len = clipper_pathd_length(ptr);
+            let mut points = Vec::<ClipperPointD>::with_capacity(len);
+            clipper_pathd_to_points(points.as_mut_ptr().cast(), ptr);
+            points.set_len(len);
+            points
+        };
+        points
+            .into_iter()
+            .map(|point| Point::new(point.x, point.y))
+            .collect()
+    }
+
+    /// Mutable access to the point buffer, used to fill paths from the
+    /// native library without reallocating.
+    pub(crate) fn points_mut(&mut self) -> &mut Vec<Point<P>> {
+        &mut self.0
     }
 
     pub(crate) unsafe fn to_clipperpath64(&self) -> *mut ClipperPath64 {
         let mem = malloc(clipper_path64_size());
         clipper_path64_of_points(
             mem,
-            self.0
-                .iter()
-                .cloned()
-                .map(|point: Point<P>| ClipperPoint64 {
-                    x: point.x_scaled(),
-                    y: point.y_scaled(),
-                })
-                .collect::<Vec<_>>()
-                .as_mut_ptr(),
+            self.0.as_ptr().cast::<ClipperPoint64>().cast_mut(),
             self.len(),
         )
     }
 }
 
-impl<P: PointScaler> IntoIterator for Path<P> {
-    type Item = Point<P>;
+impl<P: PointScaler, C: Coordinate> IntoIterator for Path<P, C> {
+    type Item = Point<P, C>;
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
@@ -436,45 +644,100 @@ impl<P: PointScaler> IntoIterator for Path<P> {
     }
 }
 
-impl<P: PointScaler> FromIterator<Point<P>> for Path<P> {
-    fn from_iter<T: IntoIterator<Item = Point<P>>>(iter: T) -> Self {
+impl<P: PointScaler, C: Coordinate> FromIterator<Point<P, C>> for Path<P, C> {
+    fn from_iter<T: IntoIterator<Item = Point<P, C>>>(iter: T) -> Self {
         Path(iter.into_iter().collect())
     }
 }
 
-impl<P: PointScaler> From<Path<P>> for Vec<Point<P>> {
-    fn from(path: Path<P>) -> Self {
+impl<P: PointScaler, C: Coordinate> FromIterator<(f64, f64)> for Path<P, C> {
+    fn from_iter<T: IntoIterator<Item = (f64, f64)>>(iter: T) -> Self {
+        Path(iter.into_iter().map(Point::from).collect())
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> FromIterator<[f64; 2]> for Path<P, C> {
+    fn from_iter<T: IntoIterator<Item = [f64; 2]>>(iter: T) -> Self {
+        Path(iter.into_iter().map(Point::from).collect())
+    }
+}
+
+impl<'a, P: PointScaler, C: Coordinate> IntoIterator for &'a mut Path<P, C> {
+    type Item = &'a mut Point<P, C>;
+    type IntoIter = std::slice::IterMut<'a, Point<P, C>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> Extend<Point<P, C>> for Path<P, C> {
+    fn extend<T: IntoIterator<Item = Point<P, C>>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> From<Path<P, C>> for Vec<Point<P, C>> {
+    fn from(path: Path<P, C>) -> Self {
         path.0.clone()
     }
 }
 
-impl<P: PointScaler> From<Path<P>> for Vec<(f64, f64)> {
-    fn from(path: Path<P>) -> Self {
+impl<P: PointScaler, C: Coordinate> From<Path<P, C>> for Vec<(f64, f64)> {
+    fn from(path: Path<P, C>) -> Self {
         path.iter().map(|point| (point.x(), point.y())).collect()
     }
 }
 
-impl<P: PointScaler> From<Path<P>> for Vec<[f64; 2]> {
-    fn from(path: Path<P>) -> Self {
+impl<P: PointScaler, C: Coordinate> From<Path<P, C>> for Vec<[f64; 2]> {
+    fn from(path: Path<P, C>) -> Self {
         path.iter().map(|point| [point.x(), point.y()]).collect()
     }
 }
 
-impl<P: PointScaler> From<Vec<Point<P>>> for Path<P> {
-    fn from(points: Vec<Point<P>>) -> Self {
+impl<P: PointScaler, C: Coordinate> From<Vec<Point<P, C>>> for Path<P, C> {
+    fn from(points: Vec<Point<P, C>>) -> Self {
         Path::new(points)
     }
 }
 
-impl<P: PointScaler> From<Vec<(f64, f64)>> for Path<P> {
+impl<P: PointScaler, C: Coordinate> From<Vec<(f64, f64)>> for Path<P, C> {
     fn from(points: Vec<(f64, f64)>) -> Self {
-        Path::<P>::new(points.iter().map(Point::<P>::from).collect())
+        Path::<P, C>::new(points.iter().map(Point::<P, C>::from).collect())
     }
 }
 
-impl<P: PointScaler> From<Vec<[f64; 2]>> for Path<P> {
+impl<P: PointScaler, C: Coordinate> From<Vec<[f64; 2]>> for Path<P, C> {
     fn from(points: Vec<[f64; 2]>) -> Self {
-        Path::<P>::new(points.iter().map(Point::<P>::from).collect())
+        Path::<P, C>::new(points.iter().map(Point::<P, C>::from).collect())
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> From<Vec<(f32, f32)>> for Path<P, C> {
+    fn from(points: Vec<(f32, f32)>) -> Self {
+        Path::<P, C>::new(points.iter().map(Point::<P, C>::from).collect())
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> From<Vec<[f32; 2]>> for Path<P, C> {
+    fn from(points: Vec<[f32; 2]>) -> Self {
+        Path::<P, C>::new(points.iter().map(Point::<P, C>::from).collect())
+    }
+}
+
+/// Any slice of point-like values, such as `&[(f64, f64)]`, `&[[f32; 2]]` or
+/// `&[Point]`, converts into a path without first collecting it into a `Vec`.
+impl<P: PointScaler, C: Coordinate, T: Copy + Into<Point<P, C>>> From<&[T]> for Path<P, C> {
+    fn from(points: &[T]) -> Self {
+        Path::<P, C>::new(points.iter().map(|point| (*point).into()).collect())
+    }
+}
+
+impl<P: PointScaler, C: Coordinate, T: Into<Point<P, C>>, const N: usize> From<[T; N]>
+    for Path<P, C>
+{
+    fn from(points: [T; N]) -> Self {
+        Path::<P, C>::new(points.into_iter().map(Into::into).collect())
     }
 }
 
@@ -491,9 +754,34 @@ pub enum PathError {
     },
 }
 
+/// Returns the start of the lexicographically least rotation of the items,
+/// in linear time by comparing two candidate starts and skipping all starts
+/// that a mismatch rules out.
+fn least_rotation<T: Ord>(items: &[T]) -> usize {
+    let len = items.len();
+    let (mut a, mut b, mut offset) = (0, 1, 0);
+
+    while a < len && b < len && offset < len {
+        match items[(a + offset) % len].cmp(&items[(b + offset) % len]) {
+            std::cmp::Ordering::Equal => {
+                offset += 1;
+                continue;
+            }
+            std::cmp::Ordering::Greater => a += offset + 1,
+            std::cmp::Ordering::Less => b += offset + 1,
+        }
+        if a == b {
+            b += 1;
+        }
+        offset = 0;
+    }
+
+    a.min(b)
+}
+
 #[cfg(test)]
 mod test {
-    use crate::Deci;
+    use crate::{Deci, One};
 
     use super::*;
 
@@ -538,6 +826,20 @@ mod test {
         assert_eq!(output, vec![(0.0, 0.0), (1.0, 1.0)]);
     }
 
+    #[test]
+    fn test_from_slices_arrays_and_iterators() {
+        let expected = Path::<Centi>::from(vec![(0.0, 0.0), (1.5, 0.0), (1.5, 1.0)]);
+        let tuples = [(0.0, 0.0), (1.5, 0.0), (1.5, 1.0)];
+        let f32_arrays = vec![[0.0f32, 0.0], [1.5, 0.0], [1.5, 1.0]];
+
+        assert_eq!(Path::<Centi>::from(&tuples[..]), expected);
+        assert_eq!(Path::<Centi>::from(tuples), expected);
+        assert_eq!(Path::<Centi>::from(f32_arrays.as_slice()), expected);
+        assert_eq!(Path::<Centi>::from(f32_arrays), expected);
+        assert_eq!(Path::<Centi>::from(&expected[..]), expected);
+        assert_eq!(tuples.iter().copied().collect::<Path<Centi>>(), expected);
+    }
+
     #[test]
     fn test_from_custom_scaler() {
         #[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
@@ -623,7 +925,7 @@ mod test {
     fn test_serde() {
         let path = Path::<Centi>::from(vec![(0.0, 0.0), (1.0, 1.0)]);
         let serialized = serde_json::to_string(&path).unwrap();
-        assert_eq!(serialized, r#"[{"x":0,"y":0},{"x":100,"y":100}]"#);
+        assert_eq!(serialized, r#"[{"x":0.0,"y":0.0},{"x":1.0,"y":1.0}]"#);
 
         let deserialized: Path = serde_json::from_str(&serialized).unwrap();
         assert_eq!(deserialized, path);
@@ -678,4 +980,112 @@ mod test {
         path[1] = Point::new(3.0, 3.0);
         assert_eq!(path[1], Point::new(3.0, 3.0));
     }
+
+    #[test]
+    fn test_capacity() {
+        let mut path = Path::<Centi>::with_capacity(8);
+        assert!(path.is_empty());
+        assert!(path.capacity() >= 8);
+
+        path.push((1.0, 2.0));
+        path.reserve(100);
+        assert!(path.capacity() >= 101);
+
+        path.shrink_to_fit();
+        assert_eq!(path.capacity(), 1);
+        assert_eq!(path[0], Point::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let square = Path::<Centi>::rectangle(0.0, 0.0, 2.0, 2.0);
+        let expected: Path<Centi> = vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)].into();
+
+        for start in 0..square.len() {
+            let mut rotated = square.clone();
+            rotated.rotate_left(start);
+            let reversed = rotated.iter().rev().copied().collect::<Path<Centi>>();
+
+            assert_eq!(rotated.canonicalize(), expected);
+            assert_eq!(reversed.canonicalize(), expected);
+        }
+
+        // Ties on the lowest point are broken by the following points.
+        let touching: Path<Centi> = vec![
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (1.0, 1.0),
+            (0.0, 0.0),
+            (-1.0, 1.0),
+            (-2.0, 0.0),
+        ]
+        .into();
+        let canonical = touching.canonicalize();
+        for start in 0..touching.len() {
+            let mut rotated = touching.clone();
+            rotated.rotate_left(start);
+            assert_eq!(rotated.canonicalize(), canonical);
+        }
+
+        assert_eq!(Path::<Centi>::default().canonicalize(), Path::default());
+    }
+
+    #[test]
+    fn test_least_rotation() {
+        let brute_force = |items: &[u8]| {
+            (0..items.len())
+                .min_by_key(|&start| [&items[start..], &items[..start]].concat())
+                .unwrap_or(0)
+        };
+
+        for items in [
+            &[][..],
+            &[3],
+            &[2, 1, 2, 1, 0, 2, 1, 0],
+            &[1, 1, 1, 1],
+            &[1, 0, 1, 0, 1, 0],
+            &[0, 0, 1, 0, 0, 1, 0, 0, 0],
+            &[5, 4, 3, 2, 1],
+        ] {
+            let rotated = |start: usize| [&items[start..], &items[..start]].concat();
+            assert_eq!(rotated(least_rotation(items)), rotated(brute_force(items)));
+        }
+    }
+
+    #[test]
+    fn test_canonicalize_long_repeating_path() {
+        // A path going around the same square many times, where every
+        // rotation by a multiple of four matches all the way around.
+        let laps = 10_000;
+        let square = Path::<Centi>::rectangle(0.0, 0.0, 1.0, 1.0);
+        let path = (0..laps)
+            .flat_map(|_| square.iter().copied())
+            .collect::<Path<Centi>>();
+
+        let mut rotated = path.clone();
+        rotated.rotate_left(2);
+        let canonical = rotated.canonicalize();
+
+        assert_eq!(canonical, path);
+        assert_eq!(canonical.len(), 4 * laps);
+    }
+
+    #[test]
+    fn test_is_positive_and_bounds() {
+        let path = Path::<One>::from_i64([(0, 0), (i64::MAX >> 3, 0), (0, 1)]);
+        assert!(path.is_positive());
+        assert!(!path
+            .iter()
+            .rev()
+            .copied()
+            .collect::<Path<One>>()
+            .is_positive());
+        // Like the native library, paths without area count as positive.
+        assert!(Path::<Centi>::default().is_positive());
+
+        let bounds = path.bounds();
+        assert_eq!(bounds.min, Point::from_scaled(0, 0));
+        assert_eq!(bounds.max, Point::from_scaled(i64::MAX >> 3, 1));
+        assert_eq!(Path::<Centi>::default().bounds(), Bounds::minmax());
+    }
 }
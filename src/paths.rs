@@ -1,13 +1,25 @@
-use std::ops;
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    mem, ops,
+    slice::SliceIndex,
+    sync::{Arc, OnceLock},
+};
 
 use clipper2c_sys::{
-    clipper_delete_path64, clipper_delete_paths64, clipper_paths64_area, clipper_paths64_get_point,
-    clipper_paths64_length, clipper_paths64_of_paths, clipper_paths64_path_length,
-    clipper_paths64_size, ClipperPath64, ClipperPaths64,
+    clipper_path64_size, clipper_paths64_area, clipper_paths64_get_path, clipper_paths64_length,
+    clipper_paths64_of_paths, clipper_paths64_size, ClipperPath64, ClipperPaths64,
 };
 
 use crate::{
-    inflate, malloc, simplify, Bounds, Centi, Clipper, EndType, JoinType, Path, Point, PointScaler,
+    ffi::{
+        clipper_path64_is_positive, clipper_paths64_bounds, clipper_paths64_lengths,
+        clipper_paths64_to_points, ClipperRect64,
+    },
+    inflate, malloc,
+    memory::{delete_path64, delete_paths64},
+    point_in_polygon, simplify, Bounds, Centi, ClipType, Clipper, ClipperError, Coordinate,
+    EndType, FillRule, JoinType, Path, Point, PointInPolygonResult, PointScaler, PolyTree,
     WithSubjects,
 };
 
@@ -21,44 +33,744 @@ use crate::{
 /// let paths_from_single_vec: Paths = vec![(0.0, 0.0), (5.0, 0.0), (5.0, 6.0), (0.0, 6.0)].into();
 /// let paths_from_vec_of_vecs: Paths = vec![vec![(0.0, 0.0), (5.0, 0.0), (5.0, 6.0), (0.0, 6.0)]].into();
 /// ```
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+///
+/// The native representation used by the Clipper2 library is built the first
+/// time the paths are used in an operation and is kept until the paths are
+/// mutated. Clones share it, so passing clones of the same paths to many
+/// operations only converts them once.
+///
+/// Each path is closed unless it's added with [`Paths::push_open`] or marked
+/// with [`Paths::set_open`]. [`Clipper::add_subject`] adds open paths as open
+/// subjects, so lines and polygons can be kept in the same set. The flags are
+/// not serialized with the `serde` feature.
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(bound = "P: PointScaler")
+    serde(bound = "P: PointScaler, C: Coordinate", transparent)
 )]
-pub struct Paths<P: PointScaler = Centi>(Vec<Path<P>>);
+pub struct Paths<P: PointScaler = Centi, C: Coordinate = i64>(
+    Vec<Path<P, C>>,
+    #[cfg_attr(feature = "serde", serde(skip))] NativePathsCache,
+    #[cfg_attr(feature = "serde", serde(skip))] OpenFlags,
+);
+
+impl<P: PointScaler + fmt::Debug, C: Coordinate> fmt::Debug for Paths<P, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Paths").field(&self.0).finish()
+    }
+}
 
-impl<P: PointScaler> ops::Index<usize> for Paths<P> {
-    type Output = Path<P>;
+/// Formats the paths as a parenthesized, comma separated list of paths, like
+/// the rings of a WKT polygon. The precision of the formatter applies to all
+/// coordinates.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let paths: Paths = vec![
+///     vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)],
+///     vec![(0.5, 0.5), (1.0, 0.5), (1.0, 1.0)],
+/// ]
+/// .into();
+///
+/// assert_eq!(paths.to_string(), "((0 0, 2 0, 2 2), (0.5 0.5, 1 0.5, 1 1))");
+/// ```
+impl<P: PointScaler, C: Coordinate> fmt::Display for Paths<P, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        for (i, path) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Display::fmt(path, f)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// Lazily built native representation of a [`Paths`], shared between clones.
+#[derive(Default, Clone)]
+struct NativePathsCache(Arc<OnceLock<NativePaths>>);
+
+impl PartialEq for NativePathsCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NativePathsCache {}
+
+impl Hash for NativePathsCache {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Which paths of a [`Paths`] are open. Paths without an entry are closed, so
+/// sets of only closed paths don't store any flags.
+#[derive(Debug, Default, Clone)]
+struct OpenFlags(Vec<bool>);
+
+impl OpenFlags {
+    fn get(&self, index: usize) -> bool {
+        self.0.get(index).copied().unwrap_or(false)
+    }
+
+    fn set(&mut self, index: usize, open: bool) {
+        if open && self.0.len() <= index {
+            self.0.resize(index + 1, false);
+        }
+        if let Some(flag) = self.0.get_mut(index) {
+            *flag = open;
+        }
+    }
 
-    fn index(&self, index: usize) -> &Self::Output {
+    fn any(&self) -> bool {
+        self.0.iter().any(|&open| open)
+    }
+
+    /// The flags without trailing closed entries, which carry no information.
+    fn significant(&self) -> &[bool] {
+        let len = self.0.iter().rposition(|&open| open).map_or(0, |i| i + 1);
+        &self.0[..len]
+    }
+}
+
+impl PartialEq for OpenFlags {
+    fn eq(&self, other: &Self) -> bool {
+        self.significant() == other.significant()
+    }
+}
+
+impl Eq for OpenFlags {}
+
+impl Hash for OpenFlags {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.significant().hash(state);
+    }
+}
+
+struct NativePaths(*mut ClipperPaths64);
+
+// The native paths are never mutated after they have been built, so they can
+// be read from several threads at once.
+unsafe impl Send for NativePaths {}
+unsafe impl Sync for NativePaths {}
+
+impl Drop for NativePaths {
+    fn drop(&mut self) {
+        unsafe { delete_paths64(self.0) }
+    }
+}
+
+impl<P: PointScaler, C: Coordinate, I: SliceIndex<[Path<P, C>]>> ops::Index<I> for Paths<P, C> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<P: PointScaler> ops::IndexMut<usize> for Paths<P> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+impl<P: PointScaler, C: Coordinate, I: SliceIndex<[Path<P, C>]>> ops::IndexMut<I> for Paths<P, C> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        self.1 = NativePathsCache::default();
         &mut self.0[index]
     }
 }
 
-impl<P: PointScaler> Paths<P> {
+/// Read-only slice access to the paths. Mutable access goes through
+/// [`ops::IndexMut`] and the other mutating methods, which keep the cached
+/// native representation up to date.
+impl<P: PointScaler, C: Coordinate> ops::Deref for Paths<P, C> {
+    type Target = [Path<P, C>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> AsRef<[Path<P, C>]> for Paths<P, C> {
+    fn as_ref(&self) -> &[Path<P, C>] {
+        &self.0
+    }
+}
+
+/// Returns `true` if the point lies on an edge of the paths.
+fn is_on<P: PointScaler>(point: &Point<P>, paths: &Paths<P>) -> bool {
+    paths.iter().any(|path| {
+        let bounds = path.bounds();
+        point.x() >= bounds.min.x()
+            && point.x() <= bounds.max.x()
+            && point.y() >= bounds.min.y()
+            && point.y() <= bounds.max.y()
+            && point_in_polygon(*point, path) == PointInPolygonResult::IsOn
+    })
+}
+
+/// How the edges of two sets of paths meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Contact {
+    /// No edges meet.
+    None,
+    /// Edges touch or run along each other, but none cross.
+    Touching,
+    /// Two edges cross each other in a point inside both of them.
+    Crossing,
+}
+
+/// The edges of a closed path, including the one back to the first point,
+/// as scaled coordinates.
+fn closed_edges<P: PointScaler>(
+    path: &Path<P>,
+) -> impl Iterator<Item = ((i64, i64), (i64, i64))> + '_ {
+    let points = path.iter().map(|p| (p.x_scaled(), p.y_scaled()));
+    points
+        .clone()
+        .zip(points.cycle().skip(1))
+        .take(if path.len() > 1 { path.len() } else { 0 })
+}
+
+/// Returns the sign of the cross product of `b - a` and `c - a`.
+fn orientation((ax, ay): (i64, i64), (bx, by): (i64, i64), (cx, cy): (i64, i64)) -> i8 {
+    let cross = (bx as i128 - ax as i128) * (cy as i128 - ay as i128)
+        - (by as i128 - ay as i128) * (cx as i128 - ax as i128);
+    cross.signum() as i8
+}
+
+/// Returns `true` if `p`, known to be on the line through `a` and `b`, lies
+/// within the segment between them.
+fn within((ax, ay): (i64, i64), (bx, by): (i64, i64), (px, py): (i64, i64)) -> bool {
+    ax.min(bx) <= px && px <= ax.max(bx) && ay.min(by) <= py && py <= ay.max(by)
+}
+
+fn segment_contact(p: ((i64, i64), (i64, i64)), q: ((i64, i64), (i64, i64))) -> Contact {
+    let d1 = orientation(p.0, p.1, q.0);
+    let d2 = orientation(p.0, p.1, q.1);
+    let d3 = orientation(q.0, q.1, p.0);
+    let d4 = orientation(q.0, q.1, p.1);
+
+    if d1 * d2 < 0 && d3 * d4 < 0 {
+        Contact::Crossing
+    } else if (d1 == 0 && within(p.0, p.1, q.0))
+        || (d2 == 0 && within(p.0, p.1, q.1))
+        || (d3 == 0 && within(q.0, q.1, p.0))
+        || (d4 == 0 && within(q.0, q.1, p.1))
+    {
+        Contact::Touching
+    } else {
+        Contact::None
+    }
+}
+
+/// Compare the edges of two sets of paths, returning as soon as two edges
+/// cross.
+fn edge_contact<P: PointScaler>(a: &Paths<P>, b: &Paths<P>) -> Contact {
+    let mut contact = Contact::None;
+
+    for path_a in a.iter() {
+        let bounds_a = path_a.bounds();
+        for path_b in b.iter() {
+            if !bounds_a.overlaps(&path_b.bounds()) {
+                continue;
+            }
+
+            for edge_a in closed_edges(path_a) {
+                for edge_b in closed_edges(path_b) {
+                    match segment_contact(edge_a, edge_b) {
+                        Contact::Crossing => return Contact::Crossing,
+                        Contact::Touching => contact = Contact::Touching,
+                        Contact::None => {}
+                    }
+                }
+            }
+        }
+    }
+
+    contact
+}
+
+/// Returns the winding number of the paths around the point, or `None` if
+/// the point lies on an edge.
+pub(crate) fn winding_number<P: PointScaler>(point: &Point<P>, paths: &Paths<P>) -> Option<i64> {
+    let p = (point.x_scaled(), point.y_scaled());
+    let mut winding = 0;
+
+    for path in paths.iter() {
+        for (a, b) in closed_edges(path) {
+            let side = orientation(a, b, p);
+            if side == 0 && within(a, b, p) {
+                return None;
+            }
+            if a.1 <= p.1 && b.1 > p.1 && side > 0 {
+                winding += 1;
+            } else if b.1 <= p.1 && a.1 > p.1 && side < 0 {
+                winding -= 1;
+            }
+        }
+    }
+
+    Some(winding)
+}
+
+impl<P: PointScaler, C: Coordinate> Paths<P, C> {
     /// Create a new paths from a vector of paths.
-    pub fn new(paths: Vec<Path<P>>) -> Self {
-        Paths(paths)
+    pub fn new(paths: Vec<Path<P, C>>) -> Self {
+        Paths(paths, NativePathsCache::default(), OpenFlags::default())
+    }
+
+    /// Returns `true` if the path at `index` is open.
+    pub fn is_open(&self, index: usize) -> bool {
+        self.2.get(index)
+    }
+
+    /// Mark the path at `index` as open or closed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set_open(&mut self, index: usize, open: bool) {
+        assert!(index < self.0.len(), "path index {index} out of bounds");
+        self.2.set(index, open);
     }
 
-    /// In place push paths onto this set of paths.
+    /// Mark all paths as open, such as the open paths returned by the
+    /// native library.
+    pub(crate) fn set_all_open(&mut self) {
+        self.2 = OpenFlags(vec![true; self.0.len()]);
+    }
+
+    /// Returns `true` if at least one of the paths is open.
+    pub fn has_open_paths(&self) -> bool {
+        self.2.any()
+    }
+
+    /// Convert the points to another [`Coordinate`] type, see
+    /// [`Point::cast`]. The open flags are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let native: Paths<Milli, f64> = vec![vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]].into();
+    /// let paths: Paths<Milli> = native.cast();
+    ///
+    /// assert_eq!(paths.signed_area(), 0.5);
+    /// ```
+    pub fn cast<T: Coordinate>(&self) -> Paths<P, T> {
+        Paths(
+            self.0.iter().map(Path::cast).collect(),
+            NativePathsCache::default(),
+            self.2.clone(),
+        )
+    }
+}
+
+impl<P: PointScaler> Paths<P> {
+    /// Create new paths from integer coordinates, stored exactly without a
+    /// round-trip through `f64`, see [`Point::from_i64`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths = Paths::<One>::from_i64(vec![vec![(0, 0), (10, 0), (10, 10), (0, 10)]]);
+    /// let shifted = Paths::<One>::from_i64(vec![vec![(5, 0), (15, 0), (15, 10), (5, 10)]]);
+    ///
+    /// let union = paths.union_with(&shifted, FillRule::NonZero).unwrap();
+    /// assert_eq!(union.signed_area(), 150.0);
+    /// ```
+    pub fn from_i64<T: Into<i64>>(
+        paths: impl IntoIterator<Item = impl IntoIterator<Item = (T, T)>>,
+    ) -> Self {
+        Self::new(paths.into_iter().map(Path::from_i64).collect())
+    }
+
+    /// Create a new empty set of paths with room for at least `capacity`
+    /// paths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let mut paths = Paths::<Centi>::with_capacity(10);
+    ///
+    /// for i in 0..10 {
+    ///     let mut path = Path::with_capacity(4);
+    ///     path.push((i as f64, 0.0));
+    ///     path.push((i as f64 + 1.0, 0.0));
+    ///     path.push((i as f64 + 1.0, 1.0));
+    ///     path.push((i as f64, 1.0));
+    ///     paths.push(path);
+    /// }
+    ///
+    /// assert_eq!(paths.len(), 10);
+    /// assert!(paths.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::new(Vec::with_capacity(capacity))
+    }
+
+    /// Returns the number of paths the set can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Reserve room for at least `additional` more paths.
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    /// Shrink the capacity of the set of paths, and of each path in it, as
+    /// much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+        for path in &mut self.0 {
+            path.shrink_to_fit();
+        }
+    }
+
+    /// In place push paths onto this set of paths, keeping their open flags.
     pub fn push(&mut self, paths: impl Into<Paths<P>>) {
-        for path in paths.into() {
+        let Paths(paths, _, open) = paths.into();
+        let offset = self.0.len();
+
+        self.1 = NativePathsCache::default();
+        for (index, path) in paths.into_iter().enumerate() {
             self.0.push(path);
+            self.2.set(offset + index, open.get(index));
         }
     }
 
+    /// In place push a closed path onto this set of paths.
+    pub fn push_closed(&mut self, path: impl Into<Path<P>>) {
+        self.1 = NativePathsCache::default();
+        self.0.push(path.into());
+    }
+
+    /// In place push an open path onto this set of paths. Open paths are added
+    /// as open subjects by [`Clipper::add_subject`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let mut paths = Paths::<Centi>::default();
+    /// paths.push_closed(Path::rectangle(0.0, 0.0, 4.0, 4.0));
+    /// paths.push_open(vec![(-1.0, 2.0), (5.0, 2.0)]);
+    ///
+    /// let result = Clipper::new()
+    ///     .add_subject(paths)
+    ///     .add_clip(Path::rectangle(2.0, -1.0, 4.0, 6.0))
+    ///     .intersect(FillRule::default())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(result.closed.signed_area(), 8.0);
+    /// assert_eq!(result.open.len(), 1);
+    /// ```
+    pub fn push_open(&mut self, path: impl Into<Path<P>>) {
+        self.push_closed(path);
+        self.2.set(self.0.len() - 1, true);
+    }
+
+    /// Split the set into its closed and its open paths, both without open
+    /// flags.
+    pub fn partition_open(&self) -> (Paths<P>, Paths<P>) {
+        let (open, closed): (Vec<_>, Vec<_>) = self
+            .0
+            .iter()
+            .enumerate()
+            .partition(|(index, _)| self.2.get(*index));
+
+        (
+            closed.into_iter().map(|(_, path)| path.clone()).collect(),
+            open.into_iter().map(|(_, path)| path.clone()).collect(),
+        )
+    }
+
+    /// Close the open paths whose last point lies within `max_gap` of the
+    /// first one, and return the indices of the closed paths.
+    ///
+    /// Outlines imported from DXF or SVG files are often open by a tiny gap,
+    /// which makes boolean operations and offsets treat them as lines
+    /// without any area. With `bridge` set, all points are kept and the
+    /// closing edge of the path bridges the gap. Otherwise the last point is
+    /// snapped onto the first one by removing it. A last point equal to the
+    /// first one is removed in either case.
+    ///
+    /// Open paths that would have fewer than three points when closed are
+    /// kept open.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let mut paths = Paths::<Centi>::default();
+    /// paths.push_open(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0), (0.0, 0.02)]);
+    /// paths.push_open(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0)]);
+    ///
+    /// let closed = paths.close_with_tolerance(0.05, false);
+    ///
+    /// assert_eq!(closed, vec![0]);
+    /// assert!(!paths.is_open(0));
+    /// assert_eq!(paths[0].len(), 4);
+    /// assert!(paths.is_open(1));
+    /// ```
+    pub fn close_with_tolerance(&mut self, max_gap: f64, bridge: bool) -> Vec<usize> {
+        let mut closed = Vec::new();
+
+        for index in 0..self.0.len() {
+            if !self.2.get(index) {
+                continue;
+            }
+
+            let path = &self.0[index];
+            let (Some(first), Some(last)) = (path.first(), path.last()) else {
+                continue;
+            };
+            if first.distance_to(last) > max_gap {
+                continue;
+            }
+
+            let snap = first == last || !bridge;
+            let remaining = if snap { path.len() - 1 } else { path.len() };
+            if remaining < 3 {
+                continue;
+            }
+
+            if snap {
+                self.1 = NativePathsCache::default();
+                self.0[index] = path[..remaining].iter().copied().collect();
+            }
+            self.2.set(index, false);
+            closed.push(index);
+        }
+
+        closed
+    }
+
+    /// Keep only the paths for which the function returns `true`, keeping the
+    /// open flags of the remaining paths.
+    pub fn retain(&mut self, mut f: impl FnMut(&Path<P>) -> bool) {
+        let mut open = OpenFlags::default();
+        let mut kept = 0;
+
+        self.1 = NativePathsCache::default();
+        for index in 0..self.0.len() {
+            if f(&self.0[index]) {
+                self.0.swap(kept, index);
+                open.set(kept, self.2.get(index));
+                kept += 1;
+            }
+        }
+        self.0.truncate(kept);
+        self.2 = open;
+    }
+
+    /// Remove the closed paths with an absolute area below the threshold, such
+    /// as the sliver artifacts left along shared edges by boolean operations.
+    /// Open paths are kept.
+    ///
+    /// Outer paths and holes are both compared by their absolute area. A hole
+    /// is never larger than the outer path surrounding it, so holes of
+    /// removed outer paths are removed too, while small holes in large outer
+    /// paths are filled in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let mut paths: Paths = vec![
+    ///     Path::rectangle(0.0, 0.0, 10.0, 10.0),
+    ///     Path::rectangle(20.0, 0.0, 0.1, 10.0),
+    /// ]
+    /// .into();
+    /// paths.retain_by_area(2.0);
+    ///
+    /// assert_eq!(paths.len(), 1);
+    /// assert_eq!(paths.signed_area(), 100.0);
+    /// ```
+    pub fn retain_by_area(&mut self, threshold: f64) {
+        let mut index = 0;
+        let open = self.2.clone();
+
+        self.retain(|path| {
+            let keep = open.get(index) || path.signed_area().abs() >= threshold;
+            index += 1;
+            keep
+        });
+    }
+
+    /// Returns the paths sorted by absolute area, largest first, keeping
+    /// their open flags. Open paths have no area and come last, and paths
+    /// with equal areas keep their order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![
+    ///     Path::rectangle(0.0, 0.0, 1.0, 1.0),
+    ///     Path::rectangle(0.0, 0.0, 3.0, 3.0),
+    ///     Path::rectangle(0.0, 0.0, 2.0, 2.0),
+    /// ]
+    /// .into();
+    ///
+    /// let areas = paths
+    ///     .sorted_by_area()
+    ///     .iter()
+    ///     .map(|path| path.signed_area())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(areas, vec![9.0, 4.0, 1.0]);
+    /// ```
+    pub fn sorted_by_area(&self) -> Self {
+        let areas = self.areas();
+        let mut order = (0..self.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| areas[b].total_cmp(&areas[a]));
+
+        self.reordered(order)
+    }
+
+    /// Returns the paths sorted by their number of points, most points first,
+    /// keeping their open flags. Paths with equal numbers of points keep
+    /// their order.
+    pub fn sorted_by_vertex_count(&self) -> Self {
+        let mut order = (0..self.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&index| std::cmp::Reverse(self.0[index].len()));
+
+        self.reordered(order)
+    }
+
+    /// Returns the paths in a deterministic order that only depends on the
+    /// paths themselves, keeping their open flags.
+    ///
+    /// The paths are ordered by their bounds, left then top then right then
+    /// bottom, then by signed area, then by their points, and closed paths
+    /// come before open paths that are otherwise equal. Sorting the results
+    /// of boolean operations this way makes them independent of the order
+    /// the engine emits paths in, for snapshot tests and caches keyed by the
+    /// serialized output. The points of each path are kept as they are, see
+    /// [`Path::canonicalize`] to also normalize start points and direction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let a = Path::rectangle(0.0, 0.0, 1.0, 1.0);
+    /// let b = Path::rectangle(5.0, 0.0, 1.0, 1.0);
+    ///
+    /// let ab: Paths = vec![a.clone(), b.clone()].into();
+    /// let ba: Paths = vec![b, a].into();
+    ///
+    /// assert_ne!(ab, ba);
+    /// assert_eq!(ab.canonical_order(), ba.canonical_order());
+    /// ```
+    pub fn canonical_order(&self) -> Self {
+        let keys = self
+            .0
+            .iter()
+            .map(|path| {
+                let bounds = path.bounds();
+                (
+                    [
+                        bounds.min.x_scaled(),
+                        bounds.min.y_scaled(),
+                        bounds.max.x_scaled(),
+                        bounds.max.y_scaled(),
+                    ],
+                    crate::validate::doubled_area(path),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut order = (0..self.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| {
+            let points = |index: usize| {
+                self.0[index]
+                    .iter()
+                    .map(|point| (point.x_scaled(), point.y_scaled()))
+            };
+            keys[a]
+                .cmp(&keys[b])
+                .then_with(|| points(a).cmp(points(b)))
+                .then_with(|| self.is_open(a).cmp(&self.is_open(b)))
+        });
+
+        self.reordered(order)
+    }
+
+    /// Returns the `n` closed paths with the largest absolute area, largest
+    /// first, such as the biggest outlines of a union result.
+    ///
+    /// Holes are compared by their absolute area like outer paths, so use
+    /// [`Paths::make_valid_tree`] first to pick outer paths with their holes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![
+    ///     Path::rectangle(0.0, 0.0, 1.0, 1.0),
+    ///     Path::rectangle(0.0, 0.0, 3.0, 3.0),
+    ///     Path::rectangle(0.0, 0.0, 2.0, 2.0),
+    /// ]
+    /// .into();
+    ///
+    /// assert_eq!(paths.largest(1).signed_area(), 9.0);
+    /// assert_eq!(paths.largest(5).len(), 3);
+    /// ```
+    pub fn largest(&self, n: usize) -> Self {
+        let areas = self.areas();
+        let mut order = (0..self.len())
+            .filter(|&index| !self.is_open(index))
+            .collect::<Vec<_>>();
+        order.sort_by(|&a, &b| areas[b].total_cmp(&areas[a]));
+        order.truncate(n);
+
+        self.reordered(order)
+    }
+
+    /// Absolute areas of the paths, zero for open paths.
+    fn areas(&self) -> Vec<f64> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(index, path)| {
+                if self.is_open(index) {
+                    0.0
+                } else {
+                    path.signed_area().abs()
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the paths at the given indices in order, with their open flags.
+    fn reordered(&self, order: impl IntoIterator<Item = usize>) -> Self {
+        let mut paths = Self::default();
+        for index in order {
+            if self.is_open(index) {
+                paths.push_open(self.0[index].clone());
+            } else {
+                paths.push_closed(self.0[index].clone());
+            }
+        }
+        paths
+    }
+
     /// Append another set of paths onto this one, cloning the other set.
     pub fn append(&mut self, paths: impl Into<Vec<Path<P>>>) {
         let mut paths = paths.into();
+        self.1 = NativePathsCache::default();
         self.0.append(&mut paths);
     }
 
@@ -72,6 +784,46 @@ impl<P: PointScaler> Paths<P> {
         self.0.is_empty()
     }
 
+    /// Returns the total number of points of all paths.
+    pub fn vertex_count(&self) -> usize {
+        self.0.iter().map(Path::len).sum()
+    }
+
+    /// Returns an estimate of the heap memory held by the paths in bytes,
+    /// for enforcing limits and reporting metrics.
+    ///
+    /// The estimate covers the capacity of the path and point buffers and,
+    /// once built, the cached native representation used by the boolean
+    /// operations, which clones of the paths share.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = Path::rectangle(0.0, 0.0, 1.0, 1.0).into();
+    /// let before = paths.memory_estimate();
+    ///
+    /// let _ = paths.union_with(&Paths::default(), FillRule::default());
+    /// assert!(paths.memory_estimate() > before);
+    /// assert_eq!(paths.vertex_count(), 4);
+    /// ```
+    pub fn memory_estimate(&self) -> usize {
+        let paths = self.0.capacity() * mem::size_of::<Path<P>>()
+            + self.0.iter().map(Path::memory_estimate).sum::<usize>()
+            + self.2 .0.capacity() * mem::size_of::<bool>();
+
+        // The native paths are a vector of vectors of 64 bit points.
+        let native = if self.1 .0.get().is_some() {
+            self.0.len() * 3 * mem::size_of::<usize>()
+                + self.vertex_count() * 2 * mem::size_of::<i64>()
+        } else {
+            0
+        };
+
+        paths + native
+    }
+
     /// Returns `true` if at least one of the paths contains a point.
     pub fn contains_points(&self) -> bool {
         for path in &self.0 {
@@ -100,6 +852,37 @@ impl<P: PointScaler> Paths<P> {
         self.0.iter()
     }
 
+    /// Returns an iterator that allows modifying each path in the paths.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Path<P>> {
+        self.1 = NativePathsCache::default();
+        self.0.iter_mut()
+    }
+
+    /// Returns an iterator that allows modifying each point of all paths in
+    /// the paths.
+    pub fn iter_points_mut(&mut self) -> impl Iterator<Item = &mut Point<P>> + '_ {
+        self.iter_mut().flat_map(|path| path.iter_mut())
+    }
+
+    /// Replace each point of all paths in the paths with the result of the
+    /// given function, without reallocating the paths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let mut paths: Paths = vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)].into();
+    /// paths.map_points_in_place(|point| point + Point::new(2.0, 0.0));
+    ///
+    /// assert_eq!(paths, vec![(2.0, 0.0), (3.0, 0.0), (3.0, 1.0)].into());
+    /// ```
+    pub fn map_points_in_place(&mut self, mut f: impl FnMut(Point<P>) -> Point<P>) {
+        for path in self.iter_mut() {
+            path.map_points_in_place(&mut f);
+        }
+    }
+
     /// Construct a clone with each point offset by a x/y distance.
     pub fn translate(&self, x: f64, y: f64) -> Self {
         Self::new(self.0.iter().map(|p| p.translate(x, y)).collect())
@@ -137,35 +920,164 @@ impl<P: PointScaler> Paths<P> {
         Self::new(self.0.iter().map(|p| p.flip_y()).collect())
     }
 
-    /// Returns the bounds for this path.
-    pub fn bounds(&self) -> Bounds<P> {
-        let mut bounds = Bounds::minmax();
+    /// Construct a clone mirrored across the horizontal line at `axis_y`,
+    /// keeping the winding semantics of the paths.
+    ///
+    /// Mirroring reverses the orientation of a path, so the points of closed
+    /// paths are also reversed to keep outer paths with a positive signed
+    /// area and holes with a negative one. Open paths keep their point order
+    /// and flags.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = Path::rectangle(0.0, 0.0, 2.0, 1.0).into();
+    /// let flipped = paths.flip_y_around(5.0);
+    ///
+    /// assert_eq!(flipped.bounds().min, Point::new(0.0, 9.0));
+    /// assert_eq!(flipped.signed_area(), paths.signed_area());
+    /// ```
+    pub fn flip_y_around(&self, axis_y: f64) -> Self {
+        self.mirrored(|point| Point::new(point.x(), 2.0 * axis_y - point.y()))
+    }
+
+    /// Construct a clone in the screen space of a viewport, with the top left
+    /// corner of the viewport at the origin and y growing downward, as used
+    /// by most renderers.
+    ///
+    /// The viewport is given in the y up space of the paths, so its top left
+    /// corner is at its minimum x and maximum y. Like
+    /// [`Paths::flip_y_around`], closed paths are reversed to keep their
+    /// signed areas, so fill rules and hole detection give the same result in
+    /// both spaces. [`Paths::from_screen_space`] converts back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let viewport = Bounds::<Centi>::new(100.0, 50.0);
+    /// let paths: Paths = Path::rectangle(10.0, 40.0, 5.0, 5.0).into();
+    ///
+    /// let screen = paths.to_screen_space(&viewport);
+    ///
+    /// assert_eq!(screen.bounds().min, Point::new(10.0, 5.0));
+    /// assert_eq!(screen.from_screen_space(&viewport), paths);
+    /// ```
+    pub fn to_screen_space(&self, viewport: &Bounds<P>) -> Self {
+        let (left, top) = (viewport.min.x(), viewport.max.y());
+        self.mirrored(|point| Point::new(point.x() - left, top - point.y()))
+    }
 
-        for p in &self.0 {
-            let b = p.bounds();
-            let min_x = b.min.x();
-            let min_y = b.min.y();
-            let max_x = b.max.x();
-            let max_y = b.max.y();
+    /// Construct a clone converted back from the screen space of a viewport,
+    /// see [`Paths::to_screen_space`].
+    pub fn from_screen_space(&self, viewport: &Bounds<P>) -> Self {
+        let (left, top) = (viewport.min.x(), viewport.max.y());
+        self.mirrored(|point| Point::new(point.x() + left, top - point.y()))
+    }
 
-            if min_x < bounds.min.x() {
-                bounds.min = Point::new(min_x, bounds.min.y());
-            }
+    /// Map the points with a mirroring function, reversing closed paths to
+    /// keep their orientation.
+    fn mirrored(&self, f: impl Fn(Point<P>) -> Point<P>) -> Self {
+        let mut paths = self.clone();
+        let open = self.2.clone();
 
-            if min_y < bounds.min.y() {
-                bounds.min = Point::new(bounds.min.x(), min_y);
+        for (index, path) in paths.iter_mut().enumerate() {
+            path.map_points_in_place(&f);
+            if !open.get(index) {
+                path.reverse();
             }
+        }
 
-            if max_x > bounds.max.x() {
-                bounds.max = Point::new(max_x, bounds.max.y());
-            }
+        paths
+    }
 
-            if max_y > bounds.max.y() {
-                bounds.max = Point::new(bounds.max.x(), max_y);
-            }
+    /// Returns the bounds for this path.
+    ///
+    /// The bounds are computed by the native library when the native
+    /// representation of the paths has already been built, see
+    /// [`Paths::prepare_native`].
+    pub fn bounds(&self) -> Bounds<P> {
+        if let Some(native) = self.cached_clipperpaths64() {
+            let mut rect = ClipperRect64::default();
+            unsafe { clipper_paths64_bounds((&mut rect as *mut ClipperRect64).cast(), native) };
+            return Bounds {
+                min: Point::from_scaled(rect.left, rect.top),
+                max: Point::from_scaled(rect.right, rect.bottom),
+            };
+        }
+
+        self.0
+            .iter()
+            .map(Path::bounds)
+            .fold(Bounds::minmax(), |bounds, path| Bounds {
+                min: Point::from_scaled(
+                    bounds.min.x_scaled().min(path.min.x_scaled()),
+                    bounds.min.y_scaled().min(path.min.y_scaled()),
+                ),
+                max: Point::from_scaled(
+                    bounds.max.x_scaled().max(path.max.x_scaled()),
+                    bounds.max.y_scaled().max(path.max.y_scaled()),
+                ),
+            })
+    }
+
+    /// Returns `true` if the path at `index` has a positive signed area, see
+    /// [`Path::is_positive`].
+    ///
+    /// When the native representation of the paths has already been built,
+    /// see [`Paths::prepare_native`], the path is read from it instead of
+    /// converting the points again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![
+    ///     vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)],
+    ///     vec![(1.0, 1.0), (1.0, 3.0), (3.0, 3.0), (3.0, 1.0)],
+    /// ]
+    /// .into();
+    /// paths.prepare_native();
+    ///
+    /// assert!(paths.is_positive(0));
+    /// assert!(!paths.is_positive(1));
+    /// ```
+    pub fn is_positive(&self, index: usize) -> bool {
+        assert!(index < self.0.len(), "path index {index} out of bounds");
+
+        match self.cached_clipperpaths64() {
+            Some(native) => unsafe {
+                let path = clipper_paths64_get_path(
+                    malloc(clipper_path64_size()),
+                    native,
+                    index as libc::c_int,
+                );
+                let positive = clipper_path64_is_positive(path) != 0;
+                delete_path64(path);
+                positive
+            },
+            None => self.0[index].is_positive(),
         }
+    }
 
-        bounds
+    /// Build the native representation of the paths now, instead of on the
+    /// first operation using them.
+    ///
+    /// The native representation is kept until the paths are mutated and
+    /// lets [`Paths::signed_area`], [`Paths::bounds`] and
+    /// [`Paths::is_positive`] ask the native library directly. For very
+    /// large paths that are queried and clipped repeatedly, this converts
+    /// the points once up front.
+    pub fn prepare_native(&self) {
+        self.as_clipperpaths64();
     }
 
     /// Construct a new set of paths offset from this one by a delta distance.
@@ -229,8 +1141,7 @@ impl<P: PointScaler> Paths<P> {
     /// let result = path.to_clipper_subject().add_clip(path2).union(FillRule::default());
     /// ```
     pub fn to_clipper_subject(&self) -> Clipper<WithSubjects, P> {
-        let clipper = Clipper::new();
-        clipper.add_subject(self.clone())
+        Clipper::new().add_subject_ref(self)
     }
 
     /// Create a [`Clipper`] builder with this set of paths as the open subject
@@ -250,6 +1161,420 @@ impl<P: PointScaler> Paths<P> {
         clipper.add_open_subject(self.clone())
     }
 
+    /// Join this set of paths with another one and return the closed paths
+    /// of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let a: Paths = Path::rectangle(0.0, 0.0, 2.0, 2.0).into();
+    /// let b: Paths = Path::rectangle(1.0, 0.0, 2.0, 2.0).into();
+    ///
+    /// assert_eq!(a.union_with(&b, FillRule::default()).unwrap().signed_area(), 6.0);
+    /// assert_eq!(a.difference_with(&b, FillRule::default()).unwrap().signed_area(), 2.0);
+    /// assert_eq!(a.intersect_with(&b, FillRule::default()).unwrap().signed_area(), 2.0);
+    /// assert_eq!(a.xor_with(&b, FillRule::default()).unwrap().signed_area(), 4.0);
+    /// ```
+    pub fn union_with(&self, other: &Paths<P>, fill_rule: FillRule) -> Result<Self, ClipperError> {
+        self.boolean_operation_with(other, ClipType::Union, fill_rule)
+    }
+
+    /// Subtract another set of paths from this one and return the closed
+    /// paths of the result, see [`Paths::union_with`].
+    pub fn difference_with(
+        &self,
+        other: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<Self, ClipperError> {
+        self.boolean_operation_with(other, ClipType::Difference, fill_rule)
+    }
+
+    /// Intersect this set of paths with another one and return the closed
+    /// paths of the result, see [`Paths::union_with`].
+    pub fn intersect_with(
+        &self,
+        other: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<Self, ClipperError> {
+        self.boolean_operation_with(other, ClipType::Intersection, fill_rule)
+    }
+
+    /// Xor this set of paths with another one and return the closed paths of
+    /// the result, see [`Paths::union_with`].
+    pub fn xor_with(&self, other: &Paths<P>, fill_rule: FillRule) -> Result<Self, ClipperError> {
+        self.boolean_operation_with(other, ClipType::Xor, fill_rule)
+    }
+
+    /// Returns the total area covered by exactly one of this set of paths
+    /// and another one, without keeping the paths of the xor.
+    ///
+    /// This is zero for equal shapes and grows with how much they differ, so
+    /// it's suitable as a regression metric for geometry changes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let a: Paths = Path::rectangle(0.0, 0.0, 2.0, 2.0).into();
+    /// let b: Paths = Path::rectangle(1.0, 0.0, 2.0, 2.0).into();
+    ///
+    /// assert_eq!(a.symmetric_difference_area(&b, FillRule::default()).unwrap(), 4.0);
+    /// assert_eq!(a.symmetric_difference_area(&a, FillRule::default()).unwrap(), 0.0);
+    /// ```
+    pub fn symmetric_difference_area(
+        &self,
+        other: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<f64, ClipperError> {
+        Ok(self.xor_with(other, fill_rule)?.signed_area().abs())
+    }
+
+    /// Returns `true` if the area covered by another set of paths lies
+    /// entirely inside the area covered by this one, as for nesting
+    /// feasibility tests. The other paths may touch the boundary of this
+    /// set, see [`Paths::contains_paths_strictly`] otherwise.
+    ///
+    /// Both sets are treated as closed paths filled with the fill rule. Sets
+    /// whose bounds don't fit are rejected without running the boolean
+    /// difference, and an empty set is contained in any set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let sheet: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+    /// let part: Paths = Path::rectangle(0.0, 2.0, 3.0, 3.0).into();
+    ///
+    /// assert!(sheet.contains_paths(&part, FillRule::default()).unwrap());
+    /// assert!(!sheet.contains_paths_strictly(&part, FillRule::default()).unwrap());
+    /// assert!(!part.contains_paths(&sheet, FillRule::default()).unwrap());
+    /// ```
+    pub fn contains_paths(
+        &self,
+        other: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<bool, ClipperError> {
+        if other.vertex_count() == 0 {
+            return Ok(true);
+        }
+
+        let (outer, inner) = (self.bounds(), other.bounds());
+        if inner.min.x() < outer.min.x()
+            || inner.min.y() < outer.min.y()
+            || inner.max.x() > outer.max.x()
+            || inner.max.y() > outer.max.y()
+        {
+            return Ok(false);
+        }
+
+        Ok(other.difference_with(self, fill_rule)?.is_empty())
+    }
+
+    /// Returns `true` if the area covered by another set of paths lies
+    /// entirely inside the area covered by this one without touching its
+    /// boundary, see [`Paths::contains_paths`].
+    ///
+    /// Edges of this set inside its own area, such as those of overlapping
+    /// paths joined with [`FillRule::NonZero`], don't count as boundary.
+    pub fn contains_paths_strictly(
+        &self,
+        other: &Paths<P>,
+        fill_rule: FillRule,
+    ) -> Result<bool, ClipperError> {
+        if !self.contains_paths(other, fill_rule)? {
+            return Ok(false);
+        }
+
+        let boundary = self.make_valid(fill_rule)?;
+
+        Ok(!other.iter().flatten().any(|point| is_on(point, &boundary))
+            && !boundary.iter().flatten().any(|point| is_on(point, other)))
+    }
+
+    /// Returns `true` if the areas covered by this and another set of paths
+    /// overlap, as for collision queries. Paths that only touch don't
+    /// overlap.
+    ///
+    /// Unlike checking the result of [`Paths::intersect_with`], this returns
+    /// as soon as an overlap is found, without computing its geometry. Sets
+    /// with disjoint bounds are rejected first, then edges crossing each
+    /// other or a vertex inside the other set settle the answer. Only when
+    /// edges touch or run along each other without crossing, or for the
+    /// [`FillRule::Positive`] and [`FillRule::Negative`] fill rules, the
+    /// intersection is computed to decide.
+    ///
+    /// All paths are treated as closed. The edges are compared pairwise,
+    /// skipping pairs of paths with disjoint bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let a: Paths = Path::rectangle(0.0, 0.0, 2.0, 2.0).into();
+    /// let b: Paths = Path::rectangle(1.0, 1.0, 2.0, 2.0).into();
+    /// let c: Paths = Path::rectangle(2.0, 0.0, 2.0, 2.0).into();
+    ///
+    /// assert!(a.intersects(&b, FillRule::default()).unwrap());
+    /// assert!(!a.intersects(&c, FillRule::default()).unwrap());
+    /// ```
+    pub fn intersects(&self, other: &Paths<P>, fill_rule: FillRule) -> Result<bool, ClipperError> {
+        let (a, b) = (self.bounds(), other.bounds());
+        if self.vertex_count() == 0 || other.vertex_count() == 0 || !a.overlaps(&b) {
+            return Ok(false);
+        }
+
+        let edges_decide = matches!(fill_rule, FillRule::EvenOdd | FillRule::NonZero);
+        if edges_decide {
+            match edge_contact(self, other) {
+                Contact::Crossing => return Ok(true),
+                Contact::None => {
+                    let inside = |points: &Paths<P>, paths: &Paths<P>| {
+                        points.iter().flatten().any(|point| {
+                            winding_number(point, paths).is_some_and(|w| fill_rule.is_filled(w))
+                        })
+                    };
+                    return Ok(inside(other, self) || inside(self, other));
+                }
+                Contact::Touching => {}
+            }
+        }
+
+        Ok(!self.intersect_with(other, fill_rule)?.is_empty())
+    }
+
+    /// Returns the Hausdorff distance to another set of paths, the furthest
+    /// any vertex of one set is from the edges of the other set.
+    ///
+    /// Only the vertices are measured against the edges, so the distance of
+    /// points in the middle of long edges is missed, see
+    /// [`Paths::hausdorff_distance_resampled`] for a closer result. This
+    /// complements [`Paths::symmetric_difference_area`] for comparing thin
+    /// features, such as the result of simplification. Closed paths include
+    /// the edge from the last point back to the first one.
+    ///
+    /// Returns zero if both sets are empty, and infinity if only one is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let a: Paths = Path::rectangle(0.0, 0.0, 4.0, 2.0).into();
+    /// let b: Paths = Path::rectangle(0.0, 0.0, 4.0, 3.0).into();
+    ///
+    /// assert_eq!(a.hausdorff_distance(&b), 1.0);
+    /// assert_eq!(a.hausdorff_distance(&a), 0.0);
+    /// ```
+    pub fn hausdorff_distance(&self, other: &Paths<P>) -> f64 {
+        self.hausdorff_distance_resampled(other, f64::INFINITY)
+    }
+
+    /// Returns the Hausdorff distance to another set of paths like
+    /// [`Paths::hausdorff_distance`], after adding points along the edges so
+    /// that they are at most `spacing` apart.
+    ///
+    /// The result is at most `spacing / 2` less than the exact Hausdorff
+    /// distance between the edges. A `spacing` that is not a positive number
+    /// measures the vertices only.
+    pub fn hausdorff_distance_resampled(&self, other: &Paths<P>, spacing: f64) -> f64 {
+        match (self.contains_points(), other.contains_points()) {
+            (false, false) => 0.0,
+            (true, true) => self
+                .directed_hausdorff_distance(other, spacing)
+                .max(other.directed_hausdorff_distance(self, spacing)),
+            _ => f64::INFINITY,
+        }
+    }
+
+    fn directed_hausdorff_distance(&self, other: &Paths<P>, spacing: f64) -> f64 {
+        let distance_to_other = |point: &Point<P>| {
+            other
+                .segments()
+                .map(|(start, end)| point.distance_to_segment(&start, &end))
+                .fold(f64::INFINITY, f64::min)
+        };
+
+        let mut furthest = 0.0f64;
+        for (start, end) in self.segments() {
+            let steps = if spacing > 0.0 {
+                (start.distance_to(&end) / spacing).ceil().max(1.0) as usize
+            } else {
+                1
+            };
+
+            for step in 0..steps {
+                let t = step as f64 / steps as f64;
+                let point = start + (end - start) * t;
+                furthest = furthest.max(distance_to_other(&point));
+            }
+            furthest = furthest.max(distance_to_other(&end));
+        }
+
+        furthest
+    }
+
+    /// Iterate over the edges of all paths, including the closing edge of
+    /// closed paths, and a zero length edge for paths with a single point.
+    fn segments(&self) -> impl Iterator<Item = (Point<P>, Point<P>)> + '_ {
+        self.iter().enumerate().flat_map(|(index, path)| {
+            let closing = match path.len() {
+                1 => Some((path[0], path[0])),
+                len if len > 2 && !self.is_open(index) => Some((path[len - 1], path[0])),
+                _ => None,
+            };
+
+            path.windows(2)
+                .map(|pair| (pair[0], pair[1]))
+                .chain(closing)
+        })
+    }
+
+    /// Repair self-intersections and hole orientations by joining the paths
+    /// with themselves, and return the closed paths of the result.
+    ///
+    /// The fill rule decides which regions of the input are filled. Crossing
+    /// edges are split at their intersections, overlapping paths are merged,
+    /// and the result has outer paths with a positive signed area and holes
+    /// with a negative signed area. Open paths are left out of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let bow_tie: Paths = vec![(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)].into();
+    /// let valid = bow_tie.make_valid(FillRule::NonZero).unwrap();
+    ///
+    /// assert_eq!(valid.len(), 2);
+    /// assert_eq!(valid.signed_area(), 2.0);
+    /// assert!(valid.validate().is_valid());
+    /// ```
+    pub fn make_valid(&self, fill_rule: FillRule) -> Result<Self, ClipperError> {
+        Ok(self
+            .to_clipper_subject()
+            .add_clip(Paths::default())
+            .union(fill_rule)?
+            .closed)
+    }
+
+    /// Round all coordinates to multiples of `cell_size` and repair the
+    /// topology that rounding breaks, as needed for integer formats such as
+    /// vector tiles or Gerber files.
+    ///
+    /// Points that collapse onto the point before them are removed, closed
+    /// paths left with less than three points are dropped, and the closed
+    /// paths are repaired with [`Paths::make_valid`] using
+    /// [`FillRule::NonZero`]. Repairing may add intersection points off the
+    /// grid, so the rounding and repair are repeated a few times until all
+    /// points are on the grid. The result always has all points on the grid,
+    /// but in rare cases the last rounding may leave edges touching.
+    ///
+    /// Open paths are rounded and kept open after the closed paths. A
+    /// `cell_size` that is not a positive number returns the paths unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![
+    ///     vec![(0.1, 0.1), (4.2, 0.0), (4.1, 3.9), (0.0, 4.1)],
+    ///     vec![(6.0, 0.0), (6.2, 0.1), (6.1, 0.3)],
+    /// ]
+    /// .into();
+    ///
+    /// let snapped = paths.snap_to_grid(1.0).unwrap();
+    ///
+    /// assert_eq!(snapped.len(), 1);
+    /// assert_eq!(snapped.signed_area(), 16.0);
+    /// ```
+    pub fn snap_to_grid(&self, cell_size: f64) -> Result<Self, ClipperError> {
+        const MAX_ROUNDS: usize = 4;
+
+        if cell_size.is_nan() || cell_size <= 0.0 {
+            return Ok(self.clone());
+        }
+
+        let snap_point = |point: &Point<P>| {
+            Point::new(
+                (point.x() / cell_size).round() * cell_size,
+                (point.y() / cell_size).round() * cell_size,
+            )
+        };
+        let snap = |path: &Path<P>, closed: bool| {
+            let mut path = path.iter().map(snap_point).collect::<Path<P>>();
+            let points = path.points_mut();
+            points.dedup();
+            while closed && points.len() > 1 && points.first() == points.last() {
+                points.pop();
+            }
+            path
+        };
+        let on_grid = |paths: &Paths<P>| {
+            paths
+                .iter()
+                .flatten()
+                .all(|point| *point == snap_point(point))
+        };
+
+        let (mut closed, open) = self.partition_open();
+
+        for _ in 0..MAX_ROUNDS {
+            let snapped = closed
+                .iter()
+                .map(|path| snap(path, true))
+                .filter(|path| path.len() >= 3)
+                .collect::<Paths<P>>();
+
+            closed = snapped.make_valid(FillRule::NonZero)?;
+            if on_grid(&closed) {
+                break;
+            }
+        }
+
+        if !on_grid(&closed) {
+            closed = closed
+                .iter()
+                .map(|path| snap(path, true))
+                .filter(|path| path.len() >= 3)
+                .collect();
+        }
+
+        for path in open.iter() {
+            closed.push_open(snap(path, false));
+        }
+
+        Ok(closed)
+    }
+
+    /// Repair the paths like [`Paths::make_valid`], but return the result as a
+    /// [`PolyTree`] with the holes nested in their outer paths.
+    pub fn make_valid_tree(&self, fill_rule: FillRule) -> Result<PolyTree<P>, ClipperError> {
+        Ok(self
+            .to_clipper_subject()
+            .add_clip(Paths::default())
+            .union_tree(fill_rule)?
+            .tree)
+    }
+
+    fn boolean_operation_with(
+        &self,
+        other: &Paths<P>,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<Self, ClipperError> {
+        Ok(self
+            .to_clipper_subject()
+            .add_clip_ref(other)
+            .boolean_operation(clip_type, fill_rule)?
+            .closed)
+    }
+
     /// This function returns the area of the supplied paths. It's assumed
     /// that the paths are closed and do not self-intersect.
     ///
@@ -259,43 +1584,114 @@ impl<P: PointScaler> Paths<P> {
     /// clockwise winding will have negative areas and counter-clockwise winding
     /// have positive areas.
     ///
-    /// Conversely, when paths are displayed where Y values increase heading
-    /// down, then clockwise paths will have positive areas, and
-    /// counter-clockwise paths will have negative areas.
+    /// Conversely, when paths are displayed where Y values increase heading
+    /// down, then clockwise paths will have positive areas, and
+    /// counter-clockwise paths will have negative areas.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]].into();
+    ///
+    /// assert_eq!(paths.signed_area(), 1.0);
+    /// ```
+    ///
+    pub fn signed_area(&self) -> f64 {
+        unsafe { clipper_paths64_area(self.as_clipperpaths64()) / (P::MULTIPLIER * P::MULTIPLIER) }
+    }
+
+    /// Returns the area covered by the closed paths when filled with the fill
+    /// rule, counting overlapping regions once and leaving out holes,
+    /// regardless of the orientation of the paths.
+    ///
+    /// Unlike [`Paths::signed_area`], which sums the signed areas of the
+    /// paths, this joins the paths with themselves like
+    /// [`Paths::make_valid`] and measures the result in the native library
+    /// without converting it. Open paths cover no area. Returns `NaN` if the
+    /// native union fails.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use clipper2::*;
     ///
-    /// let paths: Paths = vec![vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]].into();
+    /// let overlapping: Paths = vec![
+    ///     Path::rectangle(0.0, 0.0, 2.0, 2.0),
+    ///     Path::rectangle(1.0, 0.0, 2.0, 2.0),
+    /// ]
+    /// .into();
     ///
-    /// assert_eq!(paths.signed_area(), 1.0);
+    /// assert_eq!(overlapping.signed_area(), 8.0);
+    /// assert_eq!(overlapping.net_area(FillRule::NonZero), 6.0);
+    /// assert_eq!(overlapping.net_area(FillRule::EvenOdd), 4.0);
     /// ```
-    ///
-    pub fn signed_area(&self) -> f64 {
-        unsafe {
-            let paths = self.to_clipperpaths64();
-            let area = clipper_paths64_area(paths) / (P::MULTIPLIER * P::MULTIPLIER);
-            clipper_delete_paths64(paths);
-            area
-        }
+    pub fn net_area(&self, fill_rule: FillRule) -> f64 {
+        self.to_clipper_subject()
+            .add_clip(Paths::default())
+            .boolean_operation_area(ClipType::Union, fill_rule)
+            .map_or(f64::NAN, f64::abs)
+    }
+
+    /// Returns the cached native representation of the paths if it has been
+    /// built already.
+    fn cached_clipperpaths64(&self) -> Option<*mut ClipperPaths64> {
+        self.1 .0.get().map(|native| native.0)
+    }
+
+    /// Returns the cached native representation of the paths, building it
+    /// first if needed. The pointer is owned by the paths and is only valid
+    /// until they are mutated or dropped.
+    pub(crate) fn as_clipperpaths64(&self) -> *mut ClipperPaths64 {
+        self.1
+             .0
+            .get_or_init(|| NativePaths(unsafe { self.to_clipperpaths64() }))
+            .0
     }
 
     pub(crate) fn from_clipperpaths64(ptr: *mut ClipperPaths64) -> Self {
-        let paths = unsafe {
-            let len: i32 = clipper_paths64_length(ptr).try_into().unwrap();
-            (0..len)
-                .map(|i| {
-                    let point_len: i32 = clipper_paths64_path_length(ptr, i).try_into().unwrap();
-                    let points = (0..point_len)
-                        .map(|j| clipper_paths64_get_point(ptr, i, j).into())
-                        .collect();
-                    Path::new(points)
-                })
-                .collect()
-        };
-        Self::new(paths)
+        let mut paths = Self::default();
+        paths.read_clipperpaths64(ptr, &mut Vec::new(), &mut Vec::new());
+        paths
+    }
+
+    /// Replace the paths with the contents of the native paths, reusing the
+    /// point buffers of the existing paths as well as the `lengths` and
+    /// `buffers` scratch vectors.
+    pub(crate) fn read_clipperpaths64(
+        &mut self,
+        ptr: *mut ClipperPaths64,
+        lengths: &mut Vec<usize>,
+        buffers: &mut Vec<*mut libc::c_void>,
+    ) {
+        self.1 = NativePathsCache::default();
+        self.2 = OpenFlags::default();
+
+        // Read all path lengths in one call, then copy all points directly
+        // into the reserved vectors in a second call.
+        unsafe {
+            let len = clipper_paths64_length(ptr);
+            lengths.clear();
+            lengths.resize(len, 0);
+            clipper_paths64_lengths(lengths.as_mut_ptr().cast(), ptr);
+
+            self.0.truncate(len);
+            self.0.resize_with(len, Path::default);
+
+            buffers.clear();
+            for (path, &length) in self.0.iter_mut().zip(lengths.iter()) {
+                let points = path.points_mut();
+                points.clear();
+                points.reserve(length);
+                buffers.push(points.as_mut_ptr().cast());
+            }
+            clipper_paths64_to_points(buffers.as_mut_ptr(), ptr);
+
+            for (path, &length) in self.0.iter_mut().zip(lengths.iter()) {
+                path.points_mut().set_len(length);
+            }
+        }
     }
 
     pub(crate) unsafe fn to_clipperpaths64(&self) -> *mut ClipperPaths64 {
@@ -308,24 +1704,24 @@ impl<P: PointScaler> Paths<P> {
         let result = clipper_paths64_of_paths(mem, paths.as_mut_ptr(), self.len());
 
         for path in paths {
-            clipper_delete_path64(path);
+            delete_path64(path);
         }
 
         result
     }
 }
 
-impl<'a, P: PointScaler> IntoIterator for &'a Path<P> {
-    type Item = &'a Point<P>;
-    type IntoIter = std::slice::Iter<'a, Point<P>>;
+impl<'a, P: PointScaler, C: Coordinate> IntoIterator for &'a Path<P, C> {
+    type Item = &'a Point<P, C>;
+    type IntoIter = std::slice::Iter<'a, Point<P, C>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-impl<P: PointScaler> IntoIterator for Paths<P> {
-    type Item = Path<P>;
+impl<P: PointScaler, C: Coordinate> IntoIterator for Paths<P, C> {
+    type Item = Path<P, C>;
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
@@ -333,26 +1729,52 @@ impl<P: PointScaler> IntoIterator for Paths<P> {
     }
 }
 
-impl<P: PointScaler> FromIterator<Path<P>> for Paths<P> {
-    fn from_iter<T: IntoIterator<Item = Path<P>>>(iter: T) -> Self {
-        Paths(iter.into_iter().collect())
+impl<'a, P: PointScaler, C: Coordinate> IntoIterator for &'a Paths<P, C> {
+    type Item = &'a Path<P, C>;
+    type IntoIter = std::slice::Iter<'a, Path<P, C>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, P: PointScaler, C: Coordinate> IntoIterator for &'a mut Paths<P, C> {
+    type Item = &'a mut Path<P, C>;
+    type IntoIter = std::slice::IterMut<'a, Path<P, C>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.1 = NativePathsCache::default();
+        self.0.iter_mut()
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> FromIterator<Path<P, C>> for Paths<P, C> {
+    fn from_iter<T: IntoIterator<Item = Path<P, C>>>(iter: T) -> Self {
+        Paths::new(iter.into_iter().collect())
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> Extend<Path<P, C>> for Paths<P, C> {
+    fn extend<T: IntoIterator<Item = Path<P, C>>>(&mut self, iter: T) {
+        self.1 = NativePathsCache::default();
+        self.0.extend(iter);
     }
 }
 
-impl<P: PointScaler> From<Path<P>> for Paths<P> {
-    fn from(path: Path<P>) -> Self {
+impl<P: PointScaler, C: Coordinate> From<Path<P, C>> for Paths<P, C> {
+    fn from(path: Path<P, C>) -> Self {
         vec![path].into()
     }
 }
 
-impl<P: PointScaler> From<Paths<P>> for Vec<Path<P>> {
-    fn from(paths: Paths<P>) -> Self {
+impl<P: PointScaler, C: Coordinate> From<Paths<P, C>> for Vec<Path<P, C>> {
+    fn from(paths: Paths<P, C>) -> Self {
         paths.0.clone()
     }
 }
 
-impl<P: PointScaler> From<Paths<P>> for Vec<Vec<(f64, f64)>> {
-    fn from(paths: Paths<P>) -> Self {
+impl<P: PointScaler, C: Coordinate> From<Paths<P, C>> for Vec<Vec<(f64, f64)>> {
+    fn from(paths: Paths<P, C>) -> Self {
         paths
             .iter()
             .map(|path| path.iter().map(|point| (point.x(), point.y())).collect())
@@ -360,8 +1782,8 @@ impl<P: PointScaler> From<Paths<P>> for Vec<Vec<(f64, f64)>> {
     }
 }
 
-impl<P: PointScaler> From<Paths<P>> for Vec<Vec<[f64; 2]>> {
-    fn from(paths: Paths<P>) -> Self {
+impl<P: PointScaler, C: Coordinate> From<Paths<P, C>> for Vec<Vec<[f64; 2]>> {
+    fn from(paths: Paths<P, C>) -> Self {
         paths
             .iter()
             .map(|path| path.iter().map(|point| [point.x(), point.y()]).collect())
@@ -369,51 +1791,90 @@ impl<P: PointScaler> From<Paths<P>> for Vec<Vec<[f64; 2]>> {
     }
 }
 
-impl<P: PointScaler> From<Vec<Vec<Point<P>>>> for Paths<P> {
-    fn from(points: Vec<Vec<Point<P>>>) -> Self {
-        Paths::<P>::new(points.into_iter().map(|path| path.into()).collect())
+impl<P: PointScaler, C: Coordinate> From<Vec<Vec<Point<P, C>>>> for Paths<P, C> {
+    fn from(points: Vec<Vec<Point<P, C>>>) -> Self {
+        Paths::<P, C>::new(points.into_iter().map(|path| path.into()).collect())
     }
 }
 
-impl<P: PointScaler> From<Vec<Vec<(f64, f64)>>> for Paths<P> {
+impl<P: PointScaler, C: Coordinate> From<Vec<Vec<(f64, f64)>>> for Paths<P, C> {
     fn from(points: Vec<Vec<(f64, f64)>>) -> Self {
-        Paths::<P>::new(points.into_iter().map(|path| path.into()).collect())
+        Paths::<P, C>::new(points.into_iter().map(|path| path.into()).collect())
     }
 }
 
-impl<P: PointScaler> From<Vec<Vec<[f64; 2]>>> for Paths<P> {
+impl<P: PointScaler, C: Coordinate> From<Vec<Vec<[f64; 2]>>> for Paths<P, C> {
     fn from(points: Vec<Vec<[f64; 2]>>) -> Self {
-        Paths::<P>::new(points.into_iter().map(|path| path.into()).collect())
+        Paths::<P, C>::new(points.into_iter().map(|path| path.into()).collect())
     }
 }
 
-impl<P: PointScaler> From<Vec<Point<P>>> for Paths<P> {
-    fn from(points: Vec<Point<P>>) -> Self {
-        Paths::<P>::new(vec![points.into()])
+impl<P: PointScaler, C: Coordinate> From<Vec<Point<P, C>>> for Paths<P, C> {
+    fn from(points: Vec<Point<P, C>>) -> Self {
+        Paths::<P, C>::new(vec![points.into()])
     }
 }
 
-impl<P: PointScaler> From<Vec<(f64, f64)>> for Paths<P> {
+impl<P: PointScaler, C: Coordinate> From<Vec<(f64, f64)>> for Paths<P, C> {
     fn from(points: Vec<(f64, f64)>) -> Self {
-        Paths::<P>::new(vec![points.into()])
+        Paths::<P, C>::new(vec![points.into()])
     }
 }
 
-impl<P: PointScaler> From<Vec<[f64; 2]>> for Paths<P> {
+impl<P: PointScaler, C: Coordinate> From<Vec<[f64; 2]>> for Paths<P, C> {
     fn from(points: Vec<[f64; 2]>) -> Self {
-        Paths::<P>::new(vec![points.into()])
+        Paths::<P, C>::new(vec![points.into()])
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> From<Vec<Vec<(f32, f32)>>> for Paths<P, C> {
+    fn from(points: Vec<Vec<(f32, f32)>>) -> Self {
+        Paths::<P, C>::new(points.into_iter().map(|path| path.into()).collect())
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> From<Vec<Vec<[f32; 2]>>> for Paths<P, C> {
+    fn from(points: Vec<Vec<[f32; 2]>>) -> Self {
+        Paths::<P, C>::new(points.into_iter().map(|path| path.into()).collect())
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> From<Vec<(f32, f32)>> for Paths<P, C> {
+    fn from(points: Vec<(f32, f32)>) -> Self {
+        Paths::<P, C>::new(vec![points.into()])
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> From<Vec<[f32; 2]>> for Paths<P, C> {
+    fn from(points: Vec<[f32; 2]>) -> Self {
+        Paths::<P, C>::new(vec![points.into()])
+    }
+}
+
+/// A slice of point-like values converts into paths holding a single path.
+impl<P: PointScaler, C: Coordinate, T: Copy + Into<Point<P, C>>> From<&[T]> for Paths<P, C> {
+    fn from(points: &[T]) -> Self {
+        Paths::<P, C>::new(vec![points.into()])
+    }
+}
+
+impl<P: PointScaler, C: Coordinate, T: Into<Point<P, C>>, const N: usize> From<[T; N]>
+    for Paths<P, C>
+{
+    fn from(points: [T; N]) -> Self {
+        Paths::<P, C>::new(vec![points.into()])
     }
 }
 
-impl<P: PointScaler> From<Vec<Path<P>>> for Paths<P> {
-    fn from(points: Vec<Path<P>>) -> Self {
-        Paths::<P>::new(points)
+impl<P: PointScaler, C: Coordinate> From<Vec<Path<P, C>>> for Paths<P, C> {
+    fn from(points: Vec<Path<P, C>>) -> Self {
+        Paths::<P, C>::new(points)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::Deci;
+    use crate::{Deci, Milli};
 
     use super::*;
 
@@ -620,7 +2081,7 @@ mod test {
     fn test_serde() {
         let paths = Paths::<Centi>::from(vec![(0.4, 0.0), (5.0, 1.0)]);
         let serialized = serde_json::to_string(&paths).unwrap();
-        assert_eq!(serialized, r#"[[{"x":40,"y":0},{"x":500,"y":100}]]"#);
+        assert_eq!(serialized, r#"[[{"x":0.4,"y":0.0},{"x":5.0,"y":1.0}]]"#);
 
         let deserialized: Paths = serde_json::from_str(&serialized).unwrap();
         assert_eq!(deserialized, paths);
@@ -630,19 +2091,716 @@ mod test {
     fn test_index() {
         let paths = Paths::<Centi>::from(vec![
             vec![(0.0, 0.0), (1.0, 1.0)],
-            vec![(2.0, 2.0), (3.0, 3.0)]
+            vec![(2.0, 2.0), (3.0, 3.0)],
         ]);
         assert_eq!(paths[0], Path::from(vec![(0.0, 0.0), (1.0, 1.0)]));
         assert_eq!(paths[1], Path::from(vec![(2.0, 2.0), (3.0, 3.0)]));
     }
 
+    #[test]
+    fn test_capacity() {
+        let mut paths = Paths::<Centi>::with_capacity(4);
+        assert!(paths.capacity() >= 4);
+
+        let mut path = Path::with_capacity(16);
+        path.push((1.0, 1.0));
+        paths.push(path);
+        paths.reserve(10);
+        assert!(paths.capacity() >= 11);
+
+        paths.shrink_to_fit();
+        assert_eq!(paths.capacity(), 1);
+        assert_eq!(paths[0].capacity(), 1);
+        assert_eq!(paths[0][0], Point::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_boolean_operations_with() {
+        let a = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 4.0, 4.0));
+        let b = Paths::<Centi>::from(Path::rectangle(2.0, 2.0, 4.0, 4.0));
+
+        assert_eq!(
+            a.difference_with(&b, FillRule::NonZero).unwrap(),
+            crate::difference(a.clone(), b.clone(), FillRule::NonZero)
+                .unwrap()
+                .closed
+        );
+        assert_eq!(
+            a.union_with(&b, FillRule::NonZero).unwrap().signed_area(),
+            28.0
+        );
+        assert_eq!(
+            b.difference_with(&a, FillRule::NonZero)
+                .unwrap()
+                .signed_area(),
+            12.0
+        );
+    }
+
+    #[test]
+    fn test_symmetric_difference_area() {
+        let a = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 4.0, 4.0));
+        let b = Paths::<Centi>::from(Path::rectangle(2.0, 2.0, 4.0, 4.0));
+        let reversed = Paths::<Centi>::from(a[0].iter().rev().copied().collect::<Path<_>>());
+
+        assert_eq!(
+            a.symmetric_difference_area(&b, FillRule::NonZero).unwrap(),
+            24.0
+        );
+        assert_eq!(
+            a.symmetric_difference_area(&reversed, FillRule::NonZero)
+                .unwrap(),
+            0.0
+        );
+        assert_eq!(
+            a.symmetric_difference_area(&Paths::default(), FillRule::NonZero)
+                .unwrap(),
+            16.0
+        );
+    }
+
+    #[test]
+    fn test_contains_paths() {
+        let frame = Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(3.0, 3.0, 4.0, 4.0),
+        ]);
+        let contains = |other: Path<Centi>| {
+            let other = Paths::from(other);
+            (
+                frame.contains_paths(&other, FillRule::EvenOdd).unwrap(),
+                frame
+                    .contains_paths_strictly(&other, FillRule::EvenOdd)
+                    .unwrap(),
+            )
+        };
+
+        assert_eq!(contains(Path::rectangle(1.0, 1.0, 1.0, 1.0)), (true, true));
+        assert_eq!(contains(Path::rectangle(0.0, 1.0, 1.0, 1.0)), (true, false));
+        assert_eq!(contains(Path::rectangle(1.0, 1.0, 2.0, 2.0)), (true, false));
+        assert_eq!(
+            contains(Path::rectangle(4.0, 4.0, 1.0, 1.0)),
+            (false, false)
+        );
+        assert_eq!(
+            contains(Path::rectangle(2.0, 2.0, 2.0, 2.0)),
+            (false, false)
+        );
+        assert_eq!(
+            contains(Path::rectangle(9.0, 1.0, 2.0, 1.0)),
+            (false, false)
+        );
+        assert_eq!(contains(Path::default()), (true, true));
+
+        // The corner of the hole touches the edge of the other path.
+        let touching = Path::from(vec![(1.0, 2.0), (3.0, 3.0), (1.0, 4.0)]);
+        assert_eq!(contains(touching), (true, false));
+
+        // Inner edges of overlapping paths are not part of the boundary.
+        let overlapping = Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 6.0, 6.0),
+            Path::rectangle(4.0, 0.0, 6.0, 6.0),
+        ]);
+        let across = Paths::from(Path::rectangle(1.0, 1.0, 8.0, 4.0));
+        assert!(overlapping
+            .contains_paths_strictly(&across, FillRule::NonZero)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_intersects() {
+        let frame = Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(3.0, 3.0, 4.0, 4.0),
+        ]);
+        let intersects = |other: Path<Centi>, fill_rule| {
+            let other = Paths::from(other);
+            let expected = !frame.intersect_with(&other, fill_rule).unwrap().is_empty();
+            let result = frame.intersects(&other, fill_rule).unwrap();
+            assert_eq!(result, other.intersects(&frame, fill_rule).unwrap());
+            assert_eq!(result, expected);
+            result
+        };
+
+        for fill_rule in [FillRule::EvenOdd, FillRule::NonZero, FillRule::Positive] {
+            assert!(intersects(Path::rectangle(-1.0, -1.0, 2.0, 2.0), fill_rule));
+            assert!(intersects(Path::rectangle(1.0, 1.0, 1.0, 1.0), fill_rule));
+            assert!(intersects(
+                Path::rectangle(-1.0, -1.0, 12.0, 12.0),
+                fill_rule
+            ));
+            assert!(intersects(Path::rectangle(0.0, 0.0, 10.0, 10.0), fill_rule));
+            assert!(!intersects(Path::rectangle(10.0, 0.0, 1.0, 1.0), fill_rule));
+            assert!(!intersects(Path::rectangle(20.0, 0.0, 1.0, 1.0), fill_rule));
+        }
+
+        assert!(!intersects(
+            Path::rectangle(4.0, 4.0, 1.0, 1.0),
+            FillRule::EvenOdd
+        ));
+        assert!(!intersects(
+            Path::rectangle(3.0, 3.0, 4.0, 4.0),
+            FillRule::EvenOdd
+        ));
+        assert!(intersects(
+            Path::rectangle(4.0, 4.0, 1.0, 1.0),
+            FillRule::NonZero
+        ));
+
+        assert!(!frame
+            .intersects(&Paths::default(), FillRule::NonZero)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_net_area() {
+        let hole: Path<Centi> = Path::rectangle(1.0, 1.0, 2.0, 2.0)
+            .iter()
+            .rev()
+            .copied()
+            .collect();
+        let mut paths = Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 4.0, 4.0),
+            hole.clone(),
+            Path::rectangle(2.0, 2.0, 4.0, 4.0),
+        ]);
+        paths.push_open(vec![(10.0, 10.0), (20.0, 10.0), (20.0, 20.0)]);
+
+        for fill_rule in [
+            FillRule::EvenOdd,
+            FillRule::NonZero,
+            FillRule::Positive,
+            FillRule::Negative,
+        ] {
+            let expected = paths.make_valid(fill_rule).unwrap().signed_area().abs();
+            assert_eq!(paths.net_area(fill_rule), expected);
+        }
+
+        assert_eq!(paths.net_area(FillRule::NonZero), 12.0 + 16.0 - 3.0);
+        assert_eq!(paths.net_area(FillRule::Negative), 0.0);
+        assert_eq!(Paths::new(vec![hole]).net_area(FillRule::Negative), 4.0);
+        assert_eq!(Paths::<Centi>::default().net_area(FillRule::NonZero), 0.0);
+    }
+
+    #[test]
+    fn test_hausdorff_distance() {
+        let square = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 4.0, 4.0));
+        let mut open = Paths::<Centi>::default();
+        open.push_open(Path::rectangle(0.0, 0.0, 4.0, 4.0));
+
+        assert_eq!(square.hausdorff_distance(&square.translate(0.5, 0.0)), 0.5);
+        assert_eq!(square.hausdorff_distance(&open), 0.0);
+        assert_eq!(
+            Paths::<Centi>::default().hausdorff_distance(&Paths::default()),
+            0.0
+        );
+        assert_eq!(square.hausdorff_distance(&Paths::default()), f64::INFINITY);
+
+        // The missing closing edge only shows once points are added along it.
+        assert_eq!(open.hausdorff_distance_resampled(&square, 1.0), 2.0);
+    }
+
+    #[test]
+    fn test_hausdorff_distance_resampled() {
+        let mut line = Paths::<Centi>::default();
+        line.push_open(vec![(0.0, 0.0), (10.0, 0.0)]);
+        let mut ends = Paths::<Centi>::default();
+        ends.push_open(vec![(0.0, 0.0), (1.0, 0.0)]);
+        ends.push_open(vec![(9.0, 0.0), (10.0, 0.0)]);
+
+        assert_eq!(line.hausdorff_distance(&ends), 0.0);
+        assert_eq!(line.hausdorff_distance_resampled(&ends, 1.0), 4.0);
+        assert_eq!(line.hausdorff_distance_resampled(&ends, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_iterate_by_reference_and_extend() {
+        let mut paths: Paths<Centi> = (0..3)
+            .map(|i| Path::rectangle(i as f64, 0.0, 1.0, 1.0))
+            .collect();
+        assert_eq!(paths.signed_area(), 3.0);
+
+        for path in &mut paths {
+            for point in path {
+                *point = Point::new(point.x() * 2.0, point.y() * 2.0);
+            }
+        }
+        assert_eq!(paths.signed_area(), 12.0);
+
+        paths.extend([Path::rectangle(10.0, 0.0, 1.0, 1.0)]);
+        assert_eq!(paths.signed_area(), 13.0);
+
+        let mut path = paths[3].clone();
+        path.extend([Point::new(10.0, 0.5)]);
+        assert_eq!(path.len(), 5);
+
+        let lengths = (&paths).into_iter().map(Path::len).collect::<Vec<_>>();
+        assert_eq!(lengths, vec![4, 4, 4, 4]);
+    }
+
+    #[test]
+    fn test_slice_access() {
+        let mut paths = Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 1.0, 1.0),
+            Path::rectangle(0.0, 0.0, 2.0, 2.0),
+        ]);
+
+        assert_eq!(paths.last().map(|path| path.len()), Some(4));
+        assert_eq!(paths[..1].len(), 1);
+        assert!(paths
+            .iter()
+            .all(|path| path.contains(&Point::new(0.0, 0.0))));
+
+        paths[0].reverse();
+        assert_eq!(paths[0].first(), Some(&Point::new(0.0, 1.0)));
+        assert_eq!(paths.signed_area(), 3.0);
+
+        let largest = paths
+            .iter()
+            .map(|path| path.signed_area())
+            .fold(f64::MIN, f64::max);
+        assert_eq!(largest, 4.0);
+    }
+
+    #[test]
+    fn test_open_flags() {
+        let mut paths = Paths::<Centi>::default();
+        paths.push_closed(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+        paths.push_open(vec![(0.0, 0.0), (1.0, 1.0)]);
+        paths.push_closed(Path::rectangle(2.0, 0.0, 1.0, 1.0));
+
+        assert!(paths.has_open_paths());
+        assert!(!paths.is_open(0));
+        assert!(paths.is_open(1));
+        assert!(!paths.is_open(2));
+
+        let mut copy = Paths::<Centi>::from(Path::rectangle(5.0, 5.0, 1.0, 1.0));
+        copy.push(paths.clone());
+        assert!(copy.is_open(2));
+        assert!(!copy.is_open(3));
+
+        let (closed, open) = paths.partition_open();
+        assert_eq!(closed.len(), 2);
+        assert_eq!(open.len(), 1);
+        assert!(!open.has_open_paths());
+
+        assert_ne!(paths, Paths::new(paths.to_vec()));
+        paths.set_open(1, false);
+        assert_eq!(paths, Paths::new(paths.to_vec()));
+    }
+
+    #[test]
+    fn test_open_results_are_marked_open() {
+        let mut lines = Paths::<Centi>::default();
+        lines.push_open(vec![(-1.0, 1.0), (3.0, 1.0)]);
+        lines.push_open(vec![(1.0, -1.0), (1.0, 3.0)]);
+        let square = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 2.0, 2.0));
+
+        let result = lines
+            .to_clipper_subject()
+            .add_clip(square.clone())
+            .intersect(FillRule::NonZero)
+            .unwrap();
+        assert_eq!(result.open.len(), 2);
+        assert!((0..2).all(|index| result.open.is_open(index)));
+
+        // Passed on as subjects, the paths are clipped as lines again.
+        let again = result
+            .open
+            .to_clipper_subject()
+            .add_clip(square)
+            .intersect_tree(FillRule::NonZero)
+            .unwrap();
+        assert_eq!(again.tree.total_net_area(), 0.0);
+        assert_eq!(again.open.len(), 2);
+        assert!(again.open.is_open(1));
+    }
+
+    #[test]
+    fn test_display() {
+        let paths = Paths::<Milli>::from(vec![vec![(0.0, 0.125), (1.0, 2.0 / 3.0)]]);
+
+        assert_eq!(paths.to_string(), "((0 0.125, 1 0.667))");
+        assert_eq!(format!("{paths:.2}"), "((0.00 0.12, 1.00 0.67))");
+        assert_eq!(Paths::<Centi>::default().to_string(), "()");
+    }
+
+    #[test]
+    fn test_from_slices() {
+        let points = [(0.0f32, 0.0f32), (1.0, 0.0), (1.0, 1.0)];
+        let expected = Paths::<Centi>::from(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]);
+
+        assert_eq!(Paths::<Centi>::from(&points[..]), expected);
+        assert_eq!(Paths::<Centi>::from(points), expected);
+        assert_eq!(Paths::<Centi>::from(vec![points.to_vec()]), expected);
+        assert_eq!(
+            crate::union::<Centi>(&points[..], [[0.5, 0.0], [2.0, 0.0], [2.0, 1.0]], None)
+                .unwrap()
+                .closed
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_iter_points_mut_resets_native_cache() {
+        let mut paths = Paths::<Centi>::from(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]);
+        let before = paths.signed_area();
+
+        for point in paths.iter_points_mut() {
+            *point = *point * 2.0;
+        }
+
+        assert_eq!(paths.signed_area(), before * 4.0);
+        assert_eq!(paths[0][2], Point::new(2.0, 2.0));
+    }
+
+    #[test]
+    fn test_make_valid() {
+        let paths = Paths::<Centi>::from(vec![
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![(2.0, 2.0), (8.0, 2.0), (8.0, 8.0), (2.0, 8.0)],
+            vec![(20.0, 0.0), (22.0, 2.0), (22.0, 0.0), (20.0, 2.0)],
+        ]);
+        assert!(!paths.validate().is_valid());
+
+        let valid = paths.make_valid(FillRule::EvenOdd).unwrap();
+        assert!(valid.validate().is_valid());
+        assert_eq!(valid.len(), 4);
+        assert_eq!(valid.signed_area(), 100.0 - 36.0 + 2.0);
+
+        let tree = paths.make_valid_tree(FillRule::EvenOdd).unwrap();
+        assert_eq!(tree.total_net_area(), 66.0);
+    }
+
+    #[test]
+    fn test_snap_to_grid() {
+        let mut paths = Paths::<Centi>::from(vec![
+            vec![(0.1, -0.2), (10.2, 0.1), (9.8, 10.1), (-0.3, 9.9)],
+            vec![(2.1, 2.2), (2.2, 7.8), (7.9, 8.1), (7.8, 1.9)],
+            // Lies inside the hole and touches its edge once rounded.
+            vec![(1.6, 4.5), (2.4, 2.4), (3.4, 4.5)],
+            // Collapses to a single point.
+            vec![(20.1, 0.1), (20.3, 0.2), (20.2, 0.4)],
+        ]);
+        paths.push_open(vec![(0.4, 0.4), (0.6, 0.6), (5.2, 0.4)]);
+
+        let snapped = paths.snap_to_grid(1.0).unwrap();
+        let (closed, open) = snapped.partition_open();
+
+        assert!(snapped
+            .iter()
+            .flatten()
+            .all(|point| point.x().fract() == 0.0 && point.y().fract() == 0.0));
+        assert!(closed.validate().is_valid());
+        assert_eq!(closed.signed_area(), 100.0 - 36.0 + 1.5);
+        assert_eq!(
+            open,
+            Paths::from(vec![vec![(0.0, 0.0), (1.0, 1.0), (5.0, 0.0)]])
+        );
+
+        assert_eq!(paths.snap_to_grid(0.0).unwrap(), paths);
+    }
+
+    #[test]
+    fn test_retain_keeps_open_flags() {
+        let mut paths = Paths::<Centi>::default();
+        paths.push_closed(Path::rectangle(0.0, 0.0, 0.5, 0.5));
+        paths.push_open(vec![(0.0, 0.0), (0.1, 0.0)]);
+        paths.push_closed(Path::rectangle(0.0, 0.0, 10.0, 10.0));
+        paths.push_closed(
+            Path::rectangle(2.0, 2.0, 1.0, 1.0)
+                .iter()
+                .rev()
+                .copied()
+                .collect::<Path<Centi>>(),
+        );
+        paths.push_open(vec![(0.0, 0.0), (5.0, 0.0)]);
+
+        paths.retain_by_area(1.0);
+
+        assert_eq!(paths.len(), 4);
+        assert!(paths.is_open(0));
+        assert!(!paths.is_open(1));
+        assert!(!paths.is_open(2));
+        assert!(paths.is_open(3));
+        assert_eq!(paths.partition_open().0.signed_area(), 99.0);
+    }
+
+    #[test]
+    fn test_flip_keeps_winding_and_open_flags() {
+        let mut paths = Paths::<Centi>::from(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(2.0, 2.0, 2.0, 2.0)
+                .iter()
+                .rev()
+                .copied()
+                .collect(),
+        ]);
+        paths.push_open(vec![(0.0, 1.0), (5.0, 2.0)]);
+
+        let flipped = paths.flip_y_around(0.0);
+        assert_eq!(flipped[0].signed_area(), 100.0);
+        assert_eq!(flipped[1].signed_area(), -4.0);
+        assert!(flipped.is_open(2));
+        assert_eq!(flipped[2], Path::from(vec![(0.0, -1.0), (5.0, -2.0)]));
+
+        let viewport = Bounds::<Centi> {
+            min: Point::new(-10.0, -10.0),
+            max: Point::new(20.0, 20.0),
+        };
+        let screen = paths.to_screen_space(&viewport);
+        assert_eq!(screen[2], Path::from(vec![(10.0, 19.0), (15.0, 18.0)]));
+        assert_eq!(screen.signed_area(), paths.signed_area());
+        assert_eq!(screen.from_screen_space(&viewport), paths);
+    }
+
+    #[test]
+    fn test_sort_by_area_and_vertex_count() {
+        let mut paths = Paths::<Centi>::default();
+        paths.push_open(vec![(0.0, 0.0), (50.0, 0.0), (50.0, 50.0)]);
+        paths.push_closed(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+        paths.push_closed(
+            Path::rectangle(0.0, 0.0, 3.0, 3.0)
+                .iter()
+                .rev()
+                .copied()
+                .collect::<Path<Centi>>(),
+        );
+        paths.push_closed(vec![(0.0, 0.0), (2.0, 0.0), (0.0, 2.0)]);
+
+        let sorted = paths.sorted_by_area();
+        let areas = sorted.iter().map(Path::signed_area).collect::<Vec<_>>();
+        assert_eq!(areas[..3], [-9.0, 2.0, 1.0][..]);
+        assert!(sorted.is_open(3));
+        assert!((0..3).all(|index| !sorted.is_open(index)));
+
+        let largest = paths.largest(2);
+        assert_eq!(largest.len(), 2);
+        assert_eq!(largest.signed_area(), -9.0 + 2.0);
+        assert!(!largest.has_open_paths());
+
+        let by_vertices = paths.sorted_by_vertex_count();
+        assert_eq!(
+            by_vertices
+                .iter()
+                .map(|path| path.len())
+                .collect::<Vec<_>>(),
+            vec![4, 4, 3, 3]
+        );
+        assert!(by_vertices.is_open(2));
+    }
+
     #[test]
     fn test_index_mut() {
         let mut paths = Paths::<Centi>::from(vec![
             vec![(0.0, 0.0), (1.0, 1.0)],
-            vec![(2.0, 2.0), (3.0, 3.0)]
+            vec![(2.0, 2.0), (3.0, 3.0)],
         ]);
         paths[1] = Path::from(vec![(4.0, 4.0), (5.0, 5.0)]);
         assert_eq!(paths[1], Path::from(vec![(4.0, 4.0), (5.0, 5.0)]));
     }
+
+    #[test]
+    fn test_native_cache_is_shared_by_clones_and_reset_on_mutation() {
+        let mut paths = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+        let native = paths.as_clipperpaths64();
+
+        assert_eq!(paths.as_clipperpaths64(), native);
+        // The clone keeps the old native paths alive, so their address can't
+        // be reused for the rebuilt ones below.
+        let clone = paths.clone();
+        assert_eq!(clone.as_clipperpaths64(), native);
+
+        paths.push(Path::rectangle(2.0, 0.0, 1.0, 1.0));
+        assert_ne!(paths.as_clipperpaths64(), native);
+        assert_eq!(paths.signed_area(), 2.0);
+
+        paths[1] = Path::rectangle(2.0, 0.0, 2.0, 1.0);
+        assert_eq!(paths.signed_area(), 3.0);
+    }
+
+    #[test]
+    fn test_clipperpaths64_round_trip() {
+        let paths = Paths::<Centi>::new(
+            (0..50)
+                .map(|i| {
+                    (0..i)
+                        .map(|j| Point::new(i as f64 * 0.5, -(j as f64) * 0.01))
+                        .collect()
+                })
+                .collect(),
+        );
+
+        let round_trip = unsafe {
+            let ptr = paths.to_clipperpaths64();
+            let round_trip = Paths::from_clipperpaths64(ptr);
+            delete_paths64(ptr);
+            round_trip
+        };
+
+        assert_eq!(round_trip, paths);
+    }
+
+    #[test]
+    fn test_cast_coordinates() {
+        let mut paths = Paths::<Centi>::default();
+        paths.push_closed(Path::rectangle(0.0, 0.0, 2.0, 2.0));
+        paths.push_open(vec![(0.0, 0.0), (1.25, 1.0)]);
+
+        let native = paths.cast::<f64>();
+        assert!(native.is_open(1));
+        assert_eq!(native[1][1].x_scaled(), 125.0);
+        assert_eq!(native.to_string(), paths.to_string());
+
+        let device = paths.cast::<i32>();
+        assert_eq!(device[0].len(), 4);
+        assert_eq!(device.cast::<i64>(), paths);
+        assert_eq!(native.cast::<i64>().signed_area(), paths.signed_area());
+    }
+
+    #[test]
+    fn test_native_bounds_match_rust_bounds() {
+        let mut paths: Paths<Centi> = vec![
+            Path::rectangle(-1.5, 2.0, 3.0, 1.0),
+            Path::default(),
+            vec![(10.25, -4.0), (11.0, 0.0)].into(),
+        ]
+        .into();
+
+        let bounds = paths.bounds();
+        assert_eq!(bounds.min, Point::new(-1.5, -4.0));
+        assert_eq!(bounds.max, Point::new(11.0, 3.0));
+
+        paths.prepare_native();
+        assert!(paths.cached_clipperpaths64().is_some());
+        assert_eq!(paths.bounds(), bounds);
+
+        paths.push_closed(Path::rectangle(20.0, 20.0, 1.0, 1.0));
+        assert!(paths.cached_clipperpaths64().is_none());
+        assert_eq!(paths.bounds().max, Point::new(21.0, 21.0));
+
+        let empty = Paths::<Centi>::new(vec![Path::default()]);
+        assert_eq!(empty.bounds(), Bounds::minmax());
+        empty.prepare_native();
+        assert_eq!(empty.bounds(), Bounds::minmax());
+    }
+
+    #[test]
+    fn test_native_is_positive() {
+        let outer = Path::<Centi>::rectangle(0.0, 0.0, 4.0, 4.0);
+        let hole = outer.iter().rev().copied().collect::<Path<Centi>>();
+        let paths = Paths::new(vec![outer, hole]);
+
+        let rust = (0..2).map(|i| paths.is_positive(i)).collect::<Vec<_>>();
+        paths.prepare_native();
+        let native = (0..2).map(|i| paths.is_positive(i)).collect::<Vec<_>>();
+
+        assert_eq!(rust, vec![true, false]);
+        assert_eq!(native, rust);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_is_positive_out_of_bounds() {
+        Paths::<Centi>::default().is_positive(0);
+    }
+
+    #[test]
+    fn test_canonical_order_is_independent_of_input_order() {
+        let paths = vec![
+            Path::<Centi>::rectangle(2.0, 0.0, 1.0, 1.0),
+            Path::rectangle(0.0, 0.0, 3.0, 3.0),
+            Path::rectangle(0.0, 0.0, 1.0, 1.0),
+            vec![(0.0, 0.0), (1.0, 1.0), (3.0, 3.0)].into(),
+            vec![(0.0, 0.0), (3.0, 0.0), (3.0, 3.0)].into(),
+        ];
+
+        let expected = Paths::new(paths.clone()).canonical_order();
+        let bounds = expected
+            .iter()
+            .map(|path| (path.bounds().min.x(), path.bounds().max.x()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bounds,
+            vec![(0.0, 1.0), (0.0, 3.0), (0.0, 3.0), (0.0, 3.0), (2.0, 3.0)]
+        );
+        // Equal bounds are ordered by signed area: the line, the triangle
+        // and then the square.
+        assert_eq!(expected[1].signed_area(), 0.0);
+        assert_eq!(expected[2].signed_area(), 4.5);
+
+        for shift in 1..paths.len() {
+            let mut rotated = paths.clone();
+            rotated.rotate_left(shift);
+            assert_eq!(Paths::new(rotated.clone()).canonical_order(), expected);
+            rotated.reverse();
+            assert_eq!(Paths::new(rotated).canonical_order(), expected);
+        }
+    }
+
+    #[test]
+    fn test_canonical_order_ties() {
+        let square = Path::<Centi>::rectangle(0.0, 0.0, 1.0, 1.0);
+        let rotated = square
+            .iter()
+            .cycle()
+            .skip(1)
+            .take(4)
+            .copied()
+            .collect::<Path<Centi>>();
+
+        let mut paths = Paths::<Centi>::default();
+        paths.push_open(square.clone());
+        paths.push_closed(rotated.clone());
+        paths.push_closed(square.clone());
+
+        let sorted = paths.canonical_order();
+        assert_eq!(sorted.to_vec(), vec![square.clone(), square, rotated]);
+        assert!(!sorted.is_open(0));
+        assert!(sorted.is_open(1));
+        assert!(!sorted.is_open(2));
+        assert!(Paths::<Centi>::default().canonical_order().is_empty());
+    }
+
+    #[test]
+    fn test_close_with_tolerance_bridge_and_snap() {
+        let outline = vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0), (0.04, 0.0)];
+
+        let mut bridged = Paths::<Centi>::default();
+        bridged.push_open(outline.clone());
+        assert_eq!(bridged.close_with_tolerance(0.05, true), vec![0]);
+        assert!(!bridged.has_open_paths());
+        assert_eq!(bridged[0].len(), 5);
+        assert_eq!(bridged.signed_area(), 15.92);
+
+        let mut snapped = Paths::<Centi>::default();
+        snapped.push_open(outline.clone());
+        assert_eq!(snapped.close_with_tolerance(0.05, false), vec![0]);
+        assert_eq!(snapped[0].len(), 4);
+        assert_eq!(snapped.signed_area(), 16.0);
+
+        let mut too_far = Paths::<Centi>::default();
+        too_far.push_open(outline);
+        assert!(too_far.close_with_tolerance(0.03, true).is_empty());
+        assert!(too_far.is_open(0));
+    }
+
+    #[test]
+    fn test_close_with_tolerance_skips_closed_and_degenerate_paths() {
+        let mut paths = Paths::<Centi>::default();
+        paths.push_closed(vec![(0.0, 0.0), (1.0, 0.0), (0.0, 0.01)]);
+        paths.push_open(vec![(0.0, 0.0), (1.0, 0.0), (0.0, 0.0)]);
+        paths.push_open(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 0.0)]);
+        paths.push_open(Vec::<(f64, f64)>::new());
+        paths.prepare_native();
+
+        // A repeated first point is removed even when bridging.
+        assert_eq!(paths.close_with_tolerance(0.1, true), vec![2]);
+        assert_eq!(paths[0].len(), 3);
+        assert!(paths.is_open(1));
+        assert_eq!(paths[2].len(), 3);
+        assert!(paths.is_open(3));
+        assert_eq!(paths.bounds(), Bounds::new(2.0, 2.0));
+    }
 }
@@ -0,0 +1,443 @@
+use clipper2c_sys::{
+    clipper_clipper64, clipper_clipper64_add_clip, clipper_clipper64_add_subject,
+    clipper_clipper64_execute, clipper_clipper64_size, clipper_paths64, clipper_paths64_inflate,
+    clipper_paths64_length, clipper_paths64_simplify, clipper_paths64_size, ClipperPaths64,
+};
+
+use crate::{
+    ffi::{
+        clipper_paths64_bounds, clipper_paths64_scale, clipper_paths64_translate, ClipperRect64,
+    },
+    malloc,
+    memory::{delete_clipper64, delete_paths64, free},
+    ClipType, ClipperError, EndType, FillRule, JoinType, Paths, PointScaler,
+};
+
+/// A chain of clipping, offsetting, simplification and transformation stages
+/// applied to a subject, see [`Pipeline::subject`].
+///
+/// The stages only run when calling [`Pipeline::run`], and the intermediate
+/// results stay in the native representation of the Clipper2 library, so
+/// they are never converted to Rust paths between stages. Only the final
+/// result is converted back.
+///
+/// All paths are treated as closed, open flags of the subject are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let outline: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+/// let hole: Paths = Path::rectangle(4.0, 4.0, 2.0, 2.0).into();
+///
+/// let result = Pipeline::subject(outline)
+///     .difference(hole)
+///     .offset(-1.0, JoinType::Miter)
+///     .simplify(0.01)
+///     .run()
+///     .unwrap();
+///
+/// assert_eq!(result.len(), 2);
+/// assert_eq!(result.signed_area(), 64.0 - 16.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Pipeline<P: PointScaler> {
+    subject: Paths<P>,
+    fill_rule: FillRule,
+    stages: Vec<Stage<P>>,
+}
+
+#[derive(Debug, Clone)]
+enum Stage<P: PointScaler> {
+    Boolean {
+        clip_type: ClipType,
+        clip: Paths<P>,
+        fill_rule: FillRule,
+    },
+    Offset {
+        delta: f64,
+        join_type: JoinType,
+    },
+    Simplify {
+        epsilon: f64,
+    },
+    Translate {
+        x: f64,
+        y: f64,
+    },
+    Scale {
+        scale_x: f64,
+        scale_y: f64,
+    },
+}
+
+/// Miter limit used by [`Pipeline::offset`], the default of the Clipper2
+/// library.
+const MITER_LIMIT: f64 = 2.0;
+
+impl<P: PointScaler> Pipeline<P> {
+    /// Start a pipeline with the paths the first stage is applied to.
+    pub fn subject(subject: impl Into<Paths<P>>) -> Self {
+        Self {
+            subject: subject.into(),
+            fill_rule: FillRule::default(),
+            stages: Vec::new(),
+        }
+    }
+
+    /// Set the fill rule of the boolean stages added after this call, the
+    /// default is [`FillRule::default`].
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// Add a stage uniting the current paths with the clip paths.
+    pub fn union(self, clip: impl Into<Paths<P>>) -> Self {
+        self.boolean(ClipType::Union, clip.into())
+    }
+
+    /// Add a stage subtracting the clip paths from the current paths.
+    pub fn difference(self, clip: impl Into<Paths<P>>) -> Self {
+        self.boolean(ClipType::Difference, clip.into())
+    }
+
+    /// Add a stage intersecting the current paths with the clip paths.
+    pub fn intersect(self, clip: impl Into<Paths<P>>) -> Self {
+        self.boolean(ClipType::Intersection, clip.into())
+    }
+
+    /// Add a stage applying xor to the current paths and the clip paths.
+    pub fn xor(self, clip: impl Into<Paths<P>>) -> Self {
+        self.boolean(ClipType::Xor, clip.into())
+    }
+
+    /// Add a stage offsetting the current paths as polygons by `delta`, like
+    /// [`inflate`](crate::inflate) with [`EndType::Polygon`] and a miter
+    /// limit of 2.
+    pub fn offset(mut self, delta: f64, join_type: JoinType) -> Self {
+        self.stages.push(Stage::Offset { delta, join_type });
+        self
+    }
+
+    /// Add a stage simplifying the current paths as closed paths, like
+    /// [`simplify`](crate::simplify).
+    pub fn simplify(mut self, epsilon: f64) -> Self {
+        self.stages.push(Stage::Simplify { epsilon });
+        self
+    }
+
+    /// Add a stage moving the current paths by an x/y offset, like
+    /// [`Paths::translate`]. The offset is rounded to whole scaled units.
+    ///
+    /// This is useful between boolean stages, such as shifting clipped
+    /// features by the origin of a tile before clipping them again.
+    pub fn translate(mut self, x: f64, y: f64) -> Self {
+        self.stages.push(Stage::Translate { x, y });
+        self
+    }
+
+    /// Add a stage scaling the current paths around the origin, like
+    /// [`Paths::scale_around_point`] with the point `(0, 0)`.
+    ///
+    /// Running the pipeline returns [`ClipperError::InvalidScale`] if a
+    /// factor is zero or not finite, or if the scaled coordinates would be
+    /// out of range.
+    pub fn scale(mut self, scale_x: f64, scale_y: f64) -> Self {
+        self.stages.push(Stage::Scale { scale_x, scale_y });
+        self
+    }
+
+    /// Returns the number of stages.
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Returns `true` if no stages have been added.
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    fn boolean(mut self, clip_type: ClipType, clip: Paths<P>) -> Self {
+        self.stages.push(Stage::Boolean {
+            clip_type,
+            clip,
+            fill_rule: self.fill_rule,
+        });
+        self
+    }
+
+    /// Run all stages and return the resulting paths.
+    ///
+    /// Returns an error if a boolean stage fails in the native library.
+    pub fn run(&self) -> Result<Paths<P>, ClipperError> {
+        let mut current = NativeStage {
+            ptr: self.subject.as_clipperpaths64(),
+            owned: false,
+        };
+
+        for stage in &self.stages {
+            current = NativeStage {
+                ptr: unsafe { stage.apply(current.ptr)? },
+                owned: true,
+            };
+        }
+
+        Ok(Paths::from_clipperpaths64(current.ptr))
+    }
+}
+
+impl<P: PointScaler> Stage<P> {
+    /// Apply the stage to native paths, returning newly allocated native
+    /// paths owned by the caller.
+    unsafe fn apply(
+        &self,
+        paths: *mut ClipperPaths64,
+    ) -> Result<*mut ClipperPaths64, ClipperError> {
+        match self {
+            Stage::Boolean {
+                clip_type,
+                clip,
+                fill_rule,
+            } => {
+                let clipper = clipper_clipper64(malloc(clipper_clipper64_size()));
+                clipper_clipper64_add_subject(clipper, paths);
+                clipper_clipper64_add_clip(clipper, clip.as_clipperpaths64());
+
+                let closed = clipper_paths64(malloc(clipper_paths64_size()));
+                let open = clipper_paths64(malloc(clipper_paths64_size()));
+                let success = clipper_clipper64_execute(
+                    clipper,
+                    (*clip_type).into(),
+                    (*fill_rule).into(),
+                    closed,
+                    open,
+                );
+                delete_clipper64(clipper);
+                delete_paths64(open);
+
+                if success == 1 {
+                    Ok(closed)
+                } else {
+                    delete_paths64(closed);
+                    Err(ClipperError::NativeFailure {
+                        operation: clip_type.name(),
+                        fill_rule: *fill_rule,
+                        code: success,
+                        subject_paths: clipper_paths64_length(paths),
+                        open_subject_paths: 0,
+                        clip_paths: clip.len(),
+                    })
+                }
+            }
+            Stage::Offset { delta, join_type } => Ok(clipper_paths64_inflate(
+                malloc(clipper_paths64_size()),
+                paths,
+                P::scale(*delta),
+                (*join_type).into(),
+                EndType::Polygon.into(),
+                P::scale(MITER_LIMIT),
+            )),
+            Stage::Simplify { epsilon } => Ok(clipper_paths64_simplify(
+                malloc(clipper_paths64_size()),
+                paths,
+                P::scale(*epsilon),
+                false.into(),
+            )),
+            Stage::Translate { x, y } => Ok(clipper_paths64_translate(
+                malloc(clipper_paths64_size()),
+                paths,
+                P::scale(*x).round() as i64,
+                P::scale(*y).round() as i64,
+            )),
+            Stage::Scale { scale_x, scale_y } => {
+                let invalid = ClipperError::InvalidScale {
+                    scale_x: *scale_x,
+                    scale_y: *scale_y,
+                };
+                // The native library throws on invalid factors and on paths
+                // without points, so those are handled before calling it.
+                if !scale_x.is_finite()
+                    || !scale_y.is_finite()
+                    || *scale_x == 0.0
+                    || *scale_y == 0.0
+                {
+                    return Err(invalid);
+                }
+
+                let mem = malloc(clipper_paths64_size());
+                match native_bounds(paths) {
+                    // Copy the paths unchanged.
+                    None => Ok(clipper_paths64_translate(mem, paths, 0, 0)),
+                    Some(bounds) if in_range(bounds, *scale_x, *scale_y) => {
+                        let mut error_code = 0;
+                        Ok(clipper_paths64_scale(
+                            mem,
+                            paths,
+                            *scale_x,
+                            *scale_y,
+                            &mut error_code,
+                        ))
+                    }
+                    Some(_) => {
+                        free(mem, clipper_paths64_size());
+                        Err(invalid)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Largest coordinate supported by the native library.
+pub(crate) const MAX_COORD: f64 = (i64::MAX >> 2) as f64;
+
+/// Returns the bounds of the native paths, or `None` if they have no points.
+pub(crate) unsafe fn native_bounds(paths: *mut ClipperPaths64) -> Option<ClipperRect64> {
+    let mut bounds = ClipperRect64 {
+        left: 0,
+        top: 0,
+        right: 0,
+        bottom: 0,
+    };
+    clipper_paths64_bounds((&mut bounds as *mut ClipperRect64).cast(), paths);
+
+    // Paths without points have inverted bounds.
+    (bounds.left <= bounds.right).then_some(bounds)
+}
+
+/// Returns `true` if scaling the bounds keeps them in the native range.
+pub(crate) fn in_range(bounds: ClipperRect64, scale_x: f64, scale_y: f64) -> bool {
+    [bounds.left, bounds.right]
+        .iter()
+        .all(|&x| (x as f64 * scale_x).abs() <= MAX_COORD)
+        && [bounds.top, bounds.bottom]
+            .iter()
+            .all(|&y| (y as f64 * scale_y).abs() <= MAX_COORD)
+}
+
+/// Native paths between two stages, deleted when replaced by the output of
+/// the next stage unless they are the cached native paths of the subject.
+struct NativeStage {
+    ptr: *mut ClipperPaths64,
+    owned: bool,
+}
+
+impl Drop for NativeStage {
+    fn drop(&mut self) {
+        if self.owned {
+            unsafe { delete_paths64(self.ptr) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{inflate, simplify, Centi, Path};
+
+    use super::*;
+
+    #[test]
+    fn test_pipeline_matches_separate_operations() {
+        let a = Paths::<Centi>::from(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(20.0, 0.0, 5.0, 5.0),
+        ]);
+        let b = Paths::<Centi>::from(Path::rectangle(5.0, -1.0, 17.0, 3.0));
+
+        let expected = a.difference_with(&b, FillRule::NonZero).unwrap();
+        let expected = inflate(
+            expected,
+            2.0,
+            JoinType::Round,
+            EndType::Polygon,
+            MITER_LIMIT,
+        );
+        let expected = simplify(expected, 0.01, false);
+
+        let result = Pipeline::subject(a.clone())
+            .fill_rule(FillRule::NonZero)
+            .difference(b)
+            .offset(2.0, JoinType::Round)
+            .simplify(0.01)
+            .run()
+            .unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_pipeline_stages() {
+        let square = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 2.0, 2.0));
+        let shifted = Paths::<Centi>::from(Path::rectangle(1.0, 0.0, 2.0, 2.0));
+
+        let pipeline = Pipeline::subject(square.clone());
+        assert!(pipeline.is_empty());
+        assert_eq!(pipeline.run().unwrap(), square);
+
+        let area = |pipeline: Pipeline<Centi>| pipeline.run().unwrap().signed_area();
+        assert_eq!(
+            area(Pipeline::subject(square.clone()).union(shifted.clone())),
+            6.0
+        );
+        assert_eq!(
+            area(Pipeline::subject(square.clone()).intersect(shifted.clone())),
+            2.0
+        );
+        assert_eq!(
+            area(Pipeline::subject(square.clone()).xor(shifted.clone())),
+            4.0
+        );
+
+        let pipeline = Pipeline::subject(square)
+            .union(shifted.clone())
+            .difference(shifted)
+            .offset(1.0, JoinType::Miter);
+        assert_eq!(pipeline.len(), 3);
+        assert_eq!(area(pipeline), 12.0);
+    }
+
+    #[test]
+    fn test_pipeline_transforms() {
+        let features = Paths::<Centi>::from(vec![
+            Path::rectangle(-5.0, -5.0, 10.0, 10.0),
+            Path::rectangle(12.0, 2.0, 2.0, 2.0),
+        ]);
+        let tile = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 16.0, 16.0));
+        let local = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 8.0, 8.0));
+
+        let result = Pipeline::subject(features.clone())
+            .intersect(tile.clone())
+            .translate(-4.0, -1.0)
+            .scale(0.5, 2.0)
+            .intersect(local.clone())
+            .run()
+            .unwrap();
+
+        let expected = features
+            .intersect_with(&tile, FillRule::default())
+            .unwrap()
+            .translate(-4.0, -1.0)
+            .scale_around_point(0.5, 2.0, crate::Point::new(0.0, 0.0))
+            .intersect_with(&local, FillRule::default())
+            .unwrap();
+        assert_eq!(result.signed_area(), expected.signed_area());
+        assert_eq!(result.signed_area(), 4.0 + 4.0);
+
+        let scaled = Pipeline::subject(tile.clone()).scale(0.0, 1.0).run();
+        assert!(matches!(
+            scaled,
+            Err(ClipperError::InvalidScale { scale_x, .. }) if scale_x == 0.0
+        ));
+        assert!(Pipeline::subject(tile.clone())
+            .scale(1e30, 1.0)
+            .run()
+            .is_err());
+        assert!(Pipeline::subject(Paths::<Centi>::default())
+            .scale(-2.0, 1.0)
+            .run()
+            .unwrap()
+            .is_empty());
+    }
+}
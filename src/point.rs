@@ -1,7 +1,9 @@
-use std::marker::PhantomData;
+use std::{fmt, marker::PhantomData, ops};
 
 use clipper2c_sys::ClipperPoint64;
 
+use crate::LengthUnit;
+
 /// The point scaling trait allows to choose a multiplier for the point values
 /// at compile time.
 ///
@@ -13,6 +15,11 @@ pub trait PointScaler: Default + Clone + Copy + PartialEq + std::hash::Hash {
     /// the `PointScaler` trait.
     const MULTIPLIER: f64;
 
+    /// The physical unit of the coordinates, used by
+    /// [`Paths::convert_unit`](crate::Paths::convert_unit). Defaults to
+    /// `None` for scalers of unitless coordinates.
+    const UNIT: Option<LengthUnit> = None;
+
     /// Scale a value by the multiplier.
     fn scale(value: f64) -> f64 {
         value * Self::MULTIPLIER
@@ -56,6 +63,126 @@ impl PointScaler for Milli {
     const MULTIPLIER: f64 = 1000.0;
 }
 
+mod private {
+    pub trait Sealed {}
+}
+
+/// The type the scaled coordinates of a [`Point`] are stored as.
+///
+/// `i64` is the default and what the Clipper2 library operates on. `i32`
+/// holds raw integer device coordinates in half the memory and `f64` holds
+/// native doubles, which are not rounded to the scaler. Paths of these types
+/// are converted with [`Path::cast`](crate::Path::cast) before operations.
+///
+/// This trait is sealed and implemented for `i64`, `i32` and `f64` only.
+pub trait Coordinate:
+    private::Sealed
+    + Default
+    + Copy
+    + PartialEq
+    + PartialOrd
+    + fmt::Debug
+    + Send
+    + Sync
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Neg<Output = Self>
+{
+    /// Zero.
+    const ZERO: Self;
+
+    /// The smallest value.
+    const MIN: Self;
+
+    /// The largest value.
+    const MAX: Self;
+
+    /// Convert from `f64`, rounding to the nearest integer and saturating
+    /// at the bounds for integer types.
+    fn from_f64(value: f64) -> Self;
+
+    /// Convert from `i64`, saturating at the bounds.
+    fn from_i64(value: i64) -> Self;
+
+    /// Convert to `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Convert to `i64`, or `None` for floating point types.
+    fn to_i64(self) -> Option<i64>;
+}
+
+impl private::Sealed for i64 {}
+
+impl Coordinate for i64 {
+    const ZERO: Self = 0;
+    const MIN: Self = i64::MIN;
+    const MAX: Self = i64::MAX;
+
+    fn from_f64(value: f64) -> Self {
+        value.round() as i64
+    }
+
+    fn from_i64(value: i64) -> Self {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn to_i64(self) -> Option<i64> {
+        Some(self)
+    }
+}
+
+impl private::Sealed for i32 {}
+
+impl Coordinate for i32 {
+    const ZERO: Self = 0;
+    const MIN: Self = i32::MIN;
+    const MAX: Self = i32::MAX;
+
+    fn from_f64(value: f64) -> Self {
+        value.round() as i32
+    }
+
+    fn from_i64(value: i64) -> Self {
+        value.clamp(i32::MIN.into(), i32::MAX.into()) as i32
+    }
+
+    fn to_f64(self) -> f64 {
+        self.into()
+    }
+
+    fn to_i64(self) -> Option<i64> {
+        Some(self.into())
+    }
+}
+
+impl private::Sealed for f64 {}
+
+impl Coordinate for f64 {
+    const ZERO: Self = 0.0;
+    const MIN: Self = f64::MIN;
+    const MAX: Self = f64::MAX;
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn from_i64(value: i64) -> Self {
+        value as f64
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn to_i64(self) -> Option<i64> {
+        None
+    }
+}
+
 /// XY Point with custom scaler.
 ///
 /// For
@@ -65,7 +192,8 @@ impl PointScaler for Milli {
 /// Therefore you can choose a implementation of PointScaler for your
 /// use-case. This library offers `One`, `Deci`, `Centi` and `Milli` multipliers
 /// where `Centi` is the default (multiplies values by 100 when converting to
-/// i64).
+/// i64). The `Millimeters` and `Inches` scalers also tag the coordinates with
+/// a physical unit, see [`Paths::convert_unit`](crate::Paths::convert_unit).
 ///
 /// # Examples
 ///
@@ -84,88 +212,246 @@ impl PointScaler for Milli {
 /// assert_eq!(point.x_scaled(), 1000);
 /// assert_eq!(point.y_scaled(), 2000);
 /// ```
+///
+/// With the `serde` feature enabled points are serialized in user units, as
+/// `{"x": 1.0, "y": 2.0}`, and the scaler is applied again on deserialize.
+///
+/// The scaled values are stored as `i64` by default, the only [`Coordinate`]
+/// type the Clipper2 library operates on. Points with `i32` or `f64` storage
+/// hold raw device coordinates or native doubles and are converted with
+/// [`Point::cast`] before they are used in operations.
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let native = Point::<One, f64>::new(0.25, 1.5);
+/// assert_eq!(native.x_scaled(), 0.25);
+///
+/// let rounded: Point<One> = native.cast();
+/// assert_eq!((rounded.x_scaled(), rounded.y_scaled()), (0, 2));
+/// ```
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(transparent),
-    serde(bound = "P: PointScaler")
-)]
-pub struct Point<P: PointScaler = Centi>(
-    ClipperPoint64,
-    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<P>,
-);
+#[repr(transparent)]
+pub struct Point<P: PointScaler = Centi, C: Coordinate = i64>([C; 2], PhantomData<P>);
 
-impl<P: PointScaler> Point<P> {
+impl<P: PointScaler, C: Coordinate> Point<P, C> {
     /// The zero point.
-    pub const ZERO: Self = Self(ClipperPoint64 { x: 0, y: 0 }, PhantomData);
+    pub const ZERO: Self = Self([C::ZERO; 2], PhantomData);
 
     /// The minimum value for a point.
-    pub const MIN: Self = Self(
-        ClipperPoint64 {
-            x: i64::MIN,
-            y: i64::MIN,
-        },
-        PhantomData,
-    );
+    pub const MIN: Self = Self([C::MIN; 2], PhantomData);
 
     /// The maximum value for a point.
-    pub const MAX: Self = Self(
-        ClipperPoint64 {
-            x: i64::MAX,
-            y: i64::MAX,
-        },
-        PhantomData,
-    );
+    pub const MAX: Self = Self([C::MAX; 2], PhantomData);
 
     /// Create a new point.
     pub fn new(x: f64, y: f64) -> Self {
-        Self(
-            ClipperPoint64 {
-                x: P::scale(x).round() as i64,
-                y: P::scale(y).round() as i64,
-            },
-            PhantomData,
-        )
+        Self::from_scaled(C::from_f64(P::scale(x)), C::from_f64(P::scale(y)))
     }
 
     /// Create a new point from scaled values, this means that point is
     /// constructed as is without applying the scaling multiplier.
-    pub fn from_scaled(x: i64, y: i64) -> Self {
-        Self(ClipperPoint64 { x, y }, PhantomData)
+    pub fn from_scaled(x: C, y: C) -> Self {
+        Self([x, y], PhantomData)
     }
 
     /// Returns the x coordinate of the point.
     pub fn x(&self) -> f64 {
-        P::descale(self.0.x as f64)
+        P::descale(self.0[0].to_f64())
     }
 
     /// Returns the y coordinate of the point.
     pub fn y(&self) -> f64 {
-        P::descale(self.0.y as f64)
+        P::descale(self.0[1].to_f64())
     }
 
     /// Returns the scaled x coordinate of the point.
-    pub fn x_scaled(&self) -> i64 {
-        self.0.x
+    pub fn x_scaled(&self) -> C {
+        self.0[0]
     }
 
     /// Returns the scaled y coordinate of the point.
-    pub fn y_scaled(&self) -> i64 {
-        self.0.y
+    pub fn y_scaled(&self) -> C {
+        self.0[1]
+    }
+
+    /// Convert the scaled values to another [`Coordinate`] type, keeping
+    /// the scaler.
+    ///
+    /// Values are converted exactly between integer types where they fit,
+    /// rounded to the nearest integer when coming from `f64` and saturated
+    /// at the bounds of the target type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let device = Point::<One, i32>::from_scaled(640, -480);
+    /// let point: Point<One> = device.cast();
+    /// assert_eq!(point.x_scaled(), 640_i64);
+    ///
+    /// let far = Point::<One>::from_scaled(1 << 40, 0);
+    /// assert_eq!(far.cast::<i32>().x_scaled(), i32::MAX);
+    /// ```
+    pub fn cast<T: Coordinate>(self) -> Point<P, T> {
+        let convert = |value: C| match value.to_i64() {
+            Some(value) => T::from_i64(value),
+            None => T::from_f64(value.to_f64()),
+        };
+        Point::from_scaled(convert(self.0[0]), convert(self.0[1]))
     }
 
     /// Calculate the distance to another point.
     pub fn distance_to(&self, to: &Self) -> f64 {
-        ((self.x() - to.x()).powf(2.0) + (self.y() - to.y()).powf(2.0)).sqrt()
+        (self.x() - to.x()).hypot(self.y() - to.y())
+    }
+
+    /// Calculate the dot product with another point, treating both points as
+    /// vectors from the origin.
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x() * other.x() + self.y() * other.y()
+    }
+
+    /// Calculate the z component of the cross product with another point,
+    /// treating both points as vectors from the origin. The result is
+    /// positive when `other` is counter-clockwise from this point.
+    pub fn cross(&self, other: &Self) -> f64 {
+        self.x() * other.y() - self.y() * other.x()
+    }
+
+    /// Calculate the length of the point as a vector from the origin.
+    pub fn length(&self) -> f64 {
+        self.x().hypot(self.y())
+    }
+}
+
+impl<P: PointScaler> Point<P> {
+    /// Create a new point from integer coordinates.
+    ///
+    /// The coordinates are scaled with integer arithmetic when the
+    /// multiplier is a whole number, as for all scalers of this crate, so
+    /// they are stored exactly without a round-trip through `f64`. With the
+    /// [`One`] scaler the coordinates are stored as is. Other multipliers
+    /// fall back to [`Point::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scaled coordinates overflow an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let point = Point::<One>::from_i64(9_007_199_254_740_993_i64, -3_i64);
+    /// assert_eq!(point.x_scaled(), 9_007_199_254_740_993);
+    ///
+    /// let point = Point::<Centi>::from_i64(12, 5_i32);
+    /// assert_eq!((point.x_scaled(), point.y_scaled()), (1200, 500));
+    /// ```
+    pub fn from_i64(x: impl Into<i64>, y: impl Into<i64>) -> Self {
+        let (x, y) = (x.into(), y.into());
+
+        if P::MULTIPLIER.fract() != 0.0 {
+            return Self::new(x as f64, y as f64);
+        }
+
+        let multiplier = P::MULTIPLIER as i64;
+        let scale = |value: i64| {
+            value
+                .checked_mul(multiplier)
+                .expect("scaled coordinate overflows i64")
+        };
+        Self::from_scaled(scale(x), scale(y))
+    }
+
+    /// Calculate the distance to the closest point of the segment between
+    /// `start` and `end`.
+    pub(crate) fn distance_to_segment(&self, start: &Self, end: &Self) -> f64 {
+        let (edge_x, edge_y) = (end.x() - start.x(), end.y() - start.y());
+        let length_2 = edge_x * edge_x + edge_y * edge_y;
+
+        if length_2 == 0.0 {
+            return self.distance_to(start);
+        }
+
+        let t = (((self.x() - start.x()) * edge_x + (self.y() - start.y()) * edge_y) / length_2)
+            .clamp(0.0, 1.0);
+        let (x, y) = (start.x() + t * edge_x, start.y() + t * edge_y);
+        (self.x() - x).hypot(self.y() - y)
     }
 
     pub(crate) fn as_clipperpoint64(&self) -> *const ClipperPoint64 {
-        &self.0
+        // `[i64; 2]` has the same layout as the native point.
+        (&self.0 as *const [i64; 2]).cast()
     }
 }
 
-impl<P: PointScaler> Default for Point<P> {
+/// Formats the point in user units as `x y`, like a WKT coordinate. The
+/// precision of the formatter, as in `{:.2}`, applies to both coordinates.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let point = Point::<Centi>::new(1.5, 2.0);
+///
+/// assert_eq!(point.to_string(), "1.5 2");
+/// assert_eq!(format!("{point:.2}"), "1.50 2.00");
+/// ```
+impl<P: PointScaler, C: Coordinate> fmt::Display for Point<P, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} {:.*}", precision, self.x(), precision, self.y()),
+            None => write!(f, "{} {}", self.x(), self.y()),
+        }
+    }
+}
+
+/// Points are added and subtracted on the scaled values, exactly for integer
+/// storage. Like integer arithmetic, a result the storage can't represent
+/// panics in debug builds and wraps in release builds. The distance methods
+/// such as [`Point::distance_to`] compute in `f64` and don't overflow.
+impl<P: PointScaler, C: Coordinate> ops::Add for Point<P, C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_scaled(self.0[0] + rhs.0[0], self.0[1] + rhs.0[1])
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> ops::Sub for Point<P, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_scaled(self.0[0] - rhs.0[0], self.0[1] - rhs.0[1])
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> ops::Neg for Point<P, C> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_scaled(-self.0[0], -self.0[1])
+    }
+}
+
+/// Scale both coordinates, rounding the result to the nearest scaled value
+/// for integer storage.
+impl<P: PointScaler, C: Coordinate> ops::Mul<f64> for Point<P, C> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::from_scaled(
+            C::from_f64(self.0[0].to_f64() * rhs),
+            C::from_f64(self.0[1].to_f64() * rhs),
+        )
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> Default for Point<P, C> {
     fn default() -> Self {
         Self::ZERO
     }
@@ -173,52 +459,107 @@ impl<P: PointScaler> Default for Point<P> {
 
 impl<P: PointScaler> From<ClipperPoint64> for Point<P> {
     fn from(point: ClipperPoint64) -> Self {
-        Self(point, PhantomData)
+        Self::from_scaled(point.x, point.y)
     }
 }
 
 impl<P: PointScaler> From<Point<P>> for ClipperPoint64 {
     fn from(point: Point<P>) -> Self {
-        point.0
+        ClipperPoint64 {
+            x: point.0[0],
+            y: point.0[1],
+        }
     }
 }
 
-impl<P: PointScaler> From<(f64, f64)> for Point<P> {
+impl<P: PointScaler, C: Coordinate> From<(f64, f64)> for Point<P, C> {
     fn from((x, y): (f64, f64)) -> Self {
         Self::new(x, y)
     }
 }
 
-impl<P: PointScaler> From<&(f64, f64)> for Point<P> {
+impl<P: PointScaler, C: Coordinate> From<&(f64, f64)> for Point<P, C> {
     fn from((x, y): &(f64, f64)) -> Self {
         Self::new(*x, *y)
     }
 }
 
-impl<P: PointScaler> From<[f64; 2]> for Point<P> {
+impl<P: PointScaler, C: Coordinate> From<[f64; 2]> for Point<P, C> {
     fn from([x, y]: [f64; 2]) -> Self {
         Self::new(x, y)
     }
 }
 
-impl<P: PointScaler> From<&[f64; 2]> for Point<P> {
+impl<P: PointScaler, C: Coordinate> From<&[f64; 2]> for Point<P, C> {
     fn from([x, y]: &[f64; 2]) -> Self {
         Self::new(*x, *y)
     }
 }
 
-impl<P: PointScaler> From<Point<P>> for (f64, f64) {
-    fn from(point: Point<P>) -> Self {
+impl<P: PointScaler, C: Coordinate> From<(f32, f32)> for Point<P, C> {
+    fn from((x, y): (f32, f32)) -> Self {
+        Self::new(x.into(), y.into())
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> From<&(f32, f32)> for Point<P, C> {
+    fn from((x, y): &(f32, f32)) -> Self {
+        Self::new((*x).into(), (*y).into())
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> From<[f32; 2]> for Point<P, C> {
+    fn from([x, y]: [f32; 2]) -> Self {
+        Self::new(x.into(), y.into())
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> From<&[f32; 2]> for Point<P, C> {
+    fn from([x, y]: &[f32; 2]) -> Self {
+        Self::new((*x).into(), (*y).into())
+    }
+}
+
+impl<P: PointScaler, C: Coordinate> From<Point<P, C>> for (f64, f64) {
+    fn from(point: Point<P, C>) -> Self {
         (point.x(), point.y())
     }
 }
 
-impl<P: PointScaler> From<Point<P>> for [f64; 2] {
-    fn from(point: Point<P>) -> Self {
+impl<P: PointScaler, C: Coordinate> From<Point<P, C>> for [f64; 2] {
+    fn from(point: Point<P, C>) -> Self {
         [point.x(), point.y()]
     }
 }
 
+/// Unscaled representation of a point used for serialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Point")]
+struct UnscaledPoint {
+    x: f64,
+    y: f64,
+}
+
+#[cfg(feature = "serde")]
+impl<P: PointScaler, C: Coordinate> serde::Serialize for Point<P, C> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        UnscaledPoint {
+            x: self.x(),
+            y: self.y(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, P: PointScaler, C: Coordinate> serde::Deserialize<'de> for Point<P, C> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let UnscaledPoint { x, y } = UnscaledPoint::deserialize(deserializer)?;
+        Ok(Self::new(x, y))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -255,21 +596,158 @@ mod test {
         assert_eq!(point.y_scaled(), 4000);
     }
 
+    #[test]
+    fn test_scaler_need_not_be_send() {
+        // A raw pointer makes the scaler neither `Send` nor `Sync`.
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+        struct LocalScaler(PhantomData<*const ()>);
+
+        impl PointScaler for LocalScaler {
+            const MULTIPLIER: f64 = 100.0;
+        }
+
+        let squares = (0..4)
+            .map(|i| crate::Path::<LocalScaler>::rectangle(i as f64, 0.0, 2.0, 2.0))
+            .collect::<Vec<_>>();
+        let result = crate::union_all(squares.clone(), crate::FillRule::NonZero).unwrap();
+        assert_eq!(result.signed_area(), 10.0);
+
+        let paths = squares.into_iter().map(Into::into).collect::<Vec<_>>();
+        let pairs =
+            crate::pairwise_clip(&paths, &paths, crate::ClipType::Intersection, None).unwrap();
+        // All pairs but the first and last square have touching bounds.
+        assert_eq!(pairs.len(), 4 * 4 - 2);
+    }
+
+    #[test]
+    fn test_from_i64() {
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+        struct Half;
+
+        impl PointScaler for Half {
+            const MULTIPLIER: f64 = 0.5;
+        }
+
+        // Not representable as f64, 2^53 + 1.
+        let exact = 9_007_199_254_740_993_i64;
+        assert_eq!(Point::<One>::from_i64(exact, -exact).x_scaled(), exact);
+        assert_eq!(Point::<One>::from_i64(exact, -exact).y_scaled(), -exact);
+        assert_eq!(
+            Point::<Milli>::from_i64(-7, 3_i32),
+            Point::<Milli>::new(-7.0, 3.0)
+        );
+        assert_eq!(Point::<Half>::from_i64(3, 4).x_scaled(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_from_i64_overflow() {
+        let _ = Point::<Centi>::from_i64(i64::MAX / 10, 0);
+    }
+
+    #[test]
+    fn test_coordinate_storage() {
+        let native = Point::<Centi, f64>::new(0.0625, -0.5);
+        assert_eq!(native.x_scaled(), 6.25);
+        assert_eq!(native.x(), 0.0625);
+        assert_eq!(native * 0.5, Point::new(0.03125, -0.25));
+
+        let device = Point::<One, i32>::new(1.5, -2.5);
+        assert_eq!((device.x_scaled(), device.y_scaled()), (2, -3));
+        assert_eq!(device - Point::from_scaled(2, 2), Point::from_scaled(0, -5));
+        assert_eq!(device.to_string(), "2 -3");
+
+        assert_eq!(
+            std::mem::size_of::<Point<Centi, i32>>(),
+            2 * std::mem::size_of::<i32>()
+        );
+        assert_eq!(
+            std::mem::size_of::<Point<Centi>>(),
+            std::mem::size_of::<ClipperPoint64>()
+        );
+    }
+
+    #[test]
+    fn test_cast() {
+        // Not representable as f64, 2^53 + 1.
+        let exact = 9_007_199_254_740_993_i64;
+        let point = Point::<One>::from_scaled(exact, -1);
+        assert_eq!(point.cast::<i64>(), point);
+        assert_eq!(point.cast::<i32>().x_scaled(), i32::MAX);
+        assert_eq!(point.cast::<i32>().y_scaled(), -1);
+
+        let native = Point::<Milli, f64>::new(0.0025, -0.0025);
+        assert_eq!(native.cast::<i64>(), Point::<Milli>::new(0.0025, -0.0025));
+        assert_eq!(Point::<Milli, f64>::MIN.cast::<i32>().x_scaled(), i32::MIN);
+        assert_eq!(
+            Point::<Milli, i32>::MAX.cast::<f64>().x(),
+            f64::from(i32::MAX) / 1000.0
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serde() {
-        let point = Point::<Centi>::new(1.0, 2.0);
+        let point = Point::<Centi>::new(1.0, 2.5);
         let serialized = serde_json::to_string(&point).unwrap();
-        assert_eq!(serialized, r#"{"x":100,"y":200}"#);
+        assert_eq!(serialized, r#"{"x":1.0,"y":2.5}"#);
 
         let deserialized: Point<Centi> = serde_json::from_str(&serialized).unwrap();
         assert_eq!(point, deserialized);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_applies_scaler_on_deserialize() {
+        let point: Point<Milli> = serde_json::from_str(r#"{"x":1.0,"y":0.0025}"#).unwrap();
+        assert_eq!(point.x_scaled(), 1000);
+        assert_eq!(point.y_scaled(), 3);
+
+        let point: Point<Deci> = serde_json::from_str(r#"{"x":1.0,"y":0.25}"#).unwrap();
+        assert_eq!(point.x_scaled(), 10);
+        assert_eq!(point.y_scaled(), 3);
+    }
+
     #[test]
     fn test_distance_to() {
         let point1 = Point::<Centi>::new(1.0, 2.0);
         let point2 = Point::<Centi>::new(3.0, 4.0);
         assert_eq!(point1.distance_to(&point2), 2.8284271247461903);
     }
+
+    #[test]
+    fn test_distance_does_not_overflow_storage() {
+        let a = Point::<One, i32>::from_scaled(i32::MIN, 0);
+        let b = Point::<One, i32>::from_scaled(i32::MAX, 0);
+        assert_eq!(a.distance_to(&b), f64::from(i32::MAX) - f64::from(i32::MIN));
+
+        let start = Point::<One>::from_scaled(i64::MIN, 0);
+        let end = Point::<One>::from_scaled(i64::MAX, 0);
+        let point = Point::<One>::from_scaled(0, i64::MAX);
+        assert_eq!(point.distance_to_segment(&start, &end), i64::MAX as f64);
+        assert_eq!(start.distance_to(&end), 2.0 * i64::MAX as f64);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::<Centi>::new(1.5, 2.0);
+        let b = Point::<Centi>::new(0.25, -1.0);
+
+        assert_eq!(a + b, Point::new(1.75, 1.0));
+        assert_eq!(a - b, Point::new(1.25, 3.0));
+        assert_eq!(-a, Point::new(-1.5, -2.0));
+        assert_eq!(a * 2.0, Point::new(3.0, 4.0));
+        assert_eq!(b * 0.5, Point::new(0.13, -0.5));
+    }
+
+    #[test]
+    fn test_vector_products() {
+        let a = Point::<Centi>::new(3.0, 4.0);
+        let b = Point::<Centi>::new(-4.0, 3.0);
+
+        assert_eq!(a.length(), 5.0);
+        assert_eq!(a.dot(&b), 0.0);
+        assert_eq!(a.cross(&b), 25.0);
+        assert_eq!(b.cross(&a), -25.0);
+    }
 }
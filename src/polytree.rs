@@ -0,0 +1,791 @@
+use std::collections::VecDeque;
+
+use crate::{Bounds, FillRule, Path, Paths, Point, PointInPolygonResult, PointScaler, PolyTree};
+
+impl<P: PointScaler> PolyTree<P> {
+    /// Build a tree from flat paths by nesting each path inside the
+    /// smallest path that surrounds it, without running a boolean
+    /// operation.
+    ///
+    /// The paths are assumed not to intersect each other. Whether a path is
+    /// an outer or a hole follows from the fill rule applied to the paths
+    /// surrounding it, and paths that do not change whether the region is
+    /// filled (such as a path directly inside another with the same
+    /// orientation for [`FillRule::NonZero`]) are left out. Polygons are
+    /// oriented like the output of a boolean operation, outers with a
+    /// positive area and holes with a negative area.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths = Paths::<Centi>::new(vec![
+    ///     Path::rectangle(2.0, 2.0, 6.0, 6.0),
+    ///     Path::rectangle(0.0, 0.0, 10.0, 10.0),
+    /// ]);
+    ///
+    /// let tree = PolyTree::from_paths(&paths, FillRule::EvenOdd);
+    /// let outer = tree.get_child(0).unwrap();
+    ///
+    /// assert_eq!(outer.area(), 100.0);
+    /// assert!(outer.get_child(0).unwrap().is_hole());
+    /// ```
+    pub fn from_paths(paths: &Paths<P>, fill_rule: FillRule) -> Self {
+        let areas = paths
+            .iter()
+            .map(|path| path.signed_area())
+            .collect::<Vec<_>>();
+
+        let mut order = (0..paths.len())
+            .filter(|&index| paths[index].len() >= 3 && areas[index] != 0.0)
+            .collect::<Vec<_>>();
+        order.sort_by(|&a, &b| areas[b].abs().total_cmp(&areas[a].abs()));
+
+        let is_filled = |winding: i64| fill_rule.is_filled(winding);
+
+        // Parents are indices into `paths`, with `paths.len()` as the root.
+        let root = paths.len();
+        let mut winding = vec![0i64; paths.len() + 1];
+        let mut kept_parent = vec![root; paths.len()];
+        let mut kept = vec![false; paths.len() + 1];
+        let mut children = vec![Vec::new(); paths.len() + 1];
+        kept[root] = true;
+
+        for (position, &index) in order.iter().enumerate() {
+            let parent = order[..position]
+                .iter()
+                .rev()
+                .find(|&&candidate| surrounds(&paths[candidate], &paths[index]))
+                .copied()
+                .unwrap_or(root);
+
+            winding[index] = winding[parent]
+                + match fill_rule {
+                    FillRule::EvenOdd => 1,
+                    _ => areas[index].signum() as i64,
+                };
+            kept_parent[index] = if kept[parent] {
+                parent
+            } else {
+                kept_parent[parent]
+            };
+
+            if is_filled(winding[index]) != is_filled(winding[parent]) {
+                kept[index] = true;
+                children[kept_parent[index]].push(index);
+            }
+        }
+
+        let mut tree = Self {
+            children: Vec::new(),
+            is_hole: false,
+            polygon: Path::default(),
+            depth: 0,
+        };
+        tree.children = build_children(paths, &areas, &children, root, 1);
+        tree
+    }
+
+    /// Get the filled area enclosed by this node, the area of its own polygon
+    /// minus the areas of the holes below it plus the areas of the islands
+    /// inside those holes.
+    ///
+    /// The area is positive for outers and negative for holes, regardless
+    /// of the orientation of the polygons.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let outer: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+    /// let hole: Paths = Path::rectangle(2.0, 2.0, 6.0, 6.0).into();
+    ///
+    /// let tree = outer
+    ///     .to_clipper_subject()
+    ///     .add_clip(hole)
+    ///     .difference_tree(FillRule::default())
+    ///     .unwrap()
+    ///     .tree;
+    /// let outer = tree.get_child(0).unwrap();
+    ///
+    /// assert_eq!(outer.area(), 100.0);
+    /// assert_eq!(outer.net_area(), 64.0);
+    /// ```
+    pub fn net_area(&self) -> f64 {
+        let own_area = self.polygon.signed_area().abs();
+        let own_area = if self.is_hole { -own_area } else { own_area };
+
+        own_area + self.children.iter().map(PolyTree::net_area).sum::<f64>()
+    }
+
+    /// Get the total filled area of all polygons below this node, which for
+    /// the root node of a tree is the filled area of the whole tree.
+    pub fn total_net_area(&self) -> f64 {
+        self.children.iter().map(PolyTree::net_area).sum()
+    }
+
+    /// Remove all nodes below this one whose [net area](PolyTree::net_area)
+    /// is smaller than `min_area`, such as micro islands and pinholes.
+    ///
+    /// A removed node takes all its descendants with it, a removed island
+    /// leaves its area empty and a removed hole is filled along with any
+    /// islands inside it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![
+    ///     Path::rectangle(0.0, 0.0, 10.0, 10.0),
+    ///     Path::rectangle(2.0, 2.0, 0.1, 0.1),
+    ///     Path::rectangle(20.0, 0.0, 0.1, 0.1),
+    /// ]
+    /// .into();
+    /// let mut tree = PolyTree::from_paths(&paths, FillRule::EvenOdd);
+    ///
+    /// tree.prune(1.0);
+    ///
+    /// assert_eq!(tree.iter().count(), 2);
+    /// assert_eq!(tree.total_net_area(), 100.0);
+    /// ```
+    pub fn prune(&mut self, min_area: f64) {
+        self.prune_nodes(min_area, false);
+    }
+
+    /// Remove all holes below this node whose [net area](PolyTree::net_area)
+    /// is smaller than `min_area`, filling them along with any islands inside
+    /// them. Outer polygons are kept regardless of their area.
+    pub fn prune_holes(&mut self, min_area: f64) {
+        self.prune_nodes(min_area, true);
+    }
+
+    fn prune_nodes(&mut self, min_area: f64, holes_only: bool) {
+        self.children
+            .retain(|child| (holes_only && !child.is_hole) || child.net_area().abs() >= min_area);
+
+        for child in &mut self.children {
+            child.prune_nodes(min_area, holes_only);
+        }
+    }
+
+    /// Replace every point of the polygons in this node and all its
+    /// descendants with the result of `f`, keeping the hierarchy.
+    ///
+    /// Transforms that mirror the geometry reverse the orientation of the
+    /// polygons, but the nodes keep their outer/hole roles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = Path::rectangle(0.0, 0.0, 1.0, 1.0).into();
+    /// let mut tree = PolyTree::from_paths(&paths, FillRule::NonZero);
+    ///
+    /// tree.map_points(|point| Point::new(point.x() * 2.0, point.y()));
+    ///
+    /// assert_eq!(tree.total_net_area(), 2.0);
+    /// ```
+    pub fn map_points(&mut self, mut f: impl FnMut(Point<P>) -> Point<P>) {
+        for polygon in self.iter_mut() {
+            *polygon = polygon.iter().map(|point| f(*point)).collect();
+        }
+    }
+
+    /// Move this node and all its descendants by a x/y distance.
+    pub fn translate(&mut self, x: f64, y: f64) {
+        self.map_points(|point| Point::new(point.x() + x, point.y() + y));
+    }
+
+    /// Scale this node and all its descendants with the origin at the center
+    /// of their [subtree bounds](PolyTree::subtree_bounds).
+    pub fn scale(&mut self, scale_x: f64, scale_y: f64) {
+        let center = self.subtree_bounds().center();
+        self.scale_around_point(scale_x, scale_y, center);
+    }
+
+    /// Scale this node and all its descendants with the origin at a given
+    /// point.
+    pub fn scale_around_point(&mut self, scale_x: f64, scale_y: f64, origin: Point<P>) {
+        self.map_points(|point| {
+            Point::new(
+                (point.x() - origin.x()) * scale_x + origin.x(),
+                (point.y() - origin.y()) * scale_y + origin.y(),
+            )
+        });
+    }
+
+    /// Rotate this node and all its descendants counter-clockwise around the
+    /// center of their [subtree bounds](PolyTree::subtree_bounds).
+    pub fn rotate(&mut self, radians: f64) {
+        let center = self.subtree_bounds().center();
+        let (sin, cos) = radians.sin_cos();
+
+        self.map_points(|point| {
+            let x = point.x() - center.x();
+            let y = point.y() - center.y();
+            Point::new(
+                x * cos - y * sin + center.x(),
+                x * sin + y * cos + center.y(),
+            )
+        });
+    }
+
+    /// Get the bounds of the polygon of this node. For nodes without a
+    /// polygon, such as the root, this is [`Bounds::minmax`].
+    pub fn bounds(&self) -> Bounds<P> {
+        self.polygon.bounds()
+    }
+
+    /// Get the bounds covering the polygons of this node and all its
+    /// descendants.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![
+    ///     Path::rectangle(0.0, 0.0, 1.0, 1.0),
+    ///     Path::rectangle(5.0, 5.0, 1.0, 1.0),
+    /// ]
+    /// .into();
+    /// let tree = PolyTree::from_paths(&paths, FillRule::NonZero);
+    ///
+    /// assert_eq!(tree.subtree_bounds().max, Point::new(6.0, 6.0));
+    /// ```
+    pub fn subtree_bounds(&self) -> Bounds<P> {
+        self.iter()
+            .map(PolyTree::bounds)
+            .fold(Bounds::minmax(), |bounds, node| Bounds {
+                min: Point::new(
+                    bounds.min.x().min(node.min.x()),
+                    bounds.min.y().min(node.min.y()),
+                ),
+                max: Point::new(
+                    bounds.max.x().max(node.max.x()),
+                    bounds.max.y().max(node.max.y()),
+                ),
+            })
+    }
+
+    /// Find the deepest node below this one whose polygon contains the
+    /// point, or `None` if the point is outside all polygons.
+    ///
+    /// A point inside a hole returns the hole node, and a point inside an
+    /// island in that hole returns the island. Points on the edge of a
+    /// polygon count as inside it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let outer: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+    /// let hole: Paths = Path::rectangle(2.0, 2.0, 6.0, 6.0).into();
+    ///
+    /// let tree = outer
+    ///     .to_clipper_subject()
+    ///     .add_clip(hole)
+    ///     .difference_tree(FillRule::default())
+    ///     .unwrap()
+    ///     .tree;
+    ///
+    /// assert!(!tree.locate_point(Point::new(1.0, 1.0)).unwrap().is_hole());
+    /// assert!(tree.locate_point(Point::new(5.0, 5.0)).unwrap().is_hole());
+    /// assert!(tree.locate_point(Point::new(15.0, 5.0)).is_none());
+    /// ```
+    pub fn locate_point(&self, point: Point<P>) -> Option<&PolyTree<P>> {
+        let mut found = None;
+        let mut node = self;
+
+        loop {
+            if !node.polygon.is_empty() {
+                found = Some(node);
+            }
+
+            match node.children.iter().find(|child| {
+                child.polygon.is_point_inside(point) != PointInPolygonResult::IsOutside
+            }) {
+                Some(child) => node = child,
+                None => return found,
+            }
+        }
+    }
+
+    /// Iterate over this node and all its descendants depth first, each node
+    /// is visited before its children.
+    ///
+    /// The root node of a tree returned by a boolean operation has an empty
+    /// polygon and is also yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let outer: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+    /// let hole: Paths = Path::rectangle(2.0, 2.0, 6.0, 6.0).into();
+    ///
+    /// let tree = outer
+    ///     .to_clipper_subject()
+    ///     .add_clip(hole)
+    ///     .difference_tree(FillRule::default())
+    ///     .unwrap()
+    ///     .tree;
+    ///
+    /// let holes = tree.iter().filter(|node| node.is_hole()).count();
+    /// assert_eq!(holes, 1);
+    /// ```
+    pub fn iter(&self) -> PolyTreeIter<'_, P> {
+        PolyTreeIter { stack: vec![self] }
+    }
+
+    /// Iterate over this node and all its descendants depth first together
+    /// with the [depth](PolyTree::depth) of each node.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let outer: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+    /// let hole: Paths = Path::rectangle(2.0, 2.0, 6.0, 6.0).into();
+    ///
+    /// let tree = outer
+    ///     .to_clipper_subject()
+    ///     .add_clip(hole)
+    ///     .difference_tree(FillRule::default())
+    ///     .unwrap()
+    ///     .tree;
+    ///
+    /// let depths = tree.iter_with_depth().map(|(depth, _)| depth).collect::<Vec<_>>();
+    /// assert_eq!(depths, vec![0, 1, 2]);
+    /// ```
+    pub fn iter_with_depth(&self) -> impl Iterator<Item = (usize, &PolyTree<P>)> {
+        self.iter().map(|node| (node.depth, node))
+    }
+
+    /// Iterate over the outer polygons in this node and its descendants,
+    /// each together with the holes directly inside it.
+    ///
+    /// Islands inside holes are yielded as outers of their own, so the
+    /// result can be handed directly to exporters or tessellators that take
+    /// one polygon with holes at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let outer: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+    /// let hole: Paths = Path::rectangle(2.0, 2.0, 6.0, 6.0).into();
+    ///
+    /// let tree = outer
+    ///     .to_clipper_subject()
+    ///     .add_clip(hole)
+    ///     .difference_tree(FillRule::default())
+    ///     .unwrap()
+    ///     .tree;
+    ///
+    /// let polygons = tree.outers_with_holes().collect::<Vec<_>>();
+    /// assert_eq!(polygons.len(), 1);
+    /// assert_eq!(polygons[0].1.len(), 1);
+    /// ```
+    pub fn outers_with_holes(&self) -> impl Iterator<Item = (Path<P>, Vec<Path<P>>)> + '_ {
+        self.iter()
+            .filter(|node| !node.is_hole && !node.polygon.is_empty())
+            .map(|node| {
+                let holes = node
+                    .children
+                    .iter()
+                    .filter(|child| child.is_hole)
+                    .map(|child| child.polygon.clone())
+                    .collect();
+                (node.polygon.clone(), holes)
+            })
+    }
+
+    /// Iterate over this node and all its descendants breadth first, all
+    /// nodes at one depth are visited before the nodes at the next depth.
+    pub fn iter_breadth_first(&self) -> PolyTreeBreadthFirstIter<'_, P> {
+        PolyTreeBreadthFirstIter {
+            queue: VecDeque::from([self]),
+        }
+    }
+
+    /// Iterate mutably over the polygons of this node and all its
+    /// descendants, in the same depth first order as [`PolyTree::iter`].
+    pub fn iter_mut(&mut self) -> PolyTreeIterMut<'_, P> {
+        PolyTreeIterMut { stack: vec![self] }
+    }
+
+    /// Iterate mutably over the polygons of this node and all its
+    /// descendants, in the same breadth first order as
+    /// [`PolyTree::iter_breadth_first`].
+    pub fn iter_breadth_first_mut(&mut self) -> PolyTreeBreadthFirstIterMut<'_, P> {
+        PolyTreeBreadthFirstIterMut {
+            queue: VecDeque::from([self]),
+        }
+    }
+}
+
+fn surrounds<P: PointScaler>(outer: &Path<P>, inner: &Path<P>) -> bool {
+    inner
+        .iter()
+        .map(|point| outer.is_point_inside(*point))
+        .find(|result| *result != PointInPolygonResult::IsOn)
+        .is_none_or(|result| result == PointInPolygonResult::IsInside)
+}
+
+fn build_children<P: PointScaler>(
+    paths: &Paths<P>,
+    areas: &[f64],
+    children: &[Vec<usize>],
+    parent: usize,
+    depth: usize,
+) -> Vec<PolyTree<P>> {
+    children[parent]
+        .iter()
+        .map(|&index| {
+            let is_hole = depth.is_multiple_of(2);
+            let polygon = if (areas[index] > 0.0) == is_hole {
+                paths[index].iter().rev().copied().collect()
+            } else {
+                paths[index].clone()
+            };
+
+            PolyTree {
+                children: build_children(paths, areas, children, index, depth + 1),
+                is_hole,
+                polygon,
+                depth,
+            }
+        })
+        .collect()
+}
+
+impl<'a, P: PointScaler> IntoIterator for &'a PolyTree<P> {
+    type Item = &'a PolyTree<P>;
+    type IntoIter = PolyTreeIter<'a, P>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Depth first iterator over the nodes of a [`PolyTree`], created by
+/// [`PolyTree::iter`].
+#[derive(Debug, Clone)]
+pub struct PolyTreeIter<'a, P: PointScaler> {
+    stack: Vec<&'a PolyTree<P>>,
+}
+
+impl<'a, P: PointScaler> Iterator for PolyTreeIter<'a, P> {
+    type Item = &'a PolyTree<P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.children.iter().rev());
+        Some(node)
+    }
+}
+
+/// Breadth first iterator over the nodes of a [`PolyTree`], created by
+/// [`PolyTree::iter_breadth_first`].
+#[derive(Debug, Clone)]
+pub struct PolyTreeBreadthFirstIter<'a, P: PointScaler> {
+    queue: VecDeque<&'a PolyTree<P>>,
+}
+
+impl<'a, P: PointScaler> Iterator for PolyTreeBreadthFirstIter<'a, P> {
+    type Item = &'a PolyTree<P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        self.queue.extend(node.children.iter());
+        Some(node)
+    }
+}
+
+/// Depth first iterator over mutable references to the polygons of a
+/// [`PolyTree`], created by [`PolyTree::iter_mut`].
+#[derive(Debug)]
+pub struct PolyTreeIterMut<'a, P: PointScaler> {
+    stack: Vec<&'a mut PolyTree<P>>,
+}
+
+impl<'a, P: PointScaler> Iterator for PolyTreeIterMut<'a, P> {
+    type Item = &'a mut Path<P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let PolyTree {
+            children, polygon, ..
+        } = self.stack.pop()?;
+        self.stack.extend(children.iter_mut().rev());
+        Some(polygon)
+    }
+}
+
+/// Breadth first iterator over mutable references to the polygons of a
+/// [`PolyTree`], created by [`PolyTree::iter_breadth_first_mut`].
+#[derive(Debug)]
+pub struct PolyTreeBreadthFirstIterMut<'a, P: PointScaler> {
+    queue: VecDeque<&'a mut PolyTree<P>>,
+}
+
+impl<'a, P: PointScaler> Iterator for PolyTreeBreadthFirstIterMut<'a, P> {
+    type Item = &'a mut Path<P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let PolyTree {
+            children, polygon, ..
+        } = self.queue.pop_front()?;
+        self.queue.extend(children.iter_mut());
+        Some(polygon)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, FillRule, Paths};
+
+    use super::*;
+
+    /// Two separate outers, the first with a hole containing an island.
+    fn nested_tree() -> PolyTree<Centi> {
+        Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(8.0, 2.0, -6.0, 6.0),
+            Path::rectangle(4.0, 4.0, 2.0, 2.0),
+            Path::rectangle(20.0, 0.0, 1.0, 1.0),
+        ])
+        .to_clipper_subject()
+        .add_clip(Paths::default())
+        .union_tree(FillRule::NonZero)
+        .unwrap()
+        .tree
+    }
+
+    fn areas<'a>(nodes: impl Iterator<Item = &'a PolyTree<Centi>>) -> Vec<f64> {
+        nodes.map(|node| node.area().abs()).collect()
+    }
+
+    #[test]
+    fn test_iter_depth_first() {
+        let tree = nested_tree();
+
+        assert_eq!(areas(tree.iter()), vec![0.0, 100.0, 36.0, 4.0, 1.0]);
+        assert_eq!(areas((&tree).into_iter()), areas(tree.iter()));
+    }
+
+    #[test]
+    fn test_depth() {
+        let tree = nested_tree();
+
+        let depths = tree
+            .iter_with_depth()
+            .map(|(depth, node)| (depth, node.is_hole()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            depths,
+            vec![(0, false), (1, false), (2, true), (3, false), (1, false)]
+        );
+        assert_eq!(tree.children[0].children[0].depth(), 2);
+    }
+
+    #[test]
+    fn test_iter_breadth_first() {
+        let tree = nested_tree();
+
+        assert_eq!(
+            areas(tree.iter_breadth_first()),
+            vec![0.0, 100.0, 1.0, 36.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn test_outers_with_holes() {
+        let tree = nested_tree();
+
+        let polygons = tree
+            .outers_with_holes()
+            .map(|(outer, holes)| {
+                (
+                    outer.signed_area(),
+                    holes.iter().map(|hole| hole.signed_area()).collect(),
+                )
+            })
+            .collect::<Vec<(f64, Vec<f64>)>>();
+        assert_eq!(
+            polygons,
+            vec![(100.0, vec![-36.0]), (4.0, vec![]), (1.0, vec![])]
+        );
+    }
+
+    #[test]
+    fn test_from_paths_even_odd() {
+        let paths = Paths::<Centi>::new(vec![
+            Path::rectangle(4.0, 4.0, 2.0, 2.0),
+            Path::rectangle(20.0, 0.0, 1.0, 1.0),
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(2.0, 2.0, 6.0, 6.0),
+        ]);
+        let tree = PolyTree::from_paths(&paths, FillRule::EvenOdd);
+
+        let nodes = tree
+            .iter()
+            .map(|node| (node.depth(), node.is_hole(), node.area()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            nodes,
+            vec![
+                (0, false, 0.0),
+                (1, false, 100.0),
+                (2, true, -36.0),
+                (3, false, 4.0),
+                (1, false, 1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_paths_non_zero_skips_redundant_paths() {
+        let paths = Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(1.0, 1.0, 8.0, 8.0),
+            Path::rectangle(8.0, 2.0, -6.0, 6.0),
+        ]);
+        let tree = PolyTree::from_paths(&paths, FillRule::NonZero);
+
+        let nodes = tree
+            .iter()
+            .map(|node| (node.is_hole(), node.area()))
+            .collect::<Vec<_>>();
+        assert_eq!(nodes, vec![(false, 0.0), (false, 100.0)]);
+    }
+
+    #[test]
+    fn test_from_paths_matches_union_tree() {
+        let tree = nested_tree();
+        let rebuilt = PolyTree::from_paths(&tree.to_paths(), FillRule::NonZero);
+
+        assert_eq!(
+            areas(rebuilt.iter_breadth_first()),
+            areas(tree.iter_breadth_first())
+        );
+    }
+
+    #[test]
+    fn test_net_area() {
+        let tree = nested_tree();
+
+        assert_eq!(tree.children[0].net_area(), 100.0 - 36.0 + 4.0);
+        assert_eq!(tree.children[0].children[0].net_area(), -36.0 + 4.0);
+        assert_eq!(tree.total_net_area(), 100.0 - 36.0 + 4.0 + 1.0);
+        assert_eq!(tree.total_net_area(), tree.net_area());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let tree = nested_tree();
+        let outer = &tree.children[0];
+
+        assert_eq!(tree.bounds(), Bounds::minmax());
+        assert_eq!(outer.bounds().max, Point::new(10.0, 10.0));
+        assert_eq!(outer.subtree_bounds(), outer.bounds());
+        assert_eq!(outer.children[0].bounds().min, Point::new(2.0, 2.0));
+        assert_eq!(tree.subtree_bounds().min, Point::new(0.0, 0.0));
+        assert_eq!(tree.subtree_bounds().max, Point::new(21.0, 10.0));
+    }
+
+    #[test]
+    fn test_locate_point() {
+        let tree = nested_tree();
+        let locate = |x, y| {
+            tree.locate_point(Point::new(x, y))
+                .map(|node| node.area().abs())
+        };
+
+        assert_eq!(locate(1.0, 1.0), Some(100.0));
+        assert_eq!(locate(3.0, 3.0), Some(36.0));
+        assert_eq!(locate(5.0, 5.0), Some(4.0));
+        assert_eq!(locate(20.5, 0.5), Some(1.0));
+        assert_eq!(locate(0.0, 5.0), Some(100.0));
+        assert_eq!(locate(15.0, 5.0), None);
+    }
+
+    #[test]
+    fn test_prune() {
+        let mut tree = nested_tree();
+        tree.prune(2.0);
+        assert_eq!(areas(tree.iter()), vec![0.0, 100.0, 36.0, 4.0]);
+
+        let mut tree = nested_tree();
+        tree.prune(50.0);
+        assert_eq!(areas(tree.iter()), vec![0.0, 100.0]);
+    }
+
+    #[test]
+    fn test_prune_holes() {
+        let mut tree = nested_tree();
+        tree.prune_holes(50.0);
+
+        assert_eq!(areas(tree.iter()), vec![0.0, 100.0, 1.0]);
+        assert_eq!(tree.total_net_area(), 101.0);
+    }
+
+    #[test]
+    fn test_transform() {
+        let mut tree = nested_tree();
+        let net_area = tree.total_net_area();
+
+        tree.translate(-10.5, -5.0);
+        assert_eq!(tree.subtree_bounds().min, Point::new(-10.5, -5.0));
+        assert_eq!(tree.subtree_bounds().center(), Point::new(0.0, 0.0));
+
+        tree.rotate(std::f64::consts::FRAC_PI_2);
+        let bounds = tree.subtree_bounds();
+        assert_eq!(bounds.min, Point::new(-5.0, -10.5));
+        assert_eq!(bounds.max, Point::new(5.0, 10.5));
+        assert_eq!(
+            tree.children[0].children[0].bounds().min,
+            Point::new(-3.0, -8.5)
+        );
+        assert_eq!(tree.total_net_area(), net_area);
+
+        tree.scale(2.0, 0.5);
+        assert_eq!(tree.subtree_bounds().max, Point::new(10.0, 5.25));
+        assert_eq!(tree.total_net_area(), net_area);
+    }
+
+    #[test]
+    fn test_clone_and_eq() {
+        let tree = nested_tree();
+        let mut copy = tree.clone();
+        assert_eq!(copy, tree);
+
+        copy.children[0].children[0].is_hole = false;
+        assert_ne!(copy, tree);
+
+        let mut copy = tree.clone();
+        copy.translate(0.01, 0.0);
+        assert_ne!(copy, tree);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut tree = nested_tree();
+
+        for polygon in tree.iter_mut() {
+            *polygon = polygon.translate(1.0, 0.0);
+        }
+        assert_eq!(tree.children[1].polygon.bounds().min.x(), 21.0);
+
+        let count = tree.iter_breadth_first_mut().count();
+        assert_eq!(count, 5);
+    }
+}
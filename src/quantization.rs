@@ -0,0 +1,175 @@
+use crate::{Path, Paths, Point, PointScaler};
+
+/// The rounding error of a single path, see [`QuantizationReport`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PathQuantization {
+    /// Number of points in the path
+    pub points: usize,
+    /// Largest distance between an input point and its scaled point, in
+    /// user units
+    pub max_error: f64,
+    /// Root mean square of the distances between the input points and their
+    /// scaled points, in user units
+    pub rms_error: f64,
+}
+
+/// The rounding error introduced by the [`PointScaler`] when converting
+/// coordinates to paths, as returned by [`Paths::from_f64_with_report`].
+///
+/// The error of a point is the distance between the input point and the
+/// point stored after scaling and rounding, so it is at most half the
+/// diagonal of a grid cell of the scaler, `0.5 * sqrt(2) / MULTIPLIER`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuantizationReport {
+    /// The error of each path, in the order of the paths.
+    pub paths: Vec<PathQuantization>,
+}
+
+impl QuantizationReport {
+    /// Returns the largest error of all points, or zero without points.
+    pub fn max_error(&self) -> f64 {
+        self.paths
+            .iter()
+            .map(|path| path.max_error)
+            .fold(0.0, f64::max)
+    }
+
+    /// Returns the root mean square error of all points, or zero without
+    /// points.
+    pub fn rms_error(&self) -> f64 {
+        let points = self.paths.iter().map(|path| path.points).sum::<usize>();
+        if points == 0 {
+            return 0.0;
+        }
+
+        let squares = self
+            .paths
+            .iter()
+            .map(|path| path.rms_error.powi(2) * path.points as f64)
+            .sum::<f64>();
+        (squares / points as f64).sqrt()
+    }
+
+    /// Returns `true` if no point moved further than `tolerance`.
+    pub fn is_within(&self, tolerance: f64) -> bool {
+        self.max_error() <= tolerance
+    }
+}
+
+impl<P: PointScaler> Paths<P> {
+    /// Create new paths from coordinates in user units, like converting
+    /// them with [`From`], and report the rounding error the scaler
+    /// introduced for each path.
+    ///
+    /// The report documents that the input was represented within a given
+    /// tolerance before any operation ran, see [`QuantizationReport`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let (paths, report) = Paths::<Centi>::from_f64_with_report(vec![
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)],
+    ///     vec![(0.004, 0.0), (1.0, 0.003), (1.0, 1.0)],
+    /// ]);
+    ///
+    /// assert_eq!(paths.len(), 2);
+    /// assert_eq!(report.paths[0].max_error, 0.0);
+    /// assert!((report.paths[1].max_error - 0.004).abs() < 1e-12);
+    /// assert!(report.is_within(0.005));
+    /// ```
+    pub fn from_f64_with_report<T: Into<[f64; 2]>>(
+        paths: impl IntoIterator<Item = impl IntoIterator<Item = T>>,
+    ) -> (Self, QuantizationReport) {
+        let mut report = QuantizationReport::default();
+
+        let paths = paths
+            .into_iter()
+            .map(|points| {
+                let mut quantization = PathQuantization::default();
+                let mut squares = 0.0;
+
+                let path = points
+                    .into_iter()
+                    .map(|point| {
+                        let [x, y] = point.into();
+                        let scaled = Point::<P>::new(x, y);
+                        let error = (scaled.x() - x).hypot(scaled.y() - y);
+
+                        quantization.points += 1;
+                        quantization.max_error = quantization.max_error.max(error);
+                        squares += error * error;
+                        scaled
+                    })
+                    .collect::<Path<P>>();
+
+                if quantization.points > 0 {
+                    quantization.rms_error = (squares / quantization.points as f64).sqrt();
+                }
+                report.paths.push(quantization);
+                path
+            })
+            .collect();
+
+        (paths, report)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, Deci, One};
+
+    use super::*;
+
+    #[test]
+    fn test_exact_coordinates() {
+        let (paths, report) =
+            Paths::<Centi>::from_f64_with_report(vec![vec![[0.25, 0.5], [1.0, 2.0]]]);
+
+        assert_eq!(paths, Paths::from(vec![(0.25, 0.5), (1.0, 2.0)]));
+        assert_eq!(
+            report.paths,
+            vec![PathQuantization {
+                points: 2,
+                max_error: 0.0,
+                rms_error: 0.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_max_and_rms_error() {
+        let (paths, report) = Paths::<One>::from_f64_with_report(vec![
+            vec![(0.0, 0.0), (0.3, 0.4), (2.0, 0.0), (0.0, 0.0)],
+            vec![(0.0, 0.25)],
+        ]);
+
+        assert_eq!(paths[0][1], Point::from_scaled(0, 0));
+        assert!((report.paths[0].max_error - 0.5).abs() < 1e-12);
+        assert!((report.paths[0].rms_error - 0.25).abs() < 1e-12);
+        assert_eq!(report.paths[1].max_error, 0.25);
+
+        assert!((report.max_error() - 0.5).abs() < 1e-12);
+        // (0.25 + 0.0625) / 5 points.
+        assert!((report.rms_error() - 0.0625_f64.sqrt()).abs() < 1e-12);
+        assert!(!report.is_within(0.4));
+    }
+
+    #[test]
+    fn test_bounded_by_scaler() {
+        let coordinates = (0..100)
+            .map(|i| vec![(f64::from(i) * 0.0137, f64::from(i) * -0.0291)])
+            .collect::<Vec<_>>();
+
+        let (_, report) = Paths::<Deci>::from_f64_with_report(coordinates);
+
+        assert_eq!(report.paths.len(), 100);
+        assert!(report.is_within(0.5 * 2.0_f64.sqrt() / 10.0));
+        assert!(report.rms_error() <= report.max_error());
+
+        let (paths, report) = Paths::<Deci>::from_f64_with_report(Vec::<Vec<(f64, f64)>>::new());
+        assert!(paths.is_empty());
+        assert_eq!((report.max_error(), report.rms_error()), (0.0, 0.0));
+    }
+}
@@ -0,0 +1,484 @@
+//! Scan conversion of paths into grids of cells, for occupancy maps and
+//! preview thumbnails.
+//!
+//! A [`Grid`] places the cells in the coordinate space of the paths.
+//! [`rasterize`] marks the cells whose center is filled, while
+//! [`rasterize_coverage`] computes the fraction of each cell that is filled
+//! for anti-aliased output. Both honor the fill rule, so holes stay empty.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use clipper2::*;
+//! use clipper2::raster::{rasterize, rasterize_coverage, Grid};
+//!
+//! let shape: Paths = vec![
+//!     Path::rectangle(0.0, 0.0, 4.0, 4.0),
+//!     Path::rectangle(1.0, 1.0, 2.0, 2.0).iter().rev().copied().collect(),
+//! ]
+//! .into();
+//!
+//! let grid = Grid::covering(shape.bounds(), 1.0);
+//! let occupancy = rasterize(&shape, &grid, FillRule::NonZero);
+//!
+//! assert_eq!((occupancy.width(), occupancy.height()), (4, 4));
+//! assert_eq!(occupancy.get(0, 0), Some(true));
+//! assert_eq!(occupancy.get(1, 1), Some(false));
+//! assert_eq!(occupancy.count(), 12);
+//!
+//! let coverage = rasterize_coverage(&shape, &Grid::covering(shape.bounds(), 2.0), FillRule::NonZero);
+//! assert_eq!(coverage.get(0, 0), Some(0.75));
+//! ```
+
+use crate::{Bounds, Centi, FillRule, Paths, Point, PointScaler, PolyTree};
+
+/// Number of scanlines per row of cells used by [`rasterize_coverage`]. The
+/// coverage is exact horizontally and sampled vertically, so it is accurate
+/// to 1/16 of a cell.
+pub const COVERAGE_SAMPLES: usize = 16;
+
+/// Placement of a grid of square cells in the coordinate space of the paths.
+///
+/// Cell `(0, 0)` has its minimum corner at the origin, and cell `(x, y)`
+/// covers the square from `origin + (x, y) * cell_size` to
+/// `origin + (x + 1, y + 1) * cell_size`. With the y axis pointing down, as
+/// in images, row 0 is the top row.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Grid<P: PointScaler = Centi> {
+    /// Minimum corner of the first cell.
+    pub origin: Point<P>,
+    /// Width and height of each cell.
+    pub cell_size: f64,
+    /// Number of cells along the x axis.
+    pub width: usize,
+    /// Number of cells along the y axis.
+    pub height: usize,
+}
+
+impl<P: PointScaler> Grid<P> {
+    /// Create a grid of `width` by `height` cells starting at the origin.
+    pub fn new(origin: Point<P>, cell_size: f64, width: usize, height: usize) -> Self {
+        Self {
+            origin,
+            cell_size,
+            width,
+            height,
+        }
+    }
+
+    /// Create the smallest grid with the given cell size that covers the
+    /// bounds, starting at their minimum point.
+    pub fn covering(bounds: Bounds<P>, cell_size: f64) -> Self {
+        let size = bounds.size();
+        let cells = |length: f64| (length / cell_size).ceil().max(0.0) as usize;
+
+        Self::new(bounds.min, cell_size, cells(size.x()), cells(size.y()))
+    }
+
+    /// Returns the area covered by a cell.
+    pub fn cell_bounds(&self, x: usize, y: usize) -> Bounds<P> {
+        let corner = |x: usize, y: usize| {
+            Point::new(
+                self.origin.x() + x as f64 * self.cell_size,
+                self.origin.y() + y as f64 * self.cell_size,
+            )
+        };
+
+        Bounds {
+            min: corner(x, y),
+            max: corner(x + 1, y + 1),
+        }
+    }
+
+    /// Returns the cell containing the point, or `None` if the point lies
+    /// outside the grid.
+    pub fn cell_at(&self, point: Point<P>) -> Option<(usize, usize)> {
+        let (x, y) = self.project(point);
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+
+        let (x, y) = (x as usize, y as usize);
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    fn project(&self, point: Point<P>) -> (f64, f64) {
+        (
+            (point.x() - self.origin.x()) / self.cell_size,
+            (point.y() - self.origin.y()) / self.cell_size,
+        )
+    }
+}
+
+/// Values of the cells of a [`Grid`], stored row by row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Raster<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Copy> Raster<T> {
+    /// Returns the number of cells along the x axis.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of cells along the y axis.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the value of a cell, or `None` if it lies outside the raster.
+    pub fn get(&self, x: usize, y: usize) -> Option<T> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    /// Returns the values of all cells, row by row starting at row 0.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Returns an iterator over the rows of cells, starting at row 0.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+}
+
+impl Raster<bool> {
+    /// Returns the number of filled cells.
+    pub fn count(&self) -> usize {
+        self.cells.iter().filter(|&&filled| filled).count()
+    }
+}
+
+impl Raster<f32> {
+    /// Returns the cells covered by at least `threshold`.
+    pub fn threshold(&self, threshold: f32) -> Raster<bool> {
+        Raster {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(|&value| value >= threshold).collect(),
+        }
+    }
+}
+
+/// Mark the cells of the grid whose center is filled by the closed paths
+/// according to the fill rule. Open paths enclose no area and are ignored.
+pub fn rasterize<P: PointScaler>(
+    paths: &Paths<P>,
+    grid: &Grid<P>,
+    fill_rule: FillRule,
+) -> Raster<bool> {
+    let mut cells = vec![false; grid.width * grid.height];
+    let mut scanner = Scanner::new(paths, grid, fill_rule);
+
+    for (y, row) in cells.chunks_mut(grid.width.max(1)).enumerate() {
+        scanner.spans(y as f64 + 0.5, |start, end| {
+            let first = (start - 0.5).ceil().max(0.0) as usize;
+            let last = ((end - 0.5).ceil().max(0.0) as usize).min(grid.width);
+            for cell in row.iter_mut().take(last).skip(first) {
+                *cell = true;
+            }
+        });
+    }
+
+    Raster {
+        width: grid.width,
+        height: grid.height,
+        cells,
+    }
+}
+
+/// Compute the fraction of each cell of the grid that is filled by the
+/// closed paths according to the fill rule, from 0.0 for empty cells to 1.0
+/// for fully covered ones, see [`COVERAGE_SAMPLES`]. Open paths enclose no
+/// area and are ignored.
+pub fn rasterize_coverage<P: PointScaler>(
+    paths: &Paths<P>,
+    grid: &Grid<P>,
+    fill_rule: FillRule,
+) -> Raster<f32> {
+    let mut cells = vec![0.0; grid.width * grid.height];
+    let mut scanner = Scanner::new(paths, grid, fill_rule);
+    let mut sums = vec![0.0f64; grid.width];
+    let weight = 1.0 / COVERAGE_SAMPLES as f64;
+
+    for (y, row) in cells.chunks_mut(grid.width.max(1)).enumerate() {
+        sums.fill(0.0);
+        for sample in 0..COVERAGE_SAMPLES {
+            let scanline = y as f64 + (sample as f64 + 0.5) * weight;
+            scanner.spans(scanline, |start, end| {
+                let (start, end) = (start.max(0.0), end.min(grid.width as f64));
+                if start >= end {
+                    return;
+                }
+                for (x, sum) in sums
+                    .iter_mut()
+                    .enumerate()
+                    .take(end.ceil() as usize)
+                    .skip(start.floor() as usize)
+                {
+                    let overlap = end.min(x as f64 + 1.0) - start.max(x as f64);
+                    *sum += overlap * weight;
+                }
+            });
+        }
+
+        for (cell, sum) in row.iter_mut().zip(&sums) {
+            *cell = sum.min(1.0) as f32;
+        }
+    }
+
+    Raster {
+        width: grid.width,
+        height: grid.height,
+        cells,
+    }
+}
+
+/// Mark the cells of the grid whose center is filled by the polygons of the
+/// tree, see [`rasterize`]. Holes of the tree stay empty and islands inside
+/// them are filled again.
+pub fn rasterize_tree<P: PointScaler>(tree: &PolyTree<P>, grid: &Grid<P>) -> Raster<bool> {
+    rasterize(&tree.to_paths(), grid, FillRule::EvenOdd)
+}
+
+/// Compute the fraction of each cell of the grid that is filled by the
+/// polygons of the tree, see [`rasterize_coverage`].
+pub fn rasterize_tree_coverage<P: PointScaler>(tree: &PolyTree<P>, grid: &Grid<P>) -> Raster<f32> {
+    rasterize_coverage(&tree.to_paths(), grid, FillRule::EvenOdd)
+}
+
+/// An edge in grid coordinates, with `y0 < y1`.
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    x0: f64,
+    y0: f64,
+    x1: f64,
+    y1: f64,
+    /// Winding contribution when crossing the edge from left to right,
+    /// positive for edges pointing down so that paths with a positive area
+    /// have a positive winding number inside.
+    winding: i64,
+}
+
+/// Finds the filled spans of scanlines with increasing y coordinates, keeping
+/// only the edges crossing the current scanline active.
+struct Scanner {
+    edges: Vec<Edge>,
+    next: usize,
+    active: Vec<Edge>,
+    crossings: Vec<(f64, i64)>,
+    fill_rule: FillRule,
+}
+
+impl Scanner {
+    fn new<P: PointScaler>(paths: &Paths<P>, grid: &Grid<P>, fill_rule: FillRule) -> Self {
+        let mut edges = Vec::new();
+
+        for (index, path) in paths.iter().enumerate() {
+            if paths.is_open(index) || path.len() < 3 {
+                continue;
+            }
+
+            let points = path
+                .iter()
+                .map(|&point| grid.project(point))
+                .collect::<Vec<_>>();
+            for (i, &start) in points.iter().enumerate() {
+                let end = points[(i + 1) % points.len()];
+                if start.1 == end.1 {
+                    continue;
+                }
+
+                let (low, high, winding) = if start.1 < end.1 {
+                    (start, end, -1)
+                } else {
+                    (end, start, 1)
+                };
+                edges.push(Edge {
+                    x0: low.0,
+                    y0: low.1,
+                    x1: high.0,
+                    y1: high.1,
+                    winding,
+                });
+            }
+        }
+
+        edges.sort_by(|a, b| a.y0.total_cmp(&b.y0));
+
+        Self {
+            edges,
+            next: 0,
+            active: Vec::new(),
+            crossings: Vec::new(),
+            fill_rule,
+        }
+    }
+
+    /// Call `span` with the start and end x coordinates of each filled span
+    /// of the scanline. Scanlines must be passed in increasing order.
+    fn spans(&mut self, scanline: f64, mut span: impl FnMut(f64, f64)) {
+        while self.next < self.edges.len() && self.edges[self.next].y0 <= scanline {
+            self.active.push(self.edges[self.next]);
+            self.next += 1;
+        }
+        self.active.retain(|edge| edge.y1 > scanline);
+
+        self.crossings.clear();
+        self.crossings.extend(self.active.iter().map(|edge| {
+            let t = (scanline - edge.y0) / (edge.y1 - edge.y0);
+            (edge.x0 + t * (edge.x1 - edge.x0), edge.winding)
+        }));
+        self.crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut winding = 0;
+        let mut start = None;
+        for &(x, change) in &self.crossings {
+            winding += change;
+            match (start, self.fill_rule.is_filled(winding)) {
+                (None, true) => start = Some(x),
+                (Some(from), false) => {
+                    span(from, x);
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Path;
+
+    use super::*;
+
+    fn rows(raster: &Raster<bool>) -> Vec<String> {
+        raster
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|&filled| if filled { '#' } else { '.' })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rasterize_fill_rules() {
+        let paths = Paths::<Centi>::from(vec![
+            Path::rectangle(0.0, 0.0, 4.0, 4.0),
+            Path::rectangle(1.0, 1.0, 2.0, 2.0),
+        ]);
+        let grid = Grid::covering(paths.bounds(), 1.0);
+
+        assert_eq!(
+            rows(&rasterize(&paths, &grid, FillRule::EvenOdd)),
+            vec!["####", "#..#", "#..#", "####"]
+        );
+        assert_eq!(rasterize(&paths, &grid, FillRule::NonZero).count(), 16);
+        assert_eq!(rasterize(&paths, &grid, FillRule::Positive).count(), 16);
+        assert_eq!(rasterize(&paths, &grid, FillRule::Negative).count(), 0);
+    }
+
+    #[test]
+    fn test_rasterize_matches_point_in_polygon() {
+        let paths = Paths::<Centi>::from(vec![
+            vec![(0.3, 0.1), (9.7, 2.2), (4.1, 9.6)],
+            vec![(2.0, 5.0), (8.0, 5.5), (8.0, 8.0), (3.0, 9.0)],
+        ]);
+        let grid = Grid::new(Point::new(-0.5, -0.5), 0.5, 22, 22);
+
+        for fill_rule in [FillRule::EvenOdd, FillRule::NonZero] {
+            let raster = rasterize(&paths, &grid, fill_rule);
+            let filled = paths.make_valid(fill_rule).unwrap();
+
+            for y in 0..grid.height {
+                for x in 0..grid.width {
+                    let center = grid.cell_bounds(x, y).center();
+                    let expected = filled.iter().fold(false, |inside, path| {
+                        inside
+                            ^ (path.is_point_inside(center)
+                                == crate::PointInPolygonResult::IsInside)
+                    });
+                    assert_eq!(raster.get(x, y), Some(expected), "cell {x} {y}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rasterize_coverage() {
+        let triangle = Paths::<Centi>::from(vec![(0.0, 0.0), (2.0, 0.0), (0.0, 2.0)]);
+        let grid = Grid::covering(triangle.bounds(), 1.0);
+
+        let coverage = rasterize_coverage(&triangle, &grid, FillRule::NonZero);
+
+        assert_eq!(coverage.get(0, 0), Some(1.0));
+        assert_eq!(coverage.get(1, 1), Some(0.0));
+        assert!((coverage.get(1, 0).unwrap() - 0.5).abs() < 0.01);
+        assert!((coverage.get(0, 1).unwrap() - 0.5).abs() < 0.01);
+
+        let total = coverage
+            .cells()
+            .iter()
+            .map(|&value| value as f64)
+            .sum::<f64>();
+        assert!((total - triangle.signed_area()).abs() < 0.01);
+
+        assert_eq!(
+            rows(&coverage.threshold(0.5)),
+            vec!["##".to_string(), "#.".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_rasterize_tree_and_open_paths() {
+        let tree = PolyTree::from_paths(
+            &Paths::<Centi>::from(vec![
+                Path::rectangle(0.0, 0.0, 5.0, 5.0),
+                Path::rectangle(1.0, 1.0, 3.0, 3.0),
+                Path::rectangle(2.0, 2.0, 1.0, 1.0),
+            ]),
+            FillRule::EvenOdd,
+        );
+        let grid = Grid::<Centi>::new(Point::new(0.0, 0.0), 1.0, 5, 5);
+
+        assert_eq!(
+            rows(&rasterize_tree(&tree, &grid)),
+            vec!["#####", "#...#", "#.#.#", "#...#", "#####"]
+        );
+        assert_eq!(
+            rasterize_tree_coverage(&tree, &grid).threshold(1.0).count(),
+            17
+        );
+
+        let mut lines = Paths::<Centi>::default();
+        lines.push_open(Path::rectangle(0.0, 0.0, 5.0, 5.0));
+        assert_eq!(rasterize(&lines, &grid, FillRule::NonZero).count(), 0);
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::<Centi>::covering(Bounds::new(10.0, 4.5), 2.0);
+
+        assert_eq!((grid.width, grid.height), (5, 3));
+        assert_eq!(grid.cell_at(Point::new(3.0, 4.9)), Some((1, 2)));
+        assert_eq!(grid.cell_at(Point::new(-0.1, 1.0)), None);
+        assert_eq!(grid.cell_at(Point::new(10.0, 1.0)), None);
+        assert_eq!(grid.cell_bounds(1, 2).max, Point::new(4.0, 6.0));
+
+        let empty = rasterize(
+            &Paths::<Centi>::default(),
+            &Grid::covering(Bounds::default(), 1.0),
+            FillRule::NonZero,
+        );
+        assert_eq!(
+            (empty.width(), empty.height(), empty.rows().count()),
+            (0, 0, 0)
+        );
+    }
+}
@@ -0,0 +1,1344 @@
+//! Safe wrappers around the objects and functions of the native clipper2c
+//! library.
+//!
+//! The rest of this crate converts between [`Path`]/[`Paths`] and the native
+//! objects for each operation. The types in this module keep the native
+//! objects instead, so several native functions can be chained without
+//! converting in between, and the functions the high level API doesn't cover,
+//! such as Minkowski sums or Ramer-Douglas-Peucker simplification, can be
+//! used without writing unsafe code.
+//!
+//! Coordinates are the scaled integers of the native library, convert with
+//! [`Path64::to_path`]/[`Paths64::to_paths`] and the `From` implementations
+//! to apply a [`PointScaler`]. Each wrapper owns its native object and frees
+//! it when dropped. The floating point variants of the native API are not
+//! wrapped, as this crate does its own scaling.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use clipper2::raw::{Path64, Paths64, Point64};
+//! use clipper2::*;
+//!
+//! let square = Path64::from_points(&[
+//!     Point64 { x: 0, y: 0 },
+//!     Point64 { x: 100, y: 0 },
+//!     Point64 { x: 100, y: 100 },
+//!     Point64 { x: 0, y: 100 },
+//! ]);
+//! let brush = Path64::ellipse(Point64 { x: 0, y: 0 }, 10.0, 10.0, 0);
+//!
+//! let swept = square.minkowski_sum(&brush, true);
+//! let outline = Paths64::from_iter(swept.iter())
+//!     .boolean(ClipType::Union, FillRule::NonZero, &Paths64::new());
+//!
+//! assert_eq!(outline.len(), 2);
+//! // A band around the outline of the square, 20 units wide.
+//! assert!(outline.area() > 120.0 * 120.0 - 4.0 * 100.0 - 80.0 * 80.0);
+//!
+//! let converted: Paths<Centi> = outline.to_paths();
+//! assert_eq!(converted.len(), 2);
+//! ```
+
+use std::marker::PhantomData;
+
+use clipper2c_sys::{
+    clipper_clipper64, clipper_clipper64_add_clip, clipper_clipper64_add_open_subject,
+    clipper_clipper64_add_subject, clipper_clipper64_clear, clipper_clipper64_execute,
+    clipper_clipper64_execute_tree_with_open, clipper_clipper64_get_preserve_collinear,
+    clipper_clipper64_get_reverse_solution, clipper_clipper64_set_preserve_collinear,
+    clipper_clipper64_set_reverse_solution, clipper_clipper64_size, clipper_clipperoffset,
+    clipper_clipperoffset_add_path64, clipper_clipperoffset_add_paths64,
+    clipper_clipperoffset_clear, clipper_clipperoffset_error_code, clipper_clipperoffset_execute,
+    clipper_clipperoffset_get_arc_tolerance, clipper_clipperoffset_get_miter_limit,
+    clipper_clipperoffset_get_preserve_collinear, clipper_clipperoffset_get_reverse_solution,
+    clipper_clipperoffset_set_arc_tolerance, clipper_clipperoffset_set_miter_limit,
+    clipper_clipperoffset_set_preserve_collinear, clipper_clipperoffset_set_reverse_solution,
+    clipper_clipperoffset_size, clipper_path64, clipper_path64_add_point, clipper_path64_area,
+    clipper_path64_get_point, clipper_path64_length, clipper_path64_of_points,
+    clipper_path64_simplify, clipper_path64_size, clipper_paths64, clipper_paths64_add_path,
+    clipper_paths64_add_paths, clipper_paths64_area, clipper_paths64_get_path,
+    clipper_paths64_get_point, clipper_paths64_inflate, clipper_paths64_length,
+    clipper_paths64_path_length, clipper_paths64_simplify, clipper_paths64_size,
+    clipper_point_in_path64, clipper_polytree64, clipper_polytree64_area, clipper_polytree64_count,
+    clipper_polytree64_get_child, clipper_polytree64_is_hole, clipper_polytree64_polygon,
+    clipper_polytree64_size, clipper_polytree64_to_paths, ClipperClipper64, ClipperClipperOffset,
+    ClipperPath64, ClipperPaths64, ClipperPolyTree64,
+};
+
+use crate::{
+    ffi::{
+        clipper_destruct_clipperoffset, clipper_path64_bounds, clipper_path64_ellipse,
+        clipper_path64_is_positive, clipper_path64_minkowski_diff, clipper_path64_minkowski_sum,
+        clipper_path64_ramer_douglas_peucker, clipper_path64_rect_clip,
+        clipper_path64_rect_clip_line, clipper_path64_reserve, clipper_path64_scale,
+        clipper_path64_strip_duplicates, clipper_path64_strip_near_equal, clipper_path64_to_points,
+        clipper_path64_translate, clipper_path64_trim_collinear, clipper_paths64_boolean_op,
+        clipper_paths64_boolean_op_tree, clipper_paths64_bounds, clipper_paths64_minkowski_diff,
+        clipper_paths64_minkowski_sum, clipper_paths64_ramer_douglas_peucker,
+        clipper_paths64_rect_clip, clipper_paths64_rect_clip_lines, clipper_paths64_reserve,
+        clipper_paths64_scale, clipper_paths64_strip_duplicates, clipper_paths64_strip_near_equal,
+        clipper_paths64_translate, clipper_point64_distance, clipper_point64_near_collinear,
+        clipper_polytree64_clear, clipper_polytree64_fully_contains_children,
+        clipper_polytree64_level,
+    },
+    malloc,
+    memory::{delete_clipper64, delete_path64, delete_paths64, delete_polytree64, free},
+    pipeline::{in_range, native_bounds},
+    ClipType, ClipperError, EndType, FillRule, JoinType, Path, Paths, PointInPolygonResult,
+    PointScaler, PolyTree,
+};
+
+pub use crate::ffi::ClipperRect64 as Rect64;
+pub use clipper2c_sys::ClipperPoint64 as Point64;
+
+/// A path owned by the native library, the native `Path64`.
+pub struct Path64 {
+    ptr: *mut ClipperPath64,
+}
+
+/// Paths owned by the native library, the native `Paths64`.
+pub struct Paths64 {
+    ptr: *mut ClipperPaths64,
+}
+
+// The native objects are owned exclusively by their wrappers, so they can be
+// moved to another thread.
+unsafe impl Send for Path64 {}
+unsafe impl Send for Paths64 {}
+
+impl Path64 {
+    /// Create an empty path.
+    pub fn new() -> Self {
+        unsafe { Self::from_ptr(clipper_path64(malloc(clipper_path64_size()))) }
+    }
+
+    /// Create a path from points.
+    pub fn from_points(points: &[Point64]) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_path64_of_points(
+                malloc(clipper_path64_size()),
+                points.as_ptr().cast_mut(),
+                points.len(),
+            ))
+        }
+    }
+
+    /// Create an ellipse around `center`. With `steps` of zero the number of
+    /// points is derived from the radii.
+    pub fn ellipse(center: Point64, radius_x: f64, radius_y: f64, steps: usize) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_path64_ellipse(
+                malloc(clipper_path64_size()),
+                center,
+                radius_x,
+                radius_y,
+                steps.min(libc::c_int::MAX as usize) as libc::c_int,
+            ))
+        }
+    }
+
+    /// Take ownership of a native path allocated with [`malloc`].
+    unsafe fn from_ptr(ptr: *mut ClipperPath64) -> Self {
+        Self { ptr }
+    }
+
+    /// Returns the pointer to the native path, to call native functions that
+    /// aren't wrapped. The path stays owned by this wrapper.
+    pub fn as_ptr(&self) -> *mut ClipperPath64 {
+        self.ptr
+    }
+
+    /// Append a point to the path.
+    pub fn push(&mut self, point: Point64) {
+        unsafe { clipper_path64_add_point(self.ptr, point) }
+    }
+
+    /// Reserve room for `capacity` points in total.
+    pub fn reserve(&mut self, capacity: usize) {
+        unsafe { clipper_path64_reserve(self.ptr, capacity) }
+    }
+
+    /// Returns the number of points.
+    pub fn len(&self) -> usize {
+        unsafe { clipper_path64_length(self.ptr) }
+    }
+
+    /// Returns `true` if the path has no points.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the point at `index`, or `None` if it's out of bounds.
+    pub fn get(&self, index: usize) -> Option<Point64> {
+        (index < self.len()).then(|| unsafe { clipper_path64_get_point(self.ptr, index as _) })
+    }
+
+    /// Copy all points out of the native path.
+    pub fn to_points(&self) -> Vec<Point64> {
+        let len = self.len();
+        let mut points = Vec::<Point64>::with_capacity(len);
+        unsafe {
+            clipper_path64_to_points(points.as_mut_ptr().cast(), self.ptr);
+            points.set_len(len);
+        }
+        points
+    }
+
+    /// Convert to a [`Path`], interpreting the coordinates with the scaler.
+    pub fn to_path<P: PointScaler>(&self) -> Path<P> {
+        Path::from_clipperpath64(self.ptr)
+    }
+
+    /// Returns the signed area, positive for counter-clockwise paths.
+    pub fn area(&self) -> f64 {
+        unsafe { clipper_path64_area(self.ptr) }
+    }
+
+    /// Returns `true` if the area of the path is positive.
+    pub fn is_positive(&self) -> bool {
+        unsafe { clipper_path64_is_positive(self.ptr) != 0 }
+    }
+
+    /// Returns whether the point is inside, outside or on the closed path.
+    pub fn point_in_polygon(&self, point: Point64) -> PointInPolygonResult {
+        unsafe { clipper_point_in_path64(self.ptr, point).into() }
+    }
+
+    /// Returns the bounds of the path. An empty path has inverted bounds from
+    /// `i64::MAX` to `i64::MIN`.
+    pub fn bounds(&self) -> Rect64 {
+        let mut bounds = Rect64::default();
+        unsafe { clipper_path64_bounds((&mut bounds as *mut Rect64).cast(), self.ptr) };
+        bounds
+    }
+
+    /// Returns a copy moved by whole scaled units.
+    pub fn translate(&self, dx: i64, dy: i64) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_path64_translate(
+                malloc(clipper_path64_size()),
+                self.ptr,
+                dx,
+                dy,
+            ))
+        }
+    }
+
+    /// Returns a copy scaled around the origin, see [`Paths64::scale`].
+    pub fn scale(&self, scale_x: f64, scale_y: f64) -> Result<Self, ClipperError> {
+        check_scale(scale_x, scale_y)?;
+        if self.is_empty() {
+            return Ok(self.clone());
+        }
+        if !in_range(self.bounds(), scale_x, scale_y) {
+            return Err(ClipperError::InvalidScale { scale_x, scale_y });
+        }
+
+        let mut error_code = 0;
+        Ok(unsafe {
+            Self::from_ptr(clipper_path64_scale(
+                malloc(clipper_path64_size()),
+                self.ptr,
+                scale_x,
+                scale_y,
+                &mut error_code,
+            ))
+        })
+    }
+
+    /// Returns a copy without points in line with their neighbors.
+    pub fn trim_collinear(&self, is_open: bool) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_path64_trim_collinear(
+                malloc(clipper_path64_size()),
+                self.ptr,
+                is_open.into(),
+            ))
+        }
+    }
+
+    /// Returns a copy without points closer than `epsilon` to the line
+    /// through their neighbors, like [`Path::simplify`].
+    pub fn simplify(&self, epsilon: f64, is_open: bool) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_path64_simplify(
+                malloc(clipper_path64_size()),
+                self.ptr,
+                epsilon,
+                is_open.into(),
+            ))
+        }
+    }
+
+    /// Returns a copy simplified with the Ramer-Douglas-Peucker algorithm.
+    pub fn ramer_douglas_peucker(&self, epsilon: f64) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_path64_ramer_douglas_peucker(
+                malloc(clipper_path64_size()),
+                self.ptr,
+                epsilon,
+            ))
+        }
+    }
+
+    /// Returns a copy without points within the square root of
+    /// `max_distance_squared` of the previous point.
+    pub fn strip_near_equal(&self, max_distance_squared: f64, is_closed: bool) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_path64_strip_near_equal(
+                malloc(clipper_path64_size()),
+                self.ptr,
+                max_distance_squared,
+                is_closed.into(),
+            ))
+        }
+    }
+
+    /// Remove repeated points in place, including a last point repeating the
+    /// first one if the path is closed.
+    pub fn strip_duplicates(&mut self, is_closed: bool) {
+        unsafe { clipper_path64_strip_duplicates(self.ptr, is_closed.into()) }
+    }
+
+    /// Clip the closed path to the rectangle.
+    pub fn rect_clip(&self, rect: Rect64) -> Paths64 {
+        let mut rect = rect;
+        unsafe {
+            Paths64::from_ptr(clipper_path64_rect_clip(
+                malloc(clipper_paths64_size()),
+                &mut rect,
+                self.ptr,
+            ))
+        }
+    }
+
+    /// Clip the open path to the rectangle.
+    pub fn rect_clip_line(&self, rect: Rect64) -> Paths64 {
+        let mut rect = rect;
+        unsafe {
+            Paths64::from_ptr(clipper_path64_rect_clip_line(
+                malloc(clipper_paths64_size()),
+                &mut rect,
+                self.ptr,
+            ))
+        }
+    }
+
+    /// Returns the Minkowski sum of the `pattern` moved along this path.
+    pub fn minkowski_sum(&self, pattern: &Path64, is_closed: bool) -> Paths64 {
+        unsafe {
+            Paths64::from_ptr(clipper_path64_minkowski_sum(
+                malloc(clipper_paths64_size()),
+                pattern.ptr,
+                self.ptr,
+                is_closed.into(),
+            ))
+        }
+    }
+
+    /// Returns the Minkowski difference of the `pattern` moved along this
+    /// path.
+    pub fn minkowski_diff(&self, pattern: &Path64, is_closed: bool) -> Paths64 {
+        unsafe {
+            Paths64::from_ptr(clipper_path64_minkowski_diff(
+                malloc(clipper_paths64_size()),
+                pattern.ptr,
+                self.ptr,
+                is_closed.into(),
+            ))
+        }
+    }
+}
+
+impl Default for Path64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Path64 {
+    fn clone(&self) -> Self {
+        self.translate(0, 0)
+    }
+}
+
+impl std::fmt::Debug for Path64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Path64").field(&self.to_points()).finish()
+    }
+}
+
+impl PartialEq for Path64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_points() == other.to_points()
+    }
+}
+
+impl Drop for Path64 {
+    fn drop(&mut self) {
+        unsafe { delete_path64(self.ptr) }
+    }
+}
+
+impl<P: PointScaler> From<&Path<P>> for Path64 {
+    fn from(path: &Path<P>) -> Self {
+        unsafe { Self::from_ptr(path.to_clipperpath64()) }
+    }
+}
+
+impl Paths64 {
+    /// Create empty paths.
+    pub fn new() -> Self {
+        unsafe { Self::from_ptr(clipper_paths64(malloc(clipper_paths64_size()))) }
+    }
+
+    /// Take ownership of native paths allocated with [`malloc`].
+    unsafe fn from_ptr(ptr: *mut ClipperPaths64) -> Self {
+        Self { ptr }
+    }
+
+    /// Returns the pointer to the native paths, to call native functions
+    /// that aren't wrapped. The paths stay owned by this wrapper.
+    pub fn as_ptr(&self) -> *mut ClipperPaths64 {
+        self.ptr
+    }
+
+    /// Append a copy of the path.
+    pub fn push(&mut self, path: &Path64) {
+        unsafe { clipper_paths64_add_path(self.ptr, path.ptr) }
+    }
+
+    /// Append copies of all paths of `other`.
+    pub fn append(&mut self, other: &Paths64) {
+        unsafe { clipper_paths64_add_paths(self.ptr, other.ptr) }
+    }
+
+    /// Reserve room for `capacity` paths in total.
+    pub fn reserve(&mut self, capacity: usize) {
+        unsafe { clipper_paths64_reserve(self.ptr, capacity) }
+    }
+
+    /// Returns the number of paths.
+    pub fn len(&self) -> usize {
+        unsafe { clipper_paths64_length(self.ptr) }
+    }
+
+    /// Returns `true` if there are no paths.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of points of the path at `index`, or `None` if
+    /// it's out of bounds.
+    pub fn path_len(&self, index: usize) -> Option<usize> {
+        (index < self.len()).then(|| unsafe { clipper_paths64_path_length(self.ptr, index as _) })
+    }
+
+    /// Returns a copy of the path at `index`, or `None` if it's out of bounds.
+    pub fn get(&self, index: usize) -> Option<Path64> {
+        (index < self.len()).then(|| unsafe {
+            Path64::from_ptr(clipper_paths64_get_path(
+                malloc(clipper_path64_size()),
+                self.ptr,
+                index as _,
+            ))
+        })
+    }
+
+    /// Returns point `point` of path `path`, or `None` if either is out of
+    /// bounds.
+    pub fn point(&self, path: usize, point: usize) -> Option<Point64> {
+        (point < self.path_len(path)?)
+            .then(|| unsafe { clipper_paths64_get_point(self.ptr, path as _, point as _) })
+    }
+
+    /// Iterate over copies of the paths.
+    pub fn iter(&self) -> impl Iterator<Item = Path64> + '_ {
+        (0..self.len()).filter_map(|index| self.get(index))
+    }
+
+    /// Copy all points out of the native paths.
+    pub fn to_points(&self) -> Vec<Vec<Point64>> {
+        self.iter().map(|path| path.to_points()).collect()
+    }
+
+    /// Convert to [`Paths`], interpreting the coordinates with the scaler.
+    pub fn to_paths<P: PointScaler>(&self) -> Paths<P> {
+        Paths::from_clipperpaths64(self.ptr)
+    }
+
+    /// Returns the sum of the signed areas of all paths.
+    pub fn area(&self) -> f64 {
+        unsafe { clipper_paths64_area(self.ptr) }
+    }
+
+    /// Returns the bounds of all paths. Paths without points have inverted
+    /// bounds from `i64::MAX` to `i64::MIN`.
+    pub fn bounds(&self) -> Rect64 {
+        let mut bounds = Rect64::default();
+        unsafe { clipper_paths64_bounds((&mut bounds as *mut Rect64).cast(), self.ptr) };
+        bounds
+    }
+
+    /// Returns a copy moved by whole scaled units.
+    pub fn translate(&self, dx: i64, dy: i64) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_paths64_translate(
+                malloc(clipper_paths64_size()),
+                self.ptr,
+                dx,
+                dy,
+            ))
+        }
+    }
+
+    /// Returns a copy scaled around the origin.
+    ///
+    /// Returns [`ClipperError::InvalidScale`] if a factor is zero or not
+    /// finite, or if scaling would move coordinates out of the native range.
+    pub fn scale(&self, scale_x: f64, scale_y: f64) -> Result<Self, ClipperError> {
+        check_scale(scale_x, scale_y)?;
+        match unsafe { native_bounds(self.ptr) } {
+            None => Ok(self.translate(0, 0)),
+            Some(bounds) if in_range(bounds, scale_x, scale_y) => unsafe {
+                let mut error_code = 0;
+                Ok(Self::from_ptr(clipper_paths64_scale(
+                    malloc(clipper_paths64_size()),
+                    self.ptr,
+                    scale_x,
+                    scale_y,
+                    &mut error_code,
+                )))
+            },
+            Some(_) => Err(ClipperError::InvalidScale { scale_x, scale_y }),
+        }
+    }
+
+    /// Returns a copy without points closer than `epsilon` to the line
+    /// through their neighbors, like [`Paths::simplify`].
+    pub fn simplify(&self, epsilon: f64, is_open: bool) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_paths64_simplify(
+                malloc(clipper_paths64_size()),
+                self.ptr,
+                epsilon,
+                is_open.into(),
+            ))
+        }
+    }
+
+    /// Returns a copy simplified with the Ramer-Douglas-Peucker algorithm.
+    pub fn ramer_douglas_peucker(&self, epsilon: f64) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_paths64_ramer_douglas_peucker(
+                malloc(clipper_paths64_size()),
+                self.ptr,
+                epsilon,
+            ))
+        }
+    }
+
+    /// Returns a copy without points within the square root of
+    /// `max_distance_squared` of the previous point of their path.
+    pub fn strip_near_equal(&self, max_distance_squared: f64, is_closed: bool) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_paths64_strip_near_equal(
+                malloc(clipper_paths64_size()),
+                self.ptr,
+                max_distance_squared,
+                is_closed.into(),
+            ))
+        }
+    }
+
+    /// Remove repeated points from each path in place.
+    pub fn strip_duplicates(&mut self, is_closed: bool) {
+        unsafe { clipper_paths64_strip_duplicates(self.ptr, is_closed.into()) }
+    }
+
+    /// Clip the closed paths to the rectangle.
+    pub fn rect_clip(&self, rect: Rect64) -> Self {
+        let mut rect = rect;
+        unsafe {
+            Self::from_ptr(clipper_paths64_rect_clip(
+                malloc(clipper_paths64_size()),
+                &mut rect,
+                self.ptr,
+            ))
+        }
+    }
+
+    /// Clip the open paths to the rectangle.
+    pub fn rect_clip_lines(&self, rect: Rect64) -> Self {
+        let mut rect = rect;
+        unsafe {
+            Self::from_ptr(clipper_paths64_rect_clip_lines(
+                malloc(clipper_paths64_size()),
+                &mut rect,
+                self.ptr,
+            ))
+        }
+    }
+
+    /// Offset the paths by `delta` scaled units, like [`Paths::inflate`].
+    pub fn inflate(
+        &self,
+        delta: f64,
+        join_type: JoinType,
+        end_type: EndType,
+        miter_limit: f64,
+    ) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_paths64_inflate(
+                malloc(clipper_paths64_size()),
+                self.ptr,
+                delta,
+                join_type.into(),
+                end_type.into(),
+                miter_limit,
+            ))
+        }
+    }
+
+    /// Apply a boolean operation with these paths as closed subjects.
+    pub fn boolean(&self, clip_type: ClipType, fill_rule: FillRule, clips: &Paths64) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_paths64_boolean_op(
+                malloc(clipper_paths64_size()),
+                clip_type.into(),
+                fill_rule.into(),
+                self.ptr,
+                clips.ptr,
+            ))
+        }
+    }
+
+    /// Apply a boolean operation with these paths as closed subjects and
+    /// return the result as a tree.
+    pub fn boolean_tree(
+        &self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+        clips: &Paths64,
+    ) -> PolyTree64 {
+        let tree = PolyTree64::new();
+        unsafe {
+            clipper_paths64_boolean_op_tree(
+                clip_type.into(),
+                fill_rule.into(),
+                self.ptr,
+                clips.ptr,
+                tree.ptr,
+            )
+        };
+        tree
+    }
+
+    /// Returns the union of the Minkowski sums of the `pattern` moved along
+    /// each path.
+    pub fn minkowski_sum(&self, pattern: &Path64, is_closed: bool, fill_rule: FillRule) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_paths64_minkowski_sum(
+                malloc(clipper_paths64_size()),
+                pattern.ptr,
+                self.ptr,
+                is_closed.into(),
+                fill_rule.into(),
+            ))
+        }
+    }
+
+    /// Returns the union of the Minkowski differences of the `pattern` moved
+    /// along each path.
+    pub fn minkowski_diff(&self, pattern: &Path64, is_closed: bool, fill_rule: FillRule) -> Self {
+        unsafe {
+            Self::from_ptr(clipper_paths64_minkowski_diff(
+                malloc(clipper_paths64_size()),
+                pattern.ptr,
+                self.ptr,
+                is_closed.into(),
+                fill_rule.into(),
+            ))
+        }
+    }
+}
+
+impl Default for Paths64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Paths64 {
+    fn clone(&self) -> Self {
+        self.translate(0, 0)
+    }
+}
+
+impl std::fmt::Debug for Paths64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Paths64").field(&self.to_points()).finish()
+    }
+}
+
+impl PartialEq for Paths64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_points() == other.to_points()
+    }
+}
+
+impl Drop for Paths64 {
+    fn drop(&mut self) {
+        unsafe { delete_paths64(self.ptr) }
+    }
+}
+
+impl<P: PointScaler> From<&Paths<P>> for Paths64 {
+    fn from(paths: &Paths<P>) -> Self {
+        unsafe { Self::from_ptr(paths.to_clipperpaths64()) }
+    }
+}
+
+impl FromIterator<Path64> for Paths64 {
+    fn from_iter<T: IntoIterator<Item = Path64>>(iter: T) -> Self {
+        let mut paths = Self::new();
+        for path in iter {
+            paths.push(&path);
+        }
+        paths
+    }
+}
+
+/// A polygon tree owned by the native library, the native `PolyTree64`.
+///
+/// The tree is the root node without a polygon of its own, see
+/// [`PolyTree64::root`] for reading the nodes.
+pub struct PolyTree64 {
+    ptr: *mut ClipperPolyTree64,
+}
+
+// The native tree is owned exclusively by the wrapper and only changed
+// through `&mut self`.
+unsafe impl Send for PolyTree64 {}
+
+impl PolyTree64 {
+    /// Create an empty tree.
+    pub fn new() -> Self {
+        Self {
+            ptr: unsafe {
+                clipper_polytree64(malloc(clipper_polytree64_size()), std::ptr::null_mut())
+            },
+        }
+    }
+
+    /// Returns the pointer to the native tree, to call native functions
+    /// that aren't wrapped. The tree stays owned by this wrapper.
+    pub fn as_ptr(&self) -> *mut ClipperPolyTree64 {
+        self.ptr
+    }
+
+    /// Returns the root node, to walk the tree.
+    pub fn root(&self) -> PolyTreeNode64<'_> {
+        PolyTreeNode64 {
+            ptr: self.ptr,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Remove all nodes below the root.
+    pub fn clear(&mut self) {
+        unsafe { clipper_polytree64_clear(self.ptr) }
+    }
+
+    /// Returns the polygons of all nodes.
+    pub fn to_paths(&self) -> Paths64 {
+        self.root().to_paths()
+    }
+
+    /// Convert to a [`PolyTree`], interpreting the coordinates with the
+    /// scaler.
+    pub fn to_polytree<P: PointScaler>(&self) -> PolyTree<P> {
+        unsafe { PolyTree::from_ptr(self.ptr, 0) }
+    }
+}
+
+impl Default for PolyTree64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for PolyTree64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PolyTree64")
+            .field("children", &self.root().len())
+            .finish()
+    }
+}
+
+impl Drop for PolyTree64 {
+    fn drop(&mut self) {
+        unsafe { delete_polytree64(self.ptr) }
+    }
+}
+
+/// A node of a [`PolyTree64`], borrowed from the tree.
+#[derive(Clone, Copy)]
+pub struct PolyTreeNode64<'a> {
+    ptr: *mut ClipperPolyTree64,
+    _marker: PhantomData<&'a PolyTree64>,
+}
+
+impl<'a> PolyTreeNode64<'a> {
+    /// Returns the number of children.
+    pub fn len(&self) -> usize {
+        unsafe { clipper_polytree64_count(self.ptr) }
+    }
+
+    /// Returns `true` if the node has no children.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the child at `index`, or `None` if it's out of bounds.
+    pub fn child(&self, index: usize) -> Option<PolyTreeNode64<'a>> {
+        (index < self.len()).then(|| PolyTreeNode64 {
+            ptr: unsafe { clipper_polytree64_get_child(self.ptr, index) }.cast_mut(),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Iterate over the children.
+    pub fn children(&self) -> impl Iterator<Item = PolyTreeNode64<'a>> + 'a {
+        let node = *self;
+        (0..node.len()).filter_map(move |index| node.child(index))
+    }
+
+    /// Returns `true` if the polygon of the node is a hole.
+    pub fn is_hole(&self) -> bool {
+        unsafe { clipper_polytree64_is_hole(self.ptr) != 0 }
+    }
+
+    /// Returns the depth of the node, zero for the root.
+    pub fn level(&self) -> usize {
+        unsafe { clipper_polytree64_level(self.ptr).max(0) as usize }
+    }
+
+    /// Returns a copy of the polygon of the node, empty for the root.
+    pub fn polygon(&self) -> Path64 {
+        unsafe {
+            Path64::from_ptr(clipper_polytree64_polygon(
+                malloc(clipper_path64_size()),
+                self.ptr,
+            ))
+        }
+    }
+
+    /// Returns the area of the node and all its descendants, holes counting
+    /// negative.
+    pub fn area(&self) -> f64 {
+        unsafe { clipper_polytree64_area(self.ptr) }
+    }
+
+    /// Returns the polygons of the node and all its descendants.
+    pub fn to_paths(&self) -> Paths64 {
+        unsafe {
+            Paths64::from_ptr(clipper_polytree64_to_paths(
+                malloc(clipper_paths64_size()),
+                self.ptr,
+            ))
+        }
+    }
+
+    /// Returns `true` if the polygons of all descendants lie inside the
+    /// polygons of their parents.
+    pub fn fully_contains_children(&self) -> bool {
+        unsafe { clipper_polytree64_fully_contains_children(self.ptr) != 0 }
+    }
+}
+
+impl std::fmt::Debug for PolyTreeNode64<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PolyTreeNode64")
+            .field("is_hole", &self.is_hole())
+            .field("polygon", &self.polygon())
+            .field("children", &self.len())
+            .finish()
+    }
+}
+
+/// The native boolean engine, `Clipper64`, keeping the paths added to it
+/// until it's cleared.
+pub struct Clipper64 {
+    ptr: *mut ClipperClipper64,
+    subject_paths: usize,
+    open_subject_paths: usize,
+    clip_paths: usize,
+}
+
+// The native clipper is owned exclusively by the wrapper and only used
+// through `&mut self`.
+unsafe impl Send for Clipper64 {}
+
+impl Clipper64 {
+    /// Create a clipper without any paths.
+    pub fn new() -> Self {
+        Self {
+            ptr: unsafe { clipper_clipper64(malloc(clipper_clipper64_size())) },
+            subject_paths: 0,
+            open_subject_paths: 0,
+            clip_paths: 0,
+        }
+    }
+
+    /// Returns the pointer to the native clipper, to call native functions
+    /// that aren't wrapped. The clipper stays owned by this wrapper.
+    pub fn as_ptr(&self) -> *mut ClipperClipper64 {
+        self.ptr
+    }
+
+    /// Returns `true` if collinear points are kept in the results.
+    pub fn preserve_collinear(&self) -> bool {
+        unsafe { clipper_clipper64_get_preserve_collinear(self.ptr) != 0 }
+    }
+
+    /// Keep collinear points in the results.
+    pub fn set_preserve_collinear(&mut self, preserve: bool) {
+        unsafe { clipper_clipper64_set_preserve_collinear(self.ptr, preserve.into()) }
+    }
+
+    /// Returns `true` if the orientation of the results is reversed.
+    pub fn reverse_solution(&self) -> bool {
+        unsafe { clipper_clipper64_get_reverse_solution(self.ptr) != 0 }
+    }
+
+    /// Reverse the orientation of the results, making outers negative and
+    /// holes positive.
+    pub fn set_reverse_solution(&mut self, reverse: bool) {
+        unsafe { clipper_clipper64_set_reverse_solution(self.ptr, reverse.into()) }
+    }
+
+    /// Remove all paths added so far.
+    pub fn clear(&mut self) {
+        unsafe { clipper_clipper64_clear(self.ptr) };
+        self.subject_paths = 0;
+        self.open_subject_paths = 0;
+        self.clip_paths = 0;
+    }
+
+    /// Add closed subject paths.
+    pub fn add_subject(&mut self, paths: &Paths64) {
+        unsafe { clipper_clipper64_add_subject(self.ptr, paths.ptr) };
+        self.subject_paths += paths.len();
+    }
+
+    /// Add open subject paths.
+    pub fn add_open_subject(&mut self, paths: &Paths64) {
+        unsafe { clipper_clipper64_add_open_subject(self.ptr, paths.ptr) };
+        self.open_subject_paths += paths.len();
+    }
+
+    /// Add clip paths.
+    pub fn add_clip(&mut self, paths: &Paths64) {
+        unsafe { clipper_clipper64_add_clip(self.ptr, paths.ptr) };
+        self.clip_paths += paths.len();
+    }
+
+    /// Apply a boolean operation to the paths added so far and return the
+    /// closed and the open result paths. The paths are kept, so further
+    /// operations can be applied to them.
+    pub fn execute(
+        &mut self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<(Paths64, Paths64), ClipperError> {
+        let (closed, open) = (Paths64::new(), Paths64::new());
+        let code = unsafe {
+            clipper_clipper64_execute(
+                self.ptr,
+                clip_type.into(),
+                fill_rule.into(),
+                closed.ptr,
+                open.ptr,
+            )
+        };
+        self.check(code, clip_type, fill_rule)?;
+        Ok((closed, open))
+    }
+
+    /// Apply a boolean operation to the paths added so far and return the
+    /// closed result paths as a tree, along with the open result paths.
+    pub fn execute_tree(
+        &mut self,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<(PolyTree64, Paths64), ClipperError> {
+        let (tree, open) = (PolyTree64::new(), Paths64::new());
+        let code = unsafe {
+            clipper_clipper64_execute_tree_with_open(
+                self.ptr,
+                clip_type.into(),
+                fill_rule.into(),
+                tree.ptr,
+                open.ptr,
+            )
+        };
+        self.check(code, clip_type, fill_rule)?;
+        Ok((tree, open))
+    }
+
+    fn check(
+        &self,
+        code: i32,
+        clip_type: ClipType,
+        fill_rule: FillRule,
+    ) -> Result<(), ClipperError> {
+        if code == 1 {
+            return Ok(());
+        }
+        Err(ClipperError::NativeFailure {
+            operation: clip_type.name(),
+            fill_rule,
+            code,
+            subject_paths: self.subject_paths,
+            open_subject_paths: self.open_subject_paths,
+            clip_paths: self.clip_paths,
+        })
+    }
+}
+
+impl Default for Clipper64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for Clipper64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Clipper64")
+            .field("subject_paths", &self.subject_paths)
+            .field("open_subject_paths", &self.open_subject_paths)
+            .field("clip_paths", &self.clip_paths)
+            .finish()
+    }
+}
+
+impl Drop for Clipper64 {
+    fn drop(&mut self) {
+        unsafe { delete_clipper64(self.ptr) }
+    }
+}
+
+/// The native offsetting engine, `ClipperOffset`, keeping the paths added
+/// to it until it's cleared.
+pub struct ClipperOffset {
+    ptr: *mut ClipperClipperOffset,
+}
+
+// The native offsetter is owned exclusively by the wrapper and only used
+// through `&mut self`.
+unsafe impl Send for ClipperOffset {}
+
+impl ClipperOffset {
+    /// Create an offsetter without any paths.
+    ///
+    /// Miters longer than `miter_limit` times the offset are squared off, and
+    /// round joins and ends are within `arc_tolerance` scaled units of the
+    /// true arc, with zero picking a tolerance from the offset.
+    pub fn new(
+        miter_limit: f64,
+        arc_tolerance: f64,
+        preserve_collinear: bool,
+        reverse_solution: bool,
+    ) -> Self {
+        Self {
+            ptr: unsafe {
+                clipper_clipperoffset(
+                    malloc(clipper_clipperoffset_size()),
+                    miter_limit,
+                    arc_tolerance,
+                    preserve_collinear.into(),
+                    reverse_solution.into(),
+                )
+            },
+        }
+    }
+
+    /// Returns the pointer to the native offsetter, to call native functions
+    /// that aren't wrapped. The offsetter stays owned by this wrapper.
+    pub fn as_ptr(&self) -> *mut ClipperClipperOffset {
+        self.ptr
+    }
+
+    /// Returns the miter limit.
+    pub fn miter_limit(&self) -> f64 {
+        unsafe { clipper_clipperoffset_get_miter_limit(self.ptr) }
+    }
+
+    /// Set the miter limit, as a multiple of the offset.
+    pub fn set_miter_limit(&mut self, limit: f64) {
+        unsafe { clipper_clipperoffset_set_miter_limit(self.ptr, limit) }
+    }
+
+    /// Returns the arc tolerance.
+    pub fn arc_tolerance(&self) -> f64 {
+        unsafe { clipper_clipperoffset_get_arc_tolerance(self.ptr) }
+    }
+
+    /// Set the arc tolerance in scaled units.
+    pub fn set_arc_tolerance(&mut self, tolerance: f64) {
+        unsafe { clipper_clipperoffset_set_arc_tolerance(self.ptr, tolerance) }
+    }
+
+    /// Returns `true` if collinear points are kept in the results.
+    pub fn preserve_collinear(&self) -> bool {
+        unsafe { clipper_clipperoffset_get_preserve_collinear(self.ptr) != 0 }
+    }
+
+    /// Keep collinear points in the results.
+    pub fn set_preserve_collinear(&mut self, preserve: bool) {
+        unsafe { clipper_clipperoffset_set_preserve_collinear(self.ptr, preserve.into()) }
+    }
+
+    /// Returns `true` if the orientation of the results is reversed.
+    pub fn reverse_solution(&self) -> bool {
+        unsafe { clipper_clipperoffset_get_reverse_solution(self.ptr) != 0 }
+    }
+
+    /// Reverse the orientation of the results.
+    pub fn set_reverse_solution(&mut self, reverse: bool) {
+        unsafe { clipper_clipperoffset_set_reverse_solution(self.ptr, reverse.into()) }
+    }
+
+    /// Returns the error code of the last execution, zero if it succeeded.
+    pub fn error_code(&self) -> i32 {
+        unsafe { clipper_clipperoffset_error_code(self.ptr) }
+    }
+
+    /// Remove all paths added so far.
+    pub fn clear(&mut self) {
+        unsafe { clipper_clipperoffset_clear(self.ptr) }
+    }
+
+    /// Add a path to offset with the join and end types.
+    pub fn add_path(&mut self, path: &Path64, join_type: JoinType, end_type: EndType) {
+        unsafe {
+            clipper_clipperoffset_add_path64(self.ptr, path.ptr, join_type.into(), end_type.into())
+        }
+    }
+
+    /// Add paths to offset with the join and end types.
+    pub fn add_paths(&mut self, paths: &Paths64, join_type: JoinType, end_type: EndType) {
+        unsafe {
+            clipper_clipperoffset_add_paths64(
+                self.ptr,
+                paths.ptr,
+                join_type.into(),
+                end_type.into(),
+            )
+        }
+    }
+
+    /// Offset all paths added so far by `delta` scaled units.
+    pub fn execute(&mut self, delta: f64) -> Paths64 {
+        unsafe {
+            Paths64::from_ptr(clipper_clipperoffset_execute(
+                malloc(clipper_paths64_size()),
+                self.ptr,
+                delta,
+            ))
+        }
+    }
+}
+
+impl Default for ClipperOffset {
+    /// An offsetter with the defaults of the native library, a miter limit
+    /// of 2 and an arc tolerance picked from the offset.
+    fn default() -> Self {
+        Self::new(2.0, 0.0, false, false)
+    }
+}
+
+impl std::fmt::Debug for ClipperOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClipperOffset")
+            .field("miter_limit", &self.miter_limit())
+            .field("arc_tolerance", &self.arc_tolerance())
+            .finish()
+    }
+}
+
+impl Drop for ClipperOffset {
+    fn drop(&mut self) {
+        unsafe {
+            clipper_destruct_clipperoffset(self.ptr);
+            free(self.ptr.cast(), clipper_clipperoffset_size());
+        }
+    }
+}
+
+/// The native library throws on invalid factors and on paths without points,
+/// so those are handled before scaling.
+fn check_scale(scale_x: f64, scale_y: f64) -> Result<(), ClipperError> {
+    if !scale_x.is_finite() || !scale_y.is_finite() || scale_x == 0.0 || scale_y == 0.0 {
+        return Err(ClipperError::InvalidScale { scale_x, scale_y });
+    }
+    Ok(())
+}
+
+/// Returns the distance between two points.
+pub fn distance(a: Point64, b: Point64) -> f64 {
+    unsafe { clipper_point64_distance(a, b) }
+}
+
+/// Returns `true` if the angle at `b` between `a` and `c` differs from a
+/// straight line by less than the angle with the squared sine
+/// `sin_squared_min_angle`.
+pub fn near_collinear(a: Point64, b: Point64, c: Point64, sin_squared_min_angle: f64) -> bool {
+    unsafe { clipper_point64_near_collinear(a, b, c, sin_squared_min_angle) != 0 }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Centi;
+
+    use super::*;
+
+    fn point(x: i64, y: i64) -> Point64 {
+        Point64 { x, y }
+    }
+
+    fn square(x: i64, y: i64, size: i64) -> Path64 {
+        Path64::from_points(&[
+            point(x, y),
+            point(x + size, y),
+            point(x + size, y + size),
+            point(x, y + size),
+        ])
+    }
+
+    #[test]
+    fn test_path64() {
+        let mut path = square(0, 0, 10);
+        path.push(point(0, 5));
+        path.push(point(0, 5));
+
+        assert_eq!(path.len(), 6);
+        assert_eq!(path.get(1), Some(point(10, 0)));
+        assert_eq!(path.get(6), None);
+        assert_eq!(path.area(), 100.0);
+        assert!(path.is_positive());
+        assert_eq!(
+            path.bounds(),
+            Rect64 {
+                left: 0,
+                top: 0,
+                right: 10,
+                bottom: 10
+            }
+        );
+        assert_eq!(
+            path.point_in_polygon(point(5, 5)),
+            PointInPolygonResult::IsInside
+        );
+
+        path.strip_duplicates(true);
+        assert_eq!(path.len(), 5);
+        assert_eq!(path.trim_collinear(false).len(), 4);
+        assert_eq!(path.translate(5, 0).get(0), Some(point(5, 0)));
+        assert_eq!(path.scale(2.0, 1.0).unwrap().area(), 200.0);
+        assert!(path.scale(0.0, 1.0).is_err());
+        assert_eq!(path.clone(), path);
+
+        let converted: Path<Centi> = path.to_path();
+        assert_eq!(Path64::from(&converted), path);
+    }
+
+    #[test]
+    fn test_paths64_operations() {
+        let subject = Paths64::from_iter([square(0, 0, 10)]);
+        let clip = Paths64::from_iter([square(5, 5, 10)]);
+
+        let union = subject.boolean(ClipType::Union, FillRule::NonZero, &clip);
+        assert_eq!(union.area(), 175.0);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union.point(0, 100), None);
+
+        let tree = subject.boolean_tree(ClipType::Xor, FillRule::NonZero, &clip);
+        assert_eq!(tree.root().len(), 2);
+        assert_eq!(tree.root().area(), 150.0);
+        assert!(tree.root().fully_contains_children());
+        assert_eq!(tree.root().child(0).unwrap().level(), 1);
+        assert_eq!(tree.to_polytree::<Centi>().child_count(), 2);
+
+        let clipped = subject.rect_clip(Rect64 {
+            left: 2,
+            top: 2,
+            right: 4,
+            bottom: 20,
+        });
+        assert_eq!(clipped.area(), 16.0);
+
+        let grown = subject.inflate(1.0, JoinType::Miter, EndType::Polygon, 2.0);
+        assert_eq!(grown.area(), 144.0);
+
+        let mut joined = subject.clone();
+        joined.append(&clip);
+        assert_eq!(joined.len(), 2);
+        assert_eq!(joined.to_paths::<Centi>().len(), 2);
+    }
+
+    #[test]
+    fn test_clipper64_keeps_inputs() {
+        let mut clipper = Clipper64::new();
+        clipper.add_subject(&Paths64::from_iter([square(0, 0, 10)]));
+        clipper.add_clip(&Paths64::from_iter([square(5, 5, 10)]));
+        clipper.add_open_subject(&Paths64::from_iter([Path64::from_points(&[
+            point(-5, 2),
+            point(20, 2),
+        ])]));
+
+        let (closed, open) = clipper
+            .execute(ClipType::Intersection, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(closed.area(), 25.0);
+        assert!(open.is_empty());
+
+        let (tree, open) = clipper
+            .execute_tree(ClipType::Difference, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(tree.root().area(), 75.0);
+        // Only the clip paths cut the open subject, which misses them.
+        assert_eq!(open.to_points(), vec![vec![point(-5, 2), point(20, 2)]]);
+
+        clipper.clear();
+        let (closed, _) = clipper.execute(ClipType::Union, FillRule::NonZero).unwrap();
+        assert!(closed.is_empty());
+    }
+
+    #[test]
+    fn test_offset_and_minkowski() {
+        let mut offset = ClipperOffset::default();
+        offset.set_miter_limit(3.0);
+        assert_eq!(offset.miter_limit(), 3.0);
+        offset.add_path(&square(0, 0, 10), JoinType::Miter, EndType::Polygon);
+        let grown = offset.execute(2.0);
+        assert_eq!(grown.area(), 196.0);
+        assert_eq!(offset.error_code(), 0);
+
+        let line = Path64::from_points(&[point(0, 0), point(100, 0)]);
+        let brush = square(-1, -1, 2);
+        let swept = line.minkowski_sum(&brush, false);
+        let area = Paths64::from_iter(swept.iter())
+            .boolean(ClipType::Union, FillRule::NonZero, &Paths64::new())
+            .area();
+        assert_eq!(area, 102.0 * 2.0);
+
+        let circle = Path64::ellipse(point(0, 0), 100.0, 100.0, 0);
+        assert!(circle.len() > 8);
+        assert!(circle.ramer_douglas_peucker(50.0).len() < circle.len());
+        assert_eq!(distance(point(0, 0), point(3, 4)), 5.0);
+        assert!(near_collinear(point(0, 0), point(5, 0), point(10, 1), 0.1));
+    }
+}
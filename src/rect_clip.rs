@@ -0,0 +1,214 @@
+use clipper2c_sys::clipper_paths64_size;
+
+use crate::{
+    ffi::{clipper_paths64_rect_clip, clipper_paths64_rect_clip_lines, ClipperRect64},
+    malloc,
+    memory::delete_paths64,
+    Bounds, Paths, PointScaler,
+};
+
+/// Clips paths to a fixed axis aligned rectangle, using the rectangle
+/// clipping algorithm of Clipper2, which is much faster than a general
+/// intersection.
+///
+/// The rectangle is converted to its native form once, so one `RectClipper`
+/// can clip any number of path sets, such as all features falling into one
+/// tile of a tiling workload.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let tile = RectClipper::<Centi>::new(Bounds::new(10.0, 10.0));
+///
+/// let polygon: Paths = Path::rectangle(5.0, 5.0, 10.0, 10.0).into();
+/// let line: Paths = vec![(-5.0, 5.0), (15.0, 5.0)].into();
+///
+/// assert_eq!(tile.clip(&polygon).signed_area(), 25.0);
+/// assert_eq!(
+///     tile.clip_lines(&line)[0],
+///     Path::from(vec![(0.0, 5.0), (10.0, 5.0)])
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RectClipper<P: PointScaler> {
+    rect: Bounds<P>,
+    native: ClipperRect64,
+}
+
+impl<P: PointScaler> RectClipper<P> {
+    /// Create a clipper for the rectangle between the minimum and maximum
+    /// points of the bounds.
+    pub fn new(rect: Bounds<P>) -> Self {
+        Self {
+            rect,
+            native: ClipperRect64 {
+                left: rect.min.x_scaled(),
+                top: rect.min.y_scaled(),
+                right: rect.max.x_scaled(),
+                bottom: rect.max.y_scaled(),
+            },
+        }
+    }
+
+    /// Returns the rectangle paths are clipped to.
+    pub fn rect(&self) -> Bounds<P> {
+        self.rect
+    }
+
+    /// Clip the paths to the rectangle, treating all of them as closed.
+    ///
+    /// Unlike the boolean operations the paths are clipped one by one, so
+    /// overlapping paths stay separate and holes are clipped like any other
+    /// path. Returns no paths if the rectangle is empty.
+    pub fn clip(&self, paths: &Paths<P>) -> Paths<P> {
+        let mut rect = self.native;
+        unsafe {
+            let mem = malloc(clipper_paths64_size());
+            let result = clipper_paths64_rect_clip(mem, &mut rect, paths.as_clipperpaths64());
+            let clipped = Paths::from_clipperpaths64(result);
+            delete_paths64(result);
+            clipped
+        }
+    }
+
+    /// Clip the paths to the rectangle, treating all of them as open lines.
+    /// The result holds one open path for each part of a line inside the
+    /// rectangle.
+    pub fn clip_lines(&self, paths: &Paths<P>) -> Paths<P> {
+        let mut rect = self.native;
+        let mut lines = unsafe {
+            let mem = malloc(clipper_paths64_size());
+            let result = clipper_paths64_rect_clip_lines(mem, &mut rect, paths.as_clipperpaths64());
+            let clipped = Paths::from_clipperpaths64(result);
+            delete_paths64(result);
+            clipped
+        };
+
+        for index in 0..lines.len() {
+            lines.set_open(index, true);
+        }
+        lines
+    }
+
+    /// Cut the paths down to their parts inside the rectangle before a
+    /// boolean operation with the rectangle, keeping open paths open. The
+    /// parts are exactly what the boolean operation keeps from each path, so
+    /// it covers the same area while working on far fewer points.
+    pub(crate) fn reduce(&self, paths: &Paths<P>) -> Paths<P> {
+        if !paths.has_open_paths() {
+            return self.clip(paths);
+        }
+
+        let (closed, open) = paths.partition_open();
+        let mut reduced = self.clip(&closed);
+        for line in self.clip_lines(&open).iter() {
+            reduced.push_open(line.clone());
+        }
+        reduced
+    }
+}
+
+/// Returns the rectangle if the paths are a single closed axis aligned
+/// rectangle, with or without a repeated closing point.
+pub(crate) fn as_rectangle<P: PointScaler>(paths: &Paths<P>) -> Option<Bounds<P>> {
+    if paths.len() != 1 || paths.is_open(0) {
+        return None;
+    }
+
+    let mut points = paths[0]
+        .iter()
+        .map(|point| (point.x_scaled(), point.y_scaled()))
+        .collect::<Vec<_>>();
+    if points.len() == 5 && points[0] == points[4] {
+        points.pop();
+    }
+    if points.len() != 4 {
+        return None;
+    }
+
+    // Edges must alternate between horizontal and vertical, starting with
+    // either, and none may be empty.
+    let edges = (0..4).map(|index| {
+        let (start, end) = (points[index], points[(index + 1) % 4]);
+        (start.0 == end.0, start.1 == end.1)
+    });
+    let starts_vertical = points[0].0 == points[1].0;
+    let alternating = edges.enumerate().all(|(index, (vertical, horizontal))| {
+        vertical != horizontal && vertical == (starts_vertical == (index % 2 == 0))
+    });
+    if !alternating {
+        return None;
+    }
+
+    Some(paths.bounds())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, Path, Point};
+
+    use super::*;
+
+    #[test]
+    fn test_clip_many_path_sets() {
+        let clipper = RectClipper::<Centi>::new(Bounds {
+            min: Point::new(0.0, 0.0),
+            max: Point::new(4.0, 4.0),
+        });
+
+        for offset in 0..8 {
+            let square = Paths::from(Path::rectangle(offset as f64 - 2.0, 1.0, 2.0, 2.0));
+            let (left, right) = (offset as f64 - 2.0, offset as f64);
+            let width = (right.min(4.0) - left.max(0.0)).max(0.0);
+
+            assert_eq!(clipper.clip(&square).signed_area(), width * 2.0);
+        }
+    }
+
+    #[test]
+    fn test_clip_keeps_overlaps_and_holes() {
+        let clipper = RectClipper::<Centi>::new(Bounds::new(10.0, 10.0));
+        let paths = Paths::<Centi>::from(vec![
+            Path::rectangle(-5.0, -5.0, 20.0, 20.0),
+            Path::rectangle(2.0, 2.0, 2.0, 2.0)
+                .iter()
+                .rev()
+                .copied()
+                .collect(),
+            Path::rectangle(5.0, 5.0, 10.0, 10.0),
+        ]);
+
+        let clipped = clipper.clip(&paths);
+
+        assert_eq!(clipped.len(), 3);
+        assert_eq!(clipped.signed_area(), 100.0 - 4.0 + 25.0);
+    }
+
+    #[test]
+    fn test_clip_lines() {
+        let clipper = RectClipper::<Centi>::new(Bounds::new(10.0, 10.0));
+        let lines = Paths::<Centi>::from(vec![
+            vec![(-1.0, 5.0), (5.0, 5.0), (5.0, 11.0)],
+            vec![(20.0, 20.0), (30.0, 30.0)],
+        ]);
+
+        let clipped = clipper.clip_lines(&lines);
+
+        assert_eq!(clipped.len(), 1);
+        assert!(clipped.is_open(0));
+        assert_eq!(
+            clipped[0],
+            Path::from(vec![(0.0, 5.0), (5.0, 5.0), (5.0, 10.0)])
+        );
+    }
+
+    #[test]
+    fn test_empty_rect() {
+        let clipper = RectClipper::<Centi>::new(Bounds::new(0.0, 10.0));
+        let square = Paths::<Centi>::from(Path::rectangle(-1.0, -1.0, 2.0, 2.0));
+
+        assert!(clipper.clip(&square).is_empty());
+    }
+}
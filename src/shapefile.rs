@@ -0,0 +1,577 @@
+use std::io::{Read, Write};
+
+use crate::{FillRule, Path, Paths, Point, PointScaler, PolyTree};
+
+const FILE_CODE: i32 = 9994;
+const VERSION: i32 = 1000;
+const HEADER_LENGTH: usize = 100;
+
+const NULL_SHAPE: i32 = 0;
+
+/// The kind of geometry stored in a shapefile written with [`write_shp`] or
+/// [`save_shapefile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShapeType {
+    /// Open lines, every path of a record is written as a part.
+    PolyLine,
+    /// Polygons, every path of a record is written as a closed ring.
+    Polygon,
+}
+
+impl ShapeType {
+    fn code(self) -> i32 {
+        match self {
+            ShapeType::PolyLine => 3,
+            ShapeType::Polygon => 5,
+        }
+    }
+
+    /// The shape type of a code, treating the Z and M variants like the
+    /// plain types as only x and y are read.
+    fn from_code(code: i32) -> Option<Self> {
+        match code {
+            3 | 13 | 23 => Some(ShapeType::PolyLine),
+            5 | 15 | 25 => Some(ShapeType::Polygon),
+            _ => None,
+        }
+    }
+}
+
+/// Read the records of a polygon or polyline `.shp` file, returning the
+/// paths of each record in file order.
+///
+/// Every part of a multipart record becomes a path. Polygon rings are closed
+/// paths with the repeated closing point removed, and are reversed from the
+/// shapefile convention (clockwise outer rings) to the convention of the
+/// boolean operations, so outer rings have a positive area and holes a
+/// negative area. Polyline parts are open paths. The Z and M variants are
+/// read as well, keeping only the x and y coordinates, and null shapes are
+/// returned as empty [`Paths`].
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let parcel: Paths = vec![
+///     Path::rectangle(0.0, 0.0, 10.0, 10.0),
+///     Path::rectangle(2.0, 2.0, 6.0, 6.0),
+/// ]
+/// .into();
+///
+/// let (mut shp, mut shx) = (Vec::new(), Vec::new());
+/// write_shp(&[parcel.clone()], ShapeType::Polygon, &mut shp, &mut shx).unwrap();
+///
+/// let records: Vec<Paths> = read_shp(shp.as_slice()).unwrap();
+/// assert_eq!(records.len(), 1);
+/// assert_eq!(records[0].signed_area(), 64.0);
+/// ```
+pub fn read_shp<P: PointScaler>(mut reader: impl Read) -> Result<Vec<Paths<P>>, ShapefileError> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+
+    let mut bytes = Bytes::new(&data, 0);
+    let file_code = bytes.i32_be()?;
+    if file_code != FILE_CODE {
+        return Err(ShapefileError::InvalidFileCode(file_code));
+    }
+
+    let mut header = Bytes::new(&data, 32);
+    let file_type = header.i32_le()?;
+    if file_type != NULL_SHAPE && ShapeType::from_code(file_type).is_none() {
+        return Err(ShapefileError::UnsupportedShapeType(file_type));
+    }
+
+    let mut records = Vec::new();
+    let mut position = HEADER_LENGTH;
+
+    while position < data.len() {
+        let record = records.len() + 1;
+        let mut header = Bytes::new(&data, position);
+        header.i32_be()?;
+        let length = header.i32_be()?;
+
+        let start = position + 8;
+        let end = usize::try_from(length)
+            .ok()
+            .and_then(|words| start.checked_add(words * 2))
+            .filter(|&end| end <= data.len())
+            .ok_or(ShapefileError::InvalidRecord {
+                record,
+                message: "record extends past the end of the file".to_string(),
+            })?;
+
+        records.push(
+            read_record(&data[start..end])
+                .map_err(|message| ShapefileError::InvalidRecord { record, message })?,
+        );
+        position = end;
+    }
+
+    Ok(records)
+}
+
+fn read_record<P: PointScaler>(content: &[u8]) -> Result<Paths<P>, String> {
+    let mut bytes = Bytes::new(content, 0);
+    let code = bytes
+        .i32_le()
+        .map_err(|_| "missing shape type".to_string())?;
+
+    if code == NULL_SHAPE {
+        return Ok(Paths::default());
+    }
+    let shape_type = ShapeType::from_code(code).ok_or(format!("unsupported shape type {code}"))?;
+
+    let truncated = |_| "record content is truncated".to_string();
+    let mut bytes = Bytes::new(content, 4 + 32);
+    let num_parts = bytes.count().map_err(truncated)?;
+    let num_points = bytes.count().map_err(truncated)?;
+
+    let mut parts = Vec::with_capacity(num_parts.min(content.len() / 4));
+    for _ in 0..num_parts {
+        parts.push(bytes.count().map_err(truncated)?);
+    }
+    parts.push(num_points);
+
+    let mut points = Vec::with_capacity(num_points.min(content.len() / 16));
+    for _ in 0..num_points {
+        let x = bytes.f64_le().map_err(truncated)?;
+        let y = bytes.f64_le().map_err(truncated)?;
+        points.push(Point::<P>::new(x, y));
+    }
+
+    let mut paths = Paths::default();
+    for range in parts.windows(2) {
+        let (start, end) = (range[0], range[1]);
+        if start > end || end > num_points {
+            return Err(format!("invalid part range {start}..{end}"));
+        }
+
+        let mut part = points[start..end].to_vec();
+        match shape_type {
+            ShapeType::PolyLine => paths.push_open(part),
+            ShapeType::Polygon => {
+                if part.len() > 1 && part[0] == part[part.len() - 1] {
+                    part.pop();
+                }
+                part.reverse();
+                paths.push_closed(part);
+            }
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Write records to a `.shp` file and its `.shx` index, with the paths of
+/// each record written as the parts of one multipart shape.
+///
+/// For [`ShapeType::Polygon`] every path is written as a closed ring. Whether
+/// a ring is an outer or a hole is decided by how many other rings of the
+/// record surround it, as with [`FillRule::EvenOdd`], and outer rings are
+/// written clockwise and holes counter-clockwise as shapefiles require.
+/// Rings with less than three points or no area are left out. Records
+/// without any points are written as null shapes.
+///
+/// Most tools also need a `.dbf` attribute table next to the `.shp` file,
+/// [`save_shapefile`] writes all three files.
+pub fn write_shp<P: PointScaler>(
+    records: &[Paths<P>],
+    shape_type: ShapeType,
+    mut shp: impl Write,
+    mut shx: impl Write,
+) -> Result<(), ShapefileError> {
+    let contents = records
+        .iter()
+        .map(|paths| record_content(paths, shape_type))
+        .collect::<Vec<_>>();
+
+    let bounds = records
+        .iter()
+        .filter(|paths| paths.contains_points())
+        .map(|paths| {
+            let b = paths.bounds();
+            [b.min.x(), b.min.y(), b.max.x(), b.max.y()]
+        })
+        .reduce(|a, b| {
+            [
+                a[0].min(b[0]),
+                a[1].min(b[1]),
+                a[2].max(b[2]),
+                a[3].max(b[3]),
+            ]
+        })
+        .unwrap_or_default();
+
+    let shp_length = HEADER_LENGTH + contents.iter().map(|c| 8 + c.len()).sum::<usize>();
+    let shx_length = HEADER_LENGTH + 8 * contents.len();
+
+    shp.write_all(&header(shp_length, shape_type, bounds)?)?;
+    shx.write_all(&header(shx_length, shape_type, bounds)?)?;
+
+    let mut offset = HEADER_LENGTH;
+    for (index, content) in contents.iter().enumerate() {
+        let number = i32::try_from(index + 1).map_err(|_| ShapefileError::TooLarge)?;
+        let mut record = Vec::with_capacity(8);
+        record.extend(number.to_be_bytes());
+        record.extend(words(content.len())?.to_be_bytes());
+        shp.write_all(&record)?;
+        shp.write_all(content)?;
+
+        shx.write_all(&words(offset)?.to_be_bytes())?;
+        shx.write_all(&words(content.len())?.to_be_bytes())?;
+        offset += 8 + content.len();
+    }
+
+    Ok(())
+}
+
+/// Read the records of the shapefile at `path`, see [`read_shp`]. Only the
+/// `.shp` file is read.
+pub fn load_shapefile<P: PointScaler>(
+    path: impl AsRef<std::path::Path>,
+) -> Result<Vec<Paths<P>>, ShapefileError> {
+    read_shp(std::io::BufReader::new(std::fs::File::open(path)?))
+}
+
+/// Write records to the shapefile at `path`, see [`write_shp`].
+///
+/// The `.shx` index and a `.dbf` attribute table with the record number in
+/// an `ID` column are written next to the `.shp` file, replacing the
+/// extension of `path`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use clipper2::*;
+///
+/// let zones: Vec<Paths> = vec![
+///     Path::rectangle(0.0, 0.0, 10.0, 10.0).into(),
+///     Path::rectangle(20.0, 0.0, 10.0, 10.0).into(),
+/// ];
+///
+/// save_shapefile("zones.shp", &zones, ShapeType::Polygon).unwrap();
+/// ```
+pub fn save_shapefile<P: PointScaler>(
+    path: impl AsRef<std::path::Path>,
+    records: &[Paths<P>],
+    shape_type: ShapeType,
+) -> Result<(), ShapefileError> {
+    let path = path.as_ref();
+    let create = |extension| {
+        std::fs::File::create(path.with_extension(extension)).map(std::io::BufWriter::new)
+    };
+
+    let (mut shp, mut shx, mut dbf) = (create("shp")?, create("shx")?, create("dbf")?);
+    write_shp(records, shape_type, &mut shp, &mut shx)?;
+    dbf.write_all(&id_table(records.len())?)?;
+
+    shp.flush()?;
+    shx.flush()?;
+    dbf.flush()?;
+    Ok(())
+}
+
+fn record_content<P: PointScaler>(paths: &Paths<P>, shape_type: ShapeType) -> Vec<u8> {
+    let parts: Vec<Vec<Point<P>>> = match shape_type {
+        ShapeType::PolyLine => paths
+            .iter()
+            .filter(|path| !path.is_empty())
+            .map(|path| path.iter().copied().collect())
+            .collect(),
+        ShapeType::Polygon => PolyTree::from_paths(paths, FillRule::EvenOdd)
+            .iter()
+            .filter(|node| !node.polygon().is_empty())
+            .map(|node| {
+                // Outers have a positive area in the tree, shapefiles want
+                // them clockwise.
+                let mut ring = node.polygon().iter().rev().copied().collect::<Vec<_>>();
+                ring.push(ring[0]);
+                ring
+            })
+            .collect(),
+    };
+
+    if parts.is_empty() {
+        return NULL_SHAPE.to_le_bytes().to_vec();
+    }
+
+    let points = parts.iter().flatten().collect::<Vec<_>>();
+    let bounds = Path::new(points.iter().map(|&&point| point).collect()).bounds();
+
+    let mut content = Vec::with_capacity(44 + parts.len() * 4 + points.len() * 16);
+    content.extend(shape_type.code().to_le_bytes());
+    for value in [
+        bounds.min.x(),
+        bounds.min.y(),
+        bounds.max.x(),
+        bounds.max.y(),
+    ] {
+        content.extend(value.to_le_bytes());
+    }
+    content.extend((parts.len() as i32).to_le_bytes());
+    content.extend((points.len() as i32).to_le_bytes());
+
+    let mut start = 0;
+    for part in &parts {
+        content.extend((start as i32).to_le_bytes());
+        start += part.len();
+    }
+    for point in points {
+        content.extend(point.x().to_le_bytes());
+        content.extend(point.y().to_le_bytes());
+    }
+
+    content
+}
+
+fn header(
+    length: usize,
+    shape_type: ShapeType,
+    bounds: [f64; 4],
+) -> Result<Vec<u8>, ShapefileError> {
+    let mut header = Vec::with_capacity(HEADER_LENGTH);
+    header.extend(FILE_CODE.to_be_bytes());
+    header.extend([0; 20]);
+    header.extend(words(length)?.to_be_bytes());
+    header.extend(VERSION.to_le_bytes());
+    header.extend(shape_type.code().to_le_bytes());
+    for value in bounds {
+        header.extend(value.to_le_bytes());
+    }
+    // Z and M ranges are unused.
+    header.extend([0; 32]);
+    Ok(header)
+}
+
+/// Lengths and offsets are stored as a number of 16-bit words.
+fn words(bytes: usize) -> Result<i32, ShapefileError> {
+    i32::try_from(bytes / 2).map_err(|_| ShapefileError::TooLarge)
+}
+
+/// A dBase III table with a numeric `ID` column holding the record number.
+fn id_table(records: usize) -> Result<Vec<u8>, ShapefileError> {
+    const WIDTH: usize = 10;
+
+    let count = u32::try_from(records).map_err(|_| ShapefileError::TooLarge)?;
+    let mut table = vec![0x03, 100, 1, 1];
+    table.extend(count.to_le_bytes());
+    table.extend((32u16 + 32 + 1).to_le_bytes());
+    table.extend((1 + WIDTH as u16).to_le_bytes());
+    table.extend([0; 20]);
+
+    let mut field = [0; 32];
+    field[..2].copy_from_slice(b"ID");
+    field[11] = b'N';
+    field[16] = WIDTH as u8;
+    table.extend(field);
+    table.push(0x0d);
+
+    for id in 1..=records {
+        table.push(b' ');
+        table.extend(format!("{id:>WIDTH$}").bytes());
+    }
+    table.push(0x1a);
+
+    Ok(table)
+}
+
+/// Reads values from a byte slice, failing at the end of the slice.
+struct Bytes<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Bytes<'a> {
+    fn new(data: &'a [u8], position: usize) -> Self {
+        Self { data, position }
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], ShapefileError> {
+        let bytes = self
+            .data
+            .get(self.position..self.position + N)
+            .ok_or(ShapefileError::Truncated)?;
+        self.position += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn i32_be(&mut self) -> Result<i32, ShapefileError> {
+        self.take().map(i32::from_be_bytes)
+    }
+
+    fn i32_le(&mut self) -> Result<i32, ShapefileError> {
+        self.take().map(i32::from_le_bytes)
+    }
+
+    fn f64_le(&mut self) -> Result<f64, ShapefileError> {
+        self.take().map(f64::from_le_bytes)
+    }
+
+    /// A little endian count or index, which can't be negative.
+    fn count(&mut self) -> Result<usize, ShapefileError> {
+        usize::try_from(self.i32_le()?).map_err(|_| ShapefileError::Truncated)
+    }
+}
+
+/// Shapefile reading and writing related errors
+#[derive(Debug, thiserror::Error)]
+pub enum ShapefileError {
+    /// Reading or writing a file failed
+    #[error("Shapefile IO error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The file doesn't start with the shapefile file code
+    #[error("Invalid shapefile file code {0}")]
+    InvalidFileCode(i32),
+    /// The file is shorter than its header or a record header
+    #[error("Shapefile is truncated")]
+    Truncated,
+    /// The shapes are not polygons or polylines
+    #[error("Unsupported shapefile shape type {0}")]
+    UnsupportedShapeType(i32),
+    /// A record could not be read
+    #[error("Invalid shapefile record {record}: {message}")]
+    InvalidRecord {
+        /// The number of the record, starting at 1
+        record: usize,
+        /// Description of what is wrong with the record
+        message: String,
+    },
+    /// The data doesn't fit in the 32-bit sizes of the format
+    #[error("Shapefile exceeds the size limits of the format")]
+    TooLarge,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Centi;
+
+    use super::*;
+
+    fn write(records: &[Paths<Centi>], shape_type: ShapeType) -> (Vec<u8>, Vec<u8>) {
+        let (mut shp, mut shx) = (Vec::new(), Vec::new());
+        write_shp(records, shape_type, &mut shp, &mut shx).unwrap();
+        (shp, shx)
+    }
+
+    #[test]
+    fn test_polygon_round_trip() {
+        let records: Vec<Paths<Centi>> = vec![
+            vec![
+                Path::rectangle(0.0, 0.0, 10.0, 10.0),
+                Path::rectangle(2.0, 2.0, 6.0, 6.0),
+                Path::rectangle(20.0, 0.0, 1.0, 1.0),
+            ]
+            .into(),
+            Paths::default(),
+            Path::rectangle(-5.0, -5.0, 2.0, 2.0)
+                .iter()
+                .rev()
+                .copied()
+                .collect::<Path<Centi>>()
+                .into(),
+        ];
+
+        let (shp, shx) = write(&records, ShapeType::Polygon);
+        assert_eq!(shx.len(), 100 + 3 * 8);
+        assert_eq!(
+            i32::from_be_bytes(shp[24..28].try_into().unwrap()) * 2,
+            shp.len() as i32
+        );
+        assert_eq!(f64::from_le_bytes(shp[36..44].try_into().unwrap()), -5.0);
+
+        let read = read_shp::<Centi>(shp.as_slice()).unwrap();
+        assert_eq!(read.len(), 3);
+        assert_eq!(read[0].len(), 3);
+        assert_eq!(read[0].signed_area(), 100.0 - 36.0 + 1.0);
+        assert!(read[1].is_empty());
+        assert_eq!(read[2].signed_area(), 4.0);
+        assert!(read.iter().flatten().all(|path| path.len() == 4));
+    }
+
+    #[test]
+    fn test_clockwise_outer_convention() {
+        let (shp, _) = write(
+            &[Path::rectangle(0.0, 0.0, 1.0, 1.0).into()],
+            ShapeType::Polygon,
+        );
+
+        // Points of the single ring start after the 44 byte shape header and
+        // the part index.
+        let points = shp[100 + 8 + 48..]
+            .chunks(16)
+            .map(|chunk| {
+                (
+                    f64::from_le_bytes(chunk[..8].try_into().unwrap()),
+                    f64::from_le_bytes(chunk[8..].try_into().unwrap()),
+                )
+            })
+            .collect::<Vec<_>>();
+        let ring: Path<Centi> = points[..4].to_vec().into();
+
+        assert_eq!(points.len(), 5);
+        assert_eq!(points[0], points[4]);
+        assert!(ring.signed_area() < 0.0);
+    }
+
+    #[test]
+    fn test_polyline_parts() {
+        let mut lines = Paths::<Centi>::default();
+        lines.push_open(vec![(0.0, 0.0), (5.0, 0.0), (5.0, 5.0)]);
+        lines.push_open(vec![(0.0, 0.0), (0.0, 0.0)]);
+
+        let (shp, _) = write(&[lines.clone()], ShapeType::PolyLine);
+        let read = read_shp::<Centi>(shp.as_slice()).unwrap();
+
+        assert_eq!(read, vec![lines]);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(matches!(
+            read_shp::<Centi>([0u8; 100].as_slice()),
+            Err(ShapefileError::InvalidFileCode(0))
+        ));
+        assert!(matches!(
+            read_shp::<Centi>([0u8; 3].as_slice()),
+            Err(ShapefileError::Truncated)
+        ));
+
+        let (mut shp, _) = write(
+            &[Path::rectangle(0.0, 0.0, 1.0, 1.0).into()],
+            ShapeType::Polygon,
+        );
+        shp.truncate(shp.len() - 8);
+        assert!(matches!(
+            read_shp::<Centi>(shp.as_slice()),
+            Err(ShapefileError::InvalidRecord { record: 1, .. })
+        ));
+
+        let mut points = write(&[], ShapeType::Polygon).0;
+        points[32..36].copy_from_slice(&1i32.to_le_bytes());
+        assert!(matches!(
+            read_shp::<Centi>(points.as_slice()),
+            Err(ShapefileError::UnsupportedShapeType(1))
+        ));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let file = std::env::temp_dir().join("clipper2_shapefile_test_save.shp");
+        let records: Vec<Paths<Centi>> = vec![
+            Path::rectangle(0.0, 0.0, 1.0, 1.0).into(),
+            Path::rectangle(2.0, 0.0, 1.0, 1.0).into(),
+        ];
+
+        save_shapefile(&file, &records, ShapeType::Polygon).unwrap();
+
+        assert_eq!(load_shapefile::<Centi>(&file).unwrap(), records);
+        let dbf = std::fs::read(file.with_extension("dbf")).unwrap();
+        assert_eq!(dbf.len(), 32 + 32 + 1 + 2 * 11 + 1);
+        assert_eq!(&dbf[dbf.len() - 11..dbf.len() - 1], b"         2");
+
+        for extension in ["shp", "shx", "dbf"] {
+            std::fs::remove_file(file.with_extension(extension)).unwrap();
+        }
+    }
+}
@@ -0,0 +1,561 @@
+//! A spatial index over collections of paths, for the queries of large
+//! datasets that would otherwise compare every item with every other one.
+//!
+//! A [`SpatialIndex`] stores sets of [`Paths`], such as the parcels of a map
+//! or the parts of a layout, in a packed R-tree built once from their
+//! bounds. It answers which items overlap an area, which item is nearest to
+//! a point, which items contain a point, and which tiles of a grid each item
+//! falls into.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use clipper2::*;
+//! use clipper2::spatial::SpatialIndex;
+//!
+//! let parcels = (0..100).map(|i| {
+//!     let (x, y) = ((i % 10) as f64 * 10.0, (i / 10) as f64 * 10.0);
+//!     Paths::<Centi>::from(Path::rectangle(x, y, 8.0, 8.0))
+//! });
+//! let index = SpatialIndex::new(parcels);
+//!
+//! assert_eq!(index.query(&Bounds::new(15.0, 5.0)), vec![0, 1]);
+//! assert_eq!(index.locate(Point::new(34.0, 21.0), FillRule::NonZero), vec![23]);
+//! assert_eq!(index.nearest(Point::new(39.5, 4.0)), Some((4, 0.5)));
+//! ```
+
+use std::{cmp::Ordering, collections::BTreeMap, collections::BinaryHeap};
+
+use crate::{paths::winding_number, Bounds, Centi, FillRule, Paths, Point, PointScaler};
+
+/// Number of children of a node of the tree.
+const NODE_SIZE: usize = 8;
+
+/// A packed R-tree over the bounds of a collection of paths, see the
+/// [module documentation](self).
+///
+/// The index owns the items and refers to them by their position in the
+/// input. Items without points are stored but never returned by a query.
+#[derive(Debug, Clone)]
+pub struct SpatialIndex<P: PointScaler = Centi> {
+    items: Vec<Paths<P>>,
+    tree: RTree<P>,
+}
+
+impl<P: PointScaler> SpatialIndex<P> {
+    /// Build the index over the items.
+    pub fn new(items: impl IntoIterator<Item = impl Into<Paths<P>>>) -> Self {
+        let items = items.into_iter().map(Into::into).collect::<Vec<Paths<P>>>();
+        let tree = RTree::new(items.iter().map(Paths::bounds));
+
+        Self { items, tree }
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the item at `index`.
+    pub fn get(&self, index: usize) -> Option<&Paths<P>> {
+        self.items.get(index)
+    }
+
+    /// Returns all items, in the order they were added.
+    pub fn items(&self) -> &[Paths<P>] {
+        &self.items
+    }
+
+    /// Returns the bounds of all items, or [`Bounds::minmax`] without
+    /// points.
+    pub fn bounds(&self) -> Bounds<P> {
+        self.tree.bounds()
+    }
+
+    /// Returns the indices of the items whose bounds overlap the bounds,
+    /// including bounds that only touch, in ascending order.
+    pub fn query(&self, bounds: &Bounds<P>) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.tree.query(bounds, |index| found.push(index));
+        found.sort_unstable();
+        found
+    }
+
+    /// Returns the index of the item with an edge nearest to the point, and
+    /// the distance to that edge, or `None` without items.
+    ///
+    /// The distance is measured to the edges of the paths, so it is not zero
+    /// for a point inside a closed path, see [`SpatialIndex::locate`] for
+    /// that. Closed paths include the edge from their last point back to the
+    /// first one, open paths don't. Of items at the same distance the one
+    /// added first is returned.
+    pub fn nearest(&self, point: Point<P>) -> Option<(usize, f64)> {
+        self.tree
+            .nearest(point, |index| distance_to_edges(&self.items[index], point))
+    }
+
+    /// Returns the indices of the items whose filled area contains the point
+    /// according to the fill rule, in ascending order. Points on an edge are
+    /// contained. All paths are treated as closed.
+    pub fn locate(&self, point: Point<P>, fill_rule: FillRule) -> Vec<usize> {
+        let mut found = self.query(&Bounds {
+            min: point,
+            max: point,
+        });
+        found.retain(|&index| {
+            winding_number(&point, &self.items[index])
+                .is_none_or(|winding| fill_rule.is_filled(winding))
+        });
+        found
+    }
+
+    /// Assign the items to the tiles of a grid of square tiles with the
+    /// given size, aligned with the origin.
+    ///
+    /// The tiles are keyed by their column and row, where the tile `(0, 0)`
+    /// spans from the origin to `(tile_size, tile_size)`. Each tile lists
+    /// the items whose bounds overlap it in ascending order, and tiles
+    /// without items are left out. Items are assigned by their bounds, so an
+    /// item can be listed for a tile its bounds overlap but its paths don't.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tile_size` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    /// use clipper2::spatial::SpatialIndex;
+    ///
+    /// let index = SpatialIndex::new([
+    ///     Paths::<Centi>::from(Path::rectangle(1.0, 1.0, 2.0, 2.0)),
+    ///     Paths::from(Path::rectangle(12.0, 3.0, 10.0, 2.0)),
+    /// ]);
+    ///
+    /// let tiles = index.tiles(10.0);
+    ///
+    /// assert_eq!(tiles.len(), 3);
+    /// assert_eq!(tiles[&(0, 0)], vec![0]);
+    /// assert_eq!(tiles[&(1, 0)], vec![1]);
+    /// assert_eq!(tiles[&(2, 0)], vec![1]);
+    /// ```
+    pub fn tiles(&self, tile_size: f64) -> BTreeMap<(i64, i64), Vec<usize>> {
+        assert!(tile_size > 0.0, "tile size {tile_size} is not positive");
+
+        let mut tiles = BTreeMap::<(i64, i64), Vec<usize>>::new();
+        for (index, bounds) in self.tree.bounds.iter().enumerate() {
+            if is_empty(bounds) {
+                continue;
+            }
+
+            let tile = |value: f64| (value / tile_size).floor() as i64;
+            // Bounds ending exactly on a tile edge only touch the next tile.
+            let last = |value: f64| (value / tile_size).ceil() as i64 - 1;
+            let (columns, rows) = (
+                tile(bounds.min.x())..=last(bounds.max.x()).max(tile(bounds.min.x())),
+                tile(bounds.min.y())..=last(bounds.max.y()).max(tile(bounds.min.y())),
+            );
+
+            for row in rows {
+                for column in columns.clone() {
+                    tiles.entry((column, row)).or_default().push(index);
+                }
+            }
+        }
+
+        tiles
+    }
+}
+
+impl<P: PointScaler> FromIterator<Paths<P>> for SpatialIndex<P> {
+    fn from_iter<T: IntoIterator<Item = Paths<P>>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// A packed R-tree over bounds, bulk loaded by sorting the entries into
+/// slices by x and then y (Sort-Tile-Recursive).
+///
+/// The nodes are stored by level. Node `i` of level `0` covers the entries
+/// `i * NODE_SIZE..(i + 1) * NODE_SIZE` in tree order, node `i` of a higher
+/// level covers the same range of nodes of the level below.
+#[derive(Debug, Clone)]
+pub(crate) struct RTree<P: PointScaler> {
+    /// Bounds of the entries by entry index.
+    bounds: Vec<Bounds<P>>,
+    /// Entry indices in tree order, without entries with empty bounds.
+    entries: Vec<usize>,
+    levels: Vec<Vec<Bounds<P>>>,
+}
+
+impl<P: PointScaler> RTree<P> {
+    pub(crate) fn new(bounds: impl IntoIterator<Item = Bounds<P>>) -> Self {
+        let bounds = bounds.into_iter().collect::<Vec<_>>();
+        let mut entries = (0..bounds.len())
+            .filter(|&index| !is_empty(&bounds[index]))
+            .collect::<Vec<_>>();
+
+        let center = |bounds: &Bounds<P>| {
+            (
+                i128::from(bounds.min.x_scaled()) + i128::from(bounds.max.x_scaled()),
+                i128::from(bounds.min.y_scaled()) + i128::from(bounds.max.y_scaled()),
+            )
+        };
+
+        let nodes = entries.len().div_ceil(NODE_SIZE);
+        let slices = (nodes as f64).sqrt().ceil().max(1.0) as usize;
+        let slice_size = nodes.div_ceil(slices).max(1) * NODE_SIZE;
+
+        entries.sort_by_key(|&index| center(&bounds[index]).0);
+        for slice in entries.chunks_mut(slice_size) {
+            slice.sort_by_key(|&index| center(&bounds[index]).1);
+        }
+
+        let mut levels = Vec::new();
+        let mut level = entries
+            .chunks(NODE_SIZE)
+            .map(|chunk| union(chunk.iter().map(|&index| &bounds[index])))
+            .collect::<Vec<_>>();
+        while level.len() > 1 {
+            let parent = level.chunks(NODE_SIZE).map(|chunk| union(chunk)).collect();
+            levels.push(level);
+            level = parent;
+        }
+        levels.push(level);
+
+        Self {
+            bounds,
+            entries,
+            levels,
+        }
+    }
+
+    /// Returns the bounds of all entries.
+    pub(crate) fn bounds(&self) -> Bounds<P> {
+        union(self.levels.last().into_iter().flatten())
+    }
+
+    /// Call the function with each entry whose bounds overlap the bounds, in
+    /// tree order.
+    pub(crate) fn query(&self, bounds: &Bounds<P>, mut f: impl FnMut(usize)) {
+        if let Some(top) = self.levels.last() {
+            for node in 0..top.len() {
+                self.query_node(self.levels.len() - 1, node, bounds, &mut f);
+            }
+        }
+    }
+
+    fn query_node(&self, level: usize, node: usize, bounds: &Bounds<P>, f: &mut impl FnMut(usize)) {
+        if !self.levels[level][node].overlaps(bounds) {
+            return;
+        }
+
+        let children = node * NODE_SIZE..((node + 1) * NODE_SIZE);
+        if level == 0 {
+            for &index in &self.entries[children.start..children.end.min(self.entries.len())] {
+                if self.bounds[index].overlaps(bounds) {
+                    f(index);
+                }
+            }
+        } else {
+            for child in children.start..children.end.min(self.levels[level - 1].len()) {
+                self.query_node(level - 1, child, bounds, f);
+            }
+        }
+    }
+
+    /// Returns the entry with the smallest distance to the point and the
+    /// distance, where the distance of an entry is never less than the
+    /// distance to its bounds.
+    pub(crate) fn nearest(
+        &self,
+        point: Point<P>,
+        mut distance: impl FnMut(usize) -> f64,
+    ) -> Option<(usize, f64)> {
+        let mut queue = BinaryHeap::new();
+        if let Some(top) = self.levels.last() {
+            for (node, bounds) in top.iter().enumerate() {
+                queue.push(Candidate::node(self.levels.len(), node, bounds, point));
+            }
+        }
+
+        while let Some(candidate) = queue.pop() {
+            match candidate.level {
+                Candidate::EXACT => return Some((candidate.index, candidate.distance)),
+                Candidate::ENTRY => queue.push(Candidate {
+                    distance: distance(candidate.index),
+                    level: Candidate::EXACT,
+                    index: candidate.index,
+                }),
+                1 => {
+                    let end = ((candidate.index + 1) * NODE_SIZE).min(self.entries.len());
+                    for &index in &self.entries[candidate.index * NODE_SIZE..end] {
+                        let bounds = &self.bounds[index];
+                        queue.push(Candidate::node(Candidate::ENTRY, index, bounds, point));
+                    }
+                }
+                level => {
+                    let children = &self.levels[level - 2];
+                    let end = ((candidate.index + 1) * NODE_SIZE).min(children.len());
+                    let start = candidate.index * NODE_SIZE;
+                    for (child, bounds) in children[start..end].iter().enumerate() {
+                        queue.push(Candidate::node(level - 1, start + child, bounds, point));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// A node or entry of the tree in the queue of a nearest query, ordered by
+/// ascending distance and then index.
+#[derive(Debug)]
+struct Candidate {
+    distance: f64,
+    /// [`Candidate::EXACT`] for an entry with its exact distance,
+    /// [`Candidate::ENTRY`] for an entry with the distance to its bounds,
+    /// otherwise the level of the node plus one.
+    level: usize,
+    index: usize,
+}
+
+impl Candidate {
+    const EXACT: usize = usize::MAX;
+    const ENTRY: usize = 0;
+
+    fn node<P: PointScaler>(
+        level: usize,
+        index: usize,
+        bounds: &Bounds<P>,
+        point: Point<P>,
+    ) -> Self {
+        let dx = (bounds.min.x() - point.x())
+            .max(point.x() - bounds.max.x())
+            .max(0.0);
+        let dy = (bounds.min.y() - point.y())
+            .max(point.y() - bounds.max.y())
+            .max(0.0);
+
+        Self {
+            distance: dx.hypot(dy),
+            level,
+            index,
+        }
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, so the binary heap pops the nearest candidate first. Of
+        // equally near candidates, nodes and bounds are expanded before any
+        // exact entry is returned, so the first added of the nearest entries
+        // wins.
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then_with(|| (self.level == Candidate::EXACT).cmp(&(other.level == Candidate::EXACT)))
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+fn is_empty<P: PointScaler>(bounds: &Bounds<P>) -> bool {
+    bounds.min.x_scaled() > bounds.max.x_scaled()
+}
+
+fn union<'a, P: PointScaler + 'a>(bounds: impl IntoIterator<Item = &'a Bounds<P>>) -> Bounds<P> {
+    bounds
+        .into_iter()
+        .fold(Bounds::minmax(), |union, bounds| Bounds {
+            min: Point::from_scaled(
+                union.min.x_scaled().min(bounds.min.x_scaled()),
+                union.min.y_scaled().min(bounds.min.y_scaled()),
+            ),
+            max: Point::from_scaled(
+                union.max.x_scaled().max(bounds.max.x_scaled()),
+                union.max.y_scaled().max(bounds.max.y_scaled()),
+            ),
+        })
+}
+
+/// Returns the distance from the point to the nearest edge of the paths.
+fn distance_to_edges<P: PointScaler>(paths: &Paths<P>, point: Point<P>) -> f64 {
+    let mut nearest = f64::INFINITY;
+
+    for (index, path) in paths.iter().enumerate() {
+        if let [single] = path[..] {
+            nearest = nearest.min(point.distance_to(&single));
+            continue;
+        }
+
+        let closing = (!paths.is_open(index))
+            .then(|| path.last().zip(path.first()))
+            .flatten();
+        for (start, end) in path
+            .windows(2)
+            .map(|pair| (&pair[0], &pair[1]))
+            .chain(closing)
+        {
+            nearest = nearest.min(point.distance_to_segment(start, end));
+        }
+    }
+
+    nearest
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Path;
+
+    use super::*;
+
+    fn scattered(count: usize) -> Vec<Paths<Centi>> {
+        // A deterministic spread of rectangles of different sizes.
+        (0..count)
+            .map(|i| {
+                let x = ((i * 7919) % 997) as f64 / 10.0;
+                let y = ((i * 6271) % 991) as f64 / 10.0;
+                let size = 0.5 + (i % 5) as f64;
+                Path::rectangle(x, y, size, size / 2.0).into()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_query_matches_linear_scan() {
+        let items = scattered(500);
+        let index = SpatialIndex::new(items.clone());
+
+        for window in [
+            Bounds::new(10.0, 10.0),
+            Bounds {
+                min: Point::new(40.0, 55.5),
+                max: Point::new(52.25, 70.0),
+            },
+            Bounds::new(200.0, 200.0),
+            Bounds {
+                min: Point::new(-5.0, -5.0),
+                max: Point::new(-1.0, -1.0),
+            },
+        ] {
+            let expected = (0..items.len())
+                .filter(|&i| items[i].bounds().overlaps(&window))
+                .collect::<Vec<_>>();
+            assert_eq!(index.query(&window), expected);
+        }
+
+        assert_eq!(index.query(&Bounds::new(200.0, 200.0)).len(), 500);
+        assert_eq!(
+            index.bounds(),
+            items.iter().flatten().cloned().collect::<Paths>().bounds()
+        );
+    }
+
+    #[test]
+    fn test_nearest_matches_linear_scan() {
+        let mut items = scattered(300);
+        let mut line = Paths::default();
+        line.push_open(vec![(50.0, 120.0), (60.0, 120.0), (60.0, 130.0)]);
+        items.push(line);
+        items.push(Paths::default());
+        let index = SpatialIndex::new(items.clone());
+
+        for (x, y) in [
+            (0.0, 0.0),
+            (33.3, 47.1),
+            (120.0, -3.0),
+            (59.0, 129.0),
+            (55.0, 121.0),
+        ] {
+            let point = Point::new(x, y);
+            let expected = items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (i, distance_to_edges(item, point)))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .unwrap();
+            assert_eq!(index.nearest(point), Some(expected), "{point}");
+        }
+
+        // The open path has no closing edge.
+        assert_eq!(index.nearest(Point::new(55.0, 125.0)).unwrap().0, 300);
+        assert!((index.nearest(Point::new(59.0, 121.0)).unwrap().1 - 1.0).abs() < 1e-9);
+        assert_eq!(
+            SpatialIndex::<Centi>::new(Vec::<Paths>::new()).nearest(Point::ZERO),
+            None
+        );
+    }
+
+    #[test]
+    fn test_locate_honors_fill_rule() {
+        let hole = Path::rectangle(2.0, 2.0, 2.0, 2.0)
+            .iter()
+            .rev()
+            .copied()
+            .collect();
+        let frame = Paths::<Centi>::from(vec![Path::rectangle(0.0, 0.0, 6.0, 6.0), hole]);
+        let overlapping = Paths::from(vec![
+            Path::rectangle(2.0, 2.0, 2.0, 2.0),
+            Path::rectangle(2.0, 2.0, 2.0, 2.0),
+        ]);
+        let index = SpatialIndex::new([frame, overlapping, Paths::default()]);
+
+        assert_eq!(
+            index.locate(Point::new(1.0, 1.0), FillRule::NonZero),
+            vec![0]
+        );
+        assert_eq!(
+            index.locate(Point::new(3.0, 3.0), FillRule::NonZero),
+            vec![1]
+        );
+        assert!(index
+            .locate(Point::new(3.0, 3.0), FillRule::EvenOdd)
+            .is_empty());
+        assert_eq!(
+            index.locate(Point::new(2.0, 3.0), FillRule::EvenOdd),
+            vec![0, 1]
+        );
+        assert!(index
+            .locate(Point::new(7.0, 3.0), FillRule::NonZero)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_tiles() {
+        let index = SpatialIndex::new([
+            Paths::<Centi>::from(Path::rectangle(-1.0, -1.0, 2.0, 2.0)),
+            Paths::from(Path::rectangle(0.0, 0.0, 10.0, 10.0)),
+            Paths::default(),
+        ]);
+
+        let tiles = index.tiles(10.0);
+
+        assert_eq!(
+            tiles.keys().copied().collect::<Vec<_>>(),
+            vec![(-1, -1), (-1, 0), (0, -1), (0, 0)]
+        );
+        assert_eq!(tiles[&(0, 0)], vec![0, 1]);
+        assert_eq!(tiles[&(-1, -1)], vec![0]);
+    }
+}
@@ -0,0 +1,231 @@
+use std::{fmt, time::Duration, time::Instant};
+
+use clipper2c_sys::{clipper_paths64_inflate, clipper_paths64_size};
+
+use crate::{malloc, memory::delete_paths64, EndType, JoinType, Paths, PointScaler};
+
+/// Vertex counts and timing of an execution, as returned by
+/// [`Clipper::boolean_with_stats`](crate::Clipper::boolean_with_stats) and
+/// [`inflate_with_stats`].
+///
+/// The time is split between marshalling, converting paths between this
+/// crate and the native representation in either direction, and the native
+/// engine running the operation itself. Comparing the two shows whether a
+/// pipeline is better served by reusing native paths or by reducing the
+/// work of the engine, such as simplifying the input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExecutionStats {
+    /// Number of input paths
+    pub input_paths: usize,
+    /// Number of points of the input paths
+    pub input_vertices: usize,
+    /// Number of output paths, closed and open
+    pub output_paths: usize,
+    /// Number of points of the output paths
+    pub output_vertices: usize,
+    /// Time spent converting the inputs to native paths and the result back
+    pub marshalling: Duration,
+    /// Time spent in the native engine
+    pub engine: Duration,
+}
+
+impl ExecutionStats {
+    /// Returns the total time of the execution.
+    pub fn total(&self) -> Duration {
+        self.marshalling + self.engine
+    }
+}
+
+/// Formats the stats on one line, for logging.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use clipper2::*;
+///
+/// let stats = ExecutionStats {
+///     input_paths: 2,
+///     input_vertices: 8,
+///     output_paths: 1,
+///     output_vertices: 4,
+///     marshalling: Duration::from_micros(3),
+///     engine: Duration::from_micros(12),
+/// };
+///
+/// assert_eq!(
+///     stats.to_string(),
+///     "2 paths (8 vertices) -> 1 paths (4 vertices), marshalling 3µs, engine 12µs"
+/// );
+/// ```
+impl fmt::Display for ExecutionStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} paths ({} vertices) -> {} paths ({} vertices), marshalling {:?}, engine {:?}",
+            self.input_paths,
+            self.input_vertices,
+            self.output_paths,
+            self.output_vertices,
+            self.marshalling,
+            self.engine
+        )
+    }
+}
+
+/// This function offsets paths like [`inflate`](crate::inflate) and
+/// returns the result together with [`ExecutionStats`] on the execution.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let paths: Paths = Path::rectangle(0.0, 0.0, 2.0, 2.0).into();
+///
+/// let (result, stats) = inflate_with_stats(paths, 1.0, JoinType::Miter, EndType::Polygon, 2.0);
+///
+/// assert_eq!(result.signed_area(), 16.0);
+/// assert_eq!((stats.input_paths, stats.input_vertices), (1, 4));
+/// assert_eq!(stats.output_vertices, result.vertex_count());
+/// ```
+pub fn inflate_with_stats<P: PointScaler>(
+    paths: impl Into<Paths<P>>,
+    delta: f64,
+    join_type: JoinType,
+    end_type: EndType,
+    miter_limit: f64,
+) -> (Paths<P>, ExecutionStats) {
+    let paths: Paths<P> = paths.into();
+
+    let start = Instant::now();
+    let native = paths.as_clipperpaths64();
+    let input_time = start.elapsed();
+
+    unsafe {
+        let start = Instant::now();
+        let result_ptr = clipper_paths64_inflate(
+            malloc(clipper_paths64_size()),
+            native,
+            P::scale(delta),
+            join_type.into(),
+            end_type.into(),
+            P::scale(miter_limit),
+        );
+        let engine = start.elapsed();
+
+        let start = Instant::now();
+        let result = Paths::from_clipperpaths64(result_ptr);
+        delete_paths64(result_ptr);
+        let output_time = start.elapsed();
+
+        let stats = ExecutionStats {
+            input_paths: paths.len(),
+            input_vertices: paths.vertex_count(),
+            output_paths: result.len(),
+            output_vertices: result.vertex_count(),
+            marshalling: input_time + output_time,
+            engine,
+        };
+
+        (result, stats)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{inflate, Centi, ClipType, Clipper, FillRule, NoSubjects, Path};
+
+    use super::*;
+
+    #[test]
+    fn test_boolean_with_stats_matches_boolean() {
+        let subject: Paths<Centi> = vec![
+            Path::rectangle(0.0, 0.0, 4.0, 4.0),
+            Path::rectangle(10.0, 0.0, 1.0, 1.0),
+        ]
+        .into();
+        let mut open = Paths::<Centi>::default();
+        open.push_open(vec![(-1.0, 2.0), (5.0, 2.0)]);
+
+        let clipper = Clipper::<NoSubjects, Centi>::new()
+            .add_subject(subject.clone())
+            .add_open_subject(open)
+            .add_clip(Path::rectangle(2.0, -1.0, 4.0, 6.0));
+        let (result, stats) = clipper
+            .boolean_with_stats(ClipType::Intersection, FillRule::NonZero)
+            .unwrap();
+
+        let expected = subject
+            .to_clipper_subject()
+            .add_open_subject(vec![(-1.0, 2.0), (5.0, 2.0)])
+            .add_clip(Path::rectangle(2.0, -1.0, 4.0, 6.0))
+            .intersect(FillRule::NonZero)
+            .unwrap();
+        assert_eq!(result, expected);
+
+        assert_eq!(stats.input_paths, 4);
+        assert_eq!(stats.input_vertices, 4 + 4 + 2 + 4);
+        assert_eq!(stats.output_paths, 2);
+        assert_eq!(stats.output_vertices, result.vertex_count());
+        assert_eq!(stats.total(), stats.marshalling + stats.engine);
+    }
+
+    #[test]
+    fn test_boolean_with_stats_keeping_inputs() {
+        let clipper = Clipper::<NoSubjects, Centi>::new()
+            .add_subject(Path::rectangle(0.0, 0.0, 10.0, 10.0))
+            .add_clip(Path::rectangle(0.0, 0.0, 2.0, 2.0))
+            .execute_keeping_inputs();
+
+        let (first, stats) = clipper
+            .boolean_with_stats(ClipType::Difference, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(first.closed.signed_area(), 96.0);
+        assert_eq!(stats.input_paths, 2);
+
+        let clipper = clipper.add_clip(Path::rectangle(8.0, 8.0, 2.0, 2.0));
+        let (second, stats) = clipper
+            .boolean_with_stats(ClipType::Difference, FillRule::NonZero)
+            .unwrap();
+        assert_eq!(second.closed.signed_area(), 92.0);
+        assert_eq!(stats.input_paths, 3);
+    }
+
+    #[test]
+    fn test_inflate_with_stats_matches_inflate() {
+        let paths: Paths<Centi> = vec![
+            Path::rectangle(0.0, 0.0, 3.0, 3.0),
+            Path::rectangle(5.0, 0.0, 3.0, 3.0),
+        ]
+        .into();
+
+        let (result, stats) =
+            inflate_with_stats(paths.clone(), 0.5, JoinType::Round, EndType::Polygon, 0.0);
+
+        assert_eq!(
+            result,
+            inflate(paths, 0.5, JoinType::Round, EndType::Polygon, 0.0)
+        );
+        assert_eq!((stats.input_paths, stats.input_vertices), (2, 8));
+        assert_eq!(stats.output_paths, 2);
+        assert!(stats.output_vertices > 8);
+    }
+
+    #[test]
+    fn test_timing_is_measured() {
+        let many: Paths<Centi> = (0..2000)
+            .map(|i| Path::rectangle(f64::from(i % 50), f64::from(i / 50), 0.75, 0.75))
+            .collect();
+
+        let (_, stats) = many
+            .to_clipper_subject()
+            .add_clip(Paths::default())
+            .boolean_with_stats(ClipType::Union, FillRule::NonZero)
+            .unwrap();
+
+        assert!(stats.engine > Duration::ZERO);
+        assert!(stats.marshalling > Duration::ZERO);
+        assert_eq!(stats.output_paths, 2000);
+    }
+}
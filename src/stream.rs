@@ -0,0 +1,187 @@
+use crate::{BooleanResult, Centi, ClipType, Clipper, ClipperError, FillRule, Paths, PointScaler};
+
+/// Clips a stream of subjects against a fixed set of clip paths, one subject
+/// at a time.
+///
+/// The clip paths are converted to the native representation once and reused
+/// for every subject, and each result is produced only when the iterator
+/// returned by [`ClipStream::process`] is advanced. Only the clip paths and
+/// the subject currently being clipped are held in memory, so datasets larger
+/// than the available memory can be processed by streaming them in from and
+/// back out to disk.
+///
+/// # Examples
+///
+/// ```rust
+/// use clipper2::*;
+///
+/// let clips: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+/// let subjects = (0..20).map(|i| Path::<Centi>::rectangle(i as f64, 5.0, 1.0, 1.0));
+///
+/// let areas = ClipStream::new(clips)
+///     .process(subjects)
+///     .map(|result| result.unwrap().closed.signed_area())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(areas.len(), 20);
+/// assert_eq!(areas.iter().sum::<f64>(), 10.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClipStream<P: PointScaler = Centi> {
+    clips: Paths<P>,
+    clip_type: ClipType,
+    fill_rule: FillRule,
+}
+
+impl<P: PointScaler> ClipStream<P> {
+    /// Create a stream that intersects each subject with the clip paths using
+    /// the default fill rule.
+    pub fn new(clips: impl Into<Paths<P>>) -> Self {
+        Self {
+            clips: clips.into(),
+            clip_type: ClipType::Intersection,
+            fill_rule: FillRule::default(),
+        }
+    }
+
+    /// Keep the parts of each subject that are inside the clip paths. This is
+    /// the default.
+    pub fn intersect(mut self) -> Self {
+        self.clip_type = ClipType::Intersection;
+        self
+    }
+
+    /// Keep the parts of each subject that are outside the clip paths.
+    pub fn difference(mut self) -> Self {
+        self.clip_type = ClipType::Difference;
+        self
+    }
+
+    /// Join each subject with the clip paths.
+    pub fn union(mut self) -> Self {
+        self.clip_type = ClipType::Union;
+        self
+    }
+
+    /// Keep the parts covered by either each subject or the clip paths, but
+    /// not both.
+    pub fn xor(mut self) -> Self {
+        self.clip_type = ClipType::Xor;
+        self
+    }
+
+    /// Set the fill rule used for both the subjects and the clip paths.
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// Get the clip paths of the stream.
+    pub fn clips(&self) -> &Paths<P> {
+        &self.clips
+    }
+
+    /// Clip a single subject against the clip paths.
+    pub fn clip(&self, subject: impl Into<Paths<P>>) -> Result<BooleanResult<P>, ClipperError> {
+        Clipper::new()
+            .add_subject(subject)
+            .add_clip_ref(&self.clips)
+            .boolean_operation(self.clip_type, self.fill_rule)
+    }
+
+    /// Lazily clip each subject of the iterator against the clip paths,
+    /// yielding one result per subject in the same order.
+    pub fn process<I>(&self, subjects: I) -> ClipStreamIter<'_, P, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: Into<Paths<P>>,
+    {
+        ClipStreamIter {
+            stream: self,
+            subjects: subjects.into_iter(),
+        }
+    }
+}
+
+/// An iterator clipping subjects against the clip paths of a [`ClipStream`],
+/// see [`ClipStream::process`].
+#[derive(Debug)]
+pub struct ClipStreamIter<'a, P: PointScaler, I> {
+    stream: &'a ClipStream<P>,
+    subjects: I,
+}
+
+impl<P: PointScaler, I> Iterator for ClipStreamIter<'_, P, I>
+where
+    I: Iterator,
+    I::Item: Into<Paths<P>>,
+{
+    type Item = Result<BooleanResult<P>, ClipperError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.subjects
+            .next()
+            .map(|subject| self.stream.clip(subject))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.subjects.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{difference, intersect, Path};
+
+    use super::*;
+
+    #[test]
+    fn test_matches_single_operations() {
+        let clips = Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 4.0, 4.0),
+            Path::rectangle(6.0, 0.0, 4.0, 4.0),
+        ]);
+        let subjects = (0..6)
+            .map(|i| Path::<Centi>::rectangle(i as f64 * 2.0 - 1.0, 1.0, 3.0, 1.0))
+            .collect::<Vec<_>>();
+
+        let intersections = ClipStream::new(clips.clone())
+            .process(subjects.clone())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let differences = ClipStream::new(clips.clone())
+            .difference()
+            .process(subjects.iter().cloned())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(intersections.len(), subjects.len());
+        for ((subject, intersection), difference_result) in
+            subjects.into_iter().zip(intersections).zip(differences)
+        {
+            assert_eq!(
+                intersection,
+                intersect(subject.clone(), clips.clone(), FillRule::default()).unwrap()
+            );
+            assert_eq!(
+                difference_result,
+                difference(subject, clips.clone(), FillRule::default()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_lazy() {
+        let stream = ClipStream::<Centi>::new(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+        let consumed = std::cell::Cell::new(0);
+
+        let mut results = stream.process((0..).map(|i| {
+            consumed.set(consumed.get() + 1);
+            Path::<Centi>::rectangle(i as f64, 0.0, 1.0, 1.0)
+        }));
+
+        assert!(results.next().is_some());
+        assert!(results.next().is_some());
+        assert_eq!(consumed.get(), 2);
+    }
+}
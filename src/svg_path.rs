@@ -0,0 +1,640 @@
+use std::f64::consts::PI;
+
+use crate::{Path, Paths, Point, PointScaler};
+
+impl<P: PointScaler> Paths<P> {
+    /// Parse an SVG path data string (the `d` attribute of a `<path>`
+    /// element) into a set of paths, one for each subpath.
+    ///
+    /// All commands are supported, both in absolute and relative form. Cubic
+    /// and quadratic bezier curves as well as elliptical arcs are flattened
+    /// into line segments that deviate at most `tolerance` from the curve,
+    /// with at most 10 000 segments per curve.
+    ///
+    /// Numbers that aren't finite, and coordinates or arcs beyond the range
+    /// the scaler can represent, are rejected with
+    /// [`SvgPathError::OutOfRange`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = Paths::from_svg_path_data(
+    ///     "M 0 0 L 10 0 L 10 10 Z m 2 2 h 2 v 2 h -2 z",
+    ///     0.1,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(paths.len(), 2);
+    /// assert_eq!(paths[0], Path::from(vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)]));
+    /// assert_eq!(paths[1], Path::from(vec![(2.0, 2.0), (4.0, 2.0), (4.0, 4.0), (2.0, 4.0)]));
+    /// ```
+    pub fn from_svg_path_data(data: &str, tolerance: f64) -> Result<Self, SvgPathError> {
+        let mut parser = SvgPathParser::new(data, tolerance, i64::MAX as f64 / P::MULTIPLIER);
+        parser.parse()?;
+
+        Ok(Paths::new(
+            parser
+                .subpaths
+                .into_iter()
+                .map(|points| {
+                    points
+                        .into_iter()
+                        .map(|(x, y)| Point::<P>::new(x, y))
+                        .collect::<Path<P>>()
+                })
+                .collect(),
+        ))
+    }
+}
+
+type Coordinate = (f64, f64);
+
+struct SvgPathParser<'a> {
+    data: &'a [u8],
+    position: usize,
+    tolerance: f64,
+    /// Largest absolute coordinate the scaler can represent.
+    max_coordinate: f64,
+    subpaths: Vec<Vec<Coordinate>>,
+    current: Coordinate,
+    subpath_start: Coordinate,
+    last_control: Option<Coordinate>,
+    last_command: u8,
+}
+
+impl<'a> SvgPathParser<'a> {
+    fn new(data: &'a str, tolerance: f64, max_coordinate: f64) -> Self {
+        Self {
+            data: data.as_bytes(),
+            position: 0,
+            tolerance: tolerance.abs().max(f64::EPSILON),
+            max_coordinate,
+            subpaths: Vec::new(),
+            current: (0.0, 0.0),
+            subpath_start: (0.0, 0.0),
+            last_control: None,
+            last_command: b' ',
+        }
+    }
+
+    fn parse(&mut self) -> Result<(), SvgPathError> {
+        let mut command = None;
+
+        loop {
+            self.skip_separators();
+
+            let Some(&c) = self.data.get(self.position) else {
+                break;
+            };
+
+            if c.is_ascii_alphabetic() {
+                self.position += 1;
+                command = Some(c);
+            } else if command.is_none() {
+                return Err(self.error("expected a command"));
+            }
+
+            let c = command.unwrap_or_default();
+            self.parse_command(c)?;
+
+            // Coordinate pairs following a moveto are implicit lineto commands.
+            command = match c {
+                b'M' => Some(b'L'),
+                b'm' => Some(b'l'),
+                b'Z' | b'z' => None,
+                _ => command,
+            };
+        }
+
+        self.finish_subpath();
+        Ok(())
+    }
+
+    fn parse_command(&mut self, command: u8) -> Result<(), SvgPathError> {
+        let relative = command.is_ascii_lowercase();
+        let (ox, oy) = if relative { self.current } else { (0.0, 0.0) };
+
+        match command.to_ascii_uppercase() {
+            b'M' => {
+                let to = self.parse_pair(ox, oy)?;
+                self.finish_subpath();
+                self.subpaths.push(vec![to]);
+                self.current = to;
+                self.subpath_start = to;
+            }
+            b'L' => {
+                let to = self.parse_pair(ox, oy)?;
+                self.line_to(to);
+            }
+            b'H' => {
+                let x = self.parse_number()? + ox;
+                let to = self.check((x, self.current.1))?;
+                self.line_to(to);
+            }
+            b'V' => {
+                let y = self.parse_number()? + oy;
+                let to = self.check((self.current.0, y))?;
+                self.line_to(to);
+            }
+            b'C' => {
+                let c1 = self.parse_pair(ox, oy)?;
+                let c2 = self.parse_pair(ox, oy)?;
+                let to = self.parse_pair(ox, oy)?;
+                self.cubic_to(c1, c2, to);
+            }
+            b'S' => {
+                let c1 = self.reflected_control(b"CcSs");
+                let c2 = self.parse_pair(ox, oy)?;
+                let to = self.parse_pair(ox, oy)?;
+                self.cubic_to(c1, c2, to);
+            }
+            b'Q' => {
+                let c = self.parse_pair(ox, oy)?;
+                let to = self.parse_pair(ox, oy)?;
+                self.quadratic_to(c, to);
+            }
+            b'T' => {
+                let c = self.reflected_control(b"QqTt");
+                let to = self.parse_pair(ox, oy)?;
+                self.quadratic_to(c, to);
+            }
+            b'A' => {
+                let rx = self.parse_number()?;
+                let ry = self.parse_number()?;
+                let rotation = self.parse_number()?;
+                let large_arc = self.parse_flag()?;
+                let sweep = self.parse_flag()?;
+                let to = self.parse_pair(ox, oy)?;
+                self.arc_to(rx, ry, rotation, large_arc, sweep, to)?;
+            }
+            b'Z' => {
+                self.current = self.subpath_start;
+                self.last_control = None;
+                self.finish_subpath();
+            }
+            _ => {
+                self.position -= 1;
+                return Err(self.error("unknown command"));
+            }
+        }
+
+        self.last_command = command;
+        Ok(())
+    }
+
+    fn line_to(&mut self, to: Coordinate) {
+        self.push(to);
+        self.last_control = None;
+    }
+
+    fn cubic_to(&mut self, c1: Coordinate, c2: Coordinate, to: Coordinate) {
+        let p0 = self.current;
+        let dd = distance_from_origin(p0.0 - 2.0 * c1.0 + c2.0, p0.1 - 2.0 * c1.1 + c2.1).max(
+            distance_from_origin(c1.0 - 2.0 * c2.0 + to.0, c1.1 - 2.0 * c2.1 + to.1),
+        );
+        let segments = self.segment_count(0.75 * dd);
+
+        for i in 1..=segments {
+            let t = i as f64 / segments as f64;
+            let mt = 1.0 - t;
+            let a = mt * mt * mt;
+            let b = 3.0 * mt * mt * t;
+            let c = 3.0 * mt * t * t;
+            let d = t * t * t;
+            self.push((
+                a * p0.0 + b * c1.0 + c * c2.0 + d * to.0,
+                a * p0.1 + b * c1.1 + c * c2.1 + d * to.1,
+            ));
+        }
+
+        self.last_control = Some(c2);
+    }
+
+    fn quadratic_to(&mut self, c: Coordinate, to: Coordinate) {
+        let p0 = self.current;
+        let dd = distance_from_origin(p0.0 - 2.0 * c.0 + to.0, p0.1 - 2.0 * c.1 + to.1);
+        let segments = self.segment_count(0.25 * dd);
+
+        for i in 1..=segments {
+            let t = i as f64 / segments as f64;
+            let mt = 1.0 - t;
+            let a = mt * mt;
+            let b = 2.0 * mt * t;
+            let d = t * t;
+            self.push((a * p0.0 + b * c.0 + d * to.0, a * p0.1 + b * c.1 + d * to.1));
+        }
+
+        self.last_control = Some(c);
+    }
+
+    /// Flattens an elliptical arc using the endpoint to center
+    /// parameterization conversion from the SVG specification.
+    fn arc_to(
+        &mut self,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        to: Coordinate,
+    ) -> Result<(), SvgPathError> {
+        let from = self.current;
+        let mut rx = rx.abs();
+        let mut ry = ry.abs();
+
+        if from == to {
+            return Ok(());
+        }
+
+        if rx == 0.0 || ry == 0.0 {
+            self.line_to(to);
+            return Ok(());
+        }
+
+        let phi = rotation.to_radians();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        let dx = (from.0 - to.0) / 2.0;
+        let dy = (from.1 - to.1) / 2.0;
+        let x1 = cos_phi * dx + sin_phi * dy;
+        let y1 = -sin_phi * dx + cos_phi * dy;
+
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+        let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let mut coefficient = (numerator / denominator).max(0.0).sqrt();
+        if large_arc == sweep {
+            coefficient = -coefficient;
+        }
+
+        let cx1 = coefficient * rx * y1 / ry;
+        let cy1 = -coefficient * ry * x1 / rx;
+        let cx = cos_phi * cx1 - sin_phi * cy1 + (from.0 + to.0) / 2.0;
+        let cy = sin_phi * cx1 + cos_phi * cy1 + (from.1 + to.1) / 2.0;
+
+        let radius = rx.max(ry);
+        if cx.abs() + radius > self.max_coordinate || cy.abs() + radius > self.max_coordinate {
+            return Err(self.out_of_range("arc exceeds the coordinate range of the scaler"));
+        }
+
+        let start_angle = ((y1 - cy1) / ry).atan2((x1 - cx1) / rx);
+        let mut sweep_angle = ((-y1 - cy1) / ry).atan2((-x1 - cx1) / rx) - start_angle;
+        if sweep && sweep_angle < 0.0 {
+            sweep_angle += 2.0 * PI;
+        } else if !sweep && sweep_angle > 0.0 {
+            sweep_angle -= 2.0 * PI;
+        }
+
+        let step = if self.tolerance < radius {
+            2.0 * (1.0 - self.tolerance / radius).acos()
+        } else {
+            PI / 2.0
+        };
+        // The step is zero when the tolerance is negligible to the radius.
+        let segments = ((sweep_angle.abs() / step).ceil() as usize).clamp(1, 10_000);
+
+        for i in 1..segments {
+            let angle = start_angle + sweep_angle * i as f64 / segments as f64;
+            let (sin, cos) = angle.sin_cos();
+            self.push((
+                cx + rx * cos * cos_phi - ry * sin * sin_phi,
+                cy + rx * cos * sin_phi + ry * sin * cos_phi,
+            ));
+        }
+
+        self.push(to);
+        self.last_control = None;
+        Ok(())
+    }
+
+    fn reflected_control(&self, previous_commands: &[u8]) -> Coordinate {
+        match self.last_control {
+            Some((x, y)) if previous_commands.contains(&self.last_command) => {
+                (2.0 * self.current.0 - x, 2.0 * self.current.1 - y)
+            }
+            _ => self.current,
+        }
+    }
+
+    /// Number of segments needed to keep a curve with the given second
+    /// derivative bound within tolerance.
+    fn segment_count(&self, bound: f64) -> usize {
+        ((bound / self.tolerance).sqrt().ceil() as usize).clamp(1, 10_000)
+    }
+
+    fn push(&mut self, point: Coordinate) {
+        if self.subpaths.is_empty() || self.last_command == b'Z' || self.last_command == b'z' {
+            self.finish_subpath();
+            self.subpaths.push(vec![self.current]);
+            self.last_command = b' ';
+        }
+
+        if let Some(subpath) = self.subpaths.last_mut() {
+            if subpath.last() != Some(&point) {
+                subpath.push(point);
+            }
+        }
+
+        self.current = point;
+    }
+
+    /// Removes the closing point of the last subpath, if it repeats the start,
+    /// and drops subpaths that never got more than a single point.
+    fn finish_subpath(&mut self) {
+        if let Some(subpath) = self.subpaths.last_mut() {
+            if subpath.len() > 1 && subpath.first() == subpath.last() {
+                subpath.pop();
+            }
+
+            if subpath.len() < 2 {
+                self.subpaths.pop();
+            }
+        }
+    }
+
+    fn parse_pair(&mut self, ox: f64, oy: f64) -> Result<Coordinate, SvgPathError> {
+        let x = self.parse_number()?;
+        let y = self.parse_number()?;
+        self.check((x + ox, y + oy))
+    }
+
+    /// Returns the coordinate if the scaler can represent it.
+    fn check(&self, (x, y): Coordinate) -> Result<Coordinate, SvgPathError> {
+        if x.abs() > self.max_coordinate || y.abs() > self.max_coordinate {
+            return Err(self.out_of_range("coordinate exceeds the range of the scaler"));
+        }
+        Ok((x, y))
+    }
+
+    fn parse_number(&mut self) -> Result<f64, SvgPathError> {
+        self.skip_separators();
+        let start = self.position;
+
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.position += 1;
+        }
+
+        self.skip_digits();
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            self.skip_digits();
+        }
+
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let mantissa_end = self.position;
+            self.position += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.position += 1;
+            }
+            let exponent_start = self.position;
+            self.skip_digits();
+            if self.position == exponent_start {
+                self.position = mantissa_end;
+            }
+        }
+
+        std::str::from_utf8(&self.data[start..self.position])
+            .ok()
+            .and_then(|number| number.parse::<f64>().ok())
+            .ok_or_else(|| {
+                self.position = start;
+                self.error("expected a number")
+            })
+            .and_then(|number| {
+                if number.is_finite() {
+                    Ok(number)
+                } else {
+                    self.position = start;
+                    Err(self.out_of_range("number is not finite"))
+                }
+            })
+    }
+
+    fn parse_flag(&mut self) -> Result<bool, SvgPathError> {
+        self.skip_separators();
+        match self.peek() {
+            Some(b'0') => {
+                self.position += 1;
+                Ok(false)
+            }
+            Some(b'1') => {
+                self.position += 1;
+                Ok(true)
+            }
+            _ => Err(self.error("expected an arc flag")),
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.position).copied()
+    }
+
+    fn skip_digits(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_whitespace() || c == b',')
+        {
+            self.position += 1;
+        }
+    }
+
+    fn error(&self, message: &str) -> SvgPathError {
+        SvgPathError::InvalidSyntax {
+            position: self.position,
+            message: message.to_string(),
+        }
+    }
+
+    fn out_of_range(&self, message: &str) -> SvgPathError {
+        SvgPathError::OutOfRange {
+            position: self.position,
+            message: message.to_string(),
+        }
+    }
+}
+
+fn distance_from_origin(x: f64, y: f64) -> f64 {
+    (x * x + y * y).sqrt()
+}
+
+/// SVG path data parsing related errors
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum SvgPathError {
+    /// The path data string could not be parsed
+    #[error("Invalid SVG path data at position {position}: {message}")]
+    InvalidSyntax {
+        /// Byte offset in the input where parsing failed
+        position: usize,
+        /// Description of what was expected
+        message: String,
+    },
+    /// A number or coordinate can't be represented
+    #[error("SVG path data out of range at position {position}: {message}")]
+    OutOfRange {
+        /// Byte offset in the input after the rejected value
+        position: usize,
+        /// Description of the rejected value
+        message: String,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Centi, Milli, One};
+
+    use super::*;
+
+    #[test]
+    fn test_lines_and_implicit_commands() {
+        let paths = Paths::<Centi>::from_svg_path_data("M0,0 10,0 10-10.5 H0 z", 0.1).unwrap();
+        assert_eq!(
+            paths,
+            Paths::from(vec![(0.0, 0.0), (10.0, 0.0), (10.0, -10.5), (0.0, -10.5)])
+        );
+    }
+
+    #[test]
+    fn test_relative_commands_and_multiple_subpaths() {
+        let paths =
+            Paths::<Centi>::from_svg_path_data("m1 1 l2 0 0 2 -2 0z M10 10 V12 H12", 0.1).unwrap();
+        assert_eq!(
+            paths,
+            Paths::from(vec![
+                vec![(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)],
+                vec![(10.0, 10.0), (10.0, 12.0), (12.0, 12.0)],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_drawing_after_close_starts_new_subpath() {
+        let paths =
+            Paths::<Centi>::from_svg_path_data("M0 0 L1 0 L1 1 Z L-1 0 L-1 -1", 0.1).unwrap();
+        assert_eq!(
+            paths,
+            Paths::from(vec![
+                vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)],
+                vec![(0.0, 0.0), (-1.0, 0.0), (-1.0, -1.0)],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_cubic_is_flattened_within_tolerance() {
+        let tolerance = 0.05;
+        let paths = Paths::<Milli>::from_svg_path_data(
+            "M0 0 C0 5.5228 4.4772 10 10 10 S20 5.5228 20 0",
+            tolerance,
+        )
+        .unwrap();
+
+        let path = &paths[0];
+        assert!(path.len() > 4);
+        assert_eq!(path[0], Point::new(0.0, 0.0));
+        assert_eq!(path[path.len() - 1], Point::new(20.0, 0.0));
+
+        // The curve approximates two quarter circles of radius 10.
+        for point in path.iter() {
+            let distance = ((point.x() - 10.0).powi(2) + point.y().powi(2)).sqrt();
+            assert!((distance - 10.0).abs() < 0.1, "{distance}");
+        }
+    }
+
+    #[test]
+    fn test_quadratic_smooth_continuation() {
+        let paths = Paths::<Centi>::from_svg_path_data("M0 0 Q5 10 10 0 T20 0", 0.01).unwrap();
+        let path = &paths[0];
+        assert_eq!(path[path.len() - 1], Point::new(20.0, 0.0));
+        assert!(path.iter().any(|p| p.y() > 4.9));
+        assert!(path.iter().any(|p| p.y() < -4.9));
+    }
+
+    #[test]
+    fn test_arc_circle() {
+        let tolerance = 0.01;
+        let paths = Paths::<Milli>::from_svg_path_data(
+            "M 5 0 A 5 5 0 1 0 -5 0 A 5 5 0 1 0 5 0 Z",
+            tolerance,
+        )
+        .unwrap();
+
+        let path = &paths[0];
+        assert!(path.len() > 16);
+        for point in path.iter() {
+            let distance = (point.x().powi(2) + point.y().powi(2)).sqrt();
+            assert!((distance - 5.0).abs() < 0.002, "{distance}");
+        }
+
+        let area = path.signed_area().abs();
+        assert!((area - PI * 25.0).abs() < PI * 25.0 * 0.01);
+    }
+
+    #[test]
+    fn test_compact_arc_flags() {
+        let paths = Paths::<Centi>::from_svg_path_data("M0 0a1 1 0 01 2 0", 0.1).unwrap();
+        let path = &paths[0];
+        assert_eq!(path[path.len() - 1], Point::new(2.0, 0.0));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Paths::<Centi>::from_svg_path_data("0 0 L 1 1", 0.1),
+            Err(SvgPathError::InvalidSyntax {
+                position: 0,
+                message: "expected a command".to_string()
+            })
+        );
+        assert_eq!(
+            Paths::<Centi>::from_svg_path_data("M 0 0 L 1", 0.1),
+            Err(SvgPathError::InvalidSyntax {
+                position: 9,
+                message: "expected a number".to_string()
+            })
+        );
+        assert!(Paths::<Centi>::from_svg_path_data("M 0 0 X 1 1", 0.1).is_err());
+    }
+
+    #[test]
+    fn test_huge_arcs_and_out_of_range_numbers() {
+        let error = |data: &str| match Paths::<Centi>::from_svg_path_data(data, 0.1) {
+            Err(SvgPathError::OutOfRange { message, .. }) => message,
+            result => panic!("expected an out of range error, got {result:?}"),
+        };
+
+        assert_eq!(
+            error("M0 0 A1e17 1e17 0 0 1 1e17 1e17"),
+            "coordinate exceeds the range of the scaler"
+        );
+        assert_eq!(
+            error("M0 0 A1e17 1e17 0 1 1 1 0"),
+            "arc exceeds the coordinate range of the scaler"
+        );
+        assert_eq!(error("M0 0 L 1e400 0"), "number is not finite");
+        assert_eq!(error("M0 0 A1e400 1 0 0 1 1 0"), "number is not finite");
+        assert_eq!(
+            error("M9e16 0 h 9e16"),
+            "coordinate exceeds the range of the scaler"
+        );
+
+        // A radius the tolerance is negligible to is capped in segments.
+        let paths = Paths::<One>::from_svg_path_data("M0 0 A1e15 1e15 0 1 1 1 0", 0.1).unwrap();
+        assert!(paths[0].len() <= 10_001);
+
+        let paths =
+            Paths::<Milli>::from_svg_path_data("M0 0 A1 1 0 1 1 0 2 A1 1 0 1 1 0 0", 0.0).unwrap();
+        assert!(paths[0].len() <= 2 * 10_000);
+    }
+}
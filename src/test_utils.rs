@@ -0,0 +1,216 @@
+//! Helpers for golden snapshot tests that render paths to SVG and compare the
+//! result against a reviewed SVG file kept next to the tests.
+//!
+//! Numbers in the SVG documents are compared with a tolerance, so small
+//! differences from rounding don't fail the test, while any other change to
+//! the geometry or the drawing does. When the golden file doesn't exist yet
+//! it is written from the current output, and setting the
+//! `CLIPPER2_UPDATE_GOLDEN` environment variable rewrites all golden files
+//! that are checked.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use clipper2::*;
+//! use clipper2::test_utils::{assert_golden_svg, render_layers};
+//!
+//! let subject: Paths = Path::rectangle(0.0, 0.0, 6.0, 6.0).into();
+//! let clip: Paths = Path::rectangle(5.0, 5.0, 3.0, 3.0).into();
+//! let result = union(subject.clone(), clip.clone(), FillRule::default()).unwrap();
+//!
+//! let svg = render_layers([subject, clip, result.closed]);
+//! assert_golden_svg("tests/golden/union.svg", &svg, 1e-6);
+//! ```
+
+use std::path::Path as FilePath;
+
+use crate::{
+    debug::svg::{SvgLayer, SvgWriter},
+    Paths, PointScaler,
+};
+
+/// Environment variable that makes the golden checks rewrite the golden files
+/// with the current output instead of comparing against them.
+pub const UPDATE_GOLDEN_ENV: &str = "CLIPPER2_UPDATE_GOLDEN";
+
+/// Errors from comparing SVG output against a golden file.
+#[derive(Debug, thiserror::Error)]
+pub enum GoldenError {
+    /// Reading or writing the golden file failed.
+    #[error("Failed to access golden file: {0}")]
+    Io(#[from] std::io::Error),
+    /// The output differs from the golden file.
+    #[error(
+        "SVG differs from golden file at token {index}: expected `{expected}`, got `{actual}`"
+    )]
+    Mismatch {
+        /// Index of the first differing token
+        index: usize,
+        /// The token in the golden file, empty if the output is longer
+        expected: String,
+        /// The token in the output, empty if the output is shorter
+        actual: String,
+    },
+}
+
+/// Render each set of paths as its own layer with the default colors and
+/// vertex markers, in a form suitable for golden files.
+pub fn render_layers<P: PointScaler>(
+    layers: impl IntoIterator<Item = impl Into<Paths<P>>>,
+) -> String {
+    layers
+        .into_iter()
+        .fold(SvgWriter::new(), |writer, paths| {
+            writer.add_layer(SvgLayer::new(paths).show_vertices(true))
+        })
+        .to_svg_string()
+}
+
+/// Compare two SVG documents, allowing numbers to differ by at most
+/// `tolerance`. Everything but the numbers has to match exactly.
+pub fn compare_svg(actual: &str, expected: &str, tolerance: f64) -> Result<(), GoldenError> {
+    let actual_tokens = tokens(actual);
+    let expected_tokens = tokens(expected);
+
+    for index in 0..actual_tokens.len().max(expected_tokens.len()) {
+        let actual = actual_tokens.get(index).copied().unwrap_or_default();
+        let expected = expected_tokens.get(index).copied().unwrap_or_default();
+
+        let matches = match (actual.parse::<f64>(), expected.parse::<f64>()) {
+            (Ok(a), Ok(b)) => (a - b).abs() <= tolerance,
+            _ => actual == expected,
+        };
+
+        if !matches {
+            return Err(GoldenError::Mismatch {
+                index,
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Compare an SVG document against a golden file, see [`compare_svg`].
+///
+/// The golden file is written instead if it doesn't exist or if the
+/// [`UPDATE_GOLDEN_ENV`] environment variable is set.
+pub fn check_golden_svg(
+    path: impl AsRef<FilePath>,
+    svg: &str,
+    tolerance: f64,
+) -> Result<(), GoldenError> {
+    let path = path.as_ref();
+
+    if std::env::var_os(UPDATE_GOLDEN_ENV).is_some() || !path.exists() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, svg)?;
+        return Ok(());
+    }
+
+    compare_svg(svg, &std::fs::read_to_string(path)?, tolerance)
+}
+
+/// Assert that an SVG document matches a golden file, see
+/// [`check_golden_svg`].
+///
+/// # Panics
+///
+/// Panics with the first difference if the document doesn't match, or if
+/// the golden file can't be read or written.
+#[track_caller]
+pub fn assert_golden_svg(path: impl AsRef<FilePath>, svg: &str, tolerance: f64) {
+    let path = path.as_ref();
+
+    if let Err(error) = check_golden_svg(path, svg, tolerance) {
+        panic!(
+            "{error} ({}), set {UPDATE_GOLDEN_ENV}=1 to accept the new output",
+            path.display()
+        );
+    }
+}
+
+/// Split a document into numbers and the text between them, ignoring
+/// whitespace.
+fn tokens(svg: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    let mut number = false;
+
+    for (index, c) in svg.char_indices() {
+        let is_number =
+            c.is_ascii_digit() || matches!(c, '.' | '-') || (c == 'e' && start.is_some() && number);
+        let is_space = c.is_whitespace();
+
+        if let Some(from) = start {
+            if is_space || is_number != number {
+                tokens.push(&svg[from..index]);
+                start = None;
+            }
+        }
+
+        if start.is_none() && !is_space {
+            start = Some(index);
+            number = is_number;
+        }
+    }
+
+    if let Some(from) = start {
+        tokens.push(&svg[from..]);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{union, Centi, FillRule, Path};
+
+    use super::*;
+
+    #[test]
+    fn test_compare_svg_tolerance() {
+        let expected = r#"<path d="M0 0 L1.5 0 L1.5 -2 Z"/>"#;
+
+        assert!(compare_svg(r#"<path d="M0 0 L1.5001 0 L1.5 -2 Z"/>"#, expected, 1e-3).is_ok());
+        assert!(matches!(
+            compare_svg(r#"<path d="M0 0 L1.6 0 L1.5 -2 Z"/>"#, expected, 1e-3),
+            Err(GoldenError::Mismatch { expected, actual, .. }) if expected == "1.5" && actual == "1.6"
+        ));
+        assert!(compare_svg(r#"<path d="M0 0 L1.5 0 Z"/>"#, expected, 1e-3).is_err());
+        assert!(compare_svg(
+            r#"<path d="M0 0 L1.5 0 L1.5 -2 Z" fill="red"/>"#,
+            expected,
+            1e-3
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_union_golden() {
+        let subject = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 6.0, 6.0));
+        let clip = Paths::<Centi>::from(Path::rectangle(5.0, 5.0, 3.0, 3.0));
+        let result = union(subject.clone(), clip.clone(), FillRule::default()).unwrap();
+
+        assert_golden_svg(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden/union.svg"),
+            &render_layers([subject, clip, result.closed]),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn test_missing_golden_is_written() {
+        let file = std::env::temp_dir().join("clipper2_test_utils_missing_golden.svg");
+        let _ = std::fs::remove_file(&file);
+        let svg = render_layers([Path::<Centi>::rectangle(0.0, 0.0, 1.0, 1.0)]);
+
+        check_golden_svg(&file, &svg, 0.0).unwrap();
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), svg);
+        std::fs::remove_file(file).unwrap();
+    }
+}
@@ -0,0 +1,729 @@
+use crate::{Centi, ClipperError, FillRule, Path, Paths, Point, PointScaler, PolyTree};
+
+/// An indexed triangle mesh, as produced by [`Paths::triangulate`] and
+/// [`PolyTree::triangulate`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Triangulation<P: PointScaler = Centi> {
+    /// The vertices of the mesh.
+    pub vertices: Vec<Point<P>>,
+    /// Triangles as indices into `vertices`, all wound counter-clockwise
+    /// (positive area).
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl<P: PointScaler> Triangulation<P> {
+    /// Returns the total area covered by the triangles.
+    pub fn area(&self) -> f64 {
+        self.triangles
+            .iter()
+            .map(|&[a, b, c]| {
+                let (a, b, c) = (&self.vertices[a], &self.vertices[b], &self.vertices[c]);
+                ((b.x() - a.x()) * (c.y() - a.y()) - (c.x() - a.x()) * (b.y() - a.y())) / 2.0
+            })
+            .sum()
+    }
+
+    pub(crate) fn add_polygon(&mut self, outer: &Path<P>, holes: &[Path<P>]) {
+        let mut earcut = Earcut::default();
+        let outer_start = self.push_ring(&mut earcut, outer, true);
+
+        if outer_start.is_none() {
+            return;
+        }
+
+        let holes = holes
+            .iter()
+            .filter_map(|hole| self.push_ring(&mut earcut, hole, false))
+            .collect::<Vec<_>>();
+
+        earcut.triangulate(outer_start, holes, &mut self.triangles);
+    }
+
+    fn push_ring(&mut self, earcut: &mut Earcut, path: &Path<P>, outer: bool) -> Option<usize> {
+        let offset = self.vertices.len();
+        self.vertices.extend(path.iter().copied());
+
+        let ring = path
+            .iter()
+            .enumerate()
+            .map(|(index, point)| {
+                (
+                    offset + index,
+                    point.x_scaled() as f64,
+                    point.y_scaled() as f64,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        earcut.linked_list(&ring, outer)
+    }
+}
+
+impl<P: PointScaler> Paths<P> {
+    /// Triangulate the area covered by the paths into an indexed triangle
+    /// mesh.
+    ///
+    /// The paths are first unioned with the given fill rule to resolve
+    /// overlaps, self-intersections and hole nesting, so the input does not
+    /// need to be well formed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![
+    ///     vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+    ///     vec![(2.0, 2.0), (2.0, 8.0), (8.0, 8.0), (8.0, 2.0)],
+    /// ]
+    /// .into();
+    ///
+    /// let mesh = paths.triangulate(FillRule::EvenOdd).unwrap();
+    /// assert_eq!(mesh.vertices.len(), 8);
+    /// assert_eq!(mesh.triangles.len(), 8);
+    /// assert_eq!(mesh.area(), 64.0);
+    /// ```
+    pub fn triangulate(&self, fill_rule: FillRule) -> Result<Triangulation<P>, ClipperError> {
+        let tree = self
+            .to_clipper_subject()
+            .add_clip(Paths::default())
+            .union_tree(fill_rule)?
+            .tree;
+
+        Ok(tree.triangulate())
+    }
+}
+
+impl<P: PointScaler> PolyTree<P> {
+    /// Triangulate the polygons in the tree into an indexed triangle mesh,
+    /// leaving holes empty and filling islands nested inside holes.
+    pub fn triangulate(&self) -> Triangulation<P> {
+        let mut triangulation = Triangulation::default();
+        for (outer, holes) in self.outers_with_holes() {
+            triangulation.add_polygon(&outer, &holes);
+        }
+        triangulation
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    i: usize,
+    x: f64,
+    y: f64,
+    prev: usize,
+    next: usize,
+}
+
+/// Ear clipping triangulation with hole elimination, a port of the
+/// [earcut](https://github.com/mapbox/earcut) algorithm (without the z-order
+/// hashing).
+#[derive(Debug, Default)]
+struct Earcut {
+    nodes: Vec<Node>,
+}
+
+impl Earcut {
+    /// Create a circular linked list of the ring, counter-clockwise for outer
+    /// rings and clockwise for holes. Returns the start node.
+    fn linked_list(&mut self, ring: &[(usize, f64, f64)], outer: bool) -> Option<usize> {
+        let twice_area: f64 = ring
+            .iter()
+            .zip(ring.iter().cycle().skip(1))
+            .map(|(a, b)| a.1 * b.2 - b.1 * a.2)
+            .sum();
+
+        let mut last = None;
+        let mut insert = |this: &mut Self, &(i, x, y): &(usize, f64, f64)| {
+            last = Some(this.insert_node(i, x, y, last));
+        };
+
+        if outer == (twice_area > 0.0) {
+            ring.iter().for_each(|point| insert(self, point));
+        } else {
+            ring.iter().rev().for_each(|point| insert(self, point));
+        }
+
+        let mut last = last?;
+        if self.equals(last, self.nodes[last].next) {
+            let next = self.nodes[last].next;
+            self.remove_node(last);
+            last = next;
+        }
+
+        Some(last)
+    }
+
+    fn triangulate(
+        &mut self,
+        outer: Option<usize>,
+        holes: Vec<usize>,
+        triangles: &mut Vec<[usize; 3]>,
+    ) {
+        let Some(mut outer) = outer else {
+            return;
+        };
+
+        if self.nodes[outer].next == self.nodes[outer].prev {
+            return;
+        }
+
+        let mut holes = holes
+            .into_iter()
+            .map(|hole| self.leftmost(hole))
+            .collect::<Vec<_>>();
+        holes.sort_by(|&a, &b| self.nodes[a].x.total_cmp(&self.nodes[b].x));
+
+        for hole in holes {
+            outer = self.eliminate_hole(hole, outer);
+        }
+
+        self.earcut_linked(Some(outer), triangles, 0);
+    }
+
+    fn earcut_linked(&mut self, ear: Option<usize>, triangles: &mut Vec<[usize; 3]>, pass: u8) {
+        let Some(mut ear) = ear else {
+            return;
+        };
+        let mut stop = ear;
+
+        while self.nodes[ear].prev != self.nodes[ear].next {
+            let prev = self.nodes[ear].prev;
+            let next = self.nodes[ear].next;
+
+            if self.is_ear(ear) {
+                triangles.push([self.nodes[prev].i, self.nodes[ear].i, self.nodes[next].i]);
+                self.remove_node(ear);
+                ear = self.nodes[next].next;
+                stop = ear;
+                continue;
+            }
+
+            ear = next;
+
+            if ear == stop {
+                match pass {
+                    0 => {
+                        let start = self.filter_points(ear, None);
+                        self.earcut_linked(start, triangles, 1);
+                    }
+                    1 => {
+                        let start = self.filter_points(ear, None);
+                        let start =
+                            start.map(|start| self.cure_local_intersections(start, triangles));
+                        self.earcut_linked(start, triangles, 2);
+                    }
+                    _ => self.split_earcut(ear, triangles),
+                }
+                break;
+            }
+        }
+    }
+
+    fn is_ear(&self, ear: usize) -> bool {
+        let b = self.nodes[ear];
+        let a = self.nodes[b.prev];
+        let c = self.nodes[b.next];
+
+        if area(&a, &b, &c) >= 0.0 {
+            return false;
+        }
+
+        let mut p = c.next;
+        while p != b.prev {
+            let node = self.nodes[p];
+            if point_in_triangle(&a, &b, &c, node.x, node.y)
+                && area(&self.nodes[node.prev], &node, &self.nodes[node.next]) >= 0.0
+            {
+                return false;
+            }
+            p = node.next;
+        }
+
+        true
+    }
+
+    fn cure_local_intersections(&mut self, start: usize, triangles: &mut Vec<[usize; 3]>) -> usize {
+        let mut start = start;
+        let mut p = start;
+
+        loop {
+            let a = self.nodes[p].prev;
+            let p_next = self.nodes[p].next;
+            let b = self.nodes[p_next].next;
+
+            if !self.equals(a, b)
+                && self.intersects(a, p, p_next, b)
+                && self.locally_inside(a, b)
+                && self.locally_inside(b, a)
+            {
+                triangles.push([self.nodes[a].i, self.nodes[p].i, self.nodes[b].i]);
+                self.remove_node(p);
+                self.remove_node(p_next);
+                p = b;
+                start = b;
+            }
+
+            p = self.nodes[p].next;
+            if p == start {
+                break;
+            }
+        }
+
+        self.filter_points(p, None).unwrap_or(p)
+    }
+
+    fn split_earcut(&mut self, start: usize, triangles: &mut Vec<[usize; 3]>) {
+        let mut a = start;
+
+        loop {
+            let mut b = self.nodes[self.nodes[a].next].next;
+
+            while b != self.nodes[a].prev {
+                if self.nodes[a].i != self.nodes[b].i && self.is_valid_diagonal(a, b) {
+                    let c = self.split_polygon(a, b);
+                    let a = self.filter_points(a, Some(self.nodes[a].next));
+                    let c = self.filter_points(c, Some(self.nodes[c].next));
+                    self.earcut_linked(a, triangles, 0);
+                    self.earcut_linked(c, triangles, 0);
+                    return;
+                }
+                b = self.nodes[b].next;
+            }
+
+            a = self.nodes[a].next;
+            if a == start {
+                break;
+            }
+        }
+    }
+
+    fn eliminate_hole(&mut self, hole: usize, outer: usize) -> usize {
+        let Some(bridge) = self.find_hole_bridge(hole, outer) else {
+            return outer;
+        };
+
+        let bridge_reverse = self.split_polygon(bridge, hole);
+        self.filter_points(bridge_reverse, Some(self.nodes[bridge_reverse].next));
+        self.filter_points(bridge, Some(self.nodes[bridge].next))
+            .unwrap_or(outer)
+    }
+
+    /// Find a vertex on the outer ring that can be connected to the leftmost
+    /// vertex of the hole without crossing any edges.
+    fn find_hole_bridge(&self, hole: usize, outer: usize) -> Option<usize> {
+        let Node { x: hx, y: hy, .. } = self.nodes[hole];
+        let mut qx = f64::NEG_INFINITY;
+        let mut m = None;
+        let mut p = outer;
+
+        loop {
+            let node = self.nodes[p];
+            let next = self.nodes[node.next];
+
+            if hy <= node.y && hy >= next.y && next.y != node.y {
+                let x = node.x + (hy - node.y) * (next.x - node.x) / (next.y - node.y);
+                if x <= hx && x > qx {
+                    qx = x;
+                    let candidate = if node.x < next.x { p } else { node.next };
+                    if x == hx {
+                        return Some(candidate);
+                    }
+                    m = Some(candidate);
+                }
+            }
+
+            p = node.next;
+            if p == outer {
+                break;
+            }
+        }
+
+        let mut m = m?;
+        let stop = m;
+        let Node { x: mx, y: my, .. } = self.nodes[m];
+        let mut tan_min = f64::INFINITY;
+        p = m;
+
+        let (ax, cx) = if hy < my { (hx, qx) } else { (qx, hx) };
+        let a = Node {
+            x: ax,
+            y: hy,
+            ..self.nodes[m]
+        };
+        let b = Node {
+            x: mx,
+            y: my,
+            ..self.nodes[m]
+        };
+        let c = Node {
+            x: cx,
+            y: hy,
+            ..self.nodes[m]
+        };
+
+        loop {
+            let node = self.nodes[p];
+
+            if hx >= node.x
+                && node.x >= mx
+                && hx != node.x
+                && point_in_triangle(&a, &b, &c, node.x, node.y)
+            {
+                let tan = (hy - node.y).abs() / (hx - node.x);
+
+                if self.locally_inside(p, hole)
+                    && (tan < tan_min
+                        || (tan == tan_min
+                            && (node.x > self.nodes[m].x
+                                || (node.x == self.nodes[m].x
+                                    && self.sector_contains_sector(m, p)))))
+                {
+                    m = p;
+                    tan_min = tan;
+                }
+            }
+
+            p = node.next;
+            if p == stop {
+                break;
+            }
+        }
+
+        Some(m)
+    }
+
+    fn sector_contains_sector(&self, m: usize, p: usize) -> bool {
+        let (m, p) = (self.nodes[m], self.nodes[p]);
+        area(&self.nodes[m.prev], &m, &self.nodes[p.prev]) < 0.0
+            && area(&self.nodes[p.next], &m, &self.nodes[m.next]) < 0.0
+    }
+
+    fn leftmost(&self, start: usize) -> usize {
+        let mut p = start;
+        let mut leftmost = start;
+
+        loop {
+            let (node, best) = (self.nodes[p], self.nodes[leftmost]);
+            if node.x < best.x || (node.x == best.x && node.y < best.y) {
+                leftmost = p;
+            }
+
+            p = node.next;
+            if p == start {
+                break;
+            }
+        }
+
+        leftmost
+    }
+
+    fn is_valid_diagonal(&self, a: usize, b: usize) -> bool {
+        let (na, nb) = (self.nodes[a], self.nodes[b]);
+
+        self.nodes[na.next].i != nb.i
+            && self.nodes[na.prev].i != nb.i
+            && !self.intersects_polygon(a, b)
+            && ((self.locally_inside(a, b)
+                && self.locally_inside(b, a)
+                && self.middle_inside(a, b)
+                && (area(&self.nodes[na.prev], &na, &self.nodes[nb.prev]) != 0.0
+                    || area(&na, &self.nodes[nb.prev], &nb) != 0.0))
+                || (self.equals(a, b)
+                    && area(&self.nodes[na.prev], &na, &self.nodes[na.next]) > 0.0
+                    && area(&self.nodes[nb.prev], &nb, &self.nodes[nb.next]) > 0.0))
+    }
+
+    fn intersects(&self, p1: usize, q1: usize, p2: usize, q2: usize) -> bool {
+        let (p1, q1, p2, q2) = (
+            self.nodes[p1],
+            self.nodes[q1],
+            self.nodes[p2],
+            self.nodes[q2],
+        );
+        let o1 = sign(area(&p1, &q1, &p2));
+        let o2 = sign(area(&p1, &q1, &q2));
+        let o3 = sign(area(&p2, &q2, &p1));
+        let o4 = sign(area(&p2, &q2, &q1));
+
+        (o1 != o2 && o3 != o4)
+            || (o1 == 0 && on_segment(&p1, &p2, &q1))
+            || (o2 == 0 && on_segment(&p1, &q2, &q1))
+            || (o3 == 0 && on_segment(&p2, &p1, &q2))
+            || (o4 == 0 && on_segment(&p2, &q1, &q2))
+    }
+
+    fn intersects_polygon(&self, a: usize, b: usize) -> bool {
+        let (ai, bi) = (self.nodes[a].i, self.nodes[b].i);
+        let mut p = a;
+
+        loop {
+            let node = self.nodes[p];
+            let next_i = self.nodes[node.next].i;
+
+            if node.i != ai
+                && next_i != ai
+                && node.i != bi
+                && next_i != bi
+                && self.intersects(p, node.next, a, b)
+            {
+                return true;
+            }
+
+            p = node.next;
+            if p == a {
+                return false;
+            }
+        }
+    }
+
+    fn locally_inside(&self, a: usize, b: usize) -> bool {
+        let (na, nb) = (self.nodes[a], self.nodes[b]);
+        let (prev, next) = (self.nodes[na.prev], self.nodes[na.next]);
+
+        if area(&prev, &na, &next) < 0.0 {
+            area(&na, &nb, &next) >= 0.0 && area(&na, &prev, &nb) >= 0.0
+        } else {
+            area(&na, &nb, &prev) < 0.0 || area(&na, &next, &nb) < 0.0
+        }
+    }
+
+    fn middle_inside(&self, a: usize, b: usize) -> bool {
+        let (na, nb) = (self.nodes[a], self.nodes[b]);
+        let px = (na.x + nb.x) / 2.0;
+        let py = (na.y + nb.y) / 2.0;
+        let mut inside = false;
+        let mut p = a;
+
+        loop {
+            let node = self.nodes[p];
+            let next = self.nodes[node.next];
+
+            if (node.y > py) != (next.y > py)
+                && next.y != node.y
+                && px < (next.x - node.x) * (py - node.y) / (next.y - node.y) + node.x
+            {
+                inside = !inside;
+            }
+
+            p = node.next;
+            if p == a {
+                return inside;
+            }
+        }
+    }
+
+    /// Link two vertices with a bridge, splitting the ring in two if they
+    /// are on the same ring, or merging two rings into one if not. Returns
+    /// the duplicate of `b`.
+    fn split_polygon(&mut self, a: usize, b: usize) -> usize {
+        let (na, nb) = (self.nodes[a], self.nodes[b]);
+        let a2 = self.nodes.len();
+        let b2 = a2 + 1;
+        let an = na.next;
+        let bp = nb.prev;
+
+        self.nodes.push(Node {
+            prev: b2,
+            next: an,
+            ..na
+        });
+        self.nodes.push(Node {
+            prev: bp,
+            next: a2,
+            ..nb
+        });
+
+        self.nodes[a].next = b;
+        self.nodes[b].prev = a;
+        self.nodes[an].prev = a2;
+        self.nodes[bp].next = b2;
+
+        b2
+    }
+
+    /// Remove duplicate and collinear points.
+    fn filter_points(&mut self, start: usize, end: Option<usize>) -> Option<usize> {
+        let mut end = end.unwrap_or(start);
+        let mut p = start;
+
+        loop {
+            let node = self.nodes[p];
+            let mut again = false;
+
+            if self.equals(p, node.next)
+                || area(&self.nodes[node.prev], &node, &self.nodes[node.next]) == 0.0
+            {
+                self.remove_node(p);
+                p = node.prev;
+                end = p;
+                if p == self.nodes[p].next {
+                    return None;
+                }
+                again = true;
+            } else {
+                p = node.next;
+            }
+
+            if !again && p == end {
+                return Some(end);
+            }
+        }
+    }
+
+    fn insert_node(&mut self, i: usize, x: f64, y: f64, last: Option<usize>) -> usize {
+        let index = self.nodes.len();
+
+        match last {
+            None => self.nodes.push(Node {
+                i,
+                x,
+                y,
+                prev: index,
+                next: index,
+            }),
+            Some(last) => {
+                let next = self.nodes[last].next;
+                self.nodes.push(Node {
+                    i,
+                    x,
+                    y,
+                    prev: last,
+                    next,
+                });
+                self.nodes[next].prev = index;
+                self.nodes[last].next = index;
+            }
+        }
+
+        index
+    }
+
+    fn remove_node(&mut self, p: usize) {
+        let Node { prev, next, .. } = self.nodes[p];
+        self.nodes[next].prev = prev;
+        self.nodes[prev].next = next;
+    }
+
+    fn equals(&self, a: usize, b: usize) -> bool {
+        self.nodes[a].x == self.nodes[b].x && self.nodes[a].y == self.nodes[b].y
+    }
+}
+
+/// Negative for counter-clockwise turns, as in the original earcut code.
+fn area(p: &Node, q: &Node, r: &Node) -> f64 {
+    (q.y - p.y) * (r.x - q.x) - (q.x - p.x) * (r.y - q.y)
+}
+
+fn point_in_triangle(a: &Node, b: &Node, c: &Node, px: f64, py: f64) -> bool {
+    (c.x - px) * (a.y - py) >= (a.x - px) * (c.y - py)
+        && (a.x - px) * (b.y - py) >= (b.x - px) * (a.y - py)
+        && (b.x - px) * (c.y - py) >= (c.x - px) * (b.y - py)
+}
+
+fn on_segment(p: &Node, q: &Node, r: &Node) -> bool {
+    q.x <= p.x.max(r.x) && q.x >= p.x.min(r.x) && q.y <= p.y.max(r.y) && q.y >= p.y.min(r.y)
+}
+
+fn sign(value: f64) -> i8 {
+    if value > 0.0 {
+        1
+    } else if value < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{EndType, JoinType};
+
+    use super::*;
+
+    #[test]
+    fn test_triangulate_square() {
+        let paths: Paths<Centi> = Path::rectangle(0.0, 0.0, 2.0, 2.0).into();
+        let mesh = paths.triangulate(FillRule::NonZero).unwrap();
+
+        assert_eq!(mesh.vertices.len(), 4);
+        assert_eq!(mesh.triangles.len(), 2);
+        assert_eq!(mesh.area(), 4.0);
+    }
+
+    #[test]
+    fn test_triangles_are_counter_clockwise() {
+        let paths: Paths<Centi> = Path::rectangle(0.0, 0.0, 2.0, -2.0).into();
+        let mesh = paths.triangulate(FillRule::NonZero).unwrap();
+
+        for &[a, b, c] in &mesh.triangles {
+            let triangle = Path::new(vec![mesh.vertices[a], mesh.vertices[b], mesh.vertices[c]]);
+            assert!(triangle.signed_area() > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_triangulate_concave() {
+        let paths: Paths<Centi> = vec![
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 4.0),
+            (3.0, 4.0),
+            (3.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 4.0),
+            (0.0, 4.0),
+        ]
+        .into();
+        let mesh = paths.triangulate(FillRule::NonZero).unwrap();
+
+        assert_eq!(mesh.triangles.len(), 6);
+        assert_eq!(mesh.area(), paths.signed_area());
+    }
+
+    #[test]
+    fn test_triangulate_holes_and_islands() {
+        let paths = Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 10.0, 10.0),
+            Path::rectangle(1.0, 1.0, 8.0, 8.0),
+            Path::rectangle(3.0, 3.0, 4.0, 4.0),
+            Path::rectangle(20.0, 0.0, 1.0, 1.0),
+        ]);
+        let mesh = paths.triangulate(FillRule::EvenOdd).unwrap();
+
+        assert_eq!(mesh.area(), 100.0 - 64.0 + 16.0 + 1.0);
+        assert_eq!(mesh.vertices.len(), 16);
+        assert_eq!(mesh.triangles.len(), 8 + 2 + 2);
+    }
+
+    #[test]
+    fn test_triangulate_multiple_holes() {
+        let mut paths = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 20.0, 10.0));
+        for i in 0..4 {
+            paths.push(Path::rectangle(1.0 + i as f64 * 5.0, 1.0, 3.0, 8.0));
+        }
+        let mesh = paths.triangulate(FillRule::EvenOdd).unwrap();
+
+        assert!((mesh.area() - (200.0 - 4.0 * 24.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_triangulate_matches_area_of_rounded_shapes() {
+        let ring = Paths::<Centi>::new(vec![
+            Path::rectangle(0.0, 0.0, 20.0, 20.0),
+            Path::rectangle(14.0, 6.0, -8.0, 8.0),
+        ])
+        .inflate(1.5, JoinType::Round, EndType::Polygon, 0.0);
+        let mesh = ring.triangulate(FillRule::NonZero).unwrap();
+
+        assert!((mesh.area() - ring.signed_area()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_triangulate_empty() {
+        let mesh = Paths::<Centi>::default()
+            .triangulate(FillRule::NonZero)
+            .unwrap();
+        assert_eq!(mesh, Triangulation::default());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{GerberUnit, Path, Paths, Point, PointScaler};
+
+/// A physical unit of length that the coordinates of a [`PointScaler`] can be
+/// tagged with, see [`Millimeters`] and [`Inches`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    /// Coordinates are in millimeters
+    Millimeters,
+    /// Coordinates are in inches
+    Inches,
+}
+
+impl LengthUnit {
+    /// Returns the length of one unit in millimeters.
+    pub fn in_millimeters(self) -> f64 {
+        match self {
+            LengthUnit::Millimeters => 1.0,
+            LengthUnit::Inches => 25.4,
+        }
+    }
+}
+
+impl From<LengthUnit> for GerberUnit {
+    fn from(unit: LengthUnit) -> Self {
+        match unit {
+            LengthUnit::Millimeters => GerberUnit::Millimeters,
+            LengthUnit::Inches => GerberUnit::Inches,
+        }
+    }
+}
+
+/// Scale coordinates in millimeters by 1000, storing them with a resolution
+/// of one micrometer.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Hash)]
+pub struct Millimeters;
+
+impl PointScaler for Millimeters {
+    const MULTIPLIER: f64 = 1000.0;
+    const UNIT: Option<LengthUnit> = Some(LengthUnit::Millimeters);
+}
+
+/// Scale coordinates in inches by 100000, storing them with a resolution of
+/// a hundred thousandth of an inch, about a quarter of a micrometer.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Hash)]
+pub struct Inches;
+
+impl PointScaler for Inches {
+    const MULTIPLIER: f64 = 100_000.0;
+    const UNIT: Option<LengthUnit> = Some(LengthUnit::Inches);
+}
+
+/// Errors from converting coordinates between the units of two scalers.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum UnitError {
+    /// One of the scalers has no physical unit, so the conversion factor is
+    /// unknown.
+    #[error("Can not convert coordinates from {from:?} to {to:?}, both scalers need a unit")]
+    MissingUnit {
+        /// Unit of the scaler converted from
+        from: Option<LengthUnit>,
+        /// Unit of the scaler converted to
+        to: Option<LengthUnit>,
+    },
+}
+
+/// Returns the factor to multiply coordinates with when converting from the
+/// unit of `P` to the unit of `Q`.
+fn unit_factor<P: PointScaler, Q: PointScaler>() -> Result<f64, UnitError> {
+    match (P::UNIT, Q::UNIT) {
+        (Some(from), Some(to)) => Ok(from.in_millimeters() / to.in_millimeters()),
+        (from, to) => Err(UnitError::MissingUnit { from, to }),
+    }
+}
+
+fn convert_point<P: PointScaler, Q: PointScaler>(point: &Point<P>, factor: f64) -> Point<Q> {
+    Point::new(point.x() * factor, point.y() * factor)
+}
+
+fn convert_path<P: PointScaler, Q: PointScaler>(path: &Path<P>, factor: f64) -> Path<Q> {
+    path.iter()
+        .map(|point| convert_point(point, factor))
+        .collect()
+}
+
+impl<P: PointScaler> Point<P> {
+    /// Convert the point to the unit of another scaler, see
+    /// [`Paths::convert_unit`].
+    pub fn convert_unit<Q: PointScaler>(&self) -> Result<Point<Q>, UnitError> {
+        Ok(convert_point(self, unit_factor::<P, Q>()?))
+    }
+}
+
+impl<P: PointScaler> Path<P> {
+    /// Convert the path to the unit of another scaler, see
+    /// [`Paths::convert_unit`].
+    pub fn convert_unit<Q: PointScaler>(&self) -> Result<Path<Q>, UnitError> {
+        Ok(convert_path(self, unit_factor::<P, Q>()?))
+    }
+}
+
+impl<P: PointScaler> Paths<P> {
+    /// Convert the paths to the unit of another scaler, keeping their open
+    /// flags.
+    ///
+    /// Paths with scalers of different units are different types, so they
+    /// can't be mixed in an operation by mistake, and this is the checked
+    /// way to bring them to a common unit. Returns an error if either scaler
+    /// has no [`PointScaler::UNIT`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let drawing: Paths<Inches> = Path::rectangle(0.0, 0.0, 1.0, 1.0).into();
+    /// let stock: Paths<Millimeters> = Path::rectangle(0.0, 0.0, 30.0, 30.0).into();
+    ///
+    /// let drawing = drawing.convert_unit::<Millimeters>().unwrap();
+    /// let cut = stock.difference_with(&drawing, FillRule::default()).unwrap();
+    ///
+    /// assert!((cut.signed_area() - (900.0 - 25.4 * 25.4)).abs() < 1e-6);
+    /// assert!(drawing.convert_unit::<Centi>().is_err());
+    /// ```
+    ///
+    /// Mixing units without converting doesn't compile:
+    ///
+    /// ```rust,compile_fail
+    /// use clipper2::*;
+    ///
+    /// let drawing: Paths<Inches> = Path::rectangle(0.0, 0.0, 1.0, 1.0).into();
+    /// let stock: Paths<Millimeters> = Path::rectangle(0.0, 0.0, 30.0, 30.0).into();
+    ///
+    /// let cut = stock.difference_with(&drawing, FillRule::default());
+    /// ```
+    pub fn convert_unit<Q: PointScaler>(&self) -> Result<Paths<Q>, UnitError> {
+        let factor = unit_factor::<P, Q>()?;
+        let mut paths = Paths::with_capacity(self.len());
+
+        for (index, path) in self.iter().enumerate() {
+            let path = convert_path(path, factor);
+            if self.is_open(index) {
+                paths.push_open(path);
+            } else {
+                paths.push_closed(path);
+            }
+        }
+
+        Ok(paths)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Centi;
+
+    use super::*;
+
+    #[test]
+    fn test_convert_unit() {
+        let mut inches = Paths::<Inches>::from(Path::rectangle(0.0, 0.0, 2.0, 1.0));
+        inches.push_open(vec![(0.0, 0.0), (0.5, 0.0)]);
+
+        let millimeters = inches.convert_unit::<Millimeters>().unwrap();
+        assert_eq!(millimeters[0].bounds().max, Point::new(50.8, 25.4));
+        assert!(millimeters.is_open(1));
+        assert_eq!(millimeters[1][1], Point::new(12.7, 0.0));
+
+        let back = millimeters.convert_unit::<Inches>().unwrap();
+        assert_eq!(back, inches);
+
+        assert_eq!(
+            Point::<Millimeters>::new(25.4, 0.0).convert_unit::<Inches>(),
+            Ok(Point::new(1.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_convert_unit_without_unit() {
+        let paths = Paths::<Centi>::default();
+
+        assert_eq!(
+            paths.convert_unit::<Millimeters>(),
+            Err(UnitError::MissingUnit {
+                from: None,
+                to: Some(LengthUnit::Millimeters)
+            })
+        );
+        assert!(Path::<Inches>::default().convert_unit::<Centi>().is_err());
+    }
+}
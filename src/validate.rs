@@ -0,0 +1,430 @@
+use crate::{Centi, Path, Paths, Point, PointInPolygonResult, PointScaler};
+
+/// Options for [`Paths::validate_with`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValidationOptions {
+    /// The minimum number of points of a closed path, defaults to 3. Closed
+    /// paths with fewer points are reported as
+    /// [`ValidationIssue::TooFewPoints`].
+    pub min_points: usize,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self { min_points: 3 }
+    }
+}
+
+/// A problem found in a set of paths by [`Paths::validate`].
+///
+/// Paths are referred to by their index in the validated [`Paths`] and
+/// points by their index in the path.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValidationIssue<P: PointScaler = Centi> {
+    /// Two edges cross or overlap each other. The edges may belong to the
+    /// same path, in which case `path` and `other_path` are equal.
+    SelfIntersection {
+        /// Index of the path of the first edge
+        path: usize,
+        /// Index of the start point of the first edge
+        edge: usize,
+        /// Index of the path of the second edge
+        other_path: usize,
+        /// Index of the start point of the second edge
+        other_edge: usize,
+        /// The point where the edges cross, or where the overlap starts
+        point: Point<P>,
+    },
+    /// A closed path encloses no area.
+    ZeroArea {
+        /// Index of the path
+        path: usize,
+    },
+    /// A point is equal to the point before it. The first point of a closed
+    /// path follows its last point.
+    DuplicatePoint {
+        /// Index of the path
+        path: usize,
+        /// Index of the repeated point
+        index: usize,
+        /// The repeated point
+        point: Point<P>,
+    },
+    /// A hole has the same winding orientation as the path that surrounds it.
+    WrongHoleOrientation {
+        /// Index of the hole
+        path: usize,
+        /// Index of the path that surrounds the hole
+        parent: usize,
+    },
+    /// A closed path has fewer points than
+    /// [`ValidationOptions::min_points`].
+    TooFewPoints {
+        /// Index of the path
+        path: usize,
+        /// Number of points in the path
+        points: usize,
+    },
+}
+
+/// The problems found in a set of paths by [`Paths::validate`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport<P: PointScaler = Centi> {
+    /// The problems found, in the order the checks run: duplicate points,
+    /// point count and area, self-intersections and hole orientation.
+    pub issues: Vec<ValidationIssue<P>>,
+}
+
+impl<P: PointScaler> ValidationReport<P> {
+    /// Returns `true` if no problems were found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns an iterator over the problems found.
+    pub fn iter(&self) -> std::slice::Iter<'_, ValidationIssue<P>> {
+        self.issues.iter()
+    }
+}
+
+impl<P: PointScaler> Paths<P> {
+    /// Check the paths for problems that make boolean operations give
+    /// surprising results, using the default [`ValidationOptions`].
+    ///
+    /// Closed paths are checked for duplicate consecutive points, too few
+    /// points, zero area, edges crossing other edges of any path and holes
+    /// with the same winding orientation as the path surrounding them. Open
+    /// paths are only checked for duplicate consecutive points.
+    ///
+    /// Edges are compared pairwise after sorting them by their x range, so
+    /// the check is meant as a pre-flight step while debugging input rather
+    /// than something to run before every operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let bow_tie: Paths = vec![(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 3.0)].into();
+    /// let report = bow_tie.validate();
+    ///
+    /// assert!(!report.is_valid());
+    /// assert!(matches!(
+    ///     report.issues[0],
+    ///     ValidationIssue::SelfIntersection { path: 0, edge: 0, other_path: 0, other_edge: 2, .. }
+    /// ));
+    /// assert_eq!(report.issues.len(), 1);
+    /// ```
+    pub fn validate(&self) -> ValidationReport<P> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    /// Check the paths for problems, see [`Paths::validate`].
+    pub fn validate_with(&self, options: &ValidationOptions) -> ValidationReport<P> {
+        let mut issues = Vec::new();
+
+        for (index, path) in self.iter().enumerate() {
+            duplicate_points(index, path, !self.is_open(index), &mut issues);
+        }
+
+        let closed = (0..self.len())
+            .filter(|&index| !self.is_open(index))
+            .collect::<Vec<_>>();
+
+        let mut rings = Vec::new();
+        for &index in &closed {
+            let path = &self[index];
+            let points = distinct_points(path);
+            if points < options.min_points {
+                issues.push(ValidationIssue::TooFewPoints {
+                    path: index,
+                    points: path.len(),
+                });
+            } else if doubled_area(path) == 0 {
+                issues.push(ValidationIssue::ZeroArea { path: index });
+            } else {
+                rings.push(index);
+            }
+        }
+
+        self.intersections(&rings, &mut issues);
+        self.hole_orientations(&rings, &mut issues);
+
+        ValidationReport { issues }
+    }
+
+    fn intersections(&self, rings: &[usize], issues: &mut Vec<ValidationIssue<P>>) {
+        let mut edges = rings
+            .iter()
+            .flat_map(|&path| {
+                let points = &self[path];
+                (0..points.len()).filter_map(move |edge| {
+                    let a = points[edge];
+                    let b = points[(edge + 1) % points.len()];
+                    (a != b).then_some(Edge { path, edge, a, b })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        edges.sort_by_key(|edge| edge.min_x());
+
+        let mut found = Vec::new();
+        for (i, first) in edges.iter().enumerate() {
+            for second in &edges[i + 1..] {
+                if second.min_x() > first.max_x() {
+                    break;
+                }
+
+                if let Some(point) = first.intersection(second) {
+                    let (first, second) =
+                        min_max((first.path, first.edge), (second.path, second.edge));
+                    found.push((first, second, point));
+                }
+            }
+        }
+
+        found.sort_by_key(|(first, second, _)| (*first, *second));
+        issues.extend(found.into_iter().map(|(first, second, point)| {
+            ValidationIssue::SelfIntersection {
+                path: first.0,
+                edge: first.1,
+                other_path: second.0,
+                other_edge: second.1,
+                point,
+            }
+        }));
+    }
+
+    fn hole_orientations(&self, rings: &[usize], issues: &mut Vec<ValidationIssue<P>>) {
+        for &index in rings {
+            let path = &self[index];
+
+            let parent = rings
+                .iter()
+                .copied()
+                .filter(|&other| other != index && surrounds(&self[other], path))
+                .min_by_key(|&other| doubled_area(&self[other]).abs());
+
+            let Some(parent) = parent else {
+                continue;
+            };
+
+            if doubled_area(path).signum() == doubled_area(&self[parent]).signum() {
+                issues.push(ValidationIssue::WrongHoleOrientation {
+                    path: index,
+                    parent,
+                });
+            }
+        }
+    }
+}
+
+/// An edge between two distinct points of a closed path.
+struct Edge<P: PointScaler> {
+    path: usize,
+    edge: usize,
+    a: Point<P>,
+    b: Point<P>,
+}
+
+impl<P: PointScaler> Edge<P> {
+    fn min_x(&self) -> i64 {
+        self.a.x_scaled().min(self.b.x_scaled())
+    }
+
+    fn max_x(&self) -> i64 {
+        self.a.x_scaled().max(self.b.x_scaled())
+    }
+
+    /// Returns the point where the edges cross, or the start of their overlap
+    /// if they are collinear. Edges that only touch, such as consecutive edges
+    /// of a path, are not reported.
+    fn intersection(&self, other: &Self) -> Option<Point<P>> {
+        let d1 = orientation(self.a, self.b, other.a);
+        let d2 = orientation(self.a, self.b, other.b);
+        let d3 = orientation(other.a, other.b, self.a);
+        let d4 = orientation(other.a, other.b, self.b);
+
+        if d1 == 0 && d2 == 0 {
+            return self.overlap(other);
+        }
+
+        if d1 * d2 >= 0 || d3 * d4 >= 0 {
+            return None;
+        }
+
+        let t = d3 as f64 / (d3 - d4) as f64;
+        let x = self.a.x_scaled() as f64 + t * (self.b.x_scaled() - self.a.x_scaled()) as f64;
+        let y = self.a.y_scaled() as f64 + t * (self.b.y_scaled() - self.a.y_scaled()) as f64;
+
+        Some(Point::from_scaled(x.round() as i64, y.round() as i64))
+    }
+
+    /// Returns the start of the overlap of two collinear edges, if they share
+    /// more than a single point.
+    fn overlap(&self, other: &Self) -> Option<Point<P>> {
+        let key = |point: Point<P>| (point.x_scaled(), point.y_scaled());
+        let (a_min, a_max) = min_max(key(self.a), key(self.b));
+        let (b_min, b_max) = min_max(key(other.a), key(other.b));
+
+        let start = a_min.max(b_min);
+        let end = a_max.min(b_max);
+
+        (start < end).then(|| Point::from_scaled(start.0, start.1))
+    }
+}
+
+fn min_max<T: Ord>(a: T, b: T) -> (T, T) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Returns the sign of the cross product of `b - a` and `c - a`.
+fn orientation<P: PointScaler>(a: Point<P>, b: Point<P>, c: Point<P>) -> i64 {
+    let cross = (b.x_scaled() - a.x_scaled()) as i128 * (c.y_scaled() - a.y_scaled()) as i128
+        - (b.y_scaled() - a.y_scaled()) as i128 * (c.x_scaled() - a.x_scaled()) as i128;
+    cross.signum() as i64
+}
+
+/// Returns twice the signed area of the path in scaled units, computed
+/// exactly.
+pub(crate) fn doubled_area<P: PointScaler>(path: &Path<P>) -> i128 {
+    (0..path.len())
+        .map(|i| {
+            let a = path[i];
+            let b = path[(i + 1) % path.len()];
+            a.x_scaled() as i128 * b.y_scaled() as i128
+                - b.x_scaled() as i128 * a.y_scaled() as i128
+        })
+        .sum()
+}
+
+/// Returns the number of points of a closed path, not counting points equal
+/// to the point before them.
+fn distinct_points<P: PointScaler>(path: &Path<P>) -> usize {
+    (0..path.len())
+        .filter(|&i| path.len() == 1 || path[i] != path[(i + path.len() - 1) % path.len()])
+        .count()
+}
+
+fn duplicate_points<P: PointScaler>(
+    index: usize,
+    path: &Path<P>,
+    closed: bool,
+    issues: &mut Vec<ValidationIssue<P>>,
+) {
+    let start = if closed && path.len() > 1 { 0 } else { 1 };
+
+    for i in start..path.len() {
+        let previous = path[(i + path.len() - 1) % path.len()];
+        if path[i] == previous {
+            issues.push(ValidationIssue::DuplicatePoint {
+                path: index,
+                index: i,
+                point: path[i],
+            });
+        }
+    }
+}
+
+/// Returns `true` if the path is inside the other path, ignoring points on
+/// the boundary of the other path.
+fn surrounds<P: PointScaler>(outer: &Path<P>, path: &Path<P>) -> bool {
+    let mut inside = false;
+
+    for point in path {
+        match outer.is_point_inside(*point) {
+            PointInPolygonResult::IsInside => inside = true,
+            PointInPolygonResult::IsOutside => return false,
+            PointInPolygonResult::IsOn => {}
+        }
+    }
+
+    inside
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_valid_polygon_with_hole() {
+        let paths = Paths::<Centi>::from(vec![
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![(2.0, 2.0), (2.0, 8.0), (8.0, 8.0), (8.0, 2.0)],
+        ]);
+
+        assert!(paths.validate().is_valid());
+    }
+
+    #[test]
+    fn test_reports_each_issue() {
+        let mut paths = Paths::<Centi>::from(vec![
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![(2.0, 2.0), (8.0, 2.0), (8.0, 8.0), (2.0, 8.0)],
+            vec![(20.0, 0.0), (21.0, 0.0), (21.0, 0.0), (22.0, 0.0)],
+            vec![(30.0, 0.0), (31.0, 0.0)],
+        ]);
+        paths.push_open(vec![(40.0, 0.0), (40.0, 0.0)]);
+
+        let report = paths.validate();
+
+        assert_eq!(
+            report.issues,
+            vec![
+                ValidationIssue::DuplicatePoint {
+                    path: 2,
+                    index: 2,
+                    point: Point::new(21.0, 0.0),
+                },
+                ValidationIssue::DuplicatePoint {
+                    path: 4,
+                    index: 1,
+                    point: Point::new(40.0, 0.0),
+                },
+                ValidationIssue::ZeroArea { path: 2 },
+                ValidationIssue::TooFewPoints { path: 3, points: 2 },
+                ValidationIssue::WrongHoleOrientation { path: 1, parent: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_crossing_paths() {
+        let paths = Paths::<Centi>::from(vec![
+            Path::rectangle(0.0, 0.0, 2.0, 2.0),
+            Path::rectangle(1.0, 1.0, 2.0, 2.0),
+        ]);
+
+        let points = paths
+            .validate()
+            .iter()
+            .map(|issue| match issue {
+                ValidationIssue::SelfIntersection {
+                    path: 0,
+                    other_path: 1,
+                    point,
+                    ..
+                } => *point,
+                issue => panic!("unexpected issue {issue:?}"),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(points, vec![Point::new(2.0, 1.0), Point::new(1.0, 2.0)]);
+    }
+
+    #[test]
+    fn test_min_points_option() {
+        let paths = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 1.0, 1.0));
+
+        let report = paths.validate_with(&ValidationOptions { min_points: 5 });
+
+        assert_eq!(
+            report.issues,
+            vec![ValidationIssue::TooFewPoints { path: 0, points: 4 }]
+        );
+    }
+}
@@ -0,0 +1,521 @@
+use crate::{FillRule, Path, Paths, Point, PointScaler, PolyTree};
+
+const LINE_STRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTI_LINE_STRING: u32 = 5;
+const MULTI_POLYGON: u32 = 6;
+const GEOMETRY_COLLECTION: u32 = 7;
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+impl<P: PointScaler> Paths<P> {
+    /// Parse WKB or PostGIS EWKB `POLYGON`, `MULTIPOLYGON`, `LINESTRING`,
+    /// `MULTILINESTRING` or `GEOMETRYCOLLECTION` geometry into a flat set of
+    /// paths. Use [`Paths::from_ewkb`] to also get the SRID.
+    ///
+    /// Both byte orders are accepted. Polygon rings are returned as closed
+    /// paths in the order they appear with the closing point (repeating the
+    /// first point) removed, and line strings as open paths. Z and M
+    /// coordinates, in both the ISO and the EWKB encoding, are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![
+    ///     Path::rectangle(0.0, 0.0, 10.0, 10.0),
+    ///     Path::rectangle(20.0, 0.0, 5.0, 5.0),
+    /// ]
+    /// .into();
+    ///
+    /// let wkb = paths.to_wkb();
+    /// assert_eq!(Paths::from_wkb(&wkb).unwrap(), paths);
+    /// ```
+    pub fn from_wkb(wkb: &[u8]) -> Result<Self, WkbError> {
+        Self::from_ewkb(wkb).map(|(paths, _)| paths)
+    }
+
+    /// Parse WKB or PostGIS EWKB geometry like [`Paths::from_wkb`], also
+    /// returning the SRID if the geometry has one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = Path::rectangle(0.0, 0.0, 1.0, 1.0).into();
+    ///
+    /// let (read, srid) = Paths::<Centi>::from_ewkb(&paths.to_ewkb(Some(4326))).unwrap();
+    /// assert_eq!(read, paths);
+    /// assert_eq!(srid, Some(4326));
+    /// ```
+    pub fn from_ewkb(ewkb: &[u8]) -> Result<(Self, Option<u32>), WkbError> {
+        let mut reader = WkbReader::new(ewkb);
+        let mut paths = Paths::default();
+        let srid = reader.read_geometry(&mut paths)?;
+        reader.expect_end()?;
+        Ok((paths, srid))
+    }
+
+    /// Returns the set of paths as a little endian WKB `MULTIPOLYGON` where
+    /// each path is written as a polygon with a single closed ring, like
+    /// [`Paths::to_wkt`].
+    ///
+    /// [`Paths::to_wkt`]: crate::Paths::to_wkt
+    pub fn to_wkb(&self) -> Vec<u8> {
+        self.to_ewkb(None)
+    }
+
+    /// Returns the set of paths as a PostGIS EWKB `MULTIPOLYGON` like
+    /// [`Paths::to_wkb`], with the SRID if one is given. Without an SRID the
+    /// result is plain WKB.
+    pub fn to_ewkb(&self, srid: Option<u32>) -> Vec<u8> {
+        let mut writer = WkbWriter::new();
+        writer.header(MULTI_POLYGON, srid);
+        writer.count(self.len());
+
+        for path in self.iter() {
+            writer.header(POLYGON, None);
+            writer.count(1);
+            writer.ring(path);
+        }
+
+        writer.bytes
+    }
+
+    /// Returns the set of paths as a little endian WKB `MULTILINESTRING`
+    /// where each path is written as an open line string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let mut lines = Paths::<Centi>::default();
+    /// lines.push_open(vec![(0.0, 0.0), (1.0, 0.0)]);
+    ///
+    /// assert_eq!(Paths::from_wkb(&lines.to_wkb_multilinestring()).unwrap(), lines);
+    /// ```
+    pub fn to_wkb_multilinestring(&self) -> Vec<u8> {
+        let mut writer = WkbWriter::new();
+        writer.header(MULTI_LINE_STRING, None);
+        writer.count(self.len());
+
+        for path in self.iter() {
+            writer.header(LINE_STRING, None);
+            writer.line(path);
+        }
+
+        writer.bytes
+    }
+}
+
+impl<P: PointScaler> PolyTree<P> {
+    /// Parse WKB or PostGIS EWKB polygons into a tree, see
+    /// [`Paths::from_wkb`].
+    ///
+    /// The rings of all polygons are nested with [`PolyTree::from_paths`]
+    /// using [`FillRule::EvenOdd`], which puts islands inside the holes of
+    /// other polygons. Line strings are skipped.
+    pub fn from_wkb(wkb: &[u8]) -> Result<Self, WkbError> {
+        Self::from_ewkb(wkb).map(|(tree, _)| tree)
+    }
+
+    /// Parse WKB or PostGIS EWKB polygons into a tree like
+    /// [`PolyTree::from_wkb`], also returning the SRID if the geometry has
+    /// one.
+    pub fn from_ewkb(ewkb: &[u8]) -> Result<(Self, Option<u32>), WkbError> {
+        let (paths, srid) = Paths::from_ewkb(ewkb)?;
+        let (closed, _) = paths.partition_open();
+        Ok((Self::from_paths(&closed, FillRule::EvenOdd), srid))
+    }
+
+    /// Returns the tree as a little endian WKB `MULTIPOLYGON`, with every
+    /// outer polygon written as a polygon with its holes as interior rings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let outer: Paths = Path::rectangle(0.0, 0.0, 10.0, 10.0).into();
+    /// let hole: Paths = Path::rectangle(2.0, 2.0, 6.0, 6.0).into();
+    ///
+    /// let tree = outer
+    ///     .to_clipper_subject()
+    ///     .add_clip(hole)
+    ///     .difference_tree(FillRule::default())
+    ///     .unwrap()
+    ///     .tree;
+    ///
+    /// let read = PolyTree::<Centi>::from_wkb(&tree.to_wkb()).unwrap();
+    /// assert_eq!(read.total_net_area(), 64.0);
+    /// ```
+    pub fn to_wkb(&self) -> Vec<u8> {
+        self.to_ewkb(None)
+    }
+
+    /// Returns the tree as a PostGIS EWKB `MULTIPOLYGON` like
+    /// [`PolyTree::to_wkb`], with the SRID if one is given.
+    pub fn to_ewkb(&self, srid: Option<u32>) -> Vec<u8> {
+        let polygons = self.outers_with_holes().collect::<Vec<_>>();
+
+        let mut writer = WkbWriter::new();
+        writer.header(MULTI_POLYGON, srid);
+        writer.count(polygons.len());
+
+        for (outer, holes) in &polygons {
+            writer.header(POLYGON, None);
+            writer.count(1 + holes.len());
+            writer.ring(outer);
+            for hole in holes {
+                writer.ring(hole);
+            }
+        }
+
+        writer.bytes
+    }
+}
+
+/// Writes little endian WKB.
+struct WkbWriter {
+    bytes: Vec<u8>,
+}
+
+impl WkbWriter {
+    fn new() -> Self {
+        Self { bytes: Vec::new() }
+    }
+
+    fn header(&mut self, geometry_type: u32, srid: Option<u32>) {
+        self.bytes.push(1);
+        match srid {
+            Some(srid) => {
+                self.u32(geometry_type | EWKB_SRID);
+                self.u32(srid);
+            }
+            None => self.u32(geometry_type),
+        }
+    }
+
+    fn count(&mut self, count: usize) {
+        self.u32(count as u32);
+    }
+
+    fn line<P: PointScaler>(&mut self, path: &Path<P>) {
+        self.count(path.len());
+        path.iter().for_each(|point| self.point(point));
+    }
+
+    fn ring<P: PointScaler>(&mut self, path: &Path<P>) {
+        let closing = path
+            .iter()
+            .next()
+            .filter(|&first| path.iter().last() != Some(first));
+
+        self.count(path.len() + usize::from(closing.is_some()));
+        path.iter()
+            .chain(closing)
+            .for_each(|point| self.point(point));
+    }
+
+    fn point<P: PointScaler>(&mut self, point: &Point<P>) {
+        self.bytes.extend(point.x().to_le_bytes());
+        self.bytes.extend(point.y().to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes.extend(value.to_le_bytes());
+    }
+}
+
+struct WkbReader<'a> {
+    input: &'a [u8],
+    position: usize,
+    little_endian: bool,
+}
+
+impl<'a> WkbReader<'a> {
+    fn new(input: &'a [u8]) -> Self {
+        Self {
+            input,
+            position: 0,
+            little_endian: true,
+        }
+    }
+
+    /// Read a geometry with its header, adding its paths and returning the
+    /// SRID from the header.
+    fn read_geometry<P: PointScaler>(
+        &mut self,
+        paths: &mut Paths<P>,
+    ) -> Result<Option<u32>, WkbError> {
+        let byte_order = self.take::<1>()?[0];
+        self.little_endian = match byte_order {
+            0 => false,
+            1 => true,
+            _ => return Err(WkbError::InvalidByteOrder(byte_order)),
+        };
+
+        let code = self.u32()?;
+        let srid = if code & EWKB_SRID != 0 {
+            Some(self.u32()?)
+        } else {
+            None
+        };
+
+        // ISO WKB adds 1000 for Z, 2000 for M and 3000 for both, EWKB sets
+        // flags in the high bits.
+        let iso = code & 0x0fff_ffff;
+        let (geometry_type, iso_dimensions) = (iso % 1000, iso / 1000);
+        let dimensions = 2
+            + usize::from(code & EWKB_Z != 0 || matches!(iso_dimensions, 1 | 3))
+            + usize::from(code & EWKB_M != 0 || matches!(iso_dimensions, 2 | 3));
+
+        match geometry_type {
+            LINE_STRING => {
+                let line = self.points(dimensions)?;
+                paths.push_open(line);
+            }
+            POLYGON => {
+                for _ in 0..self.count(4)? {
+                    let mut ring = self.points(dimensions)?;
+                    if ring.len() > 1 && ring.first() == ring.last() {
+                        ring.pop();
+                    }
+                    paths.push_closed(ring);
+                }
+            }
+            MULTI_LINE_STRING | MULTI_POLYGON | GEOMETRY_COLLECTION => {
+                for _ in 0..self.count(5)? {
+                    self.read_geometry(paths)?;
+                }
+            }
+            _ => return Err(WkbError::UnsupportedGeometry(geometry_type)),
+        }
+
+        Ok(srid)
+    }
+
+    /// Read a point count followed by the points, keeping x and y.
+    fn points<P: PointScaler>(&mut self, dimensions: usize) -> Result<Vec<Point<P>>, WkbError> {
+        let count = self.count(8 * dimensions)?;
+        let mut points = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let x = self.f64()?;
+            let y = self.f64()?;
+            for _ in 2..dimensions {
+                self.f64()?;
+            }
+            points.push(Point::new(x, y));
+        }
+
+        Ok(points)
+    }
+
+    /// Read a count of items that are each at least `item_size` bytes long,
+    /// failing early if there aren't enough bytes left.
+    fn count(&mut self, item_size: usize) -> Result<usize, WkbError> {
+        let count = self.u32()? as usize;
+        if count.saturating_mul(item_size) > self.input.len() - self.position {
+            return Err(WkbError::UnexpectedEnd {
+                position: self.input.len(),
+            });
+        }
+        Ok(count)
+    }
+
+    fn u32(&mut self) -> Result<u32, WkbError> {
+        let bytes = self.take()?;
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self) -> Result<f64, WkbError> {
+        let bytes = self.take()?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], WkbError> {
+        let bytes =
+            self.input
+                .get(self.position..self.position + N)
+                .ok_or(WkbError::UnexpectedEnd {
+                    position: self.input.len(),
+                })?;
+        self.position += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn expect_end(&self) -> Result<(), WkbError> {
+        if self.position == self.input.len() {
+            Ok(())
+        } else {
+            Err(WkbError::TrailingBytes {
+                position: self.position,
+            })
+        }
+    }
+}
+
+/// WKB parsing related errors
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum WkbError {
+    /// The geometry type code is not one of the supported types
+    #[error("Unsupported WKB geometry type {0}")]
+    UnsupportedGeometry(u32),
+    /// The byte order marker is neither 0 nor 1
+    #[error("Invalid WKB byte order {0}")]
+    InvalidByteOrder(u8),
+    /// The input ended in the middle of a geometry
+    #[error("Unexpected end of WKB at byte {position}")]
+    UnexpectedEnd {
+        /// Length of the input
+        position: usize,
+    },
+    /// There are bytes left after the geometry
+    #[error("Unexpected trailing WKB bytes at byte {position}")]
+    TrailingBytes {
+        /// Byte offset of the first trailing byte
+        position: usize,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Centi;
+
+    use super::*;
+
+    #[test]
+    fn test_polygon_encoding() {
+        let paths = Paths::<Centi>::from(vec![vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]]);
+        let wkb = paths.to_wkb();
+
+        // Byte order, type and count of the multi polygon, then the polygon
+        // header, ring count and four points including the closing point.
+        assert_eq!(wkb.len(), 9 + 5 + 4 + 4 + 4 * 16);
+        assert_eq!(&wkb[..9], &[1, 6, 0, 0, 0, 1, 0, 0, 0]);
+        assert_eq!(&wkb[9..14], &[1, 3, 0, 0, 0]);
+        assert_eq!(&wkb[wkb.len() - 16..], &wkb[22..38]);
+    }
+
+    #[test]
+    fn test_big_endian_polygon_with_hole() {
+        let mut wkb = vec![0];
+        wkb.extend(POLYGON.to_be_bytes());
+        wkb.extend(2u32.to_be_bytes());
+        for ring in [
+            [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 0.0)],
+            [(2.0, 2.0), (2.0, 8.0), (8.0, 2.0), (2.0, 2.0)],
+        ] {
+            wkb.extend(4u32.to_be_bytes());
+            for (x, y) in ring {
+                wkb.extend(f64::to_be_bytes(x));
+                wkb.extend(f64::to_be_bytes(y));
+            }
+        }
+
+        let paths = Paths::<Centi>::from_wkb(&wkb).unwrap();
+
+        assert_eq!(
+            paths,
+            Paths::from(vec![
+                vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)],
+                vec![(2.0, 2.0), (2.0, 8.0), (8.0, 2.0)],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_dimensions_and_collections() {
+        // ISO LINESTRING Z inside an EWKB collection with an SRID and a
+        // LINESTRING M with the EWKB flag.
+        let mut wkb = vec![1];
+        wkb.extend((GEOMETRY_COLLECTION | EWKB_SRID).to_le_bytes());
+        wkb.extend(3857u32.to_le_bytes());
+        wkb.extend(2u32.to_le_bytes());
+
+        wkb.push(1);
+        wkb.extend((LINE_STRING + 1000).to_le_bytes());
+        wkb.extend(2u32.to_le_bytes());
+        for value in [0.0, 0.0, 9.0, 1.0, 1.0, 9.0] {
+            wkb.extend(f64::to_le_bytes(value));
+        }
+
+        wkb.push(1);
+        wkb.extend((LINE_STRING | EWKB_M).to_le_bytes());
+        wkb.extend(1u32.to_le_bytes());
+        for value in [5.0, 5.0, 7.0] {
+            wkb.extend(f64::to_le_bytes(value));
+        }
+
+        let (paths, srid) = Paths::<Centi>::from_ewkb(&wkb).unwrap();
+
+        assert_eq!(srid, Some(3857));
+        assert_eq!(paths.len(), 2);
+        assert!(paths.is_open(0) && paths.is_open(1));
+        assert_eq!(paths[0], Path::from(vec![(0.0, 0.0), (1.0, 1.0)]));
+        assert_eq!(paths[1], Path::from(vec![(5.0, 5.0)]));
+    }
+
+    #[test]
+    fn test_polytree_round_trip() {
+        let tree = PolyTree::<Centi>::from_paths(
+            &Paths::new(vec![
+                Path::rectangle(0.0, 0.0, 10.0, 10.0),
+                Path::rectangle(2.0, 2.0, 6.0, 6.0),
+                Path::rectangle(4.0, 4.0, 2.0, 2.0),
+            ]),
+            FillRule::EvenOdd,
+        );
+
+        let ewkb = tree.to_ewkb(Some(4326));
+        let (read, srid) = PolyTree::<Centi>::from_ewkb(&ewkb).unwrap();
+
+        assert_eq!(srid, Some(4326));
+        assert_eq!(read, tree);
+        assert_eq!(PolyTree::<Centi>::from_wkb(&tree.to_wkb()).unwrap(), tree);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let wkb = Paths::<Centi>::from(Path::rectangle(0.0, 0.0, 1.0, 1.0)).to_wkb();
+
+        assert_eq!(
+            Paths::<Centi>::from_wkb(&wkb[..wkb.len() - 1]),
+            Err(WkbError::UnexpectedEnd {
+                position: wkb.len() - 1
+            })
+        );
+        assert_eq!(
+            Paths::<Centi>::from_wkb(&[wkb.as_slice(), &[0]].concat()),
+            Err(WkbError::TrailingBytes {
+                position: wkb.len()
+            })
+        );
+        assert_eq!(
+            Paths::<Centi>::from_wkb(&[2, 3, 0, 0, 0]),
+            Err(WkbError::InvalidByteOrder(2))
+        );
+        assert_eq!(
+            Paths::<Centi>::from_wkb(&[1, 1, 0, 0, 0]),
+            Err(WkbError::UnsupportedGeometry(1))
+        );
+        assert_eq!(
+            Paths::<Centi>::from_wkb(&[1, 3, 0, 0, 0, 255, 255, 255, 255]),
+            Err(WkbError::UnexpectedEnd { position: 9 })
+        );
+    }
+}
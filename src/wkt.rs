@@ -0,0 +1,426 @@
+use crate::{Path, Paths, Point, PointScaler};
+
+impl<P: PointScaler> Path<P> {
+    /// Returns the path as a WKT `POLYGON`, closing the ring by repeating the
+    /// first point at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let path: Path = vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)].into();
+    /// assert_eq!(path.to_wkt(), "POLYGON ((0 0, 1 0, 1 1, 0 0))");
+    /// ```
+    pub fn to_wkt(&self) -> String {
+        if self.is_empty() {
+            return "POLYGON EMPTY".to_string();
+        }
+
+        format!("POLYGON ({})", wkt_ring(self))
+    }
+
+    /// Returns the path as an open WKT `LINESTRING`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let path: Path = vec![(0.0, 0.0), (1.5, 0.0), (1.5, 2.0)].into();
+    /// assert_eq!(path.to_wkt_linestring(), "LINESTRING (0 0, 1.5 0, 1.5 2)");
+    /// ```
+    pub fn to_wkt_linestring(&self) -> String {
+        if self.is_empty() {
+            return "LINESTRING EMPTY".to_string();
+        }
+
+        format!("LINESTRING {}", wkt_line(self))
+    }
+}
+
+impl<P: PointScaler> Paths<P> {
+    /// Parse a WKT `POLYGON`, `MULTIPOLYGON`, `LINESTRING` or
+    /// `MULTILINESTRING` into a flat set of paths.
+    ///
+    /// Polygon rings are returned in the order they appear with the closing
+    /// point (repeating the first point) removed. Line strings are returned
+    /// as is and marked as open, see [`Paths::is_open`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = Paths::from_wkt(
+    ///     "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 2 8, 8 8, 8 2, 2 2))",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(paths.len(), 2);
+    /// assert_eq!(paths[0].len(), 4);
+    /// ```
+    pub fn from_wkt(wkt: &str) -> Result<Self, WktError> {
+        let mut parser = WktParser::new(wkt);
+        let paths = parser.parse_geometry()?;
+        parser.expect_end()?;
+        Ok(paths)
+    }
+
+    /// Returns the set of paths as a WKT `MULTIPOLYGON` where each path is
+    /// written as a polygon with a single closed ring.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)],
+    ///     vec![(5.0, 5.0), (6.0, 5.0), (6.0, 6.0)],
+    /// ]
+    /// .into();
+    /// assert_eq!(
+    ///     paths.to_wkt(),
+    ///     "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))"
+    /// );
+    /// ```
+    pub fn to_wkt(&self) -> String {
+        if self.is_empty() {
+            return "MULTIPOLYGON EMPTY".to_string();
+        }
+
+        let polygons = self
+            .iter()
+            .map(|path| format!("({})", wkt_ring(path)))
+            .collect::<Vec<_>>();
+
+        format!("MULTIPOLYGON ({})", polygons.join(", "))
+    }
+
+    /// Returns the set of paths as a WKT `MULTILINESTRING` where each path is
+    /// written as an open line string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clipper2::*;
+    ///
+    /// let paths: Paths = vec![vec![(0.0, 0.0), (1.0, 0.0)], vec![(2.0, 2.0), (3.0, 3.0)]].into();
+    /// assert_eq!(paths.to_wkt_multilinestring(), "MULTILINESTRING ((0 0, 1 0), (2 2, 3 3))");
+    /// ```
+    pub fn to_wkt_multilinestring(&self) -> String {
+        if self.is_empty() {
+            return "MULTILINESTRING EMPTY".to_string();
+        }
+
+        let lines = self.iter().map(wkt_line).collect::<Vec<_>>();
+
+        format!("MULTILINESTRING ({})", lines.join(", "))
+    }
+}
+
+fn wkt_coordinate<P: PointScaler>(point: &Point<P>) -> String {
+    format!("{} {}", point.x(), point.y())
+}
+
+fn wkt_line<P: PointScaler>(path: &Path<P>) -> String {
+    let coordinates = path.iter().map(wkt_coordinate).collect::<Vec<_>>();
+    format!("({})", coordinates.join(", "))
+}
+
+fn wkt_ring<P: PointScaler>(path: &Path<P>) -> String {
+    let mut coordinates = path.iter().map(wkt_coordinate).collect::<Vec<_>>();
+
+    if let (Some(first), Some(last)) = (path.iter().next(), path.iter().last()) {
+        if first != last {
+            coordinates.push(wkt_coordinate(first));
+        }
+    }
+
+    format!("({})", coordinates.join(", "))
+}
+
+/// A nested, parenthesized WKT coordinate list.
+enum WktNode {
+    List(Vec<WktNode>),
+    Coordinate(f64, f64),
+}
+
+struct WktParser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> WktParser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn parse_geometry<P: PointScaler>(&mut self) -> Result<Paths<P>, WktError> {
+        let geometry_type = self.parse_word()?.to_ascii_uppercase();
+
+        let depth = match geometry_type.as_str() {
+            "LINESTRING" => 1,
+            "POLYGON" | "MULTILINESTRING" => 2,
+            "MULTIPOLYGON" => 3,
+            _ => return Err(WktError::UnsupportedGeometry(geometry_type)),
+        };
+        let closed = geometry_type.ends_with("POLYGON");
+
+        self.skip_whitespace();
+        if self
+            .rest()
+            .get(..5)
+            .is_some_and(|word| word.eq_ignore_ascii_case("EMPTY"))
+        {
+            self.position += 5;
+            return Ok(Paths::default());
+        }
+
+        let node = self.parse_node()?;
+        let mut paths = Vec::new();
+        Self::collect_paths(node, depth, closed, &mut paths).map_err(|message| {
+            WktError::InvalidSyntax {
+                position: self.position,
+                message,
+            }
+        })?;
+
+        let mut paths = Paths::new(paths);
+        if !closed {
+            paths.set_all_open();
+        }
+        Ok(paths)
+    }
+
+    fn collect_paths<P: PointScaler>(
+        node: WktNode,
+        depth: usize,
+        closed: bool,
+        paths: &mut Vec<Path<P>>,
+    ) -> Result<(), String> {
+        let WktNode::List(children) = node else {
+            return Err("expected a coordinate list".to_string());
+        };
+
+        if depth > 1 {
+            for child in children {
+                Self::collect_paths(child, depth - 1, closed, paths)?;
+            }
+            return Ok(());
+        }
+
+        let mut points = children
+            .into_iter()
+            .map(|child| match child {
+                WktNode::Coordinate(x, y) => Ok(Point::new(x, y)),
+                WktNode::List(_) => Err("unexpected nested coordinate list".to_string()),
+            })
+            .collect::<Result<Vec<Point<P>>, _>>()?;
+
+        if closed && points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+
+        paths.push(Path::new(points));
+        Ok(())
+    }
+
+    fn parse_node(&mut self) -> Result<WktNode, WktError> {
+        self.skip_whitespace();
+
+        if !self.consume('(') {
+            let x = self.parse_number()?;
+            let y = self.parse_number()?;
+            return Ok(WktNode::Coordinate(x, y));
+        }
+
+        let mut children = vec![self.parse_node()?];
+
+        loop {
+            self.skip_whitespace();
+            if self.consume(',') {
+                children.push(self.parse_node()?);
+            } else if self.consume(')') {
+                return Ok(WktNode::List(children));
+            } else {
+                return Err(self.error("expected ',' or ')'"));
+            }
+        }
+    }
+
+    fn parse_word(&mut self) -> Result<&'a str, WktError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let length = self
+            .rest()
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(self.rest().len());
+
+        if length == 0 {
+            return Err(self.error("expected a geometry type"));
+        }
+
+        self.position += length;
+        Ok(&self.input[start..self.position])
+    }
+
+    fn parse_number(&mut self) -> Result<f64, WktError> {
+        self.skip_whitespace();
+        let length = self
+            .rest()
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')))
+            .unwrap_or(self.rest().len());
+
+        let number = self.rest()[..length]
+            .parse()
+            .map_err(|_| self.error("expected a number"))?;
+
+        self.position += length;
+        Ok(number)
+    }
+
+    fn expect_end(&mut self) -> Result<(), WktError> {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("unexpected trailing input"))
+        }
+    }
+
+    fn consume(&mut self, c: char) -> bool {
+        if self.rest().starts_with(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn error(&self, message: &str) -> WktError {
+        WktError::InvalidSyntax {
+            position: self.position,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// WKT parsing related errors
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum WktError {
+    /// The geometry type is not one of the supported types
+    #[error("Unsupported WKT geometry type {0}")]
+    UnsupportedGeometry(String),
+    /// The WKT string could not be parsed
+    #[error("Invalid WKT at position {position}: {message}")]
+    InvalidSyntax {
+        /// Byte offset in the input where parsing failed
+        position: usize,
+        /// Description of what was expected
+        message: String,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Centi;
+
+    use super::*;
+
+    #[test]
+    fn test_polygon_with_hole() {
+        let paths = Paths::<Centi>::from_wkt(
+            "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 2 8, 8 8, 8 2, 2 2))",
+        )
+        .unwrap();
+
+        assert_eq!(
+            paths,
+            Paths::from(vec![
+                vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+                vec![(2.0, 2.0), (2.0, 8.0), (8.0, 8.0), (8.0, 2.0)],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_multipolygon() {
+        let paths = Paths::<Centi>::from_wkt(
+            "multipolygon(((0 0,1 0,1 1,0 0)),((5.5 5,6 5,6 6,5.5 5),(1e0 2, 3 -4.25, 1 2)))",
+        )
+        .unwrap();
+
+        assert_eq!(
+            paths,
+            Paths::from(vec![
+                vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)],
+                vec![(5.5, 5.0), (6.0, 5.0), (6.0, 6.0)],
+                vec![(1.0, 2.0), (3.0, -4.25)],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_linestring_keeps_end_point() {
+        let paths = Paths::<Centi>::from_wkt("LINESTRING (0 0, 1 1, 0 0)").unwrap();
+        let mut expected = Paths::default();
+        expected.push_open(vec![(0.0, 0.0), (1.0, 1.0), (0.0, 0.0)]);
+        assert_eq!(paths, expected);
+        assert!(paths.is_open(0));
+    }
+
+    #[test]
+    fn test_empty() {
+        let paths = Paths::<Centi>::from_wkt("MULTIPOLYGON EMPTY").unwrap();
+        assert!(paths.is_empty());
+        assert_eq!(paths.to_wkt(), "MULTIPOLYGON EMPTY");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let paths = Paths::<Centi>::from(vec![
+            vec![(0.0, 0.0), (1.25, 0.0), (1.25, 1.0)],
+            vec![(-5.0, 5.0), (6.0, 5.0), (6.0, 6.0)],
+        ]);
+        assert_eq!(Paths::from_wkt(&paths.to_wkt()).unwrap(), paths);
+
+        let lines = paths.to_wkt_multilinestring();
+        assert_eq!(
+            lines,
+            "MULTILINESTRING ((0 0, 1.25 0, 1.25 1), (-5 5, 6 5, 6 6))"
+        );
+        let parsed = Paths::from_wkt(&lines).unwrap();
+        assert_eq!(parsed.partition_open(), (Paths::default(), paths));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(Paths::<Centi>::from_wkt("POLYGON ÅÄÖ").is_err());
+        assert_eq!(
+            Paths::<Centi>::from_wkt("POINT (1 2)"),
+            Err(WktError::UnsupportedGeometry("POINT".to_string()))
+        );
+        assert!(matches!(
+            Paths::<Centi>::from_wkt("POLYGON ((0 0, 1 0, 1 1)"),
+            Err(WktError::InvalidSyntax { .. })
+        ));
+        assert!(matches!(
+            Paths::<Centi>::from_wkt("POLYGON ((0 0, 1 x, 1 1))"),
+            Err(WktError::InvalidSyntax { .. })
+        ));
+        assert!(matches!(
+            Paths::<Centi>::from_wkt("LINESTRING ((0 0, 1 1))"),
+            Err(WktError::InvalidSyntax { .. })
+        ));
+    }
+}